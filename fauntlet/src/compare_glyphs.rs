@@ -91,3 +91,67 @@ pub fn compare_glyphs(
     }
     ok
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Font;
+    use skrifa::raw::types::PenCommand;
+    use std::path::PathBuf;
+
+    fn test_font_path(file_name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../font-test-data/test_data/ttf")
+            .join(file_name)
+    }
+
+    #[test]
+    fn cff_seac_and_legacy_operators_match_freetype() {
+        let path = test_font_path("cff_seac_legacy_ops.ttf");
+        let mut font = Font::new(&path).unwrap();
+        for ppem in [0, 8, 16, 50, 72, 113, 144] {
+            let options = InstanceOptions::new(0, ppem, &[], None);
+            let instances = font.instantiate(&options).unwrap();
+            assert!(compare_glyphs(&path, &options, instances, false));
+        }
+        // Make sure we're not comparing empty outlines
+        let options = InstanceOptions::new(0, 0, &[], None);
+        let (mut ft_instance, _) = font.instantiate(&options).unwrap();
+        let mut outline = RecordingPen::default();
+        let line = |x, y| PenCommand::LineTo { x, y };
+        // Aacute: the acute accent, offset by (50, 110), followed by A
+        ft_instance.outline(GlyphId::new(3), &mut outline).unwrap();
+        assert_eq!(
+            outline.0,
+            [
+                PenCommand::MoveTo { x: 300.0, y: 860.0 },
+                line(400.0, 860.0),
+                line(460.0, 960.0),
+                line(360.0, 960.0),
+                line(300.0, 860.0),
+                PenCommand::MoveTo { x: 0.0, y: 0.0 },
+                line(600.0, 0.0),
+                line(300.0, 700.0),
+                line(0.0, 0.0),
+            ]
+        );
+        // B: lines computed with legacy operators
+        outline.clear();
+        ft_instance.outline(GlyphId::new(4), &mut outline).unwrap();
+        assert_eq!(
+            outline.0,
+            [
+                PenCommand::MoveTo { x: 90.0, y: 93.0 },
+                line(115.0, 493.0),
+                line(205.0, 453.0),
+                line(225.0, 463.0),
+                line(295.0, 543.0),
+                line(305.0, 553.0),
+                line(325.0, 583.0),
+                line(365.0, 683.0),
+                line(165.0, 733.0),
+                line(90.0, 93.0),
+            ]
+        );
+    }
+}
@@ -63,13 +63,12 @@ impl Fields {
                 ));
             }
 
-            if (matches!(fld.typ, FieldType::VarLenArray(_))
-                || fld.attrs.count.as_deref().map(Count::all).unwrap_or(false))
+            if fld.attrs.count.as_deref().map(Count::all).unwrap_or(false)
                 && i != self.fields.len() - 1
             {
                 return Err(logged_syn_error(
                     fld.name.span(),
-                    "#[count(..)] or VarLenArray fields can only be last field in table.",
+                    "#[count(..)] fields can only be last field in table.",
                 ));
             }
             fld.sanity_check(phase)?;
@@ -340,6 +339,9 @@ fn traversal_arm_for_field(
     let name_str = &fld.name.to_string();
     let name = &fld.name;
     let maybe_unwrap = fld.attrs.conditional.is_some().then(|| quote!(.unwrap()));
+    // the resolved target of a conditional nullable offset is `None` both when
+    // the field is absent and when the offset is null, so we can't unwrap it
+    let maybe_unwrap_target = maybe_unwrap.as_ref().filter(|_| !fld.is_nullable());
     if let Some(traverse_with) = &fld.attrs.traverse_with {
         let traverse_fn = &traverse_with.attr;
         if traverse_fn == "skip" {
@@ -361,7 +363,7 @@ fn traversal_arm_for_field(
                     #name_str,
                     traversal::FieldType::offset_to_array_of_records(
                         self.#name()#maybe_unwrap,
-                        self.#getter(#pass_data)#maybe_unwrap,
+                        self.#getter(#pass_data)#maybe_unwrap_target,
                         stringify!(#typ),
                         #offset_data,
                     )
@@ -373,7 +375,7 @@ fn traversal_arm_for_field(
                 OffsetTarget::Array(_) => quote!(offset_to_array_of_scalars),
             };
             let getter = fld.offset_getter_name();
-            quote!(Field::new(#name_str, FieldType::#constructor_name(self.#name()#maybe_unwrap, self.#getter(#pass_data)#maybe_unwrap)))
+            quote!(Field::new(#name_str, FieldType::#constructor_name(self.#name()#maybe_unwrap, self.#getter(#pass_data)#maybe_unwrap_target)))
         }
         FieldType::Scalar { .. } => quote!(Field::new(#name_str, self.#name()#maybe_unwrap)),
 
@@ -463,6 +465,15 @@ fn traversal_arm_for_field(
                 .unwrap_or_else(|| fld.offset_getter_data_src());
             quote!(Field::new(#name_str, self.#name() #maybe_unwrap .traversal_type(#offset_data)))
         }
+        FieldType::Struct { .. } if !in_record && fld.is_fixed_size_struct() => {
+            let offset_data = pass_data
+                .cloned()
+                .unwrap_or_else(|| fld.offset_getter_data_src());
+            quote!(Field::new(
+                    #name_str,
+                    traversal::FieldType::Record(self.#name() #maybe_unwrap .traverse(#offset_data))
+            ))
+        }
         FieldType::Struct { .. } => {
             quote!(compile_error!(concat!("another weird type: ", #name_str)))
         }
@@ -554,6 +565,12 @@ impl Field {
         matches!(&self.typ, FieldType::VarLenArray { .. })
     }
 
+    /// true if this is a record that is stored inline and does not require
+    /// any arguments to read (and so has a fixed size.)
+    fn is_fixed_size_struct(&self) -> bool {
+        matches!(self.typ, FieldType::Struct { .. }) && !self.has_computed_len()
+    }
+
     pub(crate) fn has_computed_len(&self) -> bool {
         self.attrs.count.is_some() || self.attrs.read_with_args.is_some()
     }
@@ -654,6 +671,9 @@ impl Field {
             FieldType::Offset { typ, .. } | FieldType::Scalar { typ } => {
                 quote!(#typ::RAW_BYTE_LEN)
             }
+            FieldType::Struct { typ } if self.is_fixed_size_struct() => {
+                quote!(#typ::RAW_BYTE_LEN)
+            }
             FieldType::Struct { .. }
             | FieldType::Array { .. }
             | FieldType::ComputedArray { .. }
@@ -757,7 +777,11 @@ impl Field {
         if !self.has_getter() {
             return None;
         }
-        let return_type = self.raw_getter_return_type();
+        let mut return_type = self.raw_getter_return_type();
+        // fixed size records are borrowed from the underlying data
+        if self.is_fixed_size_struct() {
+            return_type = quote!(&'a #return_type);
+        }
         if self.is_conditional() {
             Some(quote!(Option<#return_type>))
         } else {
@@ -776,9 +800,11 @@ impl Field {
             let get_args = args.to_tokens_for_table_getter();
             quote!( self.data.read_with_args(range, &#get_args).unwrap() )
         } else if is_var_array {
-            quote!(VarLenArray::read(self.data.split_off(range.start).unwrap()).unwrap())
+            quote!(VarLenArray::read(self.data.slice(range).unwrap()).unwrap())
         } else if is_array {
             quote!(self.data.read_array(range).unwrap())
+        } else if self.is_fixed_size_struct() {
+            quote!(self.data.read_ref_at(range.start).unwrap())
         } else {
             quote!(self.data.read_at(range.start).unwrap())
         };
@@ -960,6 +986,18 @@ impl Field {
             } else {
                 quote!( self. #raw_name () .#resolve )
             };
+            let context_getter_name = quote::format_ident!("{getter_name}_with_context");
+            let mut context_return_type = target
+                .getter_return_type_with_error(target_is_generic, quote!(ReadErrorWithContext));
+            if self.is_nullable() || self.attrs.conditional.is_some() {
+                context_return_type = quote!(Option<#context_return_type>);
+            }
+            let pass_data_if_needed = record.is_some().then(|| quote!(data));
+            let field_name = raw_name.to_string();
+            let context_docs = format!(
+                " Like [`{getter_name}`][Self::{getter_name}], but records the name of \
+                 this field and the position of the target on failure."
+            );
             Some(quote! {
                 #docs
                 pub fn #getter_name #decl_lifetime_if_needed (&self #input_data_if_needed) -> #return_type #where_read_clause {
@@ -967,6 +1005,12 @@ impl Field {
                     #args_if_needed
                     #getter_impl
                 }
+
+                #[doc = #context_docs]
+                #[allow(clippy::result_large_err)]
+                pub fn #context_getter_name #decl_lifetime_if_needed (&self #input_data_if_needed) -> #context_return_type #where_read_clause {
+                    self.#getter_name(#pass_data_if_needed).with_offset_context(self.#raw_name(), #field_name)
+                }
             })
         }
     }
@@ -1001,6 +1045,9 @@ impl Field {
         if let Some(getter) = &self.attrs.offset_getter {
             return Some(getter.attr.clone());
         }
+        if let Some(name) = &self.attrs.offset_getter_name {
+            return Some(name.attr.clone());
+        }
 
         let name_string = self.name.to_string();
         let name_string = remove_offset_from_field_name(&name_string);
@@ -1028,6 +1075,9 @@ impl Field {
             && !self.is_conditional()
         {
             let typ = self.typ.cooked_type_tokens();
+            if self.is_fixed_size_struct() {
+                return quote!( cursor.advance_by(#typ::RAW_BYTE_LEN); );
+            }
             return quote!( cursor.advance::<#typ>(); );
         }
 
@@ -1114,6 +1164,18 @@ impl Field {
                     _ => quote!(cursor.remaining_bytes()),
                 }
             }
+            Some(other) if self.is_var_array() => {
+                // the items have no fixed size, so we have to walk them
+                let count_expr = other.count_expr();
+                let inner = match &self.typ {
+                    FieldType::VarLenArray(array) => array.raw_inner_type(),
+                    _ => unreachable!("checked above"),
+                };
+                quote! {{
+                    let data = cursor.remaining().ok_or(ReadError::OutOfBounds)?;
+                    <#inner as VarSize>::total_len_for_count(data, #count_expr)?
+                }}
+            }
             Some(other) => {
                 let count_expr = other.count_expr();
                 let size_expr = match &self.typ {
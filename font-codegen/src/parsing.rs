@@ -185,6 +185,8 @@ pub(crate) struct FieldAttrs {
     pub(crate) skip_getter: Option<syn::Path>,
     /// specify that an offset getter has a custom impl
     pub(crate) offset_getter: Option<Attr<syn::Ident>>,
+    /// overrides the name of the generated offset getter
+    pub(crate) offset_getter_name: Option<Attr<syn::Ident>>,
     /// optionally a method on the parent type used to generate the offset data
     /// source for this item.
     pub(crate) offset_data: Option<Attr<syn::Ident>>,
@@ -1019,6 +1021,7 @@ static IF_FLAG: &str = "if_flag";
 static FORMAT: &str = "format";
 static VERSION: &str = "version";
 static OFFSET_GETTER: &str = "offset_getter";
+static OFFSET_GETTER_NAME: &str = "offset_getter_name";
 static OFFSET_DATA: &str = "offset_data_method";
 static OFFSET_ADJUSTMENT: &str = "offset_adjustment";
 static COMPILE: &str = "compile";
@@ -1052,6 +1055,8 @@ impl Parse for FieldAttrs {
                 this.skip_getter = Some(attr.path().clone());
             } else if ident == OFFSET_GETTER {
                 this.offset_getter = Some(Attr::new(ident.clone(), attr.parse_args()?));
+            } else if ident == OFFSET_GETTER_NAME {
+                this.offset_getter_name = Some(Attr::new(ident.clone(), attr.parse_args()?));
             } else if ident == OFFSET_DATA {
                 this.offset_data = Some(Attr::new(ident.clone(), attr.parse_args()?));
             } else if ident == OFFSET_ADJUSTMENT {
@@ -1703,16 +1708,26 @@ impl ReferencedFields {
 
 impl OffsetTarget {
     pub(crate) fn getter_return_type(&self, is_generic: bool) -> TokenStream {
+        self.getter_return_type_with_error(is_generic, quote!(ReadError))
+    }
+
+    /// The return type of a getter that resolves this offset, with the given
+    /// error type.
+    pub(crate) fn getter_return_type_with_error(
+        &self,
+        is_generic: bool,
+        error: TokenStream,
+    ) -> TokenStream {
         match self {
-            OffsetTarget::Table(ident) if !is_generic => quote!(Result<#ident <'a>, ReadError>),
-            OffsetTarget::Table(ident) => quote!(Result<#ident, ReadError>),
+            OffsetTarget::Table(ident) if !is_generic => quote!(Result<#ident <'a>, #error>),
+            OffsetTarget::Table(ident) => quote!(Result<#ident, #error>),
             OffsetTarget::Array(inner) => {
                 let elem_type = match inner.deref() {
                     FieldType::Scalar { typ } => quote!(BigEndian<#typ>),
                     FieldType::Struct { typ } => typ.to_token_stream(),
                     _ => panic!("we should have returned a humane error before now"),
                 };
-                quote!(Result<&'a [#elem_type], ReadError>)
+                quote!(Result<&'a [#elem_type], #error>)
             }
         }
     }
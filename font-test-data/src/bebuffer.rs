@@ -0,0 +1,41 @@
+//! A builder for hand written test data.
+
+use font_types::Scalar;
+
+/// A convenience type for generating a buffer of big-endian bytes.
+#[derive(Debug, Clone, Default)]
+pub struct BeBuffer(Vec<u8>);
+
+impl BeBuffer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Write any scalar to this buffer.
+    pub fn push(mut self, item: impl Scalar) -> Self {
+        self.0.extend(item.to_raw().as_ref());
+        self
+    }
+
+    /// Write multiple scalars into the buffer
+    pub fn extend<T: Scalar>(mut self, iter: impl IntoIterator<Item = T>) -> Self {
+        for item in iter {
+            self.0.extend(item.to_raw().as_ref());
+        }
+        self
+    }
+
+    /// Pads the buffer with zeros up to the given length.
+    pub fn pad_to(mut self, len: usize) -> Self {
+        assert!(self.0.len() <= len, "buffer is already longer than {len}");
+        self.0.resize(len, 0);
+        self
+    }
+}
+
+impl std::ops::Deref for BeBuffer {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
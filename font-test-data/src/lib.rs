@@ -1,5 +1,6 @@
 //! test data shared between various fontations crates.
 
+pub mod bebuffer;
 pub mod gdef;
 pub mod gpos;
 pub mod gsub;
@@ -11,6 +12,8 @@ pub static CMAP14_FONT1: &[u8] = include_bytes!("../test_data/ttf/cmap14_font1.t
 
 pub static CMAP4_SYMBOL_PUA: &[u8] = include_bytes!("../test_data/ttf/cmap4_symbol_pua.ttf");
 
+pub static CMAP_FORMATS: &[u8] = include_bytes!("../test_data/ttf/cmap_formats.ttf");
+
 pub static COLR_GRADIENT_RECT: &[u8] =
     include_bytes!("../test_data/ttf/linear_gradient_rect_colr_1.ttf");
 
@@ -55,6 +58,8 @@ pub static COLRV1_NO_CLIPLIST: &[u8] =
 
 pub static CVAR: &[u8] = include_bytes!("../test_data/ttf/cvar.ttf");
 
+pub static AVAR2: &[u8] = include_bytes!("../test_data/ttf/avar2.ttf");
+
 pub static STARTING_OFF_CURVE: &[u8] = include_bytes!("../test_data/ttf/starts_off_curve.ttf");
 
 pub static MOSTLY_OFF_CURVE: &[u8] = include_bytes!("../test_data/ttf/mostly_off_curve.ttf");
@@ -66,6 +71,11 @@ pub static MATERIAL_SYMBOLS_SUBSET: &[u8] =
 
 pub static GLYF_COMPONENTS: &[u8] = include_bytes!("../test_data/ttf/glyf_components.ttf");
 
+/// [`GLYF_COMPONENTS`], encoded as WOFF2 with transformed glyf, loca and
+/// hmtx tables and brotli compression.
+pub static GLYF_COMPONENTS_WOFF2: &[u8] =
+    include_bytes!("../test_data/woff2/glyf_components.woff2");
+
 pub static AUTOHINT_CMAP: &[u8] = include_bytes!("../test_data/ttf/autohint_cmap.ttf");
 
 pub mod varc {
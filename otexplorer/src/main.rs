@@ -121,6 +121,19 @@ fn get_some_table<'a>(
         tables::stat::Stat::TAG => font.stat().map(|x| Box::new(x) as _),
         tables::vhea::Vhea::TAG => font.vhea().map(|x| Box::new(x) as _),
         tables::vmtx::Vmtx::TAG => font.vmtx().map(|x| Box::new(x) as _),
+        tables::trak::Trak::TAG => font.trak().map(|x| Box::new(x) as _),
+        tables::feat::Feat::TAG => font.feat().map(|x| Box::new(x) as _),
+        tables::ltag::Ltag::TAG => font.ltag().map(|x| Box::new(x) as _),
+        tables::math::Math::TAG => font.math().map(|x| Box::new(x) as _),
+        tables::jstf::Jstf::TAG => font.jstf().map(|x| Box::new(x) as _),
+        tables::svg::Svg::TAG => font.svg().map(|x| Box::new(x) as _),
+        tables::hdmx::Hdmx::TAG => font.hdmx().map(|x| Box::new(x) as _),
+        tables::vdmx::Vdmx::TAG => font.vdmx().map(|x| Box::new(x) as _),
+        tables::ltsh::Ltsh::TAG => font.ltsh().map(|x| Box::new(x) as _),
+        tables::vorg::Vorg::TAG => font.vorg().map(|x| Box::new(x) as _),
+        tables::meta::Meta::TAG => font.meta().map(|x| Box::new(x) as _),
+        tables::pclt::Pclt::TAG => font.pclt().map(|x| Box::new(x) as _),
+        tables::dsig::Dsig::TAG => font.dsig().map(|x| Box::new(x) as _),
         _ => Err(ReadError::TableIsMissing(tag)),
     }
 }
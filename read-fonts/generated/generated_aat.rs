@@ -901,6 +901,13 @@ impl<'a> StateHeader<'a> {
         self.class_table_offset().resolve(data)
     }
 
+    /// Like [`class_table`][Self::class_table], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn class_table_with_context(&self) -> Result<ClassSubtable<'a>, ReadErrorWithContext> {
+        self.class_table()
+            .with_offset_context(self.class_table_offset(), "class_table_offset")
+    }
+
     /// Byte offset from the beginning of the state table to the state array.
     pub fn state_array_offset(&self) -> Offset16 {
         let range = self.shape.state_array_offset_byte_range();
@@ -913,6 +920,13 @@ impl<'a> StateHeader<'a> {
         self.state_array_offset().resolve(data)
     }
 
+    /// Like [`state_array`][Self::state_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn state_array_with_context(&self) -> Result<RawBytes<'a>, ReadErrorWithContext> {
+        self.state_array()
+            .with_offset_context(self.state_array_offset(), "state_array_offset")
+    }
+
     /// Byte offset from the beginning of the state table to the entry subtable.
     pub fn entry_table_offset(&self) -> Offset16 {
         let range = self.shape.entry_table_offset_byte_range();
@@ -924,6 +938,13 @@ impl<'a> StateHeader<'a> {
         let data = self.data;
         self.entry_table_offset().resolve(data)
     }
+
+    /// Like [`entry_table`][Self::entry_table], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn entry_table_with_context(&self) -> Result<RawBytes<'a>, ReadErrorWithContext> {
+        self.entry_table()
+            .with_offset_context(self.entry_table_offset(), "entry_table_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -1151,6 +1172,13 @@ impl<'a> StxHeader<'a> {
         self.class_table_offset().resolve(data)
     }
 
+    /// Like [`class_table`][Self::class_table], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn class_table_with_context(&self) -> Result<LookupU16<'a>, ReadErrorWithContext> {
+        self.class_table()
+            .with_offset_context(self.class_table_offset(), "class_table_offset")
+    }
+
     /// Byte offset from the beginning of the state table to the state array.
     pub fn state_array_offset(&self) -> Offset32 {
         let range = self.shape.state_array_offset_byte_range();
@@ -1163,6 +1191,13 @@ impl<'a> StxHeader<'a> {
         self.state_array_offset().resolve(data)
     }
 
+    /// Like [`state_array`][Self::state_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn state_array_with_context(&self) -> Result<RawWords<'a>, ReadErrorWithContext> {
+        self.state_array()
+            .with_offset_context(self.state_array_offset(), "state_array_offset")
+    }
+
     /// Byte offset from the beginning of the state table to the entry subtable.
     pub fn entry_table_offset(&self) -> Offset32 {
         let range = self.shape.entry_table_offset_byte_range();
@@ -1174,6 +1209,13 @@ impl<'a> StxHeader<'a> {
         let data = self.data;
         self.entry_table_offset().resolve(data)
     }
+
+    /// Like [`entry_table`][Self::entry_table], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn entry_table_with_context(&self) -> Result<RawBytes<'a>, ReadErrorWithContext> {
+        self.entry_table()
+            .with_offset_context(self.entry_table_offset(), "entry_table_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -77,6 +77,13 @@ impl<'a> Base<'a> {
         self.horiz_axis_offset().resolve(data)
     }
 
+    /// Like [`horiz_axis`][Self::horiz_axis], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn horiz_axis_with_context(&self) -> Option<Result<Axis<'a>, ReadErrorWithContext>> {
+        self.horiz_axis()
+            .with_offset_context(self.horiz_axis_offset(), "horiz_axis_offset")
+    }
+
     /// Offset to vertical Axis table, from beginning of BASE table (may be NULL)
     pub fn vert_axis_offset(&self) -> Nullable<Offset16> {
         let range = self.shape.vert_axis_offset_byte_range();
@@ -89,6 +96,13 @@ impl<'a> Base<'a> {
         self.vert_axis_offset().resolve(data)
     }
 
+    /// Like [`vert_axis`][Self::vert_axis], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn vert_axis_with_context(&self) -> Option<Result<Axis<'a>, ReadErrorWithContext>> {
+        self.vert_axis()
+            .with_offset_context(self.vert_axis_offset(), "vert_axis_offset")
+    }
+
     /// Offset to Item Variation Store table, from beginning of BASE table (may be null)
     pub fn item_var_store_offset(&self) -> Option<Nullable<Offset32>> {
         let range = self.shape.item_var_store_offset_byte_range()?;
@@ -100,6 +114,15 @@ impl<'a> Base<'a> {
         let data = self.data;
         self.item_var_store_offset().map(|x| x.resolve(data))?
     }
+
+    /// Like [`item_var_store`][Self::item_var_store], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn item_var_store_with_context(
+        &self,
+    ) -> Option<Result<ItemVariationStore<'a>, ReadErrorWithContext>> {
+        self.item_var_store()
+            .with_offset_context(self.item_var_store_offset(), "item_var_store_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -121,10 +144,7 @@ impl<'a> SomeTable<'a> for Base<'a> {
             )),
             3usize if version.compatible((1u16, 1u16)) => Some(Field::new(
                 "item_var_store_offset",
-                FieldType::offset(
-                    self.item_var_store_offset().unwrap(),
-                    self.item_var_store().unwrap(),
-                ),
+                FieldType::offset(self.item_var_store_offset().unwrap(), self.item_var_store()),
             )),
             _ => None,
         }
@@ -180,6 +200,15 @@ impl<'a> Axis<'a> {
         self.base_tag_list_offset().resolve(data)
     }
 
+    /// Like [`base_tag_list`][Self::base_tag_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_tag_list_with_context(
+        &self,
+    ) -> Option<Result<BaseTagList<'a>, ReadErrorWithContext>> {
+        self.base_tag_list()
+            .with_offset_context(self.base_tag_list_offset(), "base_tag_list_offset")
+    }
+
     /// Offset to BaseScriptList table, from beginning of Axis table
     pub fn base_script_list_offset(&self) -> Offset16 {
         let range = self.shape.base_script_list_offset_byte_range();
@@ -191,6 +220,15 @@ impl<'a> Axis<'a> {
         let data = self.data;
         self.base_script_list_offset().resolve(data)
     }
+
+    /// Like [`base_script_list`][Self::base_script_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_script_list_with_context(
+        &self,
+    ) -> Result<BaseScriptList<'a>, ReadErrorWithContext> {
+        self.base_script_list()
+            .with_offset_context(self.base_script_list_offset(), "base_script_list_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -399,6 +437,16 @@ impl BaseScriptRecord {
     pub fn base_script<'a>(&self, data: FontData<'a>) -> Result<BaseScript<'a>, ReadError> {
         self.base_script_offset().resolve(data)
     }
+
+    /// Like [`base_script`][Self::base_script], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_script_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<BaseScript<'a>, ReadErrorWithContext> {
+        self.base_script(data)
+            .with_offset_context(self.base_script_offset(), "base_script_offset")
+    }
 }
 
 impl FixedSize for BaseScriptRecord {
@@ -481,6 +529,13 @@ impl<'a> BaseScript<'a> {
         self.base_values_offset().resolve(data)
     }
 
+    /// Like [`base_values`][Self::base_values], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_values_with_context(&self) -> Option<Result<BaseValues<'a>, ReadErrorWithContext>> {
+        self.base_values()
+            .with_offset_context(self.base_values_offset(), "base_values_offset")
+    }
+
     /// Offset to MinMax table, from beginning of BaseScript table (may be NULL)
     pub fn default_min_max_offset(&self) -> Nullable<Offset16> {
         let range = self.shape.default_min_max_offset_byte_range();
@@ -493,6 +548,13 @@ impl<'a> BaseScript<'a> {
         self.default_min_max_offset().resolve(data)
     }
 
+    /// Like [`default_min_max`][Self::default_min_max], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn default_min_max_with_context(&self) -> Option<Result<MinMax<'a>, ReadErrorWithContext>> {
+        self.default_min_max()
+            .with_offset_context(self.default_min_max_offset(), "default_min_max_offset")
+    }
+
     /// Number of BaseLangSysRecords defined — may be zero (0)
     pub fn base_lang_sys_count(&self) -> u16 {
         let range = self.shape.base_lang_sys_count_byte_range();
@@ -575,6 +637,16 @@ impl BaseLangSysRecord {
     pub fn min_max<'a>(&self, data: FontData<'a>) -> Result<MinMax<'a>, ReadError> {
         self.min_max_offset().resolve(data)
     }
+
+    /// Like [`min_max`][Self::min_max], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn min_max_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<MinMax<'a>, ReadErrorWithContext> {
+        self.min_max(data)
+            .with_offset_context(self.min_max_offset(), "min_max_offset")
+    }
 }
 
 impl FixedSize for BaseLangSysRecord {
@@ -768,6 +840,13 @@ impl<'a> MinMax<'a> {
         self.min_coord_offset().resolve(data)
     }
 
+    /// Like [`min_coord`][Self::min_coord], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn min_coord_with_context(&self) -> Option<Result<BaseCoord<'a>, ReadErrorWithContext>> {
+        self.min_coord()
+            .with_offset_context(self.min_coord_offset(), "min_coord_offset")
+    }
+
     /// Offset to BaseCoord table that defines maximum extent value,
     /// from the beginning of MinMax table (may be NULL)
     pub fn max_coord_offset(&self) -> Nullable<Offset16> {
@@ -781,6 +860,13 @@ impl<'a> MinMax<'a> {
         self.max_coord_offset().resolve(data)
     }
 
+    /// Like [`max_coord`][Self::max_coord], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn max_coord_with_context(&self) -> Option<Result<BaseCoord<'a>, ReadErrorWithContext>> {
+        self.max_coord()
+            .with_offset_context(self.max_coord_offset(), "max_coord_offset")
+    }
+
     /// Number of FeatMinMaxRecords — may be zero (0)
     pub fn feat_min_max_count(&self) -> u16 {
         let range = self.shape.feat_min_max_count_byte_range();
@@ -865,10 +951,20 @@ impl FeatMinMaxRecord {
     ///
     /// The `data` argument should be retrieved from the parent table
     /// By calling its `offset_data` method.
-    pub fn min_coord<'a>(&self, data: FontData<'a>) -> Option<Result<MinMax<'a>, ReadError>> {
+    pub fn min_coord<'a>(&self, data: FontData<'a>) -> Option<Result<BaseCoord<'a>, ReadError>> {
         self.min_coord_offset().resolve(data)
     }
 
+    /// Like [`min_coord`][Self::min_coord], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn min_coord_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<BaseCoord<'a>, ReadErrorWithContext>> {
+        self.min_coord(data)
+            .with_offset_context(self.min_coord_offset(), "min_coord_offset")
+    }
+
     /// Offset to BaseCoord table that defines the maximum extent
     /// value, from beginning of MinMax table (may be NULL)
     pub fn max_coord_offset(&self) -> Nullable<Offset16> {
@@ -880,9 +976,19 @@ impl FeatMinMaxRecord {
     ///
     /// The `data` argument should be retrieved from the parent table
     /// By calling its `offset_data` method.
-    pub fn max_coord<'a>(&self, data: FontData<'a>) -> Option<Result<MinMax<'a>, ReadError>> {
+    pub fn max_coord<'a>(&self, data: FontData<'a>) -> Option<Result<BaseCoord<'a>, ReadError>> {
         self.max_coord_offset().resolve(data)
     }
+
+    /// Like [`max_coord`][Self::max_coord], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn max_coord_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<BaseCoord<'a>, ReadErrorWithContext>> {
+        self.max_coord(data)
+            .with_offset_context(self.max_coord_offset(), "max_coord_offset")
+    }
 }
 
 impl FixedSize for FeatMinMaxRecord {
@@ -1208,6 +1314,15 @@ impl<'a> BaseCoordFormat3<'a> {
         let data = self.data;
         self.device_offset().resolve(data)
     }
+
+    /// Like [`device`][Self::device], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn device_with_context(
+        &self,
+    ) -> Option<Result<DeviceOrVariationIndex<'a>, ReadErrorWithContext>> {
+        self.device()
+            .with_offset_context(self.device_offset(), "device_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
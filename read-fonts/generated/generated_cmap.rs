@@ -137,6 +137,16 @@ impl EncodingRecord {
     pub fn subtable<'a>(&self, data: FontData<'a>) -> Result<CmapSubtable<'a>, ReadError> {
         self.subtable_offset().resolve(data)
     }
+
+    /// Like [`subtable`][Self::subtable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn subtable_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<CmapSubtable<'a>, ReadErrorWithContext> {
+        self.subtable(data)
+            .with_offset_context(self.subtable_offset(), "subtable_offset")
+    }
 }
 
 impl FixedSize for EncodingRecord {
@@ -1681,6 +1691,16 @@ impl VariationSelector {
         self.default_uvs_offset().resolve(data)
     }
 
+    /// Like [`default_uvs`][Self::default_uvs], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn default_uvs_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<DefaultUvs<'a>, ReadErrorWithContext>> {
+        self.default_uvs(data)
+            .with_offset_context(self.default_uvs_offset(), "default_uvs_offset")
+    }
+
     /// Offset from the start of the [`Cmap14`] subtable to Non-Default
     /// UVS Table. May be NULL.
     pub fn non_default_uvs_offset(&self) -> Nullable<Offset32> {
@@ -1698,6 +1718,16 @@ impl VariationSelector {
     ) -> Option<Result<NonDefaultUvs<'a>, ReadError>> {
         self.non_default_uvs_offset().resolve(data)
     }
+
+    /// Like [`non_default_uvs`][Self::non_default_uvs], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn non_default_uvs_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<NonDefaultUvs<'a>, ReadErrorWithContext>> {
+        self.non_default_uvs(data)
+            .with_offset_context(self.non_default_uvs_offset(), "non_default_uvs_offset")
+    }
 }
 
 impl FixedSize for VariationSelector {
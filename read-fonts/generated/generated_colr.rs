@@ -147,6 +147,17 @@ impl<'a> Colr<'a> {
             .resolve_with_args(data, &args)
     }
 
+    /// Like [`base_glyph_records`][Self::base_glyph_records], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_glyph_records_with_context(
+        &self,
+    ) -> Option<Result<&'a [BaseGlyph], ReadErrorWithContext>> {
+        self.base_glyph_records().with_offset_context(
+            self.base_glyph_records_offset(),
+            "base_glyph_records_offset",
+        )
+    }
+
     /// Offset to layerRecords array (may be NULL).
     pub fn layer_records_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.layer_records_offset_byte_range();
@@ -160,6 +171,13 @@ impl<'a> Colr<'a> {
         self.layer_records_offset().resolve_with_args(data, &args)
     }
 
+    /// Like [`layer_records`][Self::layer_records], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn layer_records_with_context(&self) -> Option<Result<&'a [Layer], ReadErrorWithContext>> {
+        self.layer_records()
+            .with_offset_context(self.layer_records_offset(), "layer_records_offset")
+    }
+
     /// Number of Layer records; may be 0 in a version 1 table.
     pub fn num_layer_records(&self) -> u16 {
         let range = self.shape.num_layer_records_byte_range();
@@ -178,6 +196,15 @@ impl<'a> Colr<'a> {
         self.base_glyph_list_offset().map(|x| x.resolve(data))?
     }
 
+    /// Like [`base_glyph_list`][Self::base_glyph_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_glyph_list_with_context(
+        &self,
+    ) -> Option<Result<BaseGlyphList<'a>, ReadErrorWithContext>> {
+        self.base_glyph_list()
+            .with_offset_context(self.base_glyph_list_offset(), "base_glyph_list_offset")
+    }
+
     /// Offset to LayerList table (may be NULL).
     pub fn layer_list_offset(&self) -> Option<Nullable<Offset32>> {
         let range = self.shape.layer_list_offset_byte_range()?;
@@ -190,6 +217,13 @@ impl<'a> Colr<'a> {
         self.layer_list_offset().map(|x| x.resolve(data))?
     }
 
+    /// Like [`layer_list`][Self::layer_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn layer_list_with_context(&self) -> Option<Result<LayerList<'a>, ReadErrorWithContext>> {
+        self.layer_list()
+            .with_offset_context(self.layer_list_offset(), "layer_list_offset")
+    }
+
     /// Offset to ClipList table (may be NULL).
     pub fn clip_list_offset(&self) -> Option<Nullable<Offset32>> {
         let range = self.shape.clip_list_offset_byte_range()?;
@@ -202,6 +236,13 @@ impl<'a> Colr<'a> {
         self.clip_list_offset().map(|x| x.resolve(data))?
     }
 
+    /// Like [`clip_list`][Self::clip_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn clip_list_with_context(&self) -> Option<Result<ClipList<'a>, ReadErrorWithContext>> {
+        self.clip_list()
+            .with_offset_context(self.clip_list_offset(), "clip_list_offset")
+    }
+
     /// Offset to DeltaSetIndexMap table (may be NULL).
     pub fn var_index_map_offset(&self) -> Option<Nullable<Offset32>> {
         let range = self.shape.var_index_map_offset_byte_range()?;
@@ -214,6 +255,15 @@ impl<'a> Colr<'a> {
         self.var_index_map_offset().map(|x| x.resolve(data))?
     }
 
+    /// Like [`var_index_map`][Self::var_index_map], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn var_index_map_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.var_index_map()
+            .with_offset_context(self.var_index_map_offset(), "var_index_map_offset")
+    }
+
     /// Offset to ItemVariationStore (may be NULL).
     pub fn item_variation_store_offset(&self) -> Option<Nullable<Offset32>> {
         let range = self.shape.item_variation_store_offset_byte_range()?;
@@ -226,6 +276,17 @@ impl<'a> Colr<'a> {
         self.item_variation_store_offset()
             .map(|x| x.resolve(data))?
     }
+
+    /// Like [`item_variation_store`][Self::item_variation_store], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn item_variation_store_with_context(
+        &self,
+    ) -> Option<Result<ItemVariationStore<'a>, ReadErrorWithContext>> {
+        self.item_variation_store().with_offset_context(
+            self.item_variation_store_offset(),
+            "item_variation_store_offset",
+        )
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -264,32 +325,26 @@ impl<'a> SomeTable<'a> for Colr<'a> {
                 "base_glyph_list_offset",
                 FieldType::offset(
                     self.base_glyph_list_offset().unwrap(),
-                    self.base_glyph_list().unwrap(),
+                    self.base_glyph_list(),
                 ),
             )),
             6usize if version.compatible(1u16) => Some(Field::new(
                 "layer_list_offset",
-                FieldType::offset(
-                    self.layer_list_offset().unwrap(),
-                    self.layer_list().unwrap(),
-                ),
+                FieldType::offset(self.layer_list_offset().unwrap(), self.layer_list()),
             )),
             7usize if version.compatible(1u16) => Some(Field::new(
                 "clip_list_offset",
-                FieldType::offset(self.clip_list_offset().unwrap(), self.clip_list().unwrap()),
+                FieldType::offset(self.clip_list_offset().unwrap(), self.clip_list()),
             )),
             8usize if version.compatible(1u16) => Some(Field::new(
                 "var_index_map_offset",
-                FieldType::offset(
-                    self.var_index_map_offset().unwrap(),
-                    self.var_index_map().unwrap(),
-                ),
+                FieldType::offset(self.var_index_map_offset().unwrap(), self.var_index_map()),
             )),
             9usize if version.compatible(1u16) => Some(Field::new(
                 "item_variation_store_offset",
                 FieldType::offset(
                     self.item_variation_store_offset().unwrap(),
-                    self.item_variation_store().unwrap(),
+                    self.item_variation_store(),
                 ),
             )),
             _ => None,
@@ -503,6 +558,16 @@ impl BaseGlyphPaint {
     pub fn paint<'a>(&self, data: FontData<'a>) -> Result<Paint<'a>, ReadError> {
         self.paint_offset().resolve(data)
     }
+
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint(data)
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
 }
 
 impl FixedSize for BaseGlyphPaint {
@@ -739,6 +804,16 @@ impl Clip {
     pub fn clip_box<'a>(&self, data: FontData<'a>) -> Result<ClipBox<'a>, ReadError> {
         self.clip_box_offset().resolve(data)
     }
+
+    /// Like [`clip_box`][Self::clip_box], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn clip_box_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<ClipBox<'a>, ReadErrorWithContext> {
+        self.clip_box(data)
+            .with_offset_context(self.clip_box_offset(), "clip_box_offset")
+    }
 }
 
 impl FixedSize for Clip {
@@ -2068,6 +2143,13 @@ impl<'a> PaintLinearGradient<'a> {
         self.color_line_offset().resolve(data)
     }
 
+    /// Like [`color_line`][Self::color_line], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn color_line_with_context(&self) -> Result<ColorLine<'a>, ReadErrorWithContext> {
+        self.color_line()
+            .with_offset_context(self.color_line_offset(), "color_line_offset")
+    }
+
     /// Start point (p₀) x coordinate.
     pub fn x0(&self) -> FWord {
         let range = self.shape.x0_byte_range();
@@ -2221,6 +2303,13 @@ impl<'a> PaintVarLinearGradient<'a> {
         self.color_line_offset().resolve(data)
     }
 
+    /// Like [`color_line`][Self::color_line], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn color_line_with_context(&self) -> Result<VarColorLine<'a>, ReadErrorWithContext> {
+        self.color_line()
+            .with_offset_context(self.color_line_offset(), "color_line_offset")
+    }
+
     /// Start point (p₀) x coordinate. For variation, use
     /// varIndexBase + 0.
     pub fn x0(&self) -> FWord {
@@ -2382,6 +2471,13 @@ impl<'a> PaintRadialGradient<'a> {
         self.color_line_offset().resolve(data)
     }
 
+    /// Like [`color_line`][Self::color_line], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn color_line_with_context(&self) -> Result<ColorLine<'a>, ReadErrorWithContext> {
+        self.color_line()
+            .with_offset_context(self.color_line_offset(), "color_line_offset")
+    }
+
     /// Start circle center x coordinate.
     pub fn x0(&self) -> FWord {
         let range = self.shape.x0_byte_range();
@@ -2535,6 +2631,13 @@ impl<'a> PaintVarRadialGradient<'a> {
         self.color_line_offset().resolve(data)
     }
 
+    /// Like [`color_line`][Self::color_line], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn color_line_with_context(&self) -> Result<VarColorLine<'a>, ReadErrorWithContext> {
+        self.color_line()
+            .with_offset_context(self.color_line_offset(), "color_line_offset")
+    }
+
     /// Start circle center x coordinate. For variation, use
     /// varIndexBase + 0.
     pub fn x0(&self) -> FWord {
@@ -2684,6 +2787,13 @@ impl<'a> PaintSweepGradient<'a> {
         self.color_line_offset().resolve(data)
     }
 
+    /// Like [`color_line`][Self::color_line], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn color_line_with_context(&self) -> Result<ColorLine<'a>, ReadErrorWithContext> {
+        self.color_line()
+            .with_offset_context(self.color_line_offset(), "color_line_offset")
+    }
+
     /// Center x coordinate.
     pub fn center_x(&self) -> FWord {
         let range = self.shape.center_x_byte_range();
@@ -2815,6 +2925,13 @@ impl<'a> PaintVarSweepGradient<'a> {
         self.color_line_offset().resolve(data)
     }
 
+    /// Like [`color_line`][Self::color_line], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn color_line_with_context(&self) -> Result<VarColorLine<'a>, ReadErrorWithContext> {
+        self.color_line()
+            .with_offset_context(self.color_line_offset(), "color_line_offset")
+    }
+
     /// Center x coordinate. For variation, use varIndexBase + 0.
     pub fn center_x(&self) -> FWord {
         let range = self.shape.center_x_byte_range();
@@ -2935,6 +3052,13 @@ impl<'a> PaintGlyph<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Glyph ID for the source outline.
     pub fn glyph_id(&self) -> GlyphId16 {
         let range = self.shape.glyph_id_byte_range();
@@ -3090,6 +3214,13 @@ impl<'a> PaintTransform<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Offset to an Affine2x3 table.
     pub fn transform_offset(&self) -> Offset24 {
         let range = self.shape.transform_offset_byte_range();
@@ -3101,6 +3232,13 @@ impl<'a> PaintTransform<'a> {
         let data = self.data;
         self.transform_offset().resolve(data)
     }
+
+    /// Like [`transform`][Self::transform], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn transform_with_context(&self) -> Result<Affine2x3<'a>, ReadErrorWithContext> {
+        self.transform()
+            .with_offset_context(self.transform_offset(), "transform_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -3187,6 +3325,13 @@ impl<'a> PaintVarTransform<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Offset to a VarAffine2x3 table.
     pub fn transform_offset(&self) -> Offset24 {
         let range = self.shape.transform_offset_byte_range();
@@ -3198,6 +3343,13 @@ impl<'a> PaintVarTransform<'a> {
         let data = self.data;
         self.transform_offset().resolve(data)
     }
+
+    /// Like [`transform`][Self::transform], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn transform_with_context(&self) -> Result<VarAffine2x3<'a>, ReadErrorWithContext> {
+        self.transform()
+            .with_offset_context(self.transform_offset(), "transform_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -3527,6 +3679,13 @@ impl<'a> PaintTranslate<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Translation in x direction.
     pub fn dx(&self) -> FWord {
         let range = self.shape.dx_byte_range();
@@ -3632,6 +3791,13 @@ impl<'a> PaintVarTranslate<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Translation in x direction. For variation, use varIndexBase + 0.
     pub fn dx(&self) -> FWord {
         let range = self.shape.dx_byte_range();
@@ -3739,6 +3905,13 @@ impl<'a> PaintScale<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x direction.
     pub fn scale_x(&self) -> F2Dot14 {
         let range = self.shape.scale_x_byte_range();
@@ -3844,6 +4017,13 @@ impl<'a> PaintVarScale<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x direction. For variation, use varIndexBase +
     /// 0.
     pub fn scale_x(&self) -> F2Dot14 {
@@ -3963,6 +4143,13 @@ impl<'a> PaintScaleAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x direction.
     pub fn scale_x(&self) -> F2Dot14 {
         let range = self.shape.scale_x_byte_range();
@@ -4092,6 +4279,13 @@ impl<'a> PaintVarScaleAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x direction. For variation, use varIndexBase +
     /// 0.
     pub fn scale_x(&self) -> F2Dot14 {
@@ -4212,6 +4406,13 @@ impl<'a> PaintScaleUniform<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x and y directions.
     pub fn scale(&self) -> F2Dot14 {
         let range = self.shape.scale_byte_range();
@@ -4305,6 +4506,13 @@ impl<'a> PaintVarScaleUniform<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x and y directions. For variation, use
     /// varIndexBase + 0.
     pub fn scale(&self) -> F2Dot14 {
@@ -4411,6 +4619,13 @@ impl<'a> PaintScaleUniformAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x and y directions.
     pub fn scale(&self) -> F2Dot14 {
         let range = self.shape.scale_byte_range();
@@ -4529,6 +4744,13 @@ impl<'a> PaintVarScaleUniformAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Scale factor in x and y directions. For variation, use
     /// varIndexBase + 0.
     pub fn scale(&self) -> F2Dot14 {
@@ -4641,6 +4863,13 @@ impl<'a> PaintRotate<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Rotation angle, 180° in counter-clockwise degrees per 1.0 of
     /// value.
     pub fn angle(&self) -> F2Dot14 {
@@ -4735,6 +4964,13 @@ impl<'a> PaintVarRotate<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Rotation angle, 180° in counter-clockwise degrees per 1.0 of
     /// value. For variation, use varIndexBase + 0.
     pub fn angle(&self) -> F2Dot14 {
@@ -4841,6 +5077,13 @@ impl<'a> PaintRotateAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Rotation angle, 180° in counter-clockwise degrees per 1.0 of
     /// value.
     pub fn angle(&self) -> F2Dot14 {
@@ -4959,6 +5202,13 @@ impl<'a> PaintVarRotateAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Rotation angle, 180° in counter-clockwise degrees per 1.0 of
     /// value. For variation, use varIndexBase + 0.
     pub fn angle(&self) -> F2Dot14 {
@@ -5076,6 +5326,13 @@ impl<'a> PaintSkew<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Angle of skew in the direction of the x-axis, 180° in
     /// counter-clockwise degrees per 1.0 of value.
     pub fn x_skew_angle(&self) -> F2Dot14 {
@@ -5183,6 +5440,13 @@ impl<'a> PaintVarSkew<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Angle of skew in the direction of the x-axis, 180° ┬░ in
     /// counter-clockwise degrees per 1.0 of value. For variation, use
     /// varIndexBase + 0.
@@ -5304,6 +5568,13 @@ impl<'a> PaintSkewAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Angle of skew in the direction of the x-axis, 180° in
     /// counter-clockwise degrees per 1.0 of value.
     pub fn x_skew_angle(&self) -> F2Dot14 {
@@ -5435,6 +5706,13 @@ impl<'a> PaintVarSkewAroundCenter<'a> {
         self.paint_offset().resolve(data)
     }
 
+    /// Like [`paint`][Self::paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.paint()
+            .with_offset_context(self.paint_offset(), "paint_offset")
+    }
+
     /// Angle of skew in the direction of the x-axis, 180° in
     /// counter-clockwise degrees per 1.0 of value. For variation, use
     /// varIndexBase + 0.
@@ -5562,6 +5840,13 @@ impl<'a> PaintComposite<'a> {
         self.source_paint_offset().resolve(data)
     }
 
+    /// Like [`source_paint`][Self::source_paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn source_paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.source_paint()
+            .with_offset_context(self.source_paint_offset(), "source_paint_offset")
+    }
+
     /// A CompositeMode enumeration value.
     pub fn composite_mode(&self) -> CompositeMode {
         let range = self.shape.composite_mode_byte_range();
@@ -5579,6 +5864,13 @@ impl<'a> PaintComposite<'a> {
         let data = self.data;
         self.backdrop_paint_offset().resolve(data)
     }
+
+    /// Like [`backdrop_paint`][Self::backdrop_paint], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn backdrop_paint_with_context(&self) -> Result<Paint<'a>, ReadErrorWithContext> {
+        self.backdrop_paint()
+            .with_offset_context(self.backdrop_paint_offset(), "backdrop_paint_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
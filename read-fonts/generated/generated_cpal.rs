@@ -144,6 +144,17 @@ impl<'a> Cpal<'a> {
             .resolve_with_args(data, &args)
     }
 
+    /// Like [`color_records_array`][Self::color_records_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn color_records_array_with_context(
+        &self,
+    ) -> Option<Result<&'a [ColorRecord], ReadErrorWithContext>> {
+        self.color_records_array().with_offset_context(
+            self.color_records_array_offset(),
+            "color_records_array_offset",
+        )
+    }
+
     /// Index of each palette’s first color record in the combined
     /// color record array.
     pub fn color_record_indices(&self) -> &'a [BigEndian<u16>] {
@@ -169,6 +180,17 @@ impl<'a> Cpal<'a> {
             .map(|x| x.resolve_with_args(data, &args))?
     }
 
+    /// Like [`palette_types_array`][Self::palette_types_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn palette_types_array_with_context(
+        &self,
+    ) -> Option<Result<&'a [BigEndian<PaletteType>], ReadErrorWithContext>> {
+        self.palette_types_array().with_offset_context(
+            self.palette_types_array_offset(),
+            "palette_types_array_offset",
+        )
+    }
+
     /// Offset from the beginning of CPAL table to the [Palette Labels Array][].
     ///
     /// This is an array of 'name' table IDs (typically in the font-specific name
@@ -189,6 +211,17 @@ impl<'a> Cpal<'a> {
             .map(|x| x.resolve_with_args(data, &args))?
     }
 
+    /// Like [`palette_labels_array`][Self::palette_labels_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn palette_labels_array_with_context(
+        &self,
+    ) -> Option<Result<&'a [BigEndian<u16>], ReadErrorWithContext>> {
+        self.palette_labels_array().with_offset_context(
+            self.palette_labels_array_offset(),
+            "palette_labels_array_offset",
+        )
+    }
+
     /// Offset from the beginning of CPAL table to the [Palette Entry Labels Array][].
     ///
     /// This is an array of 'name' table IDs (typically in the font-specific name
@@ -210,6 +243,17 @@ impl<'a> Cpal<'a> {
         self.palette_entry_labels_array_offset()
             .map(|x| x.resolve_with_args(data, &args))?
     }
+
+    /// Like [`palette_entry_labels_array`][Self::palette_entry_labels_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn palette_entry_labels_array_with_context(
+        &self,
+    ) -> Option<Result<&'a [BigEndian<NameId>], ReadErrorWithContext>> {
+        self.palette_entry_labels_array().with_offset_context(
+            self.palette_entry_labels_array_offset(),
+            "palette_entry_labels_array_offset",
+        )
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -244,21 +288,21 @@ impl<'a> SomeTable<'a> for Cpal<'a> {
                 "palette_types_array_offset",
                 FieldType::offset_to_array_of_scalars(
                     self.palette_types_array_offset().unwrap(),
-                    self.palette_types_array().unwrap(),
+                    self.palette_types_array(),
                 ),
             )),
             7usize if version.compatible(1u16) => Some(Field::new(
                 "palette_labels_array_offset",
                 FieldType::offset_to_array_of_scalars(
                     self.palette_labels_array_offset().unwrap(),
-                    self.palette_labels_array().unwrap(),
+                    self.palette_labels_array(),
                 ),
             )),
             8usize if version.compatible(1u16) => Some(Field::new(
                 "palette_entry_labels_array_offset",
                 FieldType::offset_to_array_of_scalars(
                     self.palette_entry_labels_array_offset().unwrap(),
-                    self.palette_entry_labels_array().unwrap(),
+                    self.palette_entry_labels_array(),
                 ),
             )),
             _ => None,
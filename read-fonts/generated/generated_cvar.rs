@@ -81,10 +81,17 @@ impl<'a> Cvar<'a> {
         self.data_offset().resolve(data)
     }
 
+    /// Like [`data`][Self::data], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn data_with_context(&self) -> Result<FontData<'a>, ReadErrorWithContext> {
+        self.data()
+            .with_offset_context(self.data_offset(), "data_offset")
+    }
+
     /// Array of tuple variation headers.
     pub fn tuple_variation_headers(&self) -> VarLenArray<'a, TupleVariationHeader> {
         let range = self.shape.tuple_variation_headers_byte_range();
-        VarLenArray::read(self.data.split_off(range.start).unwrap()).unwrap()
+        VarLenArray::read(self.data.slice(range).unwrap()).unwrap()
     }
 }
 
@@ -90,6 +90,17 @@ impl<'a> Fvar<'a> {
             .resolve_with_args(data, &args)
     }
 
+    /// Like [`axis_instance_arrays`][Self::axis_instance_arrays], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn axis_instance_arrays_with_context(
+        &self,
+    ) -> Result<AxisInstanceArrays<'a>, ReadErrorWithContext> {
+        self.axis_instance_arrays().with_offset_context(
+            self.axis_instance_arrays_offset(),
+            "axis_instance_arrays_offset",
+        )
+    }
+
     /// The number of variation axes in the font (the number of records in the axes array).
     pub fn axis_count(&self) -> u16 {
         let range = self.shape.axis_count_byte_range();
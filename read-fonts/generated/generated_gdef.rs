@@ -101,6 +101,15 @@ impl<'a> Gdef<'a> {
         self.glyph_class_def_offset().resolve(data)
     }
 
+    /// Like [`glyph_class_def`][Self::glyph_class_def], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn glyph_class_def_with_context(
+        &self,
+    ) -> Option<Result<ClassDef<'a>, ReadErrorWithContext>> {
+        self.glyph_class_def()
+            .with_offset_context(self.glyph_class_def_offset(), "glyph_class_def_offset")
+    }
+
     /// Offset to attachment point list table, from beginning of GDEF
     /// header (may be NULL)
     pub fn attach_list_offset(&self) -> Nullable<Offset16> {
@@ -114,6 +123,13 @@ impl<'a> Gdef<'a> {
         self.attach_list_offset().resolve(data)
     }
 
+    /// Like [`attach_list`][Self::attach_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn attach_list_with_context(&self) -> Option<Result<AttachList<'a>, ReadErrorWithContext>> {
+        self.attach_list()
+            .with_offset_context(self.attach_list_offset(), "attach_list_offset")
+    }
+
     /// Offset to ligature caret list table, from beginning of GDEF
     /// header (may be NULL)
     pub fn lig_caret_list_offset(&self) -> Nullable<Offset16> {
@@ -127,6 +143,15 @@ impl<'a> Gdef<'a> {
         self.lig_caret_list_offset().resolve(data)
     }
 
+    /// Like [`lig_caret_list`][Self::lig_caret_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn lig_caret_list_with_context(
+        &self,
+    ) -> Option<Result<LigCaretList<'a>, ReadErrorWithContext>> {
+        self.lig_caret_list()
+            .with_offset_context(self.lig_caret_list_offset(), "lig_caret_list_offset")
+    }
+
     /// Offset to class definition table for mark attachment type, from
     /// beginning of GDEF header (may be NULL)
     pub fn mark_attach_class_def_offset(&self) -> Nullable<Offset16> {
@@ -140,6 +165,17 @@ impl<'a> Gdef<'a> {
         self.mark_attach_class_def_offset().resolve(data)
     }
 
+    /// Like [`mark_attach_class_def`][Self::mark_attach_class_def], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark_attach_class_def_with_context(
+        &self,
+    ) -> Option<Result<ClassDef<'a>, ReadErrorWithContext>> {
+        self.mark_attach_class_def().with_offset_context(
+            self.mark_attach_class_def_offset(),
+            "mark_attach_class_def_offset",
+        )
+    }
+
     /// Offset to the table of mark glyph set definitions, from
     /// beginning of GDEF header (may be NULL)
     pub fn mark_glyph_sets_def_offset(&self) -> Option<Nullable<Offset16>> {
@@ -153,6 +189,17 @@ impl<'a> Gdef<'a> {
         self.mark_glyph_sets_def_offset().map(|x| x.resolve(data))?
     }
 
+    /// Like [`mark_glyph_sets_def`][Self::mark_glyph_sets_def], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark_glyph_sets_def_with_context(
+        &self,
+    ) -> Option<Result<MarkGlyphSets<'a>, ReadErrorWithContext>> {
+        self.mark_glyph_sets_def().with_offset_context(
+            self.mark_glyph_sets_def_offset(),
+            "mark_glyph_sets_def_offset",
+        )
+    }
+
     /// Offset to the Item Variation Store table, from beginning of
     /// GDEF header (may be NULL)
     pub fn item_var_store_offset(&self) -> Option<Nullable<Offset32>> {
@@ -165,6 +212,15 @@ impl<'a> Gdef<'a> {
         let data = self.data;
         self.item_var_store_offset().map(|x| x.resolve(data))?
     }
+
+    /// Like [`item_var_store`][Self::item_var_store], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn item_var_store_with_context(
+        &self,
+    ) -> Option<Result<ItemVariationStore<'a>, ReadErrorWithContext>> {
+        self.item_var_store()
+            .with_offset_context(self.item_var_store_offset(), "item_var_store_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -199,15 +255,12 @@ impl<'a> SomeTable<'a> for Gdef<'a> {
                 "mark_glyph_sets_def_offset",
                 FieldType::offset(
                     self.mark_glyph_sets_def_offset().unwrap(),
-                    self.mark_glyph_sets_def().unwrap(),
+                    self.mark_glyph_sets_def(),
                 ),
             )),
             6usize if version.compatible((1u16, 3u16)) => Some(Field::new(
                 "item_var_store_offset",
-                FieldType::offset(
-                    self.item_var_store_offset().unwrap(),
-                    self.item_var_store().unwrap(),
-                ),
+                FieldType::offset(self.item_var_store_offset().unwrap(), self.item_var_store()),
             )),
             _ => None,
         }
@@ -323,6 +376,13 @@ impl<'a> AttachList<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of glyphs with attachment points
     pub fn glyph_count(&self) -> u16 {
         let range = self.shape.glyph_count_byte_range();
@@ -505,6 +565,13 @@ impl<'a> LigCaretList<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of ligature glyphs
     pub fn lig_glyph_count(&self) -> u16 {
         let range = self.shape.lig_glyph_count_byte_range();
@@ -923,6 +990,13 @@ impl<'a> CaretValueFormat3<'a> {
         let data = self.data;
         self.device_offset().resolve(data)
     }
+
+    /// Like [`device`][Self::device], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn device_with_context(&self) -> Result<DeviceOrVariationIndex<'a>, ReadErrorWithContext> {
+        self.device()
+            .with_offset_context(self.device_offset(), "device_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -84,6 +84,13 @@ impl<'a> Gpos<'a> {
         self.script_list_offset().resolve(data)
     }
 
+    /// Like [`script_list`][Self::script_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn script_list_with_context(&self) -> Result<ScriptList<'a>, ReadErrorWithContext> {
+        self.script_list()
+            .with_offset_context(self.script_list_offset(), "script_list_offset")
+    }
+
     /// Offset to FeatureList table, from beginning of GPOS table
     pub fn feature_list_offset(&self) -> Offset16 {
         let range = self.shape.feature_list_offset_byte_range();
@@ -96,6 +103,13 @@ impl<'a> Gpos<'a> {
         self.feature_list_offset().resolve(data)
     }
 
+    /// Like [`feature_list`][Self::feature_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn feature_list_with_context(&self) -> Result<FeatureList<'a>, ReadErrorWithContext> {
+        self.feature_list()
+            .with_offset_context(self.feature_list_offset(), "feature_list_offset")
+    }
+
     /// Offset to LookupList table, from beginning of GPOS table
     pub fn lookup_list_offset(&self) -> Offset16 {
         let range = self.shape.lookup_list_offset_byte_range();
@@ -108,6 +122,13 @@ impl<'a> Gpos<'a> {
         self.lookup_list_offset().resolve(data)
     }
 
+    /// Like [`lookup_list`][Self::lookup_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn lookup_list_with_context(&self) -> Result<PositionLookupList<'a>, ReadErrorWithContext> {
+        self.lookup_list()
+            .with_offset_context(self.lookup_list_offset(), "lookup_list_offset")
+    }
+
     pub fn feature_variations_offset(&self) -> Option<Nullable<Offset32>> {
         let range = self.shape.feature_variations_offset_byte_range()?;
         Some(self.data.read_at(range.start).unwrap())
@@ -118,6 +139,17 @@ impl<'a> Gpos<'a> {
         let data = self.data;
         self.feature_variations_offset().map(|x| x.resolve(data))?
     }
+
+    /// Like [`feature_variations`][Self::feature_variations], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn feature_variations_with_context(
+        &self,
+    ) -> Option<Result<FeatureVariations<'a>, ReadErrorWithContext>> {
+        self.feature_variations().with_offset_context(
+            self.feature_variations_offset(),
+            "feature_variations_offset",
+        )
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -145,7 +177,7 @@ impl<'a> SomeTable<'a> for Gpos<'a> {
                 "feature_variations_offset",
                 FieldType::offset(
                     self.feature_variations_offset().unwrap(),
-                    self.feature_variations().unwrap(),
+                    self.feature_variations(),
                 ),
             )),
             _ => None,
@@ -925,6 +957,15 @@ impl<'a> AnchorFormat3<'a> {
         self.x_device_offset().resolve(data)
     }
 
+    /// Like [`x_device`][Self::x_device], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn x_device_with_context(
+        &self,
+    ) -> Option<Result<DeviceOrVariationIndex<'a>, ReadErrorWithContext>> {
+        self.x_device()
+            .with_offset_context(self.x_device_offset(), "x_device_offset")
+    }
+
     /// Offset to Device table (non-variable font) / VariationIndex
     /// table (variable font) for Y coordinate, from beginning of
     /// Anchor table (may be NULL)
@@ -938,6 +979,15 @@ impl<'a> AnchorFormat3<'a> {
         let data = self.data;
         self.y_device_offset().resolve(data)
     }
+
+    /// Like [`y_device`][Self::y_device], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn y_device_with_context(
+        &self,
+    ) -> Option<Result<DeviceOrVariationIndex<'a>, ReadErrorWithContext>> {
+        self.y_device()
+            .with_offset_context(self.y_device_offset(), "y_device_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -1077,6 +1127,16 @@ impl MarkRecord {
     pub fn mark_anchor<'a>(&self, data: FontData<'a>) -> Result<AnchorTable<'a>, ReadError> {
         self.mark_anchor_offset().resolve(data)
     }
+
+    /// Like [`mark_anchor`][Self::mark_anchor], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark_anchor_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<AnchorTable<'a>, ReadErrorWithContext> {
+        self.mark_anchor(data)
+            .with_offset_context(self.mark_anchor_offset(), "mark_anchor_offset")
+    }
 }
 
 impl FixedSize for MarkRecord {
@@ -1246,6 +1306,13 @@ impl<'a> SinglePosFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Defines the types of data in the ValueRecord.
     pub fn value_format(&self) -> ValueFormat {
         let range = self.shape.value_format_byte_range();
@@ -1364,6 +1431,13 @@ impl<'a> SinglePosFormat2<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Defines the types of data in the ValueRecords.
     pub fn value_format(&self) -> ValueFormat {
         let range = self.shape.value_format_byte_range();
@@ -1587,6 +1661,13 @@ impl<'a> PairPosFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Defines the types of data in valueRecord1 — for the first
     /// glyph in the pair (may be zero).
     pub fn value_format1(&self) -> ValueFormat {
@@ -1984,6 +2065,13 @@ impl<'a> PairPosFormat2<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// ValueRecord definition — for the first glyph of the pair (may
     /// be zero).
     pub fn value_format1(&self) -> ValueFormat {
@@ -2011,6 +2099,13 @@ impl<'a> PairPosFormat2<'a> {
         self.class_def1_offset().resolve(data)
     }
 
+    /// Like [`class_def1`][Self::class_def1], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn class_def1_with_context(&self) -> Result<ClassDef<'a>, ReadErrorWithContext> {
+        self.class_def1()
+            .with_offset_context(self.class_def1_offset(), "class_def1_offset")
+    }
+
     /// Offset to ClassDef table, from beginning of PairPos subtable
     /// — for the second glyph of the pair.
     pub fn class_def2_offset(&self) -> Offset16 {
@@ -2024,6 +2119,13 @@ impl<'a> PairPosFormat2<'a> {
         self.class_def2_offset().resolve(data)
     }
 
+    /// Like [`class_def2`][Self::class_def2], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn class_def2_with_context(&self) -> Result<ClassDef<'a>, ReadErrorWithContext> {
+        self.class_def2()
+            .with_offset_context(self.class_def2_offset(), "class_def2_offset")
+    }
+
     /// Number of classes in classDef1 table — includes Class 0.
     pub fn class1_count(&self) -> u16 {
         let range = self.shape.class1_count_byte_range();
@@ -2336,6 +2438,13 @@ impl<'a> CursivePosFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of EntryExit records
     pub fn entry_exit_count(&self) -> u16 {
         let range = self.shape.entry_exit_count_byte_range();
@@ -2414,6 +2523,16 @@ impl EntryExitRecord {
         self.entry_anchor_offset().resolve(data)
     }
 
+    /// Like [`entry_anchor`][Self::entry_anchor], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn entry_anchor_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<AnchorTable<'a>, ReadErrorWithContext>> {
+        self.entry_anchor(data)
+            .with_offset_context(self.entry_anchor_offset(), "entry_anchor_offset")
+    }
+
     /// Offset to exitAnchor table, from beginning of CursivePos
     /// subtable (may be NULL).
     pub fn exit_anchor_offset(&self) -> Nullable<Offset16> {
@@ -2431,6 +2550,16 @@ impl EntryExitRecord {
     ) -> Option<Result<AnchorTable<'a>, ReadError>> {
         self.exit_anchor_offset().resolve(data)
     }
+
+    /// Like [`exit_anchor`][Self::exit_anchor], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn exit_anchor_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<AnchorTable<'a>, ReadErrorWithContext>> {
+        self.exit_anchor(data)
+            .with_offset_context(self.exit_anchor_offset(), "exit_anchor_offset")
+    }
 }
 
 impl FixedSize for EntryExitRecord {
@@ -2530,6 +2659,13 @@ impl<'a> MarkBasePosFormat1<'a> {
         self.mark_coverage_offset().resolve(data)
     }
 
+    /// Like [`mark_coverage`][Self::mark_coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark_coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.mark_coverage()
+            .with_offset_context(self.mark_coverage_offset(), "mark_coverage_offset")
+    }
+
     /// Offset to baseCoverage table, from beginning of MarkBasePos
     /// subtable.
     pub fn base_coverage_offset(&self) -> Offset16 {
@@ -2543,6 +2679,13 @@ impl<'a> MarkBasePosFormat1<'a> {
         self.base_coverage_offset().resolve(data)
     }
 
+    /// Like [`base_coverage`][Self::base_coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.base_coverage()
+            .with_offset_context(self.base_coverage_offset(), "base_coverage_offset")
+    }
+
     /// Number of classes defined for marks
     pub fn mark_class_count(&self) -> u16 {
         let range = self.shape.mark_class_count_byte_range();
@@ -2562,6 +2705,13 @@ impl<'a> MarkBasePosFormat1<'a> {
         self.mark_array_offset().resolve(data)
     }
 
+    /// Like [`mark_array`][Self::mark_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark_array_with_context(&self) -> Result<MarkArray<'a>, ReadErrorWithContext> {
+        self.mark_array()
+            .with_offset_context(self.mark_array_offset(), "mark_array_offset")
+    }
+
     /// Offset to BaseArray table, from beginning of MarkBasePos
     /// subtable.
     pub fn base_array_offset(&self) -> Offset16 {
@@ -2575,6 +2725,13 @@ impl<'a> MarkBasePosFormat1<'a> {
         let args = self.mark_class_count();
         self.base_array_offset().resolve_with_args(data, &args)
     }
+
+    /// Like [`base_array`][Self::base_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn base_array_with_context(&self) -> Result<BaseArray<'a>, ReadErrorWithContext> {
+        self.base_array()
+            .with_offset_context(self.base_array_offset(), "base_array_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -2882,6 +3039,13 @@ impl<'a> MarkLigPosFormat1<'a> {
         self.mark_coverage_offset().resolve(data)
     }
 
+    /// Like [`mark_coverage`][Self::mark_coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark_coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.mark_coverage()
+            .with_offset_context(self.mark_coverage_offset(), "mark_coverage_offset")
+    }
+
     /// Offset to ligatureCoverage table, from beginning of MarkLigPos
     /// subtable.
     pub fn ligature_coverage_offset(&self) -> Offset16 {
@@ -2895,6 +3059,15 @@ impl<'a> MarkLigPosFormat1<'a> {
         self.ligature_coverage_offset().resolve(data)
     }
 
+    /// Like [`ligature_coverage`][Self::ligature_coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn ligature_coverage_with_context(
+        &self,
+    ) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.ligature_coverage()
+            .with_offset_context(self.ligature_coverage_offset(), "ligature_coverage_offset")
+    }
+
     /// Number of defined mark classes
     pub fn mark_class_count(&self) -> u16 {
         let range = self.shape.mark_class_count_byte_range();
@@ -2914,6 +3087,13 @@ impl<'a> MarkLigPosFormat1<'a> {
         self.mark_array_offset().resolve(data)
     }
 
+    /// Like [`mark_array`][Self::mark_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark_array_with_context(&self) -> Result<MarkArray<'a>, ReadErrorWithContext> {
+        self.mark_array()
+            .with_offset_context(self.mark_array_offset(), "mark_array_offset")
+    }
+
     /// Offset to LigatureArray table, from beginning of MarkLigPos
     /// subtable.
     pub fn ligature_array_offset(&self) -> Offset16 {
@@ -2927,6 +3107,13 @@ impl<'a> MarkLigPosFormat1<'a> {
         let args = self.mark_class_count();
         self.ligature_array_offset().resolve_with_args(data, &args)
     }
+
+    /// Like [`ligature_array`][Self::ligature_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn ligature_array_with_context(&self) -> Result<LigatureArray<'a>, ReadErrorWithContext> {
+        self.ligature_array()
+            .with_offset_context(self.ligature_array_offset(), "ligature_array_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -3350,6 +3537,13 @@ impl<'a> MarkMarkPosFormat1<'a> {
         self.mark1_coverage_offset().resolve(data)
     }
 
+    /// Like [`mark1_coverage`][Self::mark1_coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark1_coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.mark1_coverage()
+            .with_offset_context(self.mark1_coverage_offset(), "mark1_coverage_offset")
+    }
+
     /// Offset to Base Mark Coverage table, from beginning of
     /// MarkMarkPos subtable.
     pub fn mark2_coverage_offset(&self) -> Offset16 {
@@ -3363,6 +3557,13 @@ impl<'a> MarkMarkPosFormat1<'a> {
         self.mark2_coverage_offset().resolve(data)
     }
 
+    /// Like [`mark2_coverage`][Self::mark2_coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark2_coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.mark2_coverage()
+            .with_offset_context(self.mark2_coverage_offset(), "mark2_coverage_offset")
+    }
+
     /// Number of Combining Mark classes defined
     pub fn mark_class_count(&self) -> u16 {
         let range = self.shape.mark_class_count_byte_range();
@@ -3382,6 +3583,13 @@ impl<'a> MarkMarkPosFormat1<'a> {
         self.mark1_array_offset().resolve(data)
     }
 
+    /// Like [`mark1_array`][Self::mark1_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark1_array_with_context(&self) -> Result<MarkArray<'a>, ReadErrorWithContext> {
+        self.mark1_array()
+            .with_offset_context(self.mark1_array_offset(), "mark1_array_offset")
+    }
+
     /// Offset to Mark2Array table for mark2, from beginning of
     /// MarkMarkPos subtable.
     pub fn mark2_array_offset(&self) -> Offset16 {
@@ -3395,6 +3603,13 @@ impl<'a> MarkMarkPosFormat1<'a> {
         let args = self.mark_class_count();
         self.mark2_array_offset().resolve_with_args(data, &args)
     }
+
+    /// Like [`mark2_array`][Self::mark2_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn mark2_array_with_context(&self) -> Result<Mark2Array<'a>, ReadErrorWithContext> {
+        self.mark2_array()
+            .with_offset_context(self.mark2_array_offset(), "mark2_array_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -3736,6 +3951,16 @@ impl<'a, T> ExtensionPosFormat1<'a, T> {
         let data = self.data;
         self.extension_offset().resolve(data)
     }
+
+    /// Like [`extension`][Self::extension], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn extension_with_context(&self) -> Result<T, ReadErrorWithContext>
+    where
+        T: FontRead<'a>,
+    {
+        self.extension()
+            .with_offset_context(self.extension_offset(), "extension_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -82,6 +82,13 @@ impl<'a> Gsub<'a> {
         self.script_list_offset().resolve(data)
     }
 
+    /// Like [`script_list`][Self::script_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn script_list_with_context(&self) -> Result<ScriptList<'a>, ReadErrorWithContext> {
+        self.script_list()
+            .with_offset_context(self.script_list_offset(), "script_list_offset")
+    }
+
     /// Offset to FeatureList table, from beginning of GSUB table
     pub fn feature_list_offset(&self) -> Offset16 {
         let range = self.shape.feature_list_offset_byte_range();
@@ -94,6 +101,13 @@ impl<'a> Gsub<'a> {
         self.feature_list_offset().resolve(data)
     }
 
+    /// Like [`feature_list`][Self::feature_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn feature_list_with_context(&self) -> Result<FeatureList<'a>, ReadErrorWithContext> {
+        self.feature_list()
+            .with_offset_context(self.feature_list_offset(), "feature_list_offset")
+    }
+
     /// Offset to LookupList table, from beginning of GSUB table
     pub fn lookup_list_offset(&self) -> Offset16 {
         let range = self.shape.lookup_list_offset_byte_range();
@@ -106,6 +120,15 @@ impl<'a> Gsub<'a> {
         self.lookup_list_offset().resolve(data)
     }
 
+    /// Like [`lookup_list`][Self::lookup_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn lookup_list_with_context(
+        &self,
+    ) -> Result<SubstitutionLookupList<'a>, ReadErrorWithContext> {
+        self.lookup_list()
+            .with_offset_context(self.lookup_list_offset(), "lookup_list_offset")
+    }
+
     /// Offset to FeatureVariations table, from beginning of the GSUB
     /// table (may be NULL)
     pub fn feature_variations_offset(&self) -> Option<Nullable<Offset32>> {
@@ -118,6 +141,17 @@ impl<'a> Gsub<'a> {
         let data = self.data;
         self.feature_variations_offset().map(|x| x.resolve(data))?
     }
+
+    /// Like [`feature_variations`][Self::feature_variations], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn feature_variations_with_context(
+        &self,
+    ) -> Option<Result<FeatureVariations<'a>, ReadErrorWithContext>> {
+        self.feature_variations().with_offset_context(
+            self.feature_variations_offset(),
+            "feature_variations_offset",
+        )
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -145,7 +179,7 @@ impl<'a> SomeTable<'a> for Gsub<'a> {
                 "feature_variations_offset",
                 FieldType::offset(
                     self.feature_variations_offset().unwrap(),
-                    self.feature_variations().unwrap(),
+                    self.feature_variations(),
                 ),
             )),
             _ => None,
@@ -370,6 +404,13 @@ impl<'a> SingleSubstFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Add to original glyph ID to get substitute glyph ID
     pub fn delta_glyph_id(&self) -> i16 {
         let range = self.shape.delta_glyph_id_byte_range();
@@ -471,6 +512,13 @@ impl<'a> SingleSubstFormat2<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of glyph IDs in the substituteGlyphIDs array
     pub fn glyph_count(&self) -> u16 {
         let range = self.shape.glyph_count_byte_range();
@@ -582,6 +630,13 @@ impl<'a> MultipleSubstFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of Sequence table offsets in the sequenceOffsets array
     pub fn sequence_count(&self) -> u16 {
         let range = self.shape.sequence_count_byte_range();
@@ -785,6 +840,13 @@ impl<'a> AlternateSubstFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of AlternateSet tables
     pub fn alternate_set_count(&self) -> u16 {
         let range = self.shape.alternate_set_count_byte_range();
@@ -990,6 +1052,13 @@ impl<'a> LigatureSubstFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of LigatureSet tables
     pub fn ligature_set_count(&self) -> u16 {
         let range = self.shape.ligature_set_count_byte_range();
@@ -1333,6 +1402,16 @@ impl<'a, T> ExtensionSubstFormat1<'a, T> {
         let data = self.data;
         self.extension_offset().resolve(data)
     }
+
+    /// Like [`extension`][Self::extension], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn extension_with_context(&self) -> Result<T, ReadErrorWithContext>
+    where
+        T: FontRead<'a>,
+    {
+        self.extension()
+            .with_offset_context(self.extension_offset(), "extension_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -1539,6 +1618,13 @@ impl<'a> ReverseChainSingleSubstFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of glyphs in the backtrack sequence.
     pub fn backtrack_glyph_count(&self) -> u16 {
         let range = self.shape.backtrack_glyph_count_byte_range();
@@ -111,6 +111,13 @@ impl<'a> Gvar<'a> {
         self.shared_tuples_offset().resolve_with_args(data, &args)
     }
 
+    /// Like [`shared_tuples`][Self::shared_tuples], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn shared_tuples_with_context(&self) -> Result<SharedTuples<'a>, ReadErrorWithContext> {
+        self.shared_tuples()
+            .with_offset_context(self.shared_tuples_offset(), "shared_tuples_offset")
+    }
+
     /// The number of glyphs in this font. This must match the number
     /// of glyphs stored elsewhere in the font.
     pub fn glyph_count(&self) -> u16 {
@@ -625,10 +632,17 @@ impl<'a> GlyphVariationDataHeader<'a> {
         self.serialized_data_offset().resolve(data)
     }
 
+    /// Like [`serialized_data`][Self::serialized_data], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn serialized_data_with_context(&self) -> Result<FontData<'a>, ReadErrorWithContext> {
+        self.serialized_data()
+            .with_offset_context(self.serialized_data_offset(), "serialized_data_offset")
+    }
+
     /// Array of tuple variation headers.
     pub fn tuple_variation_headers(&self) -> VarLenArray<'a, TupleVariationHeader> {
         let range = self.shape.tuple_variation_headers_byte_range();
-        VarLenArray::read(self.data.split_off(range.start).unwrap()).unwrap()
+        VarLenArray::read(self.data.slice(range).unwrap()).unwrap()
     }
 }
 
@@ -73,6 +73,17 @@ impl<'a> Hvar<'a> {
         self.item_variation_store_offset().resolve(data)
     }
 
+    /// Like [`item_variation_store`][Self::item_variation_store], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn item_variation_store_with_context(
+        &self,
+    ) -> Result<ItemVariationStore<'a>, ReadErrorWithContext> {
+        self.item_variation_store().with_offset_context(
+            self.item_variation_store_offset(),
+            "item_variation_store_offset",
+        )
+    }
+
     /// Offset in bytes from the start of this table to the delta-set index mapping for advance widths (may be NULL).
     pub fn advance_width_mapping_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.advance_width_mapping_offset_byte_range();
@@ -85,6 +96,17 @@ impl<'a> Hvar<'a> {
         self.advance_width_mapping_offset().resolve(data)
     }
 
+    /// Like [`advance_width_mapping`][Self::advance_width_mapping], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn advance_width_mapping_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.advance_width_mapping().with_offset_context(
+            self.advance_width_mapping_offset(),
+            "advance_width_mapping_offset",
+        )
+    }
+
     /// Offset in bytes from the start of this table to the delta-set index mapping for left side bearings (may be NULL).
     pub fn lsb_mapping_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.lsb_mapping_offset_byte_range();
@@ -97,6 +119,15 @@ impl<'a> Hvar<'a> {
         self.lsb_mapping_offset().resolve(data)
     }
 
+    /// Like [`lsb_mapping`][Self::lsb_mapping], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn lsb_mapping_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.lsb_mapping()
+            .with_offset_context(self.lsb_mapping_offset(), "lsb_mapping_offset")
+    }
+
     /// Offset in bytes from the start of this table to the delta-set index mapping for right side bearings (may be NULL).
     pub fn rsb_mapping_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.rsb_mapping_offset_byte_range();
@@ -108,6 +139,15 @@ impl<'a> Hvar<'a> {
         let data = self.data;
         self.rsb_mapping_offset().resolve(data)
     }
+
+    /// Like [`rsb_mapping`][Self::rsb_mapping], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn rsb_mapping_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.rsb_mapping()
+            .with_offset_context(self.rsb_mapping_offset(), "rsb_mapping_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
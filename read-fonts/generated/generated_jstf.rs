@@ -0,0 +1,1097 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [Justification](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfMarker {
+    jstf_script_records_byte_len: usize,
+}
+
+impl JstfMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + MajorMinor::RAW_BYTE_LEN
+    }
+    fn jstf_script_count_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn jstf_script_records_byte_range(&self) -> Range<usize> {
+        let start = self.jstf_script_count_byte_range().end;
+        start..start + self.jstf_script_records_byte_len
+    }
+}
+
+impl TopLevelTable for Jstf<'_> {
+    /// `JSTF`
+    const TAG: Tag = Tag::new(b"JSTF");
+}
+
+impl<'a> FontRead<'a> for Jstf<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<MajorMinor>();
+        let jstf_script_count: u16 = cursor.read()?;
+        let jstf_script_records_byte_len = (jstf_script_count as usize)
+            .checked_mul(JstfScriptRecord::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(jstf_script_records_byte_len);
+        cursor.finish(JstfMarker {
+            jstf_script_records_byte_len,
+        })
+    }
+}
+
+/// The [Justification](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) table
+pub type Jstf<'a> = TableRef<'a, JstfMarker>;
+
+impl<'a> Jstf<'a> {
+    /// Major/minor version of the JSTF table, = 1.0.
+    pub fn version(&self) -> MajorMinor {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of JstfScriptRecords in this table
+    pub fn jstf_script_count(&self) -> u16 {
+        let range = self.shape.jstf_script_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of JstfScriptRecords, in alphabetical order by jstfScriptTag
+    pub fn jstf_script_records(&self) -> &'a [JstfScriptRecord] {
+        let range = self.shape.jstf_script_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Jstf<'a> {
+    fn type_name(&self) -> &str {
+        "Jstf"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("version", self.version())),
+            1usize => Some(Field::new("jstf_script_count", self.jstf_script_count())),
+            2usize => Some(Field::new(
+                "jstf_script_records",
+                traversal::FieldType::array_of_records(
+                    stringify!(JstfScriptRecord),
+                    self.jstf_script_records(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Jstf<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfScriptRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#jstf-header)
+#[derive(Clone, Debug, Copy, bytemuck :: AnyBitPattern)]
+#[repr(C)]
+#[repr(packed)]
+pub struct JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    pub jstf_script_tag: BigEndian<Tag>,
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    pub jstf_script_offset: BigEndian<Offset16>,
+}
+
+impl JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    pub fn jstf_script_tag(&self) -> Tag {
+        self.jstf_script_tag.get()
+    }
+
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    pub fn jstf_script_offset(&self) -> Offset16 {
+        self.jstf_script_offset.get()
+    }
+
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    ///
+    /// The `data` argument should be retrieved from the parent table
+    /// By calling its `offset_data` method.
+    pub fn jstf_script<'a>(&self, data: FontData<'a>) -> Result<JstfScript<'a>, ReadError> {
+        self.jstf_script_offset().resolve(data)
+    }
+
+    /// Like [`jstf_script`][Self::jstf_script], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn jstf_script_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<JstfScript<'a>, ReadErrorWithContext> {
+        self.jstf_script(data)
+            .with_offset_context(self.jstf_script_offset(), "jstf_script_offset")
+    }
+}
+
+impl FixedSize for JstfScriptRecord {
+    const RAW_BYTE_LEN: usize = Tag::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for JstfScriptRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "JstfScriptRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("jstf_script_tag", self.jstf_script_tag())),
+                1usize => Some(Field::new(
+                    "jstf_script_offset",
+                    FieldType::offset(self.jstf_script_offset(), self.jstf_script(_data)),
+                )),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [JstfScript](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-script-table) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfScriptMarker {
+    jstf_lang_sys_records_byte_len: usize,
+}
+
+impl JstfScriptMarker {
+    fn extender_glyph_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn def_jstf_lang_sys_offset_byte_range(&self) -> Range<usize> {
+        let start = self.extender_glyph_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn jstf_lang_sys_count_byte_range(&self) -> Range<usize> {
+        let start = self.def_jstf_lang_sys_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn jstf_lang_sys_records_byte_range(&self) -> Range<usize> {
+        let start = self.jstf_lang_sys_count_byte_range().end;
+        start..start + self.jstf_lang_sys_records_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for JstfScript<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        let jstf_lang_sys_count: u16 = cursor.read()?;
+        let jstf_lang_sys_records_byte_len = (jstf_lang_sys_count as usize)
+            .checked_mul(JstfLangSysRecord::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(jstf_lang_sys_records_byte_len);
+        cursor.finish(JstfScriptMarker {
+            jstf_lang_sys_records_byte_len,
+        })
+    }
+}
+
+/// [JstfScript](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-script-table) table
+pub type JstfScript<'a> = TableRef<'a, JstfScriptMarker>;
+
+impl<'a> JstfScript<'a> {
+    /// Offset to ExtenderGlyph table, from beginning of JstfScript
+    /// table (may be NULL)
+    pub fn extender_glyph_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.extender_glyph_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`extender_glyph_offset`][Self::extender_glyph_offset].
+    pub fn extender_glyph(&self) -> Option<Result<ExtenderGlyph<'a>, ReadError>> {
+        let data = self.data;
+        self.extender_glyph_offset().resolve(data)
+    }
+
+    /// Like [`extender_glyph`][Self::extender_glyph], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn extender_glyph_with_context(
+        &self,
+    ) -> Option<Result<ExtenderGlyph<'a>, ReadErrorWithContext>> {
+        self.extender_glyph()
+            .with_offset_context(self.extender_glyph_offset(), "extender_glyph_offset")
+    }
+
+    /// Offset to default JstfLangSys table, from beginning of
+    /// JstfScript table (may be NULL)
+    pub fn def_jstf_lang_sys_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.def_jstf_lang_sys_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`def_jstf_lang_sys_offset`][Self::def_jstf_lang_sys_offset].
+    pub fn def_jstf_lang_sys(&self) -> Option<Result<JstfLangSys<'a>, ReadError>> {
+        let data = self.data;
+        self.def_jstf_lang_sys_offset().resolve(data)
+    }
+
+    /// Like [`def_jstf_lang_sys`][Self::def_jstf_lang_sys], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn def_jstf_lang_sys_with_context(
+        &self,
+    ) -> Option<Result<JstfLangSys<'a>, ReadErrorWithContext>> {
+        self.def_jstf_lang_sys()
+            .with_offset_context(self.def_jstf_lang_sys_offset(), "def_jstf_lang_sys_offset")
+    }
+
+    /// Number of JstfLangSysRecords in this table — may be zero (0)
+    pub fn jstf_lang_sys_count(&self) -> u16 {
+        let range = self.shape.jstf_lang_sys_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of JstfLangSysRecords, in alphabetical order by
+    /// JstfLangSysTag
+    pub fn jstf_lang_sys_records(&self) -> &'a [JstfLangSysRecord] {
+        let range = self.shape.jstf_lang_sys_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfScript<'a> {
+    fn type_name(&self) -> &str {
+        "JstfScript"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "extender_glyph_offset",
+                FieldType::offset(self.extender_glyph_offset(), self.extender_glyph()),
+            )),
+            1usize => Some(Field::new(
+                "def_jstf_lang_sys_offset",
+                FieldType::offset(self.def_jstf_lang_sys_offset(), self.def_jstf_lang_sys()),
+            )),
+            2usize => Some(Field::new(
+                "jstf_lang_sys_count",
+                self.jstf_lang_sys_count(),
+            )),
+            3usize => Some(Field::new(
+                "jstf_lang_sys_records",
+                traversal::FieldType::array_of_records(
+                    stringify!(JstfLangSysRecord),
+                    self.jstf_lang_sys_records(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfScript<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfLangSysRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-script-table)
+#[derive(Clone, Debug, Copy, bytemuck :: AnyBitPattern)]
+#[repr(C)]
+#[repr(packed)]
+pub struct JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    pub jstf_lang_sys_tag: BigEndian<Tag>,
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    pub jstf_lang_sys_offset: BigEndian<Offset16>,
+}
+
+impl JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    pub fn jstf_lang_sys_tag(&self) -> Tag {
+        self.jstf_lang_sys_tag.get()
+    }
+
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    pub fn jstf_lang_sys_offset(&self) -> Offset16 {
+        self.jstf_lang_sys_offset.get()
+    }
+
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    ///
+    /// The `data` argument should be retrieved from the parent table
+    /// By calling its `offset_data` method.
+    pub fn jstf_lang_sys<'a>(&self, data: FontData<'a>) -> Result<JstfLangSys<'a>, ReadError> {
+        self.jstf_lang_sys_offset().resolve(data)
+    }
+
+    /// Like [`jstf_lang_sys`][Self::jstf_lang_sys], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn jstf_lang_sys_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<JstfLangSys<'a>, ReadErrorWithContext> {
+        self.jstf_lang_sys(data)
+            .with_offset_context(self.jstf_lang_sys_offset(), "jstf_lang_sys_offset")
+    }
+}
+
+impl FixedSize for JstfLangSysRecord {
+    const RAW_BYTE_LEN: usize = Tag::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for JstfLangSysRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "JstfLangSysRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("jstf_lang_sys_tag", self.jstf_lang_sys_tag())),
+                1usize => Some(Field::new(
+                    "jstf_lang_sys_offset",
+                    FieldType::offset(self.jstf_lang_sys_offset(), self.jstf_lang_sys(_data)),
+                )),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// [ExtenderGlyph](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct ExtenderGlyphMarker {
+    extender_glyphs_byte_len: usize,
+}
+
+impl ExtenderGlyphMarker {
+    fn glyph_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn extender_glyphs_byte_range(&self) -> Range<usize> {
+        let start = self.glyph_count_byte_range().end;
+        start..start + self.extender_glyphs_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for ExtenderGlyph<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let glyph_count: u16 = cursor.read()?;
+        let extender_glyphs_byte_len = (glyph_count as usize)
+            .checked_mul(GlyphId16::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(extender_glyphs_byte_len);
+        cursor.finish(ExtenderGlyphMarker {
+            extender_glyphs_byte_len,
+        })
+    }
+}
+
+/// [ExtenderGlyph](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table) table
+pub type ExtenderGlyph<'a> = TableRef<'a, ExtenderGlyphMarker>;
+
+impl<'a> ExtenderGlyph<'a> {
+    /// Number of extender glyphs in this script
+    pub fn glyph_count(&self) -> u16 {
+        let range = self.shape.glyph_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Extender glyph IDs — in increasing numerical order
+    pub fn extender_glyphs(&self) -> &'a [BigEndian<GlyphId16>] {
+        let range = self.shape.extender_glyphs_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for ExtenderGlyph<'a> {
+    fn type_name(&self) -> &str {
+        "ExtenderGlyph"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("glyph_count", self.glyph_count())),
+            1usize => Some(Field::new("extender_glyphs", self.extender_glyphs())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for ExtenderGlyph<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfLangSys](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-language-system-table) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfLangSysMarker {
+    jstf_priority_offsets_byte_len: usize,
+}
+
+impl JstfLangSysMarker {
+    fn jstf_priority_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn jstf_priority_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.jstf_priority_count_byte_range().end;
+        start..start + self.jstf_priority_offsets_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for JstfLangSys<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let jstf_priority_count: u16 = cursor.read()?;
+        let jstf_priority_offsets_byte_len = (jstf_priority_count as usize)
+            .checked_mul(Offset16::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(jstf_priority_offsets_byte_len);
+        cursor.finish(JstfLangSysMarker {
+            jstf_priority_offsets_byte_len,
+        })
+    }
+}
+
+/// [JstfLangSys](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-language-system-table) table
+pub type JstfLangSys<'a> = TableRef<'a, JstfLangSysMarker>;
+
+impl<'a> JstfLangSys<'a> {
+    /// Number of JstfPriority tables
+    pub fn jstf_priority_count(&self) -> u16 {
+        let range = self.shape.jstf_priority_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of offsets to JstfPriority tables, from beginning of
+    /// JstfLangSys table, in priority order
+    pub fn jstf_priority_offsets(&self) -> &'a [BigEndian<Offset16>] {
+        let range = self.shape.jstf_priority_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// A dynamically resolving wrapper for [`jstf_priority_offsets`][Self::jstf_priority_offsets].
+    pub fn jstf_priorities(&self) -> ArrayOfOffsets<'a, JstfPriority<'a>, Offset16> {
+        let data = self.data;
+        let offsets = self.jstf_priority_offsets();
+        ArrayOfOffsets::new(offsets, data, ())
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfLangSys<'a> {
+    fn type_name(&self) -> &str {
+        "JstfLangSys"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "jstf_priority_count",
+                self.jstf_priority_count(),
+            )),
+            1usize => Some({
+                let data = self.data;
+                Field::new(
+                    "jstf_priority_offsets",
+                    FieldType::array_of_offsets(
+                        better_type_name::<JstfPriority>(),
+                        self.jstf_priority_offsets(),
+                        move |off| {
+                            let target = off.get().resolve::<JstfPriority>(data);
+                            FieldType::offset(off.get(), target)
+                        },
+                    ),
+                )
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfLangSys<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfPriority](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-priority-table) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfPriorityMarker {}
+
+impl JstfPriorityMarker {
+    fn gsub_shrinkage_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gsub_shrinkage_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_shrinkage_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_shrinkage_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_shrinkage_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_shrinkage_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_shrinkage_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn shrinkage_jstf_max_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_shrinkage_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gsub_extension_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.shrinkage_jstf_max_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gsub_extension_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_extension_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_extension_enable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gsub_extension_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn gpos_extension_disable_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_extension_enable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn extension_jstf_max_offset_byte_range(&self) -> Range<usize> {
+        let start = self.gpos_extension_disable_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for JstfPriority<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.finish(JstfPriorityMarker {})
+    }
+}
+
+/// [JstfPriority](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-priority-table) table
+pub type JstfPriority<'a> = TableRef<'a, JstfPriorityMarker>;
+
+impl<'a> JstfPriority<'a> {
+    /// Offset to shrinkage-enable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gsub_shrinkage_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_shrinkage_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_shrinkage_enable_offset`][Self::gsub_shrinkage_enable_offset].
+    pub fn gsub_shrinkage_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_shrinkage_enable_offset().resolve(data)
+    }
+
+    /// Like [`gsub_shrinkage_enable`][Self::gsub_shrinkage_enable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gsub_shrinkage_enable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gsub_shrinkage_enable().with_offset_context(
+            self.gsub_shrinkage_enable_offset(),
+            "gsub_shrinkage_enable_offset",
+        )
+    }
+
+    /// Offset to shrinkage-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gsub_shrinkage_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_shrinkage_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_shrinkage_disable_offset`][Self::gsub_shrinkage_disable_offset].
+    pub fn gsub_shrinkage_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_shrinkage_disable_offset().resolve(data)
+    }
+
+    /// Like [`gsub_shrinkage_disable`][Self::gsub_shrinkage_disable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gsub_shrinkage_disable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gsub_shrinkage_disable().with_offset_context(
+            self.gsub_shrinkage_disable_offset(),
+            "gsub_shrinkage_disable_offset",
+        )
+    }
+
+    /// Offset to shrinkage-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_shrinkage_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_shrinkage_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_shrinkage_enable_offset`][Self::gpos_shrinkage_enable_offset].
+    pub fn gpos_shrinkage_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_shrinkage_enable_offset().resolve(data)
+    }
+
+    /// Like [`gpos_shrinkage_enable`][Self::gpos_shrinkage_enable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gpos_shrinkage_enable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gpos_shrinkage_enable().with_offset_context(
+            self.gpos_shrinkage_enable_offset(),
+            "gpos_shrinkage_enable_offset",
+        )
+    }
+
+    /// Offset to shrinkage-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_shrinkage_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_shrinkage_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_shrinkage_disable_offset`][Self::gpos_shrinkage_disable_offset].
+    pub fn gpos_shrinkage_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_shrinkage_disable_offset().resolve(data)
+    }
+
+    /// Like [`gpos_shrinkage_disable`][Self::gpos_shrinkage_disable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gpos_shrinkage_disable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gpos_shrinkage_disable().with_offset_context(
+            self.gpos_shrinkage_disable_offset(),
+            "gpos_shrinkage_disable_offset",
+        )
+    }
+
+    /// Offset to shrinkage JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub fn shrinkage_jstf_max_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.shrinkage_jstf_max_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`shrinkage_jstf_max_offset`][Self::shrinkage_jstf_max_offset].
+    pub fn shrinkage_jstf_max(&self) -> Option<Result<JstfMax<'a>, ReadError>> {
+        let data = self.data;
+        self.shrinkage_jstf_max_offset().resolve(data)
+    }
+
+    /// Like [`shrinkage_jstf_max`][Self::shrinkage_jstf_max], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn shrinkage_jstf_max_with_context(
+        &self,
+    ) -> Option<Result<JstfMax<'a>, ReadErrorWithContext>> {
+        self.shrinkage_jstf_max().with_offset_context(
+            self.shrinkage_jstf_max_offset(),
+            "shrinkage_jstf_max_offset",
+        )
+    }
+
+    /// Offset to extension-enable JstfGSUBModList table, from
+    /// beginnning of JstfPriority table (may be NULL)
+    pub fn gsub_extension_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_extension_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_extension_enable_offset`][Self::gsub_extension_enable_offset].
+    pub fn gsub_extension_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_extension_enable_offset().resolve(data)
+    }
+
+    /// Like [`gsub_extension_enable`][Self::gsub_extension_enable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gsub_extension_enable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gsub_extension_enable().with_offset_context(
+            self.gsub_extension_enable_offset(),
+            "gsub_extension_enable_offset",
+        )
+    }
+
+    /// Offset to extension-disable JstfGSUBModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gsub_extension_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gsub_extension_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gsub_extension_disable_offset`][Self::gsub_extension_disable_offset].
+    pub fn gsub_extension_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gsub_extension_disable_offset().resolve(data)
+    }
+
+    /// Like [`gsub_extension_disable`][Self::gsub_extension_disable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gsub_extension_disable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gsub_extension_disable().with_offset_context(
+            self.gsub_extension_disable_offset(),
+            "gsub_extension_disable_offset",
+        )
+    }
+
+    /// Offset to extension-enable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_extension_enable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_extension_enable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_extension_enable_offset`][Self::gpos_extension_enable_offset].
+    pub fn gpos_extension_enable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_extension_enable_offset().resolve(data)
+    }
+
+    /// Like [`gpos_extension_enable`][Self::gpos_extension_enable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gpos_extension_enable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gpos_extension_enable().with_offset_context(
+            self.gpos_extension_enable_offset(),
+            "gpos_extension_enable_offset",
+        )
+    }
+
+    /// Offset to extension-disable JstfGPOSModList table, from
+    /// beginning of JstfPriority table (may be NULL)
+    pub fn gpos_extension_disable_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.gpos_extension_disable_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`gpos_extension_disable_offset`][Self::gpos_extension_disable_offset].
+    pub fn gpos_extension_disable(&self) -> Option<Result<JstfModList<'a>, ReadError>> {
+        let data = self.data;
+        self.gpos_extension_disable_offset().resolve(data)
+    }
+
+    /// Like [`gpos_extension_disable`][Self::gpos_extension_disable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn gpos_extension_disable_with_context(
+        &self,
+    ) -> Option<Result<JstfModList<'a>, ReadErrorWithContext>> {
+        self.gpos_extension_disable().with_offset_context(
+            self.gpos_extension_disable_offset(),
+            "gpos_extension_disable_offset",
+        )
+    }
+
+    /// Offset to extension JstfMax table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub fn extension_jstf_max_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.extension_jstf_max_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`extension_jstf_max_offset`][Self::extension_jstf_max_offset].
+    pub fn extension_jstf_max(&self) -> Option<Result<JstfMax<'a>, ReadError>> {
+        let data = self.data;
+        self.extension_jstf_max_offset().resolve(data)
+    }
+
+    /// Like [`extension_jstf_max`][Self::extension_jstf_max], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn extension_jstf_max_with_context(
+        &self,
+    ) -> Option<Result<JstfMax<'a>, ReadErrorWithContext>> {
+        self.extension_jstf_max().with_offset_context(
+            self.extension_jstf_max_offset(),
+            "extension_jstf_max_offset",
+        )
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfPriority<'a> {
+    fn type_name(&self) -> &str {
+        "JstfPriority"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new(
+                "gsub_shrinkage_enable_offset",
+                FieldType::offset(
+                    self.gsub_shrinkage_enable_offset(),
+                    self.gsub_shrinkage_enable(),
+                ),
+            )),
+            1usize => Some(Field::new(
+                "gsub_shrinkage_disable_offset",
+                FieldType::offset(
+                    self.gsub_shrinkage_disable_offset(),
+                    self.gsub_shrinkage_disable(),
+                ),
+            )),
+            2usize => Some(Field::new(
+                "gpos_shrinkage_enable_offset",
+                FieldType::offset(
+                    self.gpos_shrinkage_enable_offset(),
+                    self.gpos_shrinkage_enable(),
+                ),
+            )),
+            3usize => Some(Field::new(
+                "gpos_shrinkage_disable_offset",
+                FieldType::offset(
+                    self.gpos_shrinkage_disable_offset(),
+                    self.gpos_shrinkage_disable(),
+                ),
+            )),
+            4usize => Some(Field::new(
+                "shrinkage_jstf_max_offset",
+                FieldType::offset(self.shrinkage_jstf_max_offset(), self.shrinkage_jstf_max()),
+            )),
+            5usize => Some(Field::new(
+                "gsub_extension_enable_offset",
+                FieldType::offset(
+                    self.gsub_extension_enable_offset(),
+                    self.gsub_extension_enable(),
+                ),
+            )),
+            6usize => Some(Field::new(
+                "gsub_extension_disable_offset",
+                FieldType::offset(
+                    self.gsub_extension_disable_offset(),
+                    self.gsub_extension_disable(),
+                ),
+            )),
+            7usize => Some(Field::new(
+                "gpos_extension_enable_offset",
+                FieldType::offset(
+                    self.gpos_extension_enable_offset(),
+                    self.gpos_extension_enable(),
+                ),
+            )),
+            8usize => Some(Field::new(
+                "gpos_extension_disable_offset",
+                FieldType::offset(
+                    self.gpos_extension_disable_offset(),
+                    self.gpos_extension_disable(),
+                ),
+            )),
+            9usize => Some(Field::new(
+                "extension_jstf_max_offset",
+                FieldType::offset(self.extension_jstf_max_offset(), self.extension_jstf_max()),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfPriority<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables)
+/// and [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables)
+/// tables
+///
+/// These tables share the same layout. The lookup indices refer to the
+/// GSUB or GPOS LookupList, depending on the referencing field.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfModListMarker {
+    lookup_indices_byte_len: usize,
+}
+
+impl JstfModListMarker {
+    fn lookup_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn lookup_indices_byte_range(&self) -> Range<usize> {
+        let start = self.lookup_count_byte_range().end;
+        start..start + self.lookup_indices_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for JstfModList<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let lookup_count: u16 = cursor.read()?;
+        let lookup_indices_byte_len = (lookup_count as usize)
+            .checked_mul(u16::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(lookup_indices_byte_len);
+        cursor.finish(JstfModListMarker {
+            lookup_indices_byte_len,
+        })
+    }
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables)
+/// and [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables)
+/// tables
+///
+/// These tables share the same layout. The lookup indices refer to the
+/// GSUB or GPOS LookupList, depending on the referencing field.
+pub type JstfModList<'a> = TableRef<'a, JstfModListMarker>;
+
+impl<'a> JstfModList<'a> {
+    /// Number of lookups for this modification
+    pub fn lookup_count(&self) -> u16 {
+        let range = self.shape.lookup_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of LookupList indices, in increasing numerical order
+    pub fn lookup_indices(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.lookup_indices_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfModList<'a> {
+    fn type_name(&self) -> &str {
+        "JstfModList"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("lookup_count", self.lookup_count())),
+            1usize => Some(Field::new("lookup_indices", self.lookup_indices())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfModList<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// [JstfMax](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-maximum-table) table
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct JstfMaxMarker {
+    lookup_offsets_byte_len: usize,
+}
+
+impl JstfMaxMarker {
+    fn lookup_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn lookup_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.lookup_count_byte_range().end;
+        start..start + self.lookup_offsets_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for JstfMax<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let lookup_count: u16 = cursor.read()?;
+        let lookup_offsets_byte_len = (lookup_count as usize)
+            .checked_mul(Offset16::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(lookup_offsets_byte_len);
+        cursor.finish(JstfMaxMarker {
+            lookup_offsets_byte_len,
+        })
+    }
+}
+
+/// [JstfMax](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-maximum-table) table
+pub type JstfMax<'a> = TableRef<'a, JstfMaxMarker>;
+
+impl<'a> JstfMax<'a> {
+    /// Number of lookup Indices for this modification
+    pub fn lookup_count(&self) -> u16 {
+        let range = self.shape.lookup_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of offsets to GPOS-type lookup tables, from beginning
+    /// of JstfMax table, in design order
+    pub fn lookup_offsets(&self) -> &'a [BigEndian<Offset16>] {
+        let range = self.shape.lookup_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// A dynamically resolving wrapper for [`lookup_offsets`][Self::lookup_offsets].
+    pub fn lookups(&self) -> ArrayOfOffsets<'a, PositionLookup<'a>, Offset16> {
+        let data = self.data;
+        let offsets = self.lookup_offsets();
+        ArrayOfOffsets::new(offsets, data, ())
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for JstfMax<'a> {
+    fn type_name(&self) -> &str {
+        "JstfMax"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("lookup_count", self.lookup_count())),
+            1usize => Some({
+                let data = self.data;
+                Field::new(
+                    "lookup_offsets",
+                    FieldType::array_of_offsets(
+                        better_type_name::<PositionLookup>(),
+                        self.lookup_offsets(),
+                        move |off| {
+                            let target = off.get().resolve::<PositionLookup>(data);
+                            FieldType::offset(off.get(), target)
+                        },
+                    ),
+                )
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for JstfMax<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
@@ -111,6 +111,16 @@ impl ScriptRecord {
     pub fn script<'a>(&self, data: FontData<'a>) -> Result<Script<'a>, ReadError> {
         self.script_offset().resolve(data)
     }
+
+    /// Like [`script`][Self::script], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn script_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<Script<'a>, ReadErrorWithContext> {
+        self.script(data)
+            .with_offset_context(self.script_offset(), "script_offset")
+    }
 }
 
 impl FixedSize for ScriptRecord {
@@ -189,6 +199,15 @@ impl<'a> Script<'a> {
         self.default_lang_sys_offset().resolve(data)
     }
 
+    /// Like [`default_lang_sys`][Self::default_lang_sys], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn default_lang_sys_with_context(
+        &self,
+    ) -> Option<Result<LangSys<'a>, ReadErrorWithContext>> {
+        self.default_lang_sys()
+            .with_offset_context(self.default_lang_sys_offset(), "default_lang_sys_offset")
+    }
+
     /// Number of LangSysRecords for this script — excluding the
     /// default LangSys
     pub fn lang_sys_count(&self) -> u16 {
@@ -263,6 +282,16 @@ impl LangSysRecord {
     pub fn lang_sys<'a>(&self, data: FontData<'a>) -> Result<LangSys<'a>, ReadError> {
         self.lang_sys_offset().resolve(data)
     }
+
+    /// Like [`lang_sys`][Self::lang_sys], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn lang_sys_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<LangSys<'a>, ReadErrorWithContext> {
+        self.lang_sys(data)
+            .with_offset_context(self.lang_sys_offset(), "lang_sys_offset")
+    }
 }
 
 impl FixedSize for LangSysRecord {
@@ -490,6 +519,16 @@ impl FeatureRecord {
         let args = self.feature_tag();
         self.feature_offset().resolve_with_args(data, &args)
     }
+
+    /// Like [`feature`][Self::feature], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn feature_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<Feature<'a>, ReadErrorWithContext> {
+        self.feature(data)
+            .with_offset_context(self.feature_offset(), "feature_offset")
+    }
 }
 
 impl FixedSize for FeatureRecord {
@@ -586,6 +625,15 @@ impl<'a> Feature<'a> {
         self.feature_params_offset().resolve_with_args(data, &args)
     }
 
+    /// Like [`feature_params`][Self::feature_params], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn feature_params_with_context(
+        &self,
+    ) -> Option<Result<FeatureParams<'a>, ReadErrorWithContext>> {
+        self.feature_params()
+            .with_offset_context(self.feature_params_offset(), "feature_params_offset")
+    }
+
     /// Number of LookupList indices for this feature
     pub fn lookup_index_count(&self) -> u16 {
         let range = self.shape.lookup_index_count_byte_range();
@@ -1662,6 +1710,13 @@ impl<'a> SequenceContextFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of SequenceRuleSet tables
     pub fn seq_rule_set_count(&self) -> u16 {
         let range = self.shape.seq_rule_set_count_byte_range();
@@ -1993,6 +2048,13 @@ impl<'a> SequenceContextFormat2<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Offset to ClassDef table, from beginning of
     /// SequenceContextFormat2 table
     pub fn class_def_offset(&self) -> Offset16 {
@@ -2006,6 +2068,13 @@ impl<'a> SequenceContextFormat2<'a> {
         self.class_def_offset().resolve(data)
     }
 
+    /// Like [`class_def`][Self::class_def], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn class_def_with_context(&self) -> Result<ClassDef<'a>, ReadErrorWithContext> {
+        self.class_def()
+            .with_offset_context(self.class_def_offset(), "class_def_offset")
+    }
+
     /// Number of ClassSequenceRuleSet tables
     pub fn class_seq_rule_set_count(&self) -> u16 {
         let range = self.shape.class_seq_rule_set_count_byte_range();
@@ -2555,6 +2624,13 @@ impl<'a> ChainedSequenceContextFormat1<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Number of ChainedSequenceRuleSet tables
     pub fn chained_seq_rule_set_count(&self) -> u16 {
         let range = self.shape.chained_seq_rule_set_count_byte_range();
@@ -2969,6 +3045,13 @@ impl<'a> ChainedSequenceContextFormat2<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     /// Offset to ClassDef table containing backtrack sequence context,
     /// from beginning of ChainedSequenceContextFormat2 table
     pub fn backtrack_class_def_offset(&self) -> Offset16 {
@@ -2982,6 +3065,15 @@ impl<'a> ChainedSequenceContextFormat2<'a> {
         self.backtrack_class_def_offset().resolve(data)
     }
 
+    /// Like [`backtrack_class_def`][Self::backtrack_class_def], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn backtrack_class_def_with_context(&self) -> Result<ClassDef<'a>, ReadErrorWithContext> {
+        self.backtrack_class_def().with_offset_context(
+            self.backtrack_class_def_offset(),
+            "backtrack_class_def_offset",
+        )
+    }
+
     /// Offset to ClassDef table containing input sequence context,
     /// from beginning of ChainedSequenceContextFormat2 table
     pub fn input_class_def_offset(&self) -> Offset16 {
@@ -2995,6 +3087,13 @@ impl<'a> ChainedSequenceContextFormat2<'a> {
         self.input_class_def_offset().resolve(data)
     }
 
+    /// Like [`input_class_def`][Self::input_class_def], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn input_class_def_with_context(&self) -> Result<ClassDef<'a>, ReadErrorWithContext> {
+        self.input_class_def()
+            .with_offset_context(self.input_class_def_offset(), "input_class_def_offset")
+    }
+
     /// Offset to ClassDef table containing lookahead sequence context,
     /// from beginning of ChainedSequenceContextFormat2 table
     pub fn lookahead_class_def_offset(&self) -> Offset16 {
@@ -3008,6 +3107,15 @@ impl<'a> ChainedSequenceContextFormat2<'a> {
         self.lookahead_class_def_offset().resolve(data)
     }
 
+    /// Like [`lookahead_class_def`][Self::lookahead_class_def], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn lookahead_class_def_with_context(&self) -> Result<ClassDef<'a>, ReadErrorWithContext> {
+        self.lookahead_class_def().with_offset_context(
+            self.lookahead_class_def_offset(),
+            "lookahead_class_def_offset",
+        )
+    }
+
     /// Number of ChainedClassSequenceRuleSet tables
     pub fn chained_class_seq_rule_set_count(&self) -> u16 {
         let range = self.shape.chained_class_seq_rule_set_count_byte_range();
@@ -4086,6 +4194,16 @@ impl FeatureVariationRecord {
         self.condition_set_offset().resolve(data)
     }
 
+    /// Like [`condition_set`][Self::condition_set], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn condition_set_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<ConditionSet<'a>, ReadErrorWithContext>> {
+        self.condition_set(data)
+            .with_offset_context(self.condition_set_offset(), "condition_set_offset")
+    }
+
     /// Offset to a feature table substitution table, from beginning of
     /// the FeatureVariations table.
     pub fn feature_table_substitution_offset(&self) -> Nullable<Offset32> {
@@ -4103,6 +4221,18 @@ impl FeatureVariationRecord {
     ) -> Option<Result<FeatureTableSubstitution<'a>, ReadError>> {
         self.feature_table_substitution_offset().resolve(data)
     }
+
+    /// Like [`feature_table_substitution`][Self::feature_table_substitution], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn feature_table_substitution_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<FeatureTableSubstitution<'a>, ReadErrorWithContext>> {
+        self.feature_table_substitution(data).with_offset_context(
+            self.feature_table_substitution_offset(),
+            "feature_table_substitution_offset",
+        )
+    }
 }
 
 impl FixedSize for FeatureVariationRecord {
@@ -4746,6 +4876,13 @@ impl<'a> ConditionFormat5<'a> {
         let data = self.data;
         self.condition_offset().resolve(data)
     }
+
+    /// Like [`condition`][Self::condition], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn condition_with_context(&self) -> Result<Condition<'a>, ReadErrorWithContext> {
+        self.condition()
+            .with_offset_context(self.condition_offset(), "condition_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -97,6 +97,17 @@ impl<'a> Mvar<'a> {
         self.item_variation_store_offset().resolve(data)
     }
 
+    /// Like [`item_variation_store`][Self::item_variation_store], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn item_variation_store_with_context(
+        &self,
+    ) -> Option<Result<ItemVariationStore<'a>, ReadErrorWithContext>> {
+        self.item_variation_store().with_offset_context(
+            self.item_variation_store_offset(),
+            "item_variation_store_offset",
+        )
+    }
+
     /// Array of value records that identify target items and the associated delta-set index for each. The valueTag records must be in binary order of their valueTag field.
     pub fn value_records(&self) -> &'a [ValueRecord] {
         let range = self.shape.value_records_byte_range();
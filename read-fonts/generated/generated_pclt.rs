@@ -0,0 +1,244 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [PCLT (PCL 5)](https://docs.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct PcltMarker {
+    typeface_byte_len: usize,
+    character_complement_byte_len: usize,
+    file_name_byte_len: usize,
+}
+
+impl PcltMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + MajorMinor::RAW_BYTE_LEN
+    }
+    fn font_number_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn pitch_byte_range(&self) -> Range<usize> {
+        let start = self.font_number_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn x_height_byte_range(&self) -> Range<usize> {
+        let start = self.pitch_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn style_byte_range(&self) -> Range<usize> {
+        let start = self.x_height_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn type_family_byte_range(&self) -> Range<usize> {
+        let start = self.style_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn cap_height_byte_range(&self) -> Range<usize> {
+        let start = self.type_family_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn symbol_set_byte_range(&self) -> Range<usize> {
+        let start = self.cap_height_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn typeface_byte_range(&self) -> Range<usize> {
+        let start = self.symbol_set_byte_range().end;
+        start..start + self.typeface_byte_len
+    }
+    fn character_complement_byte_range(&self) -> Range<usize> {
+        let start = self.typeface_byte_range().end;
+        start..start + self.character_complement_byte_len
+    }
+    fn file_name_byte_range(&self) -> Range<usize> {
+        let start = self.character_complement_byte_range().end;
+        start..start + self.file_name_byte_len
+    }
+    fn stroke_weight_byte_range(&self) -> Range<usize> {
+        let start = self.file_name_byte_range().end;
+        start..start + i8::RAW_BYTE_LEN
+    }
+    fn width_type_byte_range(&self) -> Range<usize> {
+        let start = self.stroke_weight_byte_range().end;
+        start..start + i8::RAW_BYTE_LEN
+    }
+    fn serif_style_byte_range(&self) -> Range<usize> {
+        let start = self.width_type_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn _reserved_byte_range(&self) -> Range<usize> {
+        let start = self.serif_style_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+}
+
+impl TopLevelTable for Pclt<'_> {
+    /// `PCLT`
+    const TAG: Tag = Tag::new(b"PCLT");
+}
+
+impl<'a> FontRead<'a> for Pclt<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<MajorMinor>();
+        cursor.advance::<u32>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        let typeface_byte_len = (16_usize)
+            .checked_mul(u8::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(typeface_byte_len);
+        let character_complement_byte_len = (8_usize)
+            .checked_mul(u8::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(character_complement_byte_len);
+        let file_name_byte_len = (6_usize)
+            .checked_mul(u8::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(file_name_byte_len);
+        cursor.advance::<i8>();
+        cursor.advance::<i8>();
+        cursor.advance::<u8>();
+        cursor.advance::<u8>();
+        cursor.finish(PcltMarker {
+            typeface_byte_len,
+            character_complement_byte_len,
+            file_name_byte_len,
+        })
+    }
+}
+
+/// The [PCLT (PCL 5)](https://docs.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+pub type Pclt<'a> = TableRef<'a, PcltMarker>;
+
+impl<'a> Pclt<'a> {
+    /// Table version; set to 1.0.
+    pub fn version(&self) -> MajorMinor {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Unique identifier for the font, including the vendor code.
+    pub fn font_number(&self) -> u32 {
+        let range = self.shape.font_number_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Width of the space in FUnits.
+    pub fn pitch(&self) -> u16 {
+        let range = self.shape.pitch_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// x-height of the font in FUnits.
+    pub fn x_height(&self) -> u16 {
+        let range = self.shape.x_height_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Encoded style word containing the posture, appearance width and
+    /// structure.
+    pub fn style(&self) -> u16 {
+        let range = self.shape.style_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Encoded word containing the vendor code and typeface family.
+    pub fn type_family(&self) -> u16 {
+        let range = self.shape.type_family_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Height of the optical line describing the top of flat capitals, in FUnits.
+    pub fn cap_height(&self) -> u16 {
+        let range = self.shape.cap_height_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Encoded PCL symbol set identifier.
+    pub fn symbol_set(&self) -> u16 {
+        let range = self.shape.symbol_set_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// ASCII name of the typeface, padded with spaces or NULs.
+    pub fn typeface(&self) -> &'a [u8] {
+        let range = self.shape.typeface_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Bit field identifying the symbol collections provided by the font.
+    pub fn character_complement(&self) -> &'a [u8] {
+        let range = self.shape.character_complement_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Six character ASCII file name.
+    pub fn file_name(&self) -> &'a [u8] {
+        let range = self.shape.file_name_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Stroke weight, from -7 (ultra thin) to 7 (ultra black).
+    pub fn stroke_weight(&self) -> i8 {
+        let range = self.shape.stroke_weight_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Width type, from -5 (ultra compressed) to 5 (ultra expanded).
+    pub fn width_type(&self) -> i8 {
+        let range = self.shape.width_type_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Encoded serif style and stroke appearance.
+    pub fn serif_style(&self) -> u8 {
+        let range = self.shape.serif_style_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Pclt<'a> {
+    fn type_name(&self) -> &str {
+        "Pclt"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("version", self.version())),
+            1usize => Some(Field::new("font_number", self.font_number())),
+            2usize => Some(Field::new("pitch", self.pitch())),
+            3usize => Some(Field::new("x_height", self.x_height())),
+            4usize => Some(Field::new("style", self.style())),
+            5usize => Some(Field::new("type_family", self.type_family())),
+            6usize => Some(Field::new("cap_height", self.cap_height())),
+            7usize => Some(Field::new("symbol_set", self.symbol_set())),
+            8usize => Some(Field::new("typeface", self.typeface())),
+            9usize => Some(Field::new(
+                "character_complement",
+                self.character_complement(),
+            )),
+            10usize => Some(Field::new("file_name", self.file_name())),
+            11usize => Some(Field::new("stroke_weight", self.stroke_weight())),
+            12usize => Some(Field::new("width_type", self.width_type())),
+            13usize => Some(Field::new("serif_style", self.serif_style())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Pclt<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
@@ -215,7 +215,7 @@ impl<'a> Post<'a> {
     /// Storage for the string data.
     pub fn string_data(&self) -> Option<VarLenArray<'a, PString<'a>>> {
         let range = self.shape.string_data_byte_range()?;
-        Some(VarLenArray::read(self.data.split_off(range.start).unwrap()).unwrap())
+        Some(VarLenArray::read(self.data.slice(range).unwrap()).unwrap())
     }
 }
 
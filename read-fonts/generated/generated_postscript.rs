@@ -626,3 +626,700 @@ impl<'a> SomeRecord<'a> for FdSelectRange4 {
         }
     }
 }
+
+/// Charset with custom glyph id to string id mappings.
+#[derive(Clone)]
+pub enum CustomCharset<'a> {
+    Format0(CharsetFormat0<'a>),
+    Format1(CharsetFormat1<'a>),
+    Format2(CharsetFormat2<'a>),
+}
+
+impl<'a> CustomCharset<'a> {
+    ///Return the `FontData` used to resolve offsets for this table.
+    pub fn offset_data(&self) -> FontData<'a> {
+        match self {
+            Self::Format0(item) => item.offset_data(),
+            Self::Format1(item) => item.offset_data(),
+            Self::Format2(item) => item.offset_data(),
+        }
+    }
+
+    /// Format; set to 0.
+    pub fn format(&self) -> u8 {
+        match self {
+            Self::Format0(item) => item.format(),
+            Self::Format1(item) => item.format(),
+            Self::Format2(item) => item.format(),
+        }
+    }
+}
+
+impl<'a> FontRead<'a> for CustomCharset<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let format: u8 = data.read_at(0usize)?;
+        match format {
+            CharsetFormat0Marker::FORMAT => Ok(Self::Format0(FontRead::read(data)?)),
+            CharsetFormat1Marker::FORMAT => Ok(Self::Format1(FontRead::read(data)?)),
+            CharsetFormat2Marker::FORMAT => Ok(Self::Format2(FontRead::read(data)?)),
+            other => Err(ReadError::InvalidFormat(other.into())),
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> CustomCharset<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Format0(table) => table,
+            Self::Format1(table) => table,
+            Self::Format2(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for CustomCharset<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.dyn_inner().fmt(f)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for CustomCharset<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+impl Format<u8> for CharsetFormat0Marker {
+    const FORMAT: u8 = 0;
+}
+
+/// Charset format 0.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct CharsetFormat0Marker {
+    glyph_byte_len: usize,
+}
+
+impl CharsetFormat0Marker {
+    fn format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn glyph_byte_range(&self) -> Range<usize> {
+        let start = self.format_byte_range().end;
+        start..start + self.glyph_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for CharsetFormat0<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u8>();
+        let glyph_byte_len = cursor.remaining_bytes() / u16::RAW_BYTE_LEN * u16::RAW_BYTE_LEN;
+        cursor.advance_by(glyph_byte_len);
+        cursor.finish(CharsetFormat0Marker { glyph_byte_len })
+    }
+}
+
+/// Charset format 0.
+pub type CharsetFormat0<'a> = TableRef<'a, CharsetFormat0Marker>;
+
+impl<'a> CharsetFormat0<'a> {
+    /// Format; set to 0.
+    pub fn format(&self) -> u8 {
+        let range = self.shape.format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Glyph name array.
+    pub fn glyph(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.glyph_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for CharsetFormat0<'a> {
+    fn type_name(&self) -> &str {
+        "CharsetFormat0"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("format", self.format())),
+            1usize => Some(Field::new("glyph", self.glyph())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for CharsetFormat0<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+impl Format<u8> for CharsetFormat1Marker {
+    const FORMAT: u8 = 1;
+}
+
+/// Charset format 1.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct CharsetFormat1Marker {
+    ranges_byte_len: usize,
+}
+
+impl CharsetFormat1Marker {
+    fn format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn ranges_byte_range(&self) -> Range<usize> {
+        let start = self.format_byte_range().end;
+        start..start + self.ranges_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for CharsetFormat1<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u8>();
+        let ranges_byte_len =
+            cursor.remaining_bytes() / CharsetRange1::RAW_BYTE_LEN * CharsetRange1::RAW_BYTE_LEN;
+        cursor.advance_by(ranges_byte_len);
+        cursor.finish(CharsetFormat1Marker { ranges_byte_len })
+    }
+}
+
+/// Charset format 1.
+pub type CharsetFormat1<'a> = TableRef<'a, CharsetFormat1Marker>;
+
+impl<'a> CharsetFormat1<'a> {
+    /// Format; set to 1.
+    pub fn format(&self) -> u8 {
+        let range = self.shape.format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Range1 array.
+    pub fn ranges(&self) -> &'a [CharsetRange1] {
+        let range = self.shape.ranges_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for CharsetFormat1<'a> {
+    fn type_name(&self) -> &str {
+        "CharsetFormat1"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("format", self.format())),
+            1usize => Some(Field::new(
+                "ranges",
+                traversal::FieldType::array_of_records(
+                    stringify!(CharsetRange1),
+                    self.ranges(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for CharsetFormat1<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Range struct for Charset format 1.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, bytemuck :: AnyBitPattern)]
+#[repr(C)]
+#[repr(packed)]
+pub struct CharsetRange1 {
+    /// First glyph in range.
+    pub first: BigEndian<u16>,
+    /// Glyphs left in range (excluding first).
+    pub n_left: u8,
+}
+
+impl CharsetRange1 {
+    /// First glyph in range.
+    pub fn first(&self) -> u16 {
+        self.first.get()
+    }
+
+    /// Glyphs left in range (excluding first).
+    pub fn n_left(&self) -> u8 {
+        self.n_left
+    }
+}
+
+impl FixedSize for CharsetRange1 {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN + u8::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for CharsetRange1 {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "CharsetRange1",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("first", self.first())),
+                1usize => Some(Field::new("n_left", self.n_left())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+impl Format<u8> for CharsetFormat2Marker {
+    const FORMAT: u8 = 2;
+}
+
+/// Charset format 2.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct CharsetFormat2Marker {
+    ranges_byte_len: usize,
+}
+
+impl CharsetFormat2Marker {
+    fn format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn ranges_byte_range(&self) -> Range<usize> {
+        let start = self.format_byte_range().end;
+        start..start + self.ranges_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for CharsetFormat2<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u8>();
+        let ranges_byte_len =
+            cursor.remaining_bytes() / CharsetRange2::RAW_BYTE_LEN * CharsetRange2::RAW_BYTE_LEN;
+        cursor.advance_by(ranges_byte_len);
+        cursor.finish(CharsetFormat2Marker { ranges_byte_len })
+    }
+}
+
+/// Charset format 2.
+pub type CharsetFormat2<'a> = TableRef<'a, CharsetFormat2Marker>;
+
+impl<'a> CharsetFormat2<'a> {
+    /// Format; set to 2.
+    pub fn format(&self) -> u8 {
+        let range = self.shape.format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Range2 array.
+    pub fn ranges(&self) -> &'a [CharsetRange2] {
+        let range = self.shape.ranges_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for CharsetFormat2<'a> {
+    fn type_name(&self) -> &str {
+        "CharsetFormat2"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("format", self.format())),
+            1usize => Some(Field::new(
+                "ranges",
+                traversal::FieldType::array_of_records(
+                    stringify!(CharsetRange2),
+                    self.ranges(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for CharsetFormat2<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Range struct for Charset format 2.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, bytemuck :: AnyBitPattern)]
+#[repr(C)]
+#[repr(packed)]
+pub struct CharsetRange2 {
+    /// First glyph in range.
+    pub first: BigEndian<u16>,
+    /// Glyphs left in range (excluding first).
+    pub n_left: BigEndian<u16>,
+}
+
+impl CharsetRange2 {
+    /// First glyph in range.
+    pub fn first(&self) -> u16 {
+        self.first.get()
+    }
+
+    /// Glyphs left in range (excluding first).
+    pub fn n_left(&self) -> u16 {
+        self.n_left.get()
+    }
+}
+
+impl FixedSize for CharsetRange2 {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for CharsetRange2 {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "CharsetRange2",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("first", self.first())),
+                1usize => Some(Field::new("n_left", self.n_left())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// Encoding with custom code to glyph mappings.
+///
+/// The high bit of the format is set when the encoding contains
+/// supplemental code mappings.
+#[derive(Clone)]
+pub enum CustomEncoding<'a> {
+    Format0(EncodingFormat0<'a>),
+    Format1(EncodingFormat1<'a>),
+}
+
+impl<'a> CustomEncoding<'a> {
+    ///Return the `FontData` used to resolve offsets for this table.
+    pub fn offset_data(&self) -> FontData<'a> {
+        match self {
+            Self::Format0(item) => item.offset_data(),
+            Self::Format1(item) => item.offset_data(),
+        }
+    }
+
+    /// Format; set to 0, with the high bit set if supplements are present.
+    pub fn format(&self) -> u8 {
+        match self {
+            Self::Format0(item) => item.format(),
+            Self::Format1(item) => item.format(),
+        }
+    }
+}
+
+impl<'a> FontRead<'a> for CustomEncoding<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let format: u8 = data.read_at(0usize)?;
+
+        #[allow(clippy::redundant_guards)]
+        match format {
+            format if format & 0x7F == 0 => Ok(Self::Format0(FontRead::read(data)?)),
+            format if format & 0x7F == 1 => Ok(Self::Format1(FontRead::read(data)?)),
+            other => Err(ReadError::InvalidFormat(other.into())),
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> CustomEncoding<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Format0(table) => table,
+            Self::Format1(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for CustomEncoding<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.dyn_inner().fmt(f)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for CustomEncoding<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+/// Encoding format 0.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct EncodingFormat0Marker {
+    code_byte_len: usize,
+}
+
+impl EncodingFormat0Marker {
+    fn format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn n_codes_byte_range(&self) -> Range<usize> {
+        let start = self.format_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn code_byte_range(&self) -> Range<usize> {
+        let start = self.n_codes_byte_range().end;
+        start..start + self.code_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for EncodingFormat0<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u8>();
+        let n_codes: u8 = cursor.read()?;
+        let code_byte_len = (n_codes as usize)
+            .checked_mul(u8::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(code_byte_len);
+        cursor.finish(EncodingFormat0Marker { code_byte_len })
+    }
+}
+
+/// Encoding format 0.
+pub type EncodingFormat0<'a> = TableRef<'a, EncodingFormat0Marker>;
+
+impl<'a> EncodingFormat0<'a> {
+    /// Format; set to 0, with the high bit set if supplements are present.
+    pub fn format(&self) -> u8 {
+        let range = self.shape.format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of encoded glyphs.
+    pub fn n_codes(&self) -> u8 {
+        let range = self.shape.n_codes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Code array.
+    pub fn code(&self) -> &'a [u8] {
+        let range = self.shape.code_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for EncodingFormat0<'a> {
+    fn type_name(&self) -> &str {
+        "EncodingFormat0"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("format", self.format())),
+            1usize => Some(Field::new("n_codes", self.n_codes())),
+            2usize => Some(Field::new("code", self.code())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for EncodingFormat0<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Encoding format 1.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct EncodingFormat1Marker {
+    ranges_byte_len: usize,
+}
+
+impl EncodingFormat1Marker {
+    fn format_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn n_ranges_byte_range(&self) -> Range<usize> {
+        let start = self.format_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn ranges_byte_range(&self) -> Range<usize> {
+        let start = self.n_ranges_byte_range().end;
+        start..start + self.ranges_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for EncodingFormat1<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u8>();
+        let n_ranges: u8 = cursor.read()?;
+        let ranges_byte_len = (n_ranges as usize)
+            .checked_mul(EncodingRange1::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(ranges_byte_len);
+        cursor.finish(EncodingFormat1Marker { ranges_byte_len })
+    }
+}
+
+/// Encoding format 1.
+pub type EncodingFormat1<'a> = TableRef<'a, EncodingFormat1Marker>;
+
+impl<'a> EncodingFormat1<'a> {
+    /// Format; set to 1, with the high bit set if supplements are present.
+    pub fn format(&self) -> u8 {
+        let range = self.shape.format_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of code ranges.
+    pub fn n_ranges(&self) -> u8 {
+        let range = self.shape.n_ranges_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Range1 array.
+    pub fn ranges(&self) -> &'a [EncodingRange1] {
+        let range = self.shape.ranges_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for EncodingFormat1<'a> {
+    fn type_name(&self) -> &str {
+        "EncodingFormat1"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("format", self.format())),
+            1usize => Some(Field::new("n_ranges", self.n_ranges())),
+            2usize => Some(Field::new(
+                "ranges",
+                traversal::FieldType::array_of_records(
+                    stringify!(EncodingRange1),
+                    self.ranges(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for EncodingFormat1<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Range struct for Encoding format 1.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, bytemuck :: AnyBitPattern)]
+#[repr(C)]
+#[repr(packed)]
+pub struct EncodingRange1 {
+    /// First code in range.
+    pub first: u8,
+    /// Codes left in range (excluding first).
+    pub n_left: u8,
+}
+
+impl EncodingRange1 {
+    /// First code in range.
+    pub fn first(&self) -> u8 {
+        self.first
+    }
+
+    /// Codes left in range (excluding first).
+    pub fn n_left(&self) -> u8 {
+        self.n_left
+    }
+}
+
+impl FixedSize for EncodingRange1 {
+    const RAW_BYTE_LEN: usize = u8::RAW_BYTE_LEN + u8::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for EncodingRange1 {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "EncodingRange1",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("first", self.first())),
+                1usize => Some(Field::new("n_left", self.n_left())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
+
+/// Supplemental encoding record.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, bytemuck :: AnyBitPattern)]
+#[repr(C)]
+#[repr(packed)]
+pub struct EncodingSupplement {
+    /// Encoding.
+    pub code: u8,
+    /// Name.
+    pub glyph: BigEndian<u16>,
+}
+
+impl EncodingSupplement {
+    /// Encoding.
+    pub fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// Name.
+    pub fn glyph(&self) -> u16 {
+        self.glyph.get()
+    }
+}
+
+impl FixedSize for EncodingSupplement {
+    const RAW_BYTE_LEN: usize = u8::RAW_BYTE_LEN + u16::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for EncodingSupplement {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "EncodingSupplement",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("code", self.code())),
+                1usize => Some(Field::new("glyph", self.glyph())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
@@ -111,6 +111,13 @@ impl<'a> Stat<'a> {
         self.design_axes_offset().resolve_with_args(data, &args)
     }
 
+    /// Like [`design_axes`][Self::design_axes], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn design_axes_with_context(&self) -> Result<&'a [AxisRecord], ReadErrorWithContext> {
+        self.design_axes()
+            .with_offset_context(self.design_axes_offset(), "design_axes_offset")
+    }
+
     /// The number of axis value tables.
     pub fn axis_value_count(&self) -> u16 {
         let range = self.shape.axis_value_count_byte_range();
@@ -134,6 +141,17 @@ impl<'a> Stat<'a> {
             .resolve_with_args(data, &args)
     }
 
+    /// Like [`offset_to_axis_values`][Self::offset_to_axis_values], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn offset_to_axis_values_with_context(
+        &self,
+    ) -> Option<Result<AxisValueArray<'a>, ReadErrorWithContext>> {
+        self.offset_to_axis_values().with_offset_context(
+            self.offset_to_axis_value_offsets(),
+            "offset_to_axis_value_offsets",
+        )
+    }
+
     /// Name ID used as fallback when projection of names into a
     /// particular font model produces a subfamily name containing only
     /// elidable elements.
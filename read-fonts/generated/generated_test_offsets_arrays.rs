@@ -111,6 +111,13 @@ impl<'a> KindsOfOffsets<'a> {
         self.nonnullable_offset().resolve(data)
     }
 
+    /// Like [`nonnullable`][Self::nonnullable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn nonnullable_with_context(&self) -> Result<Dummy<'a>, ReadErrorWithContext> {
+        self.nonnullable()
+            .with_offset_context(self.nonnullable_offset(), "nonnullable_offset")
+    }
+
     /// An offset that is nullable, but always present
     pub fn nullable_offset(&self) -> Nullable<Offset16> {
         let range = self.shape.nullable_offset_byte_range();
@@ -123,6 +130,13 @@ impl<'a> KindsOfOffsets<'a> {
         self.nullable_offset().resolve(data)
     }
 
+    /// Like [`nullable`][Self::nullable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn nullable_with_context(&self) -> Option<Result<Dummy<'a>, ReadErrorWithContext>> {
+        self.nullable()
+            .with_offset_context(self.nullable_offset(), "nullable_offset")
+    }
+
     /// count of the array at array_offset
     pub fn array_offset_count(&self) -> u16 {
         let range = self.shape.array_offset_count_byte_range();
@@ -142,6 +156,13 @@ impl<'a> KindsOfOffsets<'a> {
         self.array_offset().resolve_with_args(data, &args)
     }
 
+    /// Like [`array`][Self::array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn array_with_context(&self) -> Result<&'a [BigEndian<u16>], ReadErrorWithContext> {
+        self.array()
+            .with_offset_context(self.array_offset(), "array_offset")
+    }
+
     /// An offset to an array of records
     pub fn record_array_offset(&self) -> Offset16 {
         let range = self.shape.record_array_offset_byte_range();
@@ -155,6 +176,13 @@ impl<'a> KindsOfOffsets<'a> {
         self.record_array_offset().resolve_with_args(data, &args)
     }
 
+    /// Like [`record_array`][Self::record_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn record_array_with_context(&self) -> Result<&'a [Shmecord], ReadErrorWithContext> {
+        self.record_array()
+            .with_offset_context(self.record_array_offset(), "record_array_offset")
+    }
+
     /// A nullable, versioned offset to an array of records
     pub fn versioned_nullable_record_array_offset(&self) -> Option<Nullable<Offset16>> {
         let range = self
@@ -171,6 +199,17 @@ impl<'a> KindsOfOffsets<'a> {
             .map(|x| x.resolve_with_args(data, &args))?
     }
 
+    /// Like [`versioned_nullable_record_array`][Self::versioned_nullable_record_array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn versioned_nullable_record_array_with_context(
+        &self,
+    ) -> Option<Result<&'a [Shmecord], ReadErrorWithContext>> {
+        self.versioned_nullable_record_array().with_offset_context(
+            self.versioned_nullable_record_array_offset(),
+            "versioned_nullable_record_array_offset",
+        )
+    }
+
     /// A normal offset that is versioned
     pub fn versioned_nonnullable_offset(&self) -> Option<Offset16> {
         let range = self.shape.versioned_nonnullable_offset_byte_range()?;
@@ -183,6 +222,17 @@ impl<'a> KindsOfOffsets<'a> {
         self.versioned_nonnullable_offset().map(|x| x.resolve(data))
     }
 
+    /// Like [`versioned_nonnullable`][Self::versioned_nonnullable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn versioned_nonnullable_with_context(
+        &self,
+    ) -> Option<Result<Dummy<'a>, ReadErrorWithContext>> {
+        self.versioned_nonnullable().with_offset_context(
+            self.versioned_nonnullable_offset(),
+            "versioned_nonnullable_offset",
+        )
+    }
+
     /// An offset that is nullable and versioned
     pub fn versioned_nullable_offset(&self) -> Option<Nullable<Offset32>> {
         let range = self.shape.versioned_nullable_offset_byte_range()?;
@@ -194,6 +244,17 @@ impl<'a> KindsOfOffsets<'a> {
         let data = self.data;
         self.versioned_nullable_offset().map(|x| x.resolve(data))?
     }
+
+    /// Like [`versioned_nullable`][Self::versioned_nullable], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn versioned_nullable_with_context(
+        &self,
+    ) -> Option<Result<Dummy<'a>, ReadErrorWithContext>> {
+        self.versioned_nullable().with_offset_context(
+            self.versioned_nullable_offset(),
+            "versioned_nullable_offset",
+        )
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -231,7 +292,7 @@ impl<'a> SomeTable<'a> for KindsOfOffsets<'a> {
                 "versioned_nullable_record_array_offset",
                 traversal::FieldType::offset_to_array_of_records(
                     self.versioned_nullable_record_array_offset().unwrap(),
-                    self.versioned_nullable_record_array().unwrap(),
+                    self.versioned_nullable_record_array(),
                     stringify!(Shmecord),
                     self.offset_data(),
                 ),
@@ -247,7 +308,7 @@ impl<'a> SomeTable<'a> for KindsOfOffsets<'a> {
                 "versioned_nullable_offset",
                 FieldType::offset(
                     self.versioned_nullable_offset().unwrap(),
-                    self.versioned_nullable().unwrap(),
+                    self.versioned_nullable(),
                 ),
             )),
             _ => None,
@@ -280,6 +280,16 @@ impl ContainsOffests {
         self.array_offset().resolve_with_args(data, &args)
     }
 
+    /// Like [`array`][Self::array], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn array_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<&'a [SimpleRecord], ReadErrorWithContext> {
+        self.array(data)
+            .with_offset_context(self.array_offset(), "array_offset")
+    }
+
     pub fn other_offset(&self) -> Offset32 {
         self.other_offset.get()
     }
@@ -290,6 +300,16 @@ impl ContainsOffests {
     pub fn other<'a>(&self, data: FontData<'a>) -> Result<BasicTable<'a>, ReadError> {
         self.other_offset().resolve(data)
     }
+
+    /// Like [`other`][Self::other], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn other_with_context<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Result<BasicTable<'a>, ReadErrorWithContext> {
+        self.other(data)
+            .with_offset_context(self.other_offset(), "other_offset")
+    }
 }
 
 impl FixedSize for ContainsOffests {
@@ -322,3 +342,76 @@ impl<'a> SomeRecord<'a> for ContainsOffests {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct InlineRecordMarker {}
+
+impl InlineRecordMarker {
+    fn leading_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn inline_byte_range(&self) -> Range<usize> {
+        let start = self.leading_byte_range().end;
+        start..start + SimpleRecord::RAW_BYTE_LEN
+    }
+    fn trailing_byte_range(&self) -> Range<usize> {
+        let start = self.inline_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for InlineRecord<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance_by(SimpleRecord::RAW_BYTE_LEN);
+        cursor.advance::<u16>();
+        cursor.finish(InlineRecordMarker {})
+    }
+}
+
+pub type InlineRecord<'a> = TableRef<'a, InlineRecordMarker>;
+
+impl<'a> InlineRecord<'a> {
+    pub fn leading(&self) -> u16 {
+        let range = self.shape.leading_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    pub fn inline(&self) -> &'a SimpleRecord {
+        let range = self.shape.inline_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    pub fn trailing(&self) -> u16 {
+        let range = self.shape.trailing_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for InlineRecord<'a> {
+    fn type_name(&self) -> &str {
+        "InlineRecord"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("leading", self.leading())),
+            1usize => Some(Field::new(
+                "inline",
+                traversal::FieldType::Record(self.inline().traverse(self.offset_data())),
+            )),
+            2usize => Some(Field::new("trailing", self.trailing())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for InlineRecord<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
@@ -80,6 +80,13 @@ impl<'a> Varc<'a> {
         self.coverage_offset().resolve(data)
     }
 
+    /// Like [`coverage`][Self::coverage], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn coverage_with_context(&self) -> Result<CoverageTable<'a>, ReadErrorWithContext> {
+        self.coverage()
+            .with_offset_context(self.coverage_offset(), "coverage_offset")
+    }
+
     pub fn multi_var_store_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.multi_var_store_offset_byte_range();
         self.data.read_at(range.start).unwrap()
@@ -91,6 +98,15 @@ impl<'a> Varc<'a> {
         self.multi_var_store_offset().resolve(data)
     }
 
+    /// Like [`multi_var_store`][Self::multi_var_store], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn multi_var_store_with_context(
+        &self,
+    ) -> Option<Result<MultiItemVariationStore<'a>, ReadErrorWithContext>> {
+        self.multi_var_store()
+            .with_offset_context(self.multi_var_store_offset(), "multi_var_store_offset")
+    }
+
     pub fn condition_list_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.condition_list_offset_byte_range();
         self.data.read_at(range.start).unwrap()
@@ -102,6 +118,15 @@ impl<'a> Varc<'a> {
         self.condition_list_offset().resolve(data)
     }
 
+    /// Like [`condition_list`][Self::condition_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn condition_list_with_context(
+        &self,
+    ) -> Option<Result<ConditionList<'a>, ReadErrorWithContext>> {
+        self.condition_list()
+            .with_offset_context(self.condition_list_offset(), "condition_list_offset")
+    }
+
     pub fn axis_indices_list_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.axis_indices_list_offset_byte_range();
         self.data.read_at(range.start).unwrap()
@@ -113,6 +138,15 @@ impl<'a> Varc<'a> {
         self.axis_indices_list_offset().resolve(data)
     }
 
+    /// Like [`axis_indices_list`][Self::axis_indices_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn axis_indices_list_with_context(
+        &self,
+    ) -> Option<Result<Index2<'a>, ReadErrorWithContext>> {
+        self.axis_indices_list()
+            .with_offset_context(self.axis_indices_list_offset(), "axis_indices_list_offset")
+    }
+
     pub fn var_composite_glyphs_offset(&self) -> Offset32 {
         let range = self.shape.var_composite_glyphs_offset_byte_range();
         self.data.read_at(range.start).unwrap()
@@ -123,6 +157,15 @@ impl<'a> Varc<'a> {
         let data = self.data;
         self.var_composite_glyphs_offset().resolve(data)
     }
+
+    /// Like [`var_composite_glyphs`][Self::var_composite_glyphs], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn var_composite_glyphs_with_context(&self) -> Result<Index2<'a>, ReadErrorWithContext> {
+        self.var_composite_glyphs().with_offset_context(
+            self.var_composite_glyphs_offset(),
+            "var_composite_glyphs_offset",
+        )
+    }
 }
 
 #[cfg(feature = "traversal")]
@@ -236,6 +279,15 @@ impl<'a> MultiItemVariationStore<'a> {
         self.region_list_offset().resolve(data)
     }
 
+    /// Like [`region_list`][Self::region_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn region_list_with_context(
+        &self,
+    ) -> Result<SparseVariationRegionList<'a>, ReadErrorWithContext> {
+        self.region_list()
+            .with_offset_context(self.region_list_offset(), "region_list_offset")
+    }
+
     pub fn variation_data_count(&self) -> u16 {
         let range = self.shape.variation_data_count_byte_range();
         self.data.read_at(range.start).unwrap()
@@ -1060,6 +1060,17 @@ impl<'a> ItemVariationStore<'a> {
         self.variation_region_list_offset().resolve(data)
     }
 
+    /// Like [`variation_region_list`][Self::variation_region_list], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn variation_region_list_with_context(
+        &self,
+    ) -> Result<VariationRegionList<'a>, ReadErrorWithContext> {
+        self.variation_region_list().with_offset_context(
+            self.variation_region_list_offset(),
+            "variation_region_list_offset",
+        )
+    }
+
     /// The number of item variation data subtables.
     pub fn item_variation_data_count(&self) -> u16 {
         let range = self.shape.item_variation_data_count_byte_range();
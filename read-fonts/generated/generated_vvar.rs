@@ -78,6 +78,17 @@ impl<'a> Vvar<'a> {
         self.item_variation_store_offset().resolve(data)
     }
 
+    /// Like [`item_variation_store`][Self::item_variation_store], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn item_variation_store_with_context(
+        &self,
+    ) -> Result<ItemVariationStore<'a>, ReadErrorWithContext> {
+        self.item_variation_store().with_offset_context(
+            self.item_variation_store_offset(),
+            "item_variation_store_offset",
+        )
+    }
+
     /// Offset in bytes from the start of this table to the delta-set index mapping for advance heights (may be NULL).
     pub fn advance_height_mapping_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.advance_height_mapping_offset_byte_range();
@@ -90,6 +101,17 @@ impl<'a> Vvar<'a> {
         self.advance_height_mapping_offset().resolve(data)
     }
 
+    /// Like [`advance_height_mapping`][Self::advance_height_mapping], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn advance_height_mapping_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.advance_height_mapping().with_offset_context(
+            self.advance_height_mapping_offset(),
+            "advance_height_mapping_offset",
+        )
+    }
+
     /// Offset in bytes from the start of this table to the delta-set index mapping for top side bearings (may be NULL).
     pub fn tsb_mapping_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.tsb_mapping_offset_byte_range();
@@ -102,6 +124,15 @@ impl<'a> Vvar<'a> {
         self.tsb_mapping_offset().resolve(data)
     }
 
+    /// Like [`tsb_mapping`][Self::tsb_mapping], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn tsb_mapping_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.tsb_mapping()
+            .with_offset_context(self.tsb_mapping_offset(), "tsb_mapping_offset")
+    }
+
     /// Offset in bytes from the start of this table to the delta-set index mapping for bottom side bearings (may be NULL).
     pub fn bsb_mapping_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.bsb_mapping_offset_byte_range();
@@ -114,6 +145,15 @@ impl<'a> Vvar<'a> {
         self.bsb_mapping_offset().resolve(data)
     }
 
+    /// Like [`bsb_mapping`][Self::bsb_mapping], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn bsb_mapping_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.bsb_mapping()
+            .with_offset_context(self.bsb_mapping_offset(), "bsb_mapping_offset")
+    }
+
     /// Offset in bytes from the start of this table to the delta-set index mapping for Y coordinates of vertical origins (may be NULL).
     pub fn v_org_mapping_offset(&self) -> Nullable<Offset32> {
         let range = self.shape.v_org_mapping_offset_byte_range();
@@ -125,6 +165,15 @@ impl<'a> Vvar<'a> {
         let data = self.data;
         self.v_org_mapping_offset().resolve(data)
     }
+
+    /// Like [`v_org_mapping`][Self::v_org_mapping], but records the name of this field and the position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn v_org_mapping_with_context(
+        &self,
+    ) -> Option<Result<DeltaSetIndexMap<'a>, ReadErrorWithContext>> {
+        self.v_org_mapping()
+            .with_offset_context(self.v_org_mapping_offset(), "v_org_mapping_offset")
+    }
 }
 
 #[cfg(feature = "traversal")]
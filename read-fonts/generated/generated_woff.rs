@@ -0,0 +1,293 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [WOFF header](https://www.w3.org/TR/WOFF/#WOFFHeader), followed by the
+/// table directory.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct WoffHeaderMarker {
+    table_directory_byte_len: usize,
+}
+
+impl WoffHeaderMarker {
+    fn signature_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Tag::RAW_BYTE_LEN
+    }
+    fn flavor_byte_range(&self) -> Range<usize> {
+        let start = self.signature_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn length_byte_range(&self) -> Range<usize> {
+        let start = self.flavor_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn num_tables_byte_range(&self) -> Range<usize> {
+        let start = self.length_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn _reserved_byte_range(&self) -> Range<usize> {
+        let start = self.num_tables_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn total_sfnt_size_byte_range(&self) -> Range<usize> {
+        let start = self._reserved_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn major_version_byte_range(&self) -> Range<usize> {
+        let start = self.total_sfnt_size_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn minor_version_byte_range(&self) -> Range<usize> {
+        let start = self.major_version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn meta_offset_byte_range(&self) -> Range<usize> {
+        let start = self.minor_version_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn meta_length_byte_range(&self) -> Range<usize> {
+        let start = self.meta_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn meta_orig_length_byte_range(&self) -> Range<usize> {
+        let start = self.meta_length_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn priv_offset_byte_range(&self) -> Range<usize> {
+        let start = self.meta_orig_length_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn priv_length_byte_range(&self) -> Range<usize> {
+        let start = self.priv_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn table_directory_byte_range(&self) -> Range<usize> {
+        let start = self.priv_length_byte_range().end;
+        start..start + self.table_directory_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for WoffHeader<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Tag>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let num_tables: u16 = cursor.read()?;
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let table_directory_byte_len = (num_tables as usize)
+            .checked_mul(WoffTableDirectoryEntry::RAW_BYTE_LEN)
+            .ok_or(ReadError::OutOfBounds)?;
+        cursor.advance_by(table_directory_byte_len);
+        cursor.finish(WoffHeaderMarker {
+            table_directory_byte_len,
+        })
+    }
+}
+
+/// The [WOFF header](https://www.w3.org/TR/WOFF/#WOFFHeader), followed by the
+/// table directory.
+pub type WoffHeader<'a> = TableRef<'a, WoffHeaderMarker>;
+
+impl<'a> WoffHeader<'a> {
+    /// 0x774F4646 'wOFF'
+    pub fn signature(&self) -> Tag {
+        let range = self.shape.signature_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The \"sfnt version\" of the input font.
+    pub fn flavor(&self) -> u32 {
+        let range = self.shape.flavor_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Total size of the WOFF file.
+    pub fn length(&self) -> u32 {
+        let range = self.shape.length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of entries in directory of font tables.
+    pub fn num_tables(&self) -> u16 {
+        let range = self.shape.num_tables_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Total size needed for the uncompressed font data, including the sfnt
+    /// header, directory, and font tables (including padding).
+    pub fn total_sfnt_size(&self) -> u32 {
+        let range = self.shape.total_sfnt_size_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Major version of the WOFF file.
+    pub fn major_version(&self) -> u16 {
+        let range = self.shape.major_version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Minor version of the WOFF file.
+    pub fn minor_version(&self) -> u16 {
+        let range = self.shape.minor_version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to metadata block, from beginning of WOFF file.
+    pub fn meta_offset(&self) -> u32 {
+        let range = self.shape.meta_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Length of compressed metadata block.
+    pub fn meta_length(&self) -> u32 {
+        let range = self.shape.meta_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Uncompressed size of metadata block.
+    pub fn meta_orig_length(&self) -> u32 {
+        let range = self.shape.meta_orig_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to private data block, from beginning of WOFF file.
+    pub fn priv_offset(&self) -> u32 {
+        let range = self.shape.priv_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Length of private data block.
+    pub fn priv_length(&self) -> u32 {
+        let range = self.shape.priv_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The table directory, sorted by tag.
+    pub fn table_directory(&self) -> &'a [WoffTableDirectoryEntry] {
+        let range = self.shape.table_directory_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for WoffHeader<'a> {
+    fn type_name(&self) -> &str {
+        "WoffHeader"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(Field::new("signature", self.signature())),
+            1usize => Some(Field::new("flavor", self.flavor())),
+            2usize => Some(Field::new("length", self.length())),
+            3usize => Some(Field::new("num_tables", self.num_tables())),
+            4usize => Some(Field::new("total_sfnt_size", self.total_sfnt_size())),
+            5usize => Some(Field::new("major_version", self.major_version())),
+            6usize => Some(Field::new("minor_version", self.minor_version())),
+            7usize => Some(Field::new("meta_offset", self.meta_offset())),
+            8usize => Some(Field::new("meta_length", self.meta_length())),
+            9usize => Some(Field::new("meta_orig_length", self.meta_orig_length())),
+            10usize => Some(Field::new("priv_offset", self.priv_offset())),
+            11usize => Some(Field::new("priv_length", self.priv_length())),
+            12usize => Some(Field::new(
+                "table_directory",
+                traversal::FieldType::array_of_records(
+                    stringify!(WoffTableDirectoryEntry),
+                    self.table_directory(),
+                    self.offset_data(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for WoffHeader<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A [WOFF table directory entry](https://www.w3.org/TR/WOFF/#TableDirectory).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, bytemuck :: AnyBitPattern)]
+#[repr(C)]
+#[repr(packed)]
+pub struct WoffTableDirectoryEntry {
+    /// 4-byte sfnt table identifier.
+    pub tag: BigEndian<Tag>,
+    /// Offset to the data, from beginning of WOFF file.
+    pub offset: BigEndian<u32>,
+    /// Length of the compressed data, excluding padding.
+    pub comp_length: BigEndian<u32>,
+    /// Length of the uncompressed table, excluding padding.
+    pub orig_length: BigEndian<u32>,
+    /// Checksum of the uncompressed table.
+    pub orig_checksum: BigEndian<u32>,
+}
+
+impl WoffTableDirectoryEntry {
+    /// 4-byte sfnt table identifier.
+    pub fn tag(&self) -> Tag {
+        self.tag.get()
+    }
+
+    /// Offset to the data, from beginning of WOFF file.
+    pub fn offset(&self) -> u32 {
+        self.offset.get()
+    }
+
+    /// Length of the compressed data, excluding padding.
+    pub fn comp_length(&self) -> u32 {
+        self.comp_length.get()
+    }
+
+    /// Length of the uncompressed table, excluding padding.
+    pub fn orig_length(&self) -> u32 {
+        self.orig_length.get()
+    }
+
+    /// Checksum of the uncompressed table.
+    pub fn orig_checksum(&self) -> u32 {
+        self.orig_checksum.get()
+    }
+}
+
+impl FixedSize for WoffTableDirectoryEntry {
+    const RAW_BYTE_LEN: usize = Tag::RAW_BYTE_LEN
+        + u32::RAW_BYTE_LEN
+        + u32::RAW_BYTE_LEN
+        + u32::RAW_BYTE_LEN
+        + u32::RAW_BYTE_LEN;
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for WoffTableDirectoryEntry {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "WoffTableDirectoryEntry",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("tag", self.tag())),
+                1usize => Some(Field::new("offset", self.offset())),
+                2usize => Some(Field::new("comp_length", self.comp_length())),
+                3usize => Some(Field::new("orig_length", self.orig_length())),
+                4usize => Some(Field::new("orig_checksum", self.orig_checksum())),
+                _ => None,
+            }),
+            data,
+        }
+    }
+}
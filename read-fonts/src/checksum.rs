@@ -0,0 +1,220 @@
+//! Verification of table checksums.
+
+use types::Tag;
+
+use crate::{tables::head::Head, FontData, FontRef, TableRecord, TopLevelTable};
+
+/// The value that the checksum of an entire font should equal, after
+/// accounting for the `checkSumAdjustment` field in the `head` table.
+pub(crate) const CHECKSUM_MAGIC: u32 = 0xB1B0AFBA;
+
+/// The offset of the `checkSumAdjustment` field in the `head` table.
+pub(crate) const HEAD_ADJUSTMENT_OFFSET: usize = 8;
+
+/// A checksum in a font that does not match the data it covers.
+///
+/// Returned by [`FontRef::verify_checksums`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumMismatch {
+    /// The checksum in a table record doesn't match the table data.
+    Table {
+        /// The tag of the table.
+        tag: Tag,
+        /// The checksum stored in the table record.
+        expected: u32,
+        /// The checksum computed from the table data.
+        actual: u32,
+    },
+    /// The table record points outside of the font data, so the checksum
+    /// could not be computed.
+    TableOutOfBounds(Tag),
+    /// The `checkSumAdjustment` field of the `head` table doesn't match the
+    /// font data.
+    Adjustment {
+        /// The value computed from the font data.
+        expected: u32,
+        /// The value stored in the `head` table.
+        actual: u32,
+    },
+}
+
+impl<'a> FontRef<'a> {
+    /// Verifies the checksums of all tables in the font, as well as the
+    /// whole font `checkSumAdjustment` field of the `head` table.
+    ///
+    /// Returns an iterator over any mismatches; if all checksums are valid
+    /// the iterator is empty.
+    ///
+    /// The `checkSumAdjustment` is only verified for standalone fonts, since
+    /// there is no meaningful whole font checksum for a member of a font
+    /// collection. It is also skipped if any table is out of bounds.
+    pub fn verify_checksums(&self) -> impl Iterator<Item = ChecksumMismatch> + 'a {
+        let font = self.clone();
+        let mut font_sum = 0u32;
+        let mut all_in_bounds = true;
+        let mut tables = self.table_directory.table_records().iter();
+        core::iter::from_fn(move || {
+            for record in tables.by_ref() {
+                let tag = record.tag();
+                let Some(data) = font.table_record_data(record) else {
+                    all_in_bounds = false;
+                    return Some(ChecksumMismatch::TableOutOfBounds(tag));
+                };
+                let actual = if tag == Head::TAG {
+                    head_checksum(data.as_bytes())
+                } else {
+                    checksum(data.as_bytes())
+                };
+                font_sum = font_sum.wrapping_add(actual);
+                let expected = record.checksum();
+                if actual != expected {
+                    return Some(ChecksumMismatch::Table {
+                        tag,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+            // Only check the adjustment once, after all tables
+            if !core::mem::take(&mut all_in_bounds) {
+                return None;
+            }
+            font.verify_adjustment(font_sum)
+        })
+    }
+
+    fn verify_adjustment(&self, table_sum: u32) -> Option<ChecksumMismatch> {
+        let directory = self.table_directory.offset_data();
+        // The table directory of a collection member isn't at the start of
+        // the file
+        if self.data.position_of(directory) != Some(0) {
+            return None;
+        }
+        let actual = self
+            .table_data(Head::TAG)?
+            .read_at::<u32>(HEAD_ADJUSTMENT_OFFSET)
+            .ok()?;
+        let directory_len = 12 + self.table_directory.table_records().len() * 16;
+        let directory_sum = checksum(directory.slice(..directory_len)?.as_bytes());
+        let expected = CHECKSUM_MAGIC.wrapping_sub(directory_sum.wrapping_add(table_sum));
+        (actual != expected).then_some(ChecksumMismatch::Adjustment { expected, actual })
+    }
+
+    fn table_record_data(&self, record: &TableRecord) -> Option<FontData<'a>> {
+        let start = record.offset() as usize;
+        let end = start.checked_add(record.length() as usize)?;
+        self.data.slice(start..end)
+    }
+}
+
+/// Computes the checksum of the given table data.
+///
+/// The data is treated as a sequence of big endian `u32` values, padded with
+/// zeros to a multiple of four bytes.
+pub(crate) fn checksum(data: &[u8]) -> u32 {
+    let mut chunks = data.chunks_exact(4);
+    let mut sum = chunks.by_ref().fold(0u32, |sum, chunk| {
+        sum.wrapping_add(u32::from_be_bytes(chunk.try_into().unwrap()))
+    });
+    let rem = chunks.remainder();
+    if !rem.is_empty() {
+        let mut last = [0u8; 4];
+        last[..rem.len()].copy_from_slice(rem);
+        sum = sum.wrapping_add(u32::from_be_bytes(last));
+    }
+    sum
+}
+
+/// Computes the checksum of the `head` table, which is calculated with the
+/// `checkSumAdjustment` field set to zero.
+pub(crate) fn head_checksum(data: &[u8]) -> u32 {
+    let adjustment = data
+        .get(HEAD_ADJUSTMENT_OFFSET..HEAD_ADJUSTMENT_OFFSET + 4)
+        .map(|bytes| u32::from_be_bytes(bytes.try_into().unwrap()))
+        .unwrap_or_default();
+    checksum(data).wrapping_sub(adjustment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::patch, TableProvider};
+    use font_test_data as data;
+
+    #[test]
+    fn checksum_padding() {
+        assert_eq!(checksum(&[]), 0);
+        assert_eq!(checksum(&[0, 0, 0, 1, 0, 0, 0, 2]), 3);
+        assert_eq!(checksum(&[0, 0, 0, 1, 1]), 0x0100_0001);
+        assert_eq!(checksum(&[0xFF; 8]), 0xFFFF_FFFE);
+    }
+
+    #[test]
+    fn valid_fonts() {
+        for font in [
+            data::VAZIRMATN_VAR,
+            data::SIMPLE_GLYF,
+            data::CANTARELL_VF_TRIMMED,
+            data::COLRV0V1,
+        ] {
+            let font = FontRef::new(font).unwrap();
+            assert_eq!(font.verify_checksums().count(), 0);
+        }
+    }
+
+    #[test]
+    fn table_mismatch() {
+        let tag = Tag::new(b"maxp");
+        let font = patch(data::VAZIRMATN_VAR, tag, 4, &[0xFF]);
+        let font = FontRef::new(&font).unwrap();
+        let mismatches = font.verify_checksums().collect::<Vec<_>>();
+        assert_eq!(mismatches.len(), 2);
+        let ChecksumMismatch::Table {
+            tag: mismatch_tag,
+            expected,
+            actual,
+        } = mismatches[0]
+        else {
+            panic!("expected table mismatch, got {mismatches:?}");
+        };
+        assert_eq!(mismatch_tag, tag);
+        assert_eq!(actual.wrapping_sub(expected), 0xFF00_0000);
+        // the data changed, so the font checksum is also wrong
+        assert!(matches!(mismatches[1], ChecksumMismatch::Adjustment { .. }));
+    }
+
+    #[test]
+    fn adjustment_mismatch() {
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        let adjustment = font.head().unwrap().checksum_adjustment();
+        let patched = patch(
+            data::VAZIRMATN_VAR,
+            Head::TAG,
+            HEAD_ADJUSTMENT_OFFSET,
+            &(adjustment + 1).to_be_bytes(),
+        );
+        let font = FontRef::new(&patched).unwrap();
+        // the head table checksum ignores the adjustment
+        assert_eq!(
+            font.verify_checksums().collect::<Vec<_>>(),
+            [ChecksumMismatch::Adjustment {
+                expected: adjustment,
+                actual: adjustment + 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn table_out_of_bounds() {
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        let tag = font.table_directory.table_records()[0].tag();
+        let mut data = data::VAZIRMATN_VAR.to_vec();
+        // the length of the first table record
+        data[24..28].copy_from_slice(&u32::MAX.to_be_bytes());
+        let font = FontRef::new(&data).unwrap();
+        assert_eq!(
+            font.verify_checksums().collect::<Vec<_>>(),
+            [ChecksumMismatch::TableOutOfBounds(tag)]
+        );
+    }
+}
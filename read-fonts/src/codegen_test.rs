@@ -10,6 +10,21 @@
 
 pub mod records {
     include!("../generated/generated_test_records.rs");
+
+    #[test]
+    fn inline_record() {
+        let builder = crate::test_helpers::BeBuffer::new()
+            .push(1u16)
+            .push(2u16) // inline.val1
+            .push(3u32) // inline.va2
+            .push(4u16);
+
+        let table = InlineRecord::read(builder.font_data()).unwrap();
+        assert_eq!(table.leading(), 1);
+        assert_eq!(table.inline().val1(), 2);
+        assert_eq!(table.inline().va2(), 3);
+        assert_eq!(table.trailing(), 4);
+    }
 }
 
 pub mod formats {
@@ -0,0 +1,424 @@
+//! Recording where a read error occurred.
+
+#![deny(clippy::arithmetic_side_effects)]
+// The context is stored inline since we can't allocate in no_std builds. It
+// is only constructed on the error path, so the size isn't a concern there.
+#![allow(clippy::result_large_err)]
+
+use types::Tag;
+
+use crate::ReadError;
+
+/// A single step in the path to the location of an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A named field, such as an offset or array.
+    Field(&'static str),
+    /// An index into an array.
+    Index(u32),
+}
+
+/// Information about the location of a [`ReadError`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    table: Option<Tag>,
+    // stored innermost first, since that is the order in which segments
+    // are added as an error propagates
+    path: [Option<PathSegment>; ErrorContext::MAX_PATH_LEN],
+    path_len: u8,
+    truncated: bool,
+    byte_offset: Option<u32>,
+}
+
+impl ErrorContext {
+    /// The maximum number of path segments that are recorded.
+    ///
+    /// Segments beyond this limit are dropped from the outermost end of the
+    /// path.
+    pub const MAX_PATH_LEN: usize = 8;
+
+    /// Returns the tag of the table in which the error occurred, if known.
+    pub fn table(&self) -> Option<Tag> {
+        self.table
+    }
+
+    /// Returns an iterator over the path to the error, starting from the
+    /// outermost segment.
+    pub fn path(&self) -> impl Iterator<Item = PathSegment> + '_ {
+        self.path[..self.path_len as usize]
+            .iter()
+            .rev()
+            .filter_map(|segment| *segment)
+    }
+
+    /// Returns true if the path was too long to be recorded completely.
+    ///
+    /// In this case, the outermost segments are missing.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the byte offset of the error, if known.
+    ///
+    /// The offset is relative to the start of the outermost data for which
+    /// a position was recorded, which is usually the start of the table.
+    pub fn byte_offset(&self) -> Option<u32> {
+        self.byte_offset
+    }
+
+    fn push(&mut self, segment: PathSegment) {
+        match self.path.get_mut(self.path_len as usize) {
+            Some(slot) => {
+                *slot = Some(segment);
+                self.path_len = self.path_len.saturating_add(1);
+            }
+            None => self.truncated = true,
+        }
+    }
+
+    fn add_byte_offset(&mut self, base: usize) {
+        let base = u32::try_from(base).unwrap_or(u32::MAX);
+        self.byte_offset = Some(self.byte_offset.unwrap_or(0).saturating_add(base));
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut needs_dot = false;
+        if let Some(table) = self.table {
+            write!(f, "{table}")?;
+            needs_dot = true;
+        }
+        if self.truncated {
+            write!(f, "...")?;
+            needs_dot = false;
+        }
+        for segment in self.path() {
+            match segment {
+                PathSegment::Field(name) if needs_dot => write!(f, ".{name}")?,
+                PathSegment::Field(name) => write!(f, "{name}")?,
+                PathSegment::Index(ix) => write!(f, "[{ix}]")?,
+            }
+            needs_dot = true;
+        }
+        if let Some(offset) = self.byte_offset {
+            if needs_dot {
+                write!(f, " ")?;
+            }
+            write!(f, "at byte {offset}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`ReadError`] along with the location at which it occurred.
+///
+/// A `ReadError` on its own only describes *what* went wrong. When diagnosing
+/// a malformed font it is also useful to know *where* it went wrong: this type
+/// records the table tag, the path of fields and array indices that were
+/// followed, and the byte offset of the failure.
+///
+/// Context is opt-in. [`FontRead`](crate::FontRead) and the plain generated
+/// accessors return a bare `ReadError`, and context is recorded by the
+/// following:
+///
+/// * [`TableProvider::expect_table_with_context`](crate::TableProvider::expect_table_with_context)
+///   records the tag of a top-level table that fails to load.
+/// * Each generated offset getter has a `_with_context` sibling (for example,
+///   `Gsub::lookup_list_with_context`) that records the name of the offset
+///   field and the position of its target.
+/// * [`ArrayOfOffsets::get_with_context`](crate::ArrayOfOffsets::get_with_context)
+///   records the index and position of an offset in an array.
+/// * [`ResolveOffset::resolve_with_context`](crate::ResolveOffset::resolve_with_context)
+///   does the same for offsets resolved by hand.
+/// * The methods of the [`ReadContext`] trait add table tags, fields, array
+///   indices and byte offsets as an error is propagated.
+///
+/// Context is only recorded on the error path and requires no allocation, so
+/// it is available in `no_std` builds and costs nothing when reads succeed.
+#[derive(Clone, Debug)]
+pub struct ReadErrorWithContext {
+    /// The underlying error.
+    pub error: ReadError,
+    /// The location of the error.
+    pub context: ErrorContext,
+}
+
+impl From<ReadError> for ReadErrorWithContext {
+    fn from(error: ReadError) -> Self {
+        Self {
+            error,
+            context: ErrorContext::default(),
+        }
+    }
+}
+
+impl From<ReadErrorWithContext> for ReadError {
+    fn from(value: ReadErrorWithContext) -> Self {
+        value.error
+    }
+}
+
+impl std::fmt::Display for ReadErrorWithContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.context == ErrorContext::default() {
+            self.error.fmt(f)
+        } else {
+            write!(f, "{} ({})", self.error, self.context)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReadErrorWithContext {}
+
+/// Methods for attaching context to a failed read.
+///
+/// Context should be added from the inside out: first the innermost field
+/// and position, then the fields and positions of enclosing subtables and
+/// finally the table tag. Each method only does work when the result is an
+/// error.
+pub trait ReadContext<T>: Sized {
+    /// Appends a field name to the path.
+    fn field(self, name: &'static str) -> Result<T, ReadErrorWithContext>;
+
+    /// Appends an array index to the path.
+    fn index(self, index: usize) -> Result<T, ReadErrorWithContext>;
+
+    /// Records the byte offset of the failing data within its parent.
+    ///
+    /// Offsets accumulate, so adding the position of each enclosing
+    /// subtable produces an offset relative to the outermost one.
+    fn byte_offset(self, offset: usize) -> Result<T, ReadErrorWithContext>;
+
+    /// Records the tag of the table in which the error occurred.
+    fn table(self, tag: Tag) -> Result<T, ReadErrorWithContext>;
+}
+
+impl<T, E: Into<ReadErrorWithContext>> ReadContext<T> for Result<T, E> {
+    fn field(self, name: &'static str) -> Result<T, ReadErrorWithContext> {
+        self.map_err(|e| {
+            let mut e = e.into();
+            e.context.push(PathSegment::Field(name));
+            e
+        })
+    }
+
+    fn index(self, index: usize) -> Result<T, ReadErrorWithContext> {
+        self.map_err(|e| {
+            let mut e = e.into();
+            let index = u32::try_from(index).unwrap_or(u32::MAX);
+            e.context.push(PathSegment::Index(index));
+            e
+        })
+    }
+
+    fn byte_offset(self, offset: usize) -> Result<T, ReadErrorWithContext> {
+        self.map_err(|e| {
+            let mut e = e.into();
+            e.context.add_byte_offset(offset);
+            e
+        })
+    }
+
+    fn table(self, tag: Tag) -> Result<T, ReadErrorWithContext> {
+        self.map_err(|e| {
+            let mut e = e.into();
+            e.context.table = Some(tag);
+            e
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fail() -> Result<(), ReadError> {
+        Err(ReadError::OutOfBounds)
+    }
+
+    #[test]
+    fn unused_context() {
+        let err = fail().map_err(ReadErrorWithContext::from).unwrap_err();
+        assert_eq!(err.context, ErrorContext::default());
+        assert_eq!(err.to_string(), ReadError::OutOfBounds.to_string());
+    }
+
+    #[test]
+    fn path_and_offset() {
+        let err = fail()
+            .byte_offset(4)
+            .field("coverage_offset")
+            .index(2)
+            .byte_offset(20)
+            .field("subtable_offsets")
+            .byte_offset(100)
+            .table(Tag::new(b"GSUB"))
+            .unwrap_err();
+        assert!(matches!(err.error, ReadError::OutOfBounds));
+        let context = err.context;
+        assert_eq!(context.table(), Some(Tag::new(b"GSUB")));
+        assert_eq!(
+            context.path().collect::<Vec<_>>(),
+            [
+                PathSegment::Field("subtable_offsets"),
+                PathSegment::Index(2),
+                PathSegment::Field("coverage_offset")
+            ]
+        );
+        assert_eq!(context.byte_offset(), Some(124));
+        assert_eq!(
+            context.to_string(),
+            "GSUB.subtable_offsets[2].coverage_offset at byte 124"
+        );
+    }
+
+    /// Serves the given tables.
+    struct Provider<'a>(&'a [(Tag, &'a [u8])]);
+
+    impl<'a> crate::TableProvider<'a> for Provider<'a> {
+        fn data_for_tag(&self, tag: Tag) -> Option<crate::FontData<'a>> {
+            self.0
+                .iter()
+                .find(|(table_tag, _)| *table_tag == tag)
+                .map(|(_, data)| crate::FontData::new(data))
+        }
+    }
+
+    #[test]
+    fn table_read_failure() {
+        use crate::{tables::head::Head, TableProvider};
+
+        // truncated head table
+        let head = [0u8; 10];
+        let provider = Provider(&[(Tag::new(b"head"), &head)]);
+        let err = provider.expect_table_with_context::<Head>().unwrap_err();
+        assert!(matches!(err.error, ReadError::OutOfBounds));
+        assert_eq!(err.context.table(), Some(Tag::new(b"head")));
+        assert_eq!(err.to_string(), "An offset was out of bounds (head)");
+        // missing table
+        let provider = Provider(&[]);
+        let err = provider.expect_table_with_context::<Head>().unwrap_err();
+        assert!(matches!(err.error, ReadError::TableIsMissing(_)));
+        assert_eq!(err.context.table(), Some(Tag::new(b"head")));
+    }
+
+    #[test]
+    fn offset_read_failure() {
+        use crate::{
+            tables::cmap::{Cmap, CmapSubtable},
+            test_helpers::BeBuffer,
+            ResolveOffset, TableProvider, TopLevelTable,
+        };
+
+        // cmap with a single encoding record pointing beyond the end of the
+        // table
+        let cmap = BeBuffer::new()
+            .push(0u16) // version
+            .push(1u16) // num tables
+            .push(3u16) // platform id
+            .push(1u16) // encoding id
+            .push(40u32); // subtable offset
+        let provider = Provider(&[(Tag::new(b"cmap"), &cmap[..])]);
+        let cmap = provider.expect_table_with_context::<Cmap>().unwrap();
+        let record = cmap.encoding_records()[0];
+        let err = record
+            .subtable_offset()
+            .resolve_with_context::<CmapSubtable>(cmap.offset_data(), "subtable_offset")
+            .table(Cmap::TAG)
+            .unwrap_err();
+        assert!(matches!(err.error, ReadError::OutOfBounds));
+        assert_eq!(err.context.byte_offset(), Some(40));
+        assert_eq!(
+            err.to_string(),
+            "An offset was out of bounds (cmap.subtable_offset at byte 40)"
+        );
+    }
+
+    #[test]
+    fn generated_getter_failure() {
+        use crate::{
+            tables::{cmap::Cmap, gsub::Gsub},
+            test_helpers::BeBuffer,
+            FontRead, Offset, TableProvider, TopLevelTable,
+        };
+
+        // record offset
+        let cmap = BeBuffer::new()
+            .push(0u16) // version
+            .push(1u16) // num tables
+            .push(3u16) // platform id
+            .push(1u16) // encoding id
+            .push(40u32); // subtable offset
+        let provider = Provider(&[(Tag::new(b"cmap"), &cmap[..])]);
+        let cmap = provider.expect_table_with_context::<Cmap>().unwrap();
+        let err = cmap.encoding_records()[0]
+            .subtable_with_context(cmap.offset_data())
+            .table(Cmap::TAG)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "An offset was out of bounds (cmap.subtable_offset at byte 40)"
+        );
+
+        // table offsets and arrays of offsets
+        let gsub = BeBuffer::new()
+            .push(1u16) // major version
+            .push(0u16) // minor version
+            .push(0u16) // script list offset
+            .push(0u16) // feature list offset
+            .push(10u16) // lookup list offset
+            .push(1u16) // lookup count
+            .push(100u16); // lookup offset
+        let gsub = Gsub::read(gsub.font_data()).unwrap();
+        let err = gsub.script_list_with_context().unwrap_err();
+        assert!(matches!(err.error, ReadError::NullOffset));
+        assert_eq!(err.context.byte_offset(), None);
+        assert_eq!(
+            err.to_string(),
+            "An offset was unexpectedly null (script_list_offset)"
+        );
+        let lookup_list = gsub.lookup_list_with_context().unwrap();
+        let err = lookup_list
+            .lookups()
+            .get_with_context(0)
+            .field("lookup_offsets")
+            .byte_offset(gsub.lookup_list_offset().to_usize())
+            .field("lookup_list_offset")
+            .table(Gsub::TAG)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "An offset was out of bounds (GSUB.lookup_list_offset.lookup_offsets[0] at byte 110)"
+        );
+    }
+
+    #[test]
+    fn data_position() {
+        use crate::FontData;
+
+        let bytes = [0u8; 16];
+        let data = FontData::new(&bytes);
+        let child = data.slice(4..10).unwrap();
+        assert_eq!(data.position_of(child), Some(4));
+        assert_eq!(child.position_of(child.split_off(2).unwrap()), Some(2));
+        // not a subslice
+        assert_eq!(child.position_of(data), None);
+        let other = [0u8; 4];
+        assert_eq!(data.position_of(FontData::new(&other)), None);
+    }
+
+    #[test]
+    fn truncated_path() {
+        let mut result = fail().field("innermost");
+        for _ in 0..ErrorContext::MAX_PATH_LEN {
+            result = result.field("outer");
+        }
+        let context = result.table(Tag::new(b"GPOS")).unwrap_err().context;
+        assert!(context.is_truncated());
+        assert_eq!(context.path().count(), ErrorContext::MAX_PATH_LEN);
+        assert_eq!(context.path().last(), Some(PathSegment::Field("innermost")));
+        assert!(context.to_string().starts_with("GPOS...outer."));
+    }
+}
@@ -70,6 +70,16 @@ impl<'a> FontData<'a> {
         Some(FontData { bytes: head })
     }
 
+    /// Returns the position of `child` within this data, or `None` if `child`
+    /// is not a subslice of this data.
+    ///
+    /// This is useful for computing the byte offset of a subtable when
+    /// recording [error context](crate::ReadErrorWithContext).
+    pub fn position_of(&self, child: FontData) -> Option<usize> {
+        let pos = (child.bytes.as_ptr() as usize).checked_sub(self.bytes.as_ptr() as usize)?;
+        (pos.checked_add(child.len())? <= self.len()).then_some(pos)
+    }
+
     pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<FontData<'a>> {
         let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
         self.bytes.get(bounds).map(|bytes| FontData { bytes })
@@ -68,16 +68,30 @@ extern crate std;
 #[macro_use]
 extern crate core as std;
 
+extern crate alloc;
+
 pub mod array;
+mod checksum;
+mod error_context;
 mod font_data;
 mod offset;
 mod offset_array;
 mod read;
+#[cfg(feature = "traversal")]
+pub mod sanitize;
+#[cfg(any(feature = "woff", feature = "woff2"))]
+mod sfnt_builder;
+#[cfg(feature = "std")]
+mod shared;
 mod table_provider;
 mod table_ref;
 pub mod tables;
 #[cfg(feature = "traversal")]
 pub mod traversal;
+#[cfg(feature = "woff")]
+pub mod woff;
+#[cfg(feature = "woff2")]
+pub mod woff2;
 
 #[cfg(any(test, feature = "codegen_test"))]
 pub mod codegen_test;
@@ -89,10 +103,14 @@ mod test_helpers;
 #[cfg(any(test, feature = "scaler_test"))]
 pub mod scaler_test;
 
+pub use checksum::ChecksumMismatch;
+pub use error_context::{ErrorContext, PathSegment, ReadContext, ReadErrorWithContext};
 pub use font_data::FontData;
 pub use offset::{Offset, ResolveNullableOffset, ResolveOffset};
 pub use offset_array::{ArrayOfNullableOffsets, ArrayOfOffsets};
 pub use read::{ComputeSize, FontRead, FontReadWithArgs, ReadArgs, ReadError, VarSize};
+#[cfg(feature = "std")]
+pub use shared::SharedFont;
 pub use table_provider::{TableProvider, TopLevelTable};
 pub use table_ref::TableRef;
 
@@ -103,7 +121,9 @@ pub extern crate font_types as types;
 #[doc(hidden)]
 pub(crate) mod codegen_prelude {
     pub use crate::array::{ComputedArray, VarLenArray};
+    pub use crate::error_context::ReadErrorWithContext;
     pub use crate::font_data::{Cursor, FontData};
+    pub(crate) use crate::offset::WithOffsetContext;
     pub use crate::offset::{Offset, ResolveNullableOffset, ResolveOffset};
     pub use crate::offset_array::{ArrayOfNullableOffsets, ArrayOfOffsets};
     //pub(crate) use crate::read::sealed;
@@ -1,7 +1,11 @@
 //! Handling offsets
 
 use super::read::{FontRead, ReadError};
-use crate::{font_data::FontData, read::FontReadWithArgs};
+use crate::{
+    error_context::{ReadContext, ReadErrorWithContext},
+    font_data::FontData,
+    read::FontReadWithArgs,
+};
 use types::{Nullable, Offset16, Offset24, Offset32};
 
 /// Any offset type.
@@ -40,6 +44,19 @@ pub trait ResolveOffset {
         data: FontData<'a>,
         args: &T::Args,
     ) -> Result<T, ReadError>;
+
+    /// Resolve the offset, recording the field name and byte offset of the
+    /// target on failure.
+    ///
+    /// See [`ReadContext`] for more information.
+    #[allow(clippy::result_large_err)]
+    fn resolve_with_context<'a, T: FontRead<'a>>(
+        &self,
+        data: FontData<'a>,
+        field: &'static str,
+    ) -> Result<T, ReadErrorWithContext> {
+        self.resolve(data).field(field)
+    }
 }
 
 /// A helper trait providing a 'resolve' method for nullable offset types
@@ -51,6 +68,19 @@ pub trait ResolveNullableOffset {
         data: FontData<'a>,
         args: &T::Args,
     ) -> Option<Result<T, ReadError>>;
+
+    /// Resolve the offset, recording the field name and byte offset of the
+    /// target on failure.
+    ///
+    /// See [`ReadContext`] for more information.
+    #[allow(clippy::result_large_err)]
+    fn resolve_with_context<'a, T: FontRead<'a>>(
+        &self,
+        data: FontData<'a>,
+        field: &'static str,
+    ) -> Option<Result<T, ReadErrorWithContext>> {
+        self.resolve(data).map(|result| result.field(field))
+    }
 }
 
 impl<O: Offset> ResolveNullableOffset for Nullable<O> {
@@ -73,6 +103,19 @@ impl<O: Offset> ResolveNullableOffset for Nullable<O> {
             Err(e) => Some(Err(e)),
         }
     }
+
+    #[allow(clippy::result_large_err)]
+    fn resolve_with_context<'a, T: FontRead<'a>>(
+        &self,
+        data: FontData<'a>,
+        field: &'static str,
+    ) -> Option<Result<T, ReadErrorWithContext>> {
+        match self.offset().resolve_with_context(data, field) {
+            Ok(thing) => Some(Ok(thing)),
+            Err(e) if matches!(e.error, ReadError::NullOffset) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
 
 impl<O: Offset> ResolveOffset for O {
@@ -93,4 +136,75 @@ impl<O: Offset> ResolveOffset for O {
             .and_then(|off| data.split_off(off).ok_or(ReadError::OutOfBounds))
             .and_then(|data| T::read_with_args(data, args))
     }
+
+    #[allow(clippy::result_large_err)]
+    fn resolve_with_context<'a, T: FontRead<'a>>(
+        &self,
+        data: FontData<'a>,
+        field: &'static str,
+    ) -> Result<T, ReadErrorWithContext> {
+        let offset = self.to_usize();
+        self.resolve(data).byte_offset(offset).field(field)
+    }
+}
+
+/// The position of an offset's target, used when recording error context.
+///
+/// This is implemented for the raw values returned by generated offset
+/// getters, including nullable and conditional offsets.
+pub(crate) trait OffsetPosition {
+    fn position(&self) -> Option<usize>;
+}
+
+macro_rules! impl_offset_position {
+    ($name:ident) => {
+        impl OffsetPosition for $name {
+            fn position(&self) -> Option<usize> {
+                self.non_null()
+            }
+        }
+    };
+}
+
+impl_offset_position!(Offset16);
+impl_offset_position!(Offset24);
+impl_offset_position!(Offset32);
+
+impl<O: Offset> OffsetPosition for Nullable<O> {
+    fn position(&self) -> Option<usize> {
+        self.offset().non_null()
+    }
+}
+
+impl<T: OffsetPosition> OffsetPosition for Option<T> {
+    fn position(&self) -> Option<usize> {
+        self.as_ref().and_then(OffsetPosition::position)
+    }
+}
+
+/// Attaches context to the result of a generated offset getter.
+pub(crate) trait WithOffsetContext {
+    type Output;
+
+    fn with_offset_context(self, offset: impl OffsetPosition, field: &'static str) -> Self::Output;
+}
+
+impl<T> WithOffsetContext for Result<T, ReadError> {
+    type Output = Result<T, ReadErrorWithContext>;
+
+    fn with_offset_context(self, offset: impl OffsetPosition, field: &'static str) -> Self::Output {
+        match offset.position() {
+            Some(position) => self.byte_offset(position).field(field),
+            None => self.field(field),
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+impl<T> WithOffsetContext for Option<Result<T, ReadError>> {
+    type Output = Option<Result<T, ReadErrorWithContext>>;
+
+    fn with_offset_context(self, offset: impl OffsetPosition, field: &'static str) -> Self::Output {
+        self.map(|result| result.with_offset_context(offset, field))
+    }
 }
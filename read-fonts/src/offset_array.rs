@@ -6,7 +6,10 @@
 use crate::offset::ResolveNullableOffset;
 use font_types::{BigEndian, Nullable, Offset16, Scalar};
 
-use crate::{FontData, FontReadWithArgs, Offset, ReadArgs, ReadError, ResolveOffset};
+use crate::{
+    FontData, FontReadWithArgs, Offset, ReadArgs, ReadContext, ReadError, ReadErrorWithContext,
+    ResolveOffset,
+};
 
 /// An array of offsets that can be resolved on access.
 ///
@@ -73,6 +76,17 @@ where
             .and_then(|o| o.get().resolve_with_args(self.data, &self.args))
     }
 
+    /// Resolve the offset at the provided index, recording the index and
+    /// position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn get_with_context(&self, idx: usize) -> Result<T, ReadErrorWithContext> {
+        let result = self.get(idx);
+        match self.offsets.get(idx).and_then(|o| o.get().non_null()) {
+            Some(position) => result.byte_offset(position).index(idx),
+            None => result.index(idx),
+        }
+    }
+
     /// Iterate over all of the offset targets.
     ///
     /// Each offset will be resolved as it is encountered.
@@ -133,6 +147,23 @@ where
         offset.get().resolve_with_args(self.data, &self.args)
     }
 
+    /// Resolve the offset at the provided index, recording the index and
+    /// position of the target on failure.
+    #[allow(clippy::result_large_err)]
+    pub fn get_with_context(&self, idx: usize) -> Option<Result<T, ReadErrorWithContext>> {
+        let result = self.get(idx)?;
+        Some(
+            match self
+                .offsets
+                .get(idx)
+                .and_then(|o| o.get().offset().non_null())
+            {
+                Some(position) => result.byte_offset(position).index(idx),
+                None => result.index(idx),
+            },
+        )
+    }
+
     /// Iterate over all of the offset targets.
     ///
     /// Each offset will be resolved as it is encountered.
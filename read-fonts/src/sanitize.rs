@@ -0,0 +1,669 @@
+//! Eager validation of font data.
+//!
+//! Parsing in this crate is lazy: tables are only validated as far as is
+//! required to access them, and problems deeper in a table are only reported
+//! when a particular path is followed. The [`sanitize`] function instead walks
+//! every offset and array in every supported table (using the [`traversal`]
+//! machinery) and also checks a number of invariants that span multiple
+//! tables, collecting every problem it finds into a [`SanitizeReport`].
+//!
+//! [`traversal`]: crate::traversal
+
+use std::fmt;
+
+use types::Tag;
+
+use crate::{
+    tables,
+    traversal::{FieldType, SomeArray, SomeTable},
+    FontRef, ReadError, TableProvider, TopLevelTable,
+};
+
+/// The maximum depth of nested offsets that will be followed.
+///
+/// This protects against cycles in offset graphs.
+const MAX_DEPTH: usize = 64;
+
+/// The maximum number of tables, records and array elements that will be
+/// visited in a single font.
+///
+/// Offsets to shared subtables are followed each time they are encountered,
+/// so this bounds the cost of fonts that share subtables heavily.
+const MAX_VISITED: usize = 1 << 22;
+
+/// Validates the entire font, returning a report of any problems found.
+///
+/// This is considerably more expensive than simply reading a font and is
+/// intended for cases where untrusted data should be rejected up front.
+pub fn sanitize(font: &FontRef) -> SanitizeReport {
+    let mut sanitizer = Sanitizer {
+        issues: Vec::new(),
+        path: Vec::new(),
+        table: Tag::default(),
+        visited: 0,
+    };
+    sanitizer.check_table_directory(font);
+    for record in font.table_directory.table_records() {
+        let tag = record.tag();
+        if let Some(table) = some_table(font, tag) {
+            sanitizer.table = tag;
+            match table {
+                Ok(table) => sanitizer.visit_table(&table, 0),
+                Err(err) => sanitizer.push(IssueKind::Read(err)),
+            }
+        }
+    }
+    sanitizer.check_invariants(font);
+    SanitizeReport {
+        issues: sanitizer.issues,
+    }
+}
+
+/// The result of sanitizing a font.
+#[derive(Clone, Debug, Default)]
+pub struct SanitizeReport {
+    issues: Vec<SanitizeIssue>,
+}
+
+impl SanitizeReport {
+    /// Returns true if no problems were found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns all problems that were found, in the order they were
+    /// encountered.
+    pub fn issues(&self) -> &[SanitizeIssue] {
+        &self.issues
+    }
+
+    /// Returns the problems found in the table with the given tag.
+    pub fn issues_for_table(&self, tag: Tag) -> impl Iterator<Item = &SanitizeIssue> + '_ {
+        self.issues.iter().filter(move |issue| issue.table == tag)
+    }
+}
+
+impl fmt::Display for SanitizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single problem found while sanitizing a font.
+#[derive(Clone, Debug)]
+pub struct SanitizeIssue {
+    /// The tag of the table containing the problem.
+    ///
+    /// Problems with the table directory are reported with the tag of the
+    /// affected table record.
+    pub table: Tag,
+    /// The path to the problematic field, starting at the root of the
+    /// table, such as `lookup_list_offset.lookup_offsets[2]`.
+    ///
+    /// This is empty if the problem applies to the table as a whole.
+    pub path: String,
+    /// A description of the problem.
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for SanitizeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}: {}", self.table, self.kind)
+        } else {
+            write!(f, "{}.{}: {}", self.table, self.path, self.kind)
+        }
+    }
+}
+
+/// The kinds of problems that can be reported by [`sanitize`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum IssueKind {
+    /// The table or subtable could not be read.
+    Read(ReadError),
+    /// An element of an array could not be read.
+    ArrayElement(usize),
+    /// The table record extends beyond the end of the font data.
+    TableOutOfBounds,
+    /// The table records are not sorted by tag, or contain duplicates.
+    UnsortedTableDirectory,
+    /// Offsets were nested more deeply than the sanitizer will follow,
+    /// which usually indicates a cycle.
+    MaxDepthExceeded,
+    /// The font is too complex to validate completely; the remaining data
+    /// was not checked.
+    MaxVisitedExceeded,
+    /// The glyph count implied by a table does not match the `maxp` table.
+    NumGlyphsMismatch {
+        /// The number of glyphs in `maxp`.
+        expected: u32,
+        /// The number of glyphs implied by the table.
+        actual: u32,
+    },
+    /// An entry in the `loca` table is smaller than the previous entry.
+    LocaNotMonotonic {
+        /// The index of the entry.
+        index: u32,
+    },
+    /// An entry in the `loca` table is beyond the end of the `glyf` table.
+    LocaOutOfBounds {
+        /// The index of the entry.
+        index: u32,
+    },
+    /// The number of long metrics in the `hhea` or `vhea` table is invalid.
+    InvalidNumberOfLongMetrics(u16),
+    /// The metrics table is too short for the number of metrics declared
+    /// by the header table.
+    MetricsTooShort {
+        /// The number of bytes required.
+        expected: usize,
+        /// The actual length of the table.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueKind::Read(err) => write!(f, "{err}"),
+            IssueKind::ArrayElement(ix) => write!(f, "failed to read array element {ix}"),
+            IssueKind::TableOutOfBounds => write!(f, "table extends beyond the end of the font"),
+            IssueKind::UnsortedTableDirectory => {
+                write!(f, "table records are not sorted by tag")
+            }
+            IssueKind::MaxDepthExceeded => write!(f, "maximum offset depth exceeded"),
+            IssueKind::MaxVisitedExceeded => write!(f, "maximum number of items exceeded"),
+            IssueKind::NumGlyphsMismatch { expected, actual } => {
+                write!(f, "expected {expected} glyphs, found {actual}")
+            }
+            IssueKind::LocaNotMonotonic { index } => {
+                write!(f, "entry {index} is smaller than the previous entry")
+            }
+            IssueKind::LocaOutOfBounds { index } => {
+                write!(f, "entry {index} is beyond the end of the glyf table")
+            }
+            IssueKind::InvalidNumberOfLongMetrics(count) => {
+                write!(f, "invalid number of long metrics {count}")
+            }
+            IssueKind::MetricsTooShort { expected, actual } => {
+                write!(f, "expected at least {expected} bytes, found {actual}")
+            }
+        }
+    }
+}
+
+enum PathElement {
+    Field(&'static str),
+    Index(usize),
+}
+
+struct Sanitizer {
+    issues: Vec<SanitizeIssue>,
+    path: Vec<PathElement>,
+    table: Tag,
+    visited: usize,
+}
+
+impl Sanitizer {
+    fn push(&mut self, kind: IssueKind) {
+        let mut path = String::new();
+        for element in &self.path {
+            match element {
+                PathElement::Field(name) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(name);
+                }
+                PathElement::Index(ix) => path.push_str(&format!("[{ix}]")),
+            }
+        }
+        self.issues.push(SanitizeIssue {
+            table: self.table,
+            path,
+            kind,
+        });
+    }
+
+    fn push_for_table(&mut self, table: Tag, kind: IssueKind) {
+        self.table = table;
+        self.path.clear();
+        self.push(kind);
+    }
+
+    /// Returns false if traversal should stop.
+    fn visit(&mut self) -> bool {
+        self.visited += 1;
+        if self.visited == MAX_VISITED {
+            self.push(IssueKind::MaxVisitedExceeded);
+        }
+        self.visited < MAX_VISITED
+    }
+
+    fn visit_table<'a>(&mut self, table: &(dyn SomeTable<'a> + 'a), depth: usize) {
+        if depth > MAX_DEPTH {
+            self.push(IssueKind::MaxDepthExceeded);
+            return;
+        }
+        for field in table.iter() {
+            if !self.visit() {
+                return;
+            }
+            self.path.push(PathElement::Field(field.name));
+            self.visit_field(field.value, depth);
+            self.path.pop();
+        }
+    }
+
+    fn visit_field<'a>(&mut self, field: FieldType<'a>, depth: usize) {
+        match field {
+            FieldType::ResolvedOffset(offset) => match offset.target {
+                Ok(table) => self.visit_table(&table, depth + 1),
+                Err(err) => self.push(IssueKind::Read(err)),
+            },
+            FieldType::ArrayOffset(offset) => match offset.target {
+                Ok(array) => self.visit_array(&array, depth + 1),
+                Err(err) => self.push(IssueKind::Read(err)),
+            },
+            FieldType::StringOffset(offset) => {
+                if let Err(err) = offset.target {
+                    self.push(IssueKind::Read(err));
+                }
+            }
+            FieldType::Record(record) => self.visit_table(&record, depth),
+            FieldType::Array(array) => self.visit_array(&array, depth),
+            _ => (),
+        }
+    }
+
+    fn visit_array<'a>(&mut self, array: &(dyn SomeArray<'a> + 'a), depth: usize) {
+        for ix in 0..array.len() {
+            let Some(item) = array.get(ix) else {
+                self.push(IssueKind::ArrayElement(ix));
+                return;
+            };
+            if ix == 0 && !may_contain_offsets(&item) {
+                // Arrays are homogeneous, so the remaining elements are
+                // scalars as well and were validated when the parent was read
+                return;
+            }
+            if !self.visit() {
+                return;
+            }
+            self.path.push(PathElement::Index(ix));
+            self.visit_field(item, depth);
+            self.path.pop();
+        }
+    }
+
+    fn check_table_directory(&mut self, font: &FontRef) {
+        let records = font.table_directory.table_records();
+        let data_len = font.data.len() as u64;
+        for (ix, record) in records.iter().enumerate() {
+            let end = record.offset() as u64 + record.length() as u64;
+            if end > data_len {
+                self.push_for_table(record.tag(), IssueKind::TableOutOfBounds);
+            }
+            if ix > 0 && records[ix - 1].tag() >= record.tag() {
+                self.push_for_table(record.tag(), IssueKind::UnsortedTableDirectory);
+            }
+        }
+    }
+
+    fn check_invariants(&mut self, font: &FontRef) {
+        self.path.clear();
+        let Ok(num_glyphs) = font.maxp().map(|maxp| maxp.num_glyphs()) else {
+            return;
+        };
+        self.check_loca(font, num_glyphs);
+        if let Ok(hhea) = font.hhea() {
+            self.check_metrics(
+                font,
+                tables::hmtx::Hmtx::TAG,
+                hhea.number_of_long_metrics(),
+                num_glyphs,
+            );
+        }
+        if let Ok(vhea) = font.vhea() {
+            self.check_metrics(
+                font,
+                tables::vmtx::Vmtx::TAG,
+                vhea.number_of_long_ver_metrics(),
+                num_glyphs,
+            );
+        }
+        if let Ok(gvar) = font.gvar() {
+            self.check_num_glyphs(tables::gvar::Gvar::TAG, num_glyphs, gvar.glyph_count());
+        }
+        if let Some(post_glyphs) = font.post().ok().and_then(|post| post.num_glyphs()) {
+            self.check_num_glyphs(tables::post::Post::TAG, num_glyphs, post_glyphs);
+        }
+    }
+
+    fn check_num_glyphs(&mut self, tag: Tag, expected: u16, actual: u16) {
+        if expected != actual {
+            self.push_for_table(
+                tag,
+                IssueKind::NumGlyphsMismatch {
+                    expected: expected as u32,
+                    actual: actual as u32,
+                },
+            );
+        }
+    }
+
+    fn check_loca(&mut self, font: &FontRef, num_glyphs: u16) {
+        let tag = tables::loca::Loca::TAG;
+        let Ok(loca) = font.loca(None) else {
+            return;
+        };
+        // Loca::len returns the number of glyphs, which is one less than the
+        // number of entries
+        if loca.len() < num_glyphs as usize {
+            self.push_for_table(
+                tag,
+                IssueKind::NumGlyphsMismatch {
+                    expected: num_glyphs as u32,
+                    actual: loca.len() as u32,
+                },
+            );
+        }
+        let glyf_len = font
+            .table_data(tables::glyf::Glyf::TAG)
+            .map(|data| data.len() as u32);
+        let mut prev = 0;
+        for ix in 0..=num_glyphs as usize {
+            let Some(offset) = loca.get_raw(ix) else {
+                break;
+            };
+            let index = ix as u32;
+            if offset < prev {
+                self.push_for_table(tag, IssueKind::LocaNotMonotonic { index });
+            }
+            if glyf_len.is_some_and(|len| offset > len) {
+                self.push_for_table(tag, IssueKind::LocaOutOfBounds { index });
+            }
+            prev = offset;
+        }
+    }
+
+    fn check_metrics(&mut self, font: &FontRef, tag: Tag, num_long_metrics: u16, num_glyphs: u16) {
+        let Some(data) = font.table_data(tag) else {
+            return;
+        };
+        if num_long_metrics == 0 || num_long_metrics > num_glyphs {
+            self.push_for_table(tag, IssueKind::InvalidNumberOfLongMetrics(num_long_metrics));
+            return;
+        }
+        // Each long metric is 4 bytes and the remaining glyphs each have a
+        // 2 byte side bearing
+        let expected = num_long_metrics as usize * 4 + (num_glyphs - num_long_metrics) as usize * 2;
+        if data.len() < expected {
+            self.push_for_table(
+                tag,
+                IssueKind::MetricsTooShort {
+                    expected,
+                    actual: data.len(),
+                },
+            );
+        }
+    }
+}
+
+fn may_contain_offsets(field: &FieldType) -> bool {
+    matches!(
+        field,
+        FieldType::ResolvedOffset(_)
+            | FieldType::ArrayOffset(_)
+            | FieldType::StringOffset(_)
+            | FieldType::Record(_)
+            | FieldType::Array(_)
+    )
+}
+
+/// Returns the given table as a traversable object, or `None` if the table
+/// is not supported.
+fn some_table<'a>(
+    font: &FontRef<'a>,
+    tag: Tag,
+) -> Option<Result<Box<dyn SomeTable<'a> + 'a>, ReadError>> {
+    fn boxed<'a, T: SomeTable<'a> + 'a>(
+        table: Result<T, ReadError>,
+    ) -> Option<Result<Box<dyn SomeTable<'a> + 'a>, ReadError>> {
+        Some(table.map(|table| Box::new(table) as _))
+    }
+    use tables::*;
+    match tag {
+        avar::Avar::TAG => boxed(font.avar()),
+        cbdt::Cbdt::TAG => boxed(font.cbdt()),
+        cblc::Cblc::TAG => boxed(font.cblc()),
+        cmap::Cmap::TAG => boxed(font.cmap()),
+        colr::Colr::TAG => boxed(font.colr()),
+        cpal::Cpal::TAG => boxed(font.cpal()),
+        cvar::Cvar::TAG => boxed(font.cvar()),
+        dsig::Dsig::TAG => boxed(font.dsig()),
+        ebdt::Ebdt::TAG => boxed(font.ebdt()),
+        eblc::Eblc::TAG => boxed(font.eblc()),
+        feat::Feat::TAG => boxed(font.feat()),
+        fvar::Fvar::TAG => boxed(font.fvar()),
+        gdef::Gdef::TAG => boxed(font.gdef()),
+        glyf::Glyf::TAG => boxed(font.glyf()),
+        gpos::Gpos::TAG => boxed(font.gpos()),
+        gsub::Gsub::TAG => boxed(font.gsub()),
+        gvar::Gvar::TAG => boxed(font.gvar()),
+        hdmx::Hdmx::TAG => boxed(font.hdmx()),
+        head::Head::TAG => boxed(font.head()),
+        hhea::Hhea::TAG => boxed(font.hhea()),
+        hmtx::Hmtx::TAG => boxed(font.hmtx()),
+        hvar::Hvar::TAG => boxed(font.hvar()),
+        jstf::Jstf::TAG => boxed(font.jstf()),
+        loca::Loca::TAG => boxed(font.loca(None)),
+        ltag::Ltag::TAG => boxed(font.ltag()),
+        ltsh::Ltsh::TAG => boxed(font.ltsh()),
+        math::Math::TAG => boxed(font.math()),
+        maxp::Maxp::TAG => boxed(font.maxp()),
+        meta::Meta::TAG => boxed(font.meta()),
+        mvar::Mvar::TAG => boxed(font.mvar()),
+        name::Name::TAG => boxed(font.name()),
+        os2::Os2::TAG => boxed(font.os2()),
+        pclt::Pclt::TAG => boxed(font.pclt()),
+        post::Post::TAG => boxed(font.post()),
+        sbix::Sbix::TAG => boxed(font.sbix()),
+        stat::Stat::TAG => boxed(font.stat()),
+        svg::Svg::TAG => boxed(font.svg()),
+        trak::Trak::TAG => boxed(font.trak()),
+        varc::Varc::TAG => boxed(font.varc()),
+        vdmx::Vdmx::TAG => boxed(font.vdmx()),
+        vhea::Vhea::TAG => boxed(font.vhea()),
+        vmtx::Vmtx::TAG => boxed(font.vmtx()),
+        vorg::Vorg::TAG => boxed(font.vorg()),
+        vvar::Vvar::TAG => boxed(font.vvar()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::patch;
+    use font_test_data as data;
+
+    fn kinds(report: &SanitizeReport, tag: &[u8; 4]) -> Vec<String> {
+        report
+            .issues_for_table(Tag::new(tag))
+            .map(|issue| format!("{:?}", issue.kind))
+            .collect()
+    }
+
+    #[test]
+    fn valid_fonts() {
+        for font in [
+            data::VAZIRMATN_VAR,
+            data::CANTARELL_VF_TRIMMED,
+            data::COLRV0V1_VARIABLE,
+            data::CBDT,
+            data::AVAR2,
+            data::closure::RECURSIVE_CONTEXTUAL,
+        ] {
+            let report = sanitize(&FontRef::new(font).unwrap());
+            assert!(report.is_ok(), "{report}");
+        }
+    }
+
+    #[test]
+    fn missing_dependency() {
+        // hmtx can't be read without hhea
+        let report = sanitize(&FontRef::new(data::SIMPLE_GLYF).unwrap());
+        assert_eq!(report.issues().len(), 1);
+        let issue = &report.issues()[0];
+        assert_eq!(issue.table, Tag::new(b"hmtx"));
+        assert!(issue.path.is_empty());
+        assert!(matches!(
+            issue.kind,
+            IssueKind::Read(ReadError::TableIsMissing(tag)) if tag == Tag::new(b"hhea")
+        ));
+    }
+
+    #[test]
+    fn bad_offset_path() {
+        // point the lookup list beyond the end of the table
+        let font = patch(
+            data::closure::RECURSIVE_CONTEXTUAL,
+            Tag::new(b"GSUB"),
+            8,
+            &0xFFF0u16.to_be_bytes(),
+        );
+        let report = sanitize(&FontRef::new(&font).unwrap());
+        let issue = report
+            .issues_for_table(Tag::new(b"GSUB"))
+            .next()
+            .expect("missing GSUB issue");
+        assert_eq!(issue.path, "lookup_list_offset");
+        assert!(matches!(issue.kind, IssueKind::Read(_)));
+        assert_eq!(
+            issue.to_string(),
+            "GSUB.lookup_list_offset: An offset was out of bounds"
+        );
+    }
+
+    #[test]
+    fn nested_path() {
+        let gsub = FontRef::new(data::closure::RECURSIVE_CONTEXTUAL)
+            .unwrap()
+            .gsub()
+            .unwrap();
+        let lookup_list = gsub.lookup_list_offset().to_u32() as usize;
+        let first_lookup = gsub.lookup_list().unwrap().lookup_offsets()[0]
+            .get()
+            .to_u32() as usize;
+        // the first subtable offset of the first lookup
+        let subtable = lookup_list + first_lookup + 6;
+        let font = patch(
+            data::closure::RECURSIVE_CONTEXTUAL,
+            Tag::new(b"GSUB"),
+            subtable,
+            &0xFFF0u16.to_be_bytes(),
+        );
+        let report = sanitize(&FontRef::new(&font).unwrap());
+        let paths = report
+            .issues_for_table(Tag::new(b"GSUB"))
+            .map(|issue| issue.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            ["lookup_list_offset.lookup_offsets[0].subtable_offsets[0]"]
+        );
+    }
+
+    #[test]
+    fn num_glyphs_mismatch() {
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        let num_glyphs = font.maxp().unwrap().num_glyphs();
+        let patched = patch(
+            data::VAZIRMATN_VAR,
+            Tag::new(b"maxp"),
+            4,
+            &(num_glyphs + 2).to_be_bytes(),
+        );
+        let report = sanitize(&FontRef::new(&patched).unwrap());
+        let expected = num_glyphs as u32 + 2;
+        let actual = num_glyphs as u32;
+        let mismatch = format!("NumGlyphsMismatch {{ expected: {expected}, actual: {actual} }}");
+        assert!(kinds(&report, b"loca").contains(&mismatch), "{report}");
+        assert!(kinds(&report, b"gvar").contains(&mismatch), "{report}");
+        assert!(
+            kinds(&report, b"hmtx")
+                .iter()
+                .any(|kind| kind.starts_with("MetricsTooShort")),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn invalid_long_metrics() {
+        // numberOfHMetrics is the final field of hhea
+        let font = patch(
+            data::VAZIRMATN_VAR,
+            Tag::new(b"hhea"),
+            34,
+            &0u16.to_be_bytes(),
+        );
+        let report = sanitize(&FontRef::new(&font).unwrap());
+        assert!(kinds(&report, b"hmtx").contains(&"InvalidNumberOfLongMetrics(0)".into()));
+    }
+
+    #[test]
+    fn loca_not_monotonic() {
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        let is_long = font.head().unwrap().index_to_loc_format() == 1;
+        let glyf_len = font.table_data(Tag::new(b"glyf")).unwrap().len();
+        let patched = if is_long {
+            patch(
+                data::VAZIRMATN_VAR,
+                Tag::new(b"loca"),
+                4,
+                &(glyf_len as u32 + 4).to_be_bytes(),
+            )
+        } else {
+            patch(
+                data::VAZIRMATN_VAR,
+                Tag::new(b"loca"),
+                2,
+                &(glyf_len as u16 / 2 + 2).to_be_bytes(),
+            )
+        };
+        let report = sanitize(&FontRef::new(&patched).unwrap());
+        let kinds = kinds(&report, b"loca");
+        assert!(
+            kinds.contains(&"LocaOutOfBounds { index: 1 }".into()),
+            "{report}"
+        );
+        assert!(
+            kinds.contains(&"LocaNotMonotonic { index: 2 }".into()),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn table_directory() {
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        let records = font.table_directory.table_records();
+        // the tag and length of the first record
+        let mut data = data::VAZIRMATN_VAR.to_vec();
+        data[12..16].copy_from_slice(b"zzzz");
+        data[24..28].copy_from_slice(&u32::MAX.to_be_bytes());
+        let report = sanitize(&FontRef::new(&data).unwrap());
+        let issues = report
+            .issues_for_table(Tag::new(b"zzzz"))
+            .collect::<Vec<_>>();
+        assert!(matches!(issues[0].kind, IssueKind::TableOutOfBounds));
+        let second = records[1].tag();
+        assert!(report
+            .issues_for_table(second)
+            .any(|issue| matches!(issue.kind, IssueKind::UnsortedTableDirectory)));
+    }
+}
@@ -0,0 +1,165 @@
+//! Writing sfnt fonts and collections, for decoders of wrapper formats.
+
+use alloc::vec::Vec;
+
+use types::{Tag, TTC_HEADER_TAG};
+
+use crate::ReadError;
+
+/// Size of the sfnt header, before the table records.
+const SFNT_HEADER_LEN: usize = 12;
+
+/// Size of a single sfnt table record.
+const SFNT_TABLE_RECORD_LEN: usize = 16;
+
+/// Upper bound on the size of a decoded table, metadata block or font.
+///
+/// This guards against excessive allocation for malicious files.
+pub(crate) const MAX_DECODED_LEN: usize = 256 * 1024 * 1024;
+
+/// Writes an sfnt font or font collection from a sequence of tables.
+///
+/// The table directories are written up front, with empty table records that
+/// are filled in as tables are added. Table data is written in the order that
+/// tables are pushed.
+pub(crate) struct SfntBuilder {
+    data: Vec<u8>,
+    // the offset and number of tables of each table directory
+    directories: Vec<(usize, usize)>,
+}
+
+impl SfntBuilder {
+    /// Creates a builder for a single font.
+    pub(crate) fn new(flavor: u32, num_tables: usize) -> Result<Self, ReadError> {
+        let mut builder = Self {
+            data: Vec::new(),
+            directories: Vec::new(),
+        };
+        builder.add_directory(flavor, num_tables)?;
+        Ok(builder)
+    }
+
+    /// Creates a builder for a font collection.
+    ///
+    /// Each font is described by its flavor ("sfnt version") and number of
+    /// tables. Fonts are identified by their index in this list when adding
+    /// table records.
+    #[cfg_attr(not(feature = "woff2"), allow(dead_code))]
+    pub(crate) fn new_collection(version: u32, fonts: &[(u32, usize)]) -> Result<Self, ReadError> {
+        let num_fonts = u32::try_from(fonts.len()).map_err(|_| ReadError::OutOfBounds)?;
+        let mut data = Vec::new();
+        data.extend_from_slice(&TTC_HEADER_TAG.to_be_bytes());
+        data.extend_from_slice(&version.to_be_bytes());
+        data.extend_from_slice(&num_fonts.to_be_bytes());
+        let offsets_start = data.len();
+        data.resize(offsets_start + fonts.len() * 4, 0);
+        // version 2.0 headers include the (empty) DSIG tag, length and offset
+        if version >= 0x0002_0000 {
+            data.resize(data.len() + 12, 0);
+        }
+        let mut builder = Self {
+            data,
+            directories: Vec::with_capacity(fonts.len()),
+        };
+        for (i, (flavor, num_tables)) in fonts.iter().enumerate() {
+            let offset = builder.data.len() as u32;
+            let start = offsets_start + i * 4;
+            builder.data[start..start + 4].copy_from_slice(&offset.to_be_bytes());
+            builder.add_directory(*flavor, *num_tables)?;
+        }
+        Ok(builder)
+    }
+
+    fn add_directory(&mut self, flavor: u32, num_tables: usize) -> Result<(), ReadError> {
+        let num_tables_u16 = u16::try_from(num_tables).map_err(|_| ReadError::OutOfBounds)?;
+        let start = self.data.len();
+        // search_range is the largest power of two less than or equal to the
+        // number of tables, multiplied by 16
+        let entry_selector = if num_tables == 0 {
+            0
+        } else {
+            15 - num_tables_u16.leading_zeros() as u16
+        };
+        let search_range = (1u16 << entry_selector).wrapping_mul(16);
+        let range_shift = num_tables_u16.wrapping_mul(16).wrapping_sub(search_range);
+        self.data.extend_from_slice(&flavor.to_be_bytes());
+        for value in [num_tables_u16, search_range, entry_selector, range_shift] {
+            self.data.extend_from_slice(&value.to_be_bytes());
+        }
+        self.data.resize(
+            start + SFNT_HEADER_LEN + num_tables * SFNT_TABLE_RECORD_LEN,
+            0,
+        );
+        self.directories.push((start, num_tables));
+        Ok(())
+    }
+
+    pub(crate) fn reserve(&mut self, total_len: usize) {
+        let additional = total_len
+            .min(MAX_DECODED_LEN)
+            .saturating_sub(self.data.len());
+        self.data.reserve(additional);
+    }
+
+    /// Appends the data for a table to a single font, and writes its record
+    /// at the given index in the table directory.
+    #[cfg_attr(not(feature = "woff"), allow(dead_code))]
+    pub(crate) fn push(
+        &mut self,
+        index: usize,
+        tag: Tag,
+        checksum: u32,
+        table: &[u8],
+    ) -> Result<(), ReadError> {
+        let offset = self.push_data(table)?;
+        self.write_record(0, index, tag, checksum, offset, table.len())
+    }
+
+    /// Appends table data without writing a table record, returning the
+    /// offset of the data.
+    ///
+    /// This is used for collections, where a table may be shared by multiple
+    /// fonts.
+    pub(crate) fn push_data(&mut self, table: &[u8]) -> Result<usize, ReadError> {
+        if self.data.len() + table.len() > MAX_DECODED_LEN {
+            return Err(ReadError::MalformedData("decoded font is too large"));
+        }
+        let offset = self.data.len();
+        self.data.extend_from_slice(table);
+        // tables are padded to a four byte boundary
+        self.data.resize(self.data.len().next_multiple_of(4), 0);
+        Ok(offset)
+    }
+
+    /// Writes the table record at `index` in the table directory of the
+    /// given font.
+    pub(crate) fn write_record(
+        &mut self,
+        font: usize,
+        index: usize,
+        tag: Tag,
+        checksum: u32,
+        offset: usize,
+        len: usize,
+    ) -> Result<(), ReadError> {
+        let (directory_start, num_tables) = self
+            .directories
+            .get(font)
+            .copied()
+            .ok_or(ReadError::OutOfBounds)?;
+        if index >= num_tables {
+            return Err(ReadError::OutOfBounds);
+        }
+        let record_start = directory_start + SFNT_HEADER_LEN + index * SFNT_TABLE_RECORD_LEN;
+        let record = &mut self.data[record_start..record_start + SFNT_TABLE_RECORD_LEN];
+        record[0..4].copy_from_slice(&tag.to_be_bytes());
+        record[4..8].copy_from_slice(&checksum.to_be_bytes());
+        record[8..12].copy_from_slice(&(offset as u32).to_be_bytes());
+        record[12..16].copy_from_slice(&(len as u32).to_be_bytes());
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Vec<u8> {
+        self.data
+    }
+}
@@ -0,0 +1,166 @@
+//! Fonts with shared ownership of their data.
+
+use std::sync::Arc;
+
+use types::{Offset32, Tag};
+
+use crate::{FontData, FontRef, Offset, ReadError, TableProvider, TableRecord};
+
+/// An owned font backed by reference counted data.
+///
+/// This is cheap to clone and can be stored in long-lived structures such as
+/// caches without borrowing from a separate buffer. The data is validated on
+/// construction.
+///
+/// Tables are accessed through the [`TableProvider`] implementation for
+/// `&SharedFont`, which means that tables and other objects created from a
+/// shared font borrow from it, rather than from the underlying buffer. Since
+/// cloning is cheap, the usual pattern is to store the font and recreate
+/// these objects as needed. The table directory is read once, on
+/// construction, so looking up tables is as fast as with a [`FontRef`]:
+///
+/// ```
+/// # use read_fonts::{SharedFont, TableProvider, ReadError};
+/// # fn example(data: Vec<u8>) -> Result<(), ReadError> {
+/// let font = SharedFont::new(data)?;
+/// let upem = (&font).head()?.units_per_em();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SharedFont {
+    data: Arc<[u8]>,
+    index: u32,
+    tables: Arc<[TableRecord]>,
+}
+
+impl SharedFont {
+    /// Creates a new shared font from the given data.
+    ///
+    /// The data must be a single font (not a font collection). To load a
+    /// font from a font collection, use [`SharedFont::from_index`] instead.
+    pub fn new(data: impl Into<Arc<[u8]>>) -> Result<Self, ReadError> {
+        let data = data.into();
+        let tables = table_records(&FontRef::new(&data)?);
+        Ok(Self {
+            data,
+            index: 0,
+            tables,
+        })
+    }
+
+    /// Creates a new shared font at the specified index in the given data.
+    ///
+    /// As with [`FontRef::from_index`], this accepts both font collection and
+    /// single font files. If a single font file is provided, the index must
+    /// be 0.
+    pub fn from_index(data: impl Into<Arc<[u8]>>, index: u32) -> Result<Self, ReadError> {
+        let data = data.into();
+        let tables = table_records(&FontRef::from_index(&data, index)?);
+        Ok(Self {
+            data,
+            index,
+            tables,
+        })
+    }
+
+    /// Returns the shared data of the file containing this font.
+    pub fn data(&self) -> &Arc<[u8]> {
+        &self.data
+    }
+
+    /// Returns the index of this font in its collection, or 0 if the data is
+    /// a single font.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns a borrowed reference to this font.
+    pub fn font_ref(&self) -> FontRef<'_> {
+        FontRef::from_index(&self.data, self.index)
+            .expect("font data is validated when the shared font is created")
+    }
+}
+
+impl<'a> TableProvider<'a> for &'a SharedFont {
+    fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+        let idx = self
+            .tables
+            .binary_search_by(|rec| rec.tag.get().cmp(&tag))
+            .ok()?;
+        let record = self.tables.get(idx)?;
+        let start = Offset32::new(record.offset()).non_null()?;
+        let len = record.length() as usize;
+        FontData::new(&self.data).slice(start..start.checked_add(len)?)
+    }
+}
+
+fn table_records(font: &FontRef) -> Arc<[TableRecord]> {
+    font.table_directory.table_records().into()
+}
+
+impl std::fmt::Debug for SharedFont {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedFont")
+            .field("len", &self.data.len())
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use font_test_data::SIMPLE_GLYF;
+
+    #[test]
+    fn tables_match_font_ref() {
+        let font = SharedFont::new(SIMPLE_GLYF.to_vec()).unwrap();
+        let font_ref = FontRef::new(SIMPLE_GLYF).unwrap();
+        assert_eq!(
+            (&font).head().unwrap().units_per_em(),
+            font_ref.head().unwrap().units_per_em()
+        );
+        assert_eq!(
+            (&font).maxp().unwrap().num_glyphs(),
+            font_ref.maxp().unwrap().num_glyphs()
+        );
+        // clones share the same data
+        let clone = font.clone();
+        assert!(Arc::ptr_eq(font.data(), clone.data()));
+    }
+
+    #[test]
+    fn from_index() {
+        let font = SharedFont::from_index(SIMPLE_GLYF, 0).unwrap();
+        let font_ref = FontRef::new(SIMPLE_GLYF).unwrap();
+        assert_eq!(font.index(), 0);
+        assert_eq!(
+            (&font).data_for_tag(Tag::new(b"glyf")).unwrap().as_bytes(),
+            font_ref.table_data(Tag::new(b"glyf")).unwrap().as_bytes()
+        );
+        assert!(matches!(
+            SharedFont::from_index(SIMPLE_GLYF, 1),
+            Err(ReadError::InvalidCollectionIndex(1))
+        ));
+    }
+
+    #[test]
+    fn cached_table_directory() {
+        let font = SharedFont::new(SIMPLE_GLYF).unwrap();
+        let font_ref = FontRef::new(SIMPLE_GLYF).unwrap();
+        for record in font_ref.table_directory.table_records() {
+            assert_eq!(
+                (&font).data_for_tag(record.tag()).unwrap().as_bytes(),
+                font_ref.table_data(record.tag()).unwrap().as_bytes()
+            );
+        }
+        assert!((&font).data_for_tag(Tag::new(b"GSUB")).is_none());
+    }
+
+    #[test]
+    fn invalid_data() {
+        assert!(SharedFont::new(vec![0u8; 4]).is_err());
+        assert!(SharedFont::new(&SIMPLE_GLYF[..8]).is_err());
+    }
+}
@@ -2,7 +2,7 @@
 
 use types::Tag;
 
-use crate::{tables, FontData, FontRead, ReadError};
+use crate::{tables, FontData, FontRead, ReadContext, ReadError, ReadErrorWithContext};
 
 /// A table that has an associated tag.
 ///
@@ -24,6 +24,18 @@ pub trait TableProvider<'a> {
         self.expect_data_for_tag(T::TAG).and_then(FontRead::read)
     }
 
+    /// Like [`expect_table`](Self::expect_table), but records the tag of the
+    /// table in the error context on failure.
+    ///
+    /// Further context can be added when resolving offsets within the table
+    /// with [`resolve_with_context`](crate::ResolveOffset::resolve_with_context).
+    #[allow(clippy::result_large_err)]
+    fn expect_table_with_context<T: TopLevelTable + FontRead<'a>>(
+        &self,
+    ) -> Result<T, ReadErrorWithContext> {
+        self.expect_table().table(T::TAG)
+    }
+
     fn head(&self) -> Result<tables::head::Head<'a>, ReadError> {
         self.expect_table()
     }
@@ -172,6 +184,64 @@ pub trait TableProvider<'a> {
     fn varc(&self) -> Result<tables::varc::Varc<'a>, ReadError> {
         self.expect_table()
     }
+
+    fn trak(&self) -> Result<tables::trak::Trak<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn feat(&self) -> Result<tables::feat::Feat<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn ltag(&self) -> Result<tables::ltag::Ltag<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn math(&self) -> Result<tables::math::Math<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn base(&self) -> Result<tables::base::Base<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn jstf(&self) -> Result<tables::jstf::Jstf<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn svg(&self) -> Result<tables::svg::Svg<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn hdmx(&self) -> Result<tables::hdmx::Hdmx<'a>, ReadError> {
+        let num_glyphs = self.maxp().map(|maxp| maxp.num_glyphs())?;
+        let data = self.expect_data_for_tag(tables::hdmx::Hdmx::TAG)?;
+        tables::hdmx::Hdmx::read(data, num_glyphs)
+    }
+
+    fn vdmx(&self) -> Result<tables::vdmx::Vdmx<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn ltsh(&self) -> Result<tables::ltsh::Ltsh<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn vorg(&self) -> Result<tables::vorg::Vorg<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn meta(&self) -> Result<tables::meta::Meta<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn pclt(&self) -> Result<tables::pclt::Pclt<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn dsig(&self) -> Result<tables::dsig::Dsig<'a>, ReadError> {
+        self.expect_table()
+    }
 }
 
 #[cfg(test)]
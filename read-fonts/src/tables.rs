@@ -12,8 +12,10 @@ pub mod cmap;
 pub mod colr;
 pub mod cpal;
 pub mod cvar;
+pub mod dsig;
 pub mod ebdt;
 pub mod eblc;
+pub mod feat;
 pub mod fvar;
 pub mod gasp;
 pub mod gdef;
@@ -21,22 +23,33 @@ pub mod glyf;
 pub mod gpos;
 pub mod gsub;
 pub mod gvar;
+pub mod hdmx;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
 pub mod hvar;
+pub mod jstf;
 pub mod layout;
 pub mod loca;
+pub mod ltag;
+pub mod ltsh;
+pub mod math;
 pub mod maxp;
+pub mod meta;
 pub mod mvar;
 pub mod name;
 pub mod os2;
+pub mod pclt;
 pub mod post;
 pub mod postscript;
 pub mod sbix;
 pub mod stat;
+pub mod svg;
+pub mod trak;
 pub mod varc;
 pub mod variations;
+pub mod vdmx;
 pub mod vhea;
 pub mod vmtx;
+pub mod vorg;
 pub mod vvar;
@@ -1,7 +1,78 @@
 //! The [Axis Variations](https://docs.microsoft.com/en-us/typography/opentype/spec/avar) table
 
+use super::variations::{DeltaSetIndex, DeltaSetIndexMap, ItemVariationStore};
+use alloc::vec::Vec;
+
 include!("../../generated/generated_avar.rs");
 
+/// Maximum number of axes for which the version 2 mapping avoids allocating.
+///
+/// Computing the deltas requires a copy of the intermediate coordinates. We
+/// keep that on the stack for up to this many axes and on the heap otherwise.
+const MAX_STACK_AXES: usize = 64;
+
+impl<'a> Avar<'a> {
+    /// Applies the full axis variation mapping to a set of normalized
+    /// coordinates, in place.
+    ///
+    /// On input, `coords` should contain one coordinate per axis, in `fvar`
+    /// order, as produced by default normalization (see
+    /// [`VariationAxisRecord::normalize`](super::fvar::VariationAxisRecord::normalize)).
+    ///
+    /// Each coordinate is first remapped by the segment map for its axis. For
+    /// version 2 tables, the remapped coordinates are then adjusted by deltas
+    /// from the item variation store, selected through the axis index map
+    /// when present, and clamped to the range -1.0 to 1.0.
+    pub fn normalize(&self, coords: &mut [Fixed]) {
+        for (coord, segment_map) in coords.iter_mut().zip(self.axis_segment_maps().iter()) {
+            if let Ok(segment_map) = segment_map {
+                *coord = segment_map.apply(*coord);
+            }
+        }
+        if self.version() < MajorMinor::VERSION_2_0 {
+            return;
+        }
+        let Some(Ok(var_store)) = self.var_store() else {
+            return;
+        };
+        let axis_index_map = self.axis_index_map().transpose().ok().flatten();
+        // Deltas are computed from the coordinates produced by the segment
+        // maps, so keep a copy of those around while we modify the originals
+        let mut stack_coords = [F2Dot14::ZERO; MAX_STACK_AXES];
+        let mut heap_coords = Vec::new();
+        let mapped_coords = match stack_coords.get_mut(..coords.len()) {
+            Some(stack_coords) => stack_coords,
+            None => {
+                heap_coords.resize(coords.len(), F2Dot14::ZERO);
+                heap_coords.as_mut_slice()
+            }
+        };
+        for (mapped, coord) in mapped_coords.iter_mut().zip(coords.iter()) {
+            *mapped = coord.to_f2dot14();
+        }
+        let mapped_coords = &*mapped_coords;
+        for (i, coord) in coords.iter_mut().enumerate() {
+            let index = match &axis_index_map {
+                Some(map) => match map.get(i as u32) {
+                    Ok(index) => index,
+                    Err(_) => continue,
+                },
+                None => DeltaSetIndex {
+                    outer: 0,
+                    inner: i as u16,
+                },
+            };
+            let Ok(delta) = var_store.compute_delta(index, mapped_coords) else {
+                continue;
+            };
+            // Deltas are in F2Dot14 units
+            *coord = coord
+                .saturating_add(Fixed::from_bits(delta.saturating_mul(4)))
+                .clamp(-Fixed::ONE, Fixed::ONE);
+        }
+    }
+}
+
 impl<'a> SegmentMaps<'a> {
     /// Applies the piecewise linear mapping to the specified coordinate.
     pub fn apply(&self, coord: Fixed) -> Fixed {
@@ -156,4 +227,81 @@ mod tests {
                 .collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn version_2_fields() {
+        let font = FontRef::new(font_test_data::AVAR2).unwrap();
+        let avar = font.avar().unwrap();
+        assert_eq!(avar.version(), MajorMinor::VERSION_2_0);
+        // segment maps must be bounded by axis count so that the offsets
+        // following them are read from the correct position
+        assert_eq!(avar.axis_segment_maps().iter().count(), 2);
+        let axis_index_map = avar.axis_index_map().unwrap().unwrap();
+        assert_eq!(
+            axis_index_map.get(0).unwrap(),
+            DeltaSetIndex { outer: 0, inner: 1 }
+        );
+        assert_eq!(
+            axis_index_map.get(1).unwrap(),
+            DeltaSetIndex { outer: 0, inner: 0 }
+        );
+        let var_store = avar.var_store().unwrap().unwrap();
+        assert_eq!(var_store.item_variation_data_count(), 1);
+    }
+
+    #[test]
+    fn version_1_has_no_v2_fields() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let avar = font.avar().unwrap();
+        assert!(avar.axis_index_map_offset().is_none());
+        assert!(avar.var_store_offset().is_none());
+    }
+
+    #[test]
+    fn normalize_v2() {
+        let font = FontRef::new(font_test_data::AVAR2).unwrap();
+        let avar = font.avar().unwrap();
+        // (wght, wdth) pairs of default normalized coords along with the
+        // expected results. The wght axis is only modified by the segment
+        // map while the wdth axis is shifted by -0.5 * wght
+        let cases = [
+            ([0.0, 0.0], [0.0, 0.0]),
+            ([1.0, 0.0], [1.0, -0.5]),
+            ([0.5, 0.0], [0.75, -0.375]),
+            ([1.0, 1.0], [1.0, 0.5]),
+            // clamped to -1.0
+            ([1.0, -1.0], [1.0, -1.0]),
+            // negative wght is outside the region
+            ([-0.5, 0.25], [-0.5, 0.25]),
+        ];
+        for (coords, expected) in cases {
+            let mut coords = coords.map(Fixed::from_f64);
+            avar.normalize(&mut coords);
+            assert_eq!(coords.map(Fixed::to_f64), expected, "{coords:?}");
+        }
+    }
+
+    #[test]
+    fn normalize_v2_many_axes() {
+        let font = FontRef::new(font_test_data::AVAR2).unwrap();
+        let avar = font.avar().unwrap();
+        // more coordinates than fit in the stack buffer
+        let mut coords = [Fixed::ZERO; MAX_STACK_AXES + 6];
+        coords[0] = Fixed::ONE;
+        avar.normalize(&mut coords);
+        assert_eq!(
+            coords[..2].iter().map(|c| c.to_f64()).collect::<Vec<_>>(),
+            [1.0, -0.5]
+        );
+    }
+
+    #[test]
+    fn normalize_v1() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let avar = font.avar().unwrap();
+        let mut coords = [Fixed::from_f64(-0.5), Fixed::from_f64(0.5)];
+        avar.normalize(&mut coords);
+        // only one segment map, so the second coord is unchanged
+        assert_eq!(coords.map(Fixed::to_f64), [-0.375, 0.5]);
+    }
 }
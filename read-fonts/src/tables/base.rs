@@ -1,9 +1,199 @@
 //! The [BASE](https://learn.microsoft.com/en-us/typography/opentype/spec/base) table
 
-use super::{layout::DeviceOrVariationIndex, variations::ItemVariationStore};
+use super::{
+    gpos::PositionResolver, layout::DeviceOrVariationIndex, variations::ItemVariationStore,
+};
 
 include!("../../generated/generated_base.rs");
 
+/// Script tag used when the requested script is not present.
+const DFLT_SCRIPT: Tag = Tag::new(b"DFLT");
+
+/// Text direction, which selects the axis table used for queries.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Horizontal text, using the horizontal axis table. Baselines are y
+    /// coordinates.
+    Horizontal,
+    /// Vertical text, using the vertical axis table. Baselines are x
+    /// coordinates.
+    Vertical,
+}
+
+/// Minimum and maximum extents for a script, language or feature.
+///
+/// Values are in font units, with device and variation adjustments applied.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct MinMaxExtents {
+    /// The minimum extent, if defined.
+    pub min: Option<i32>,
+    /// The maximum extent, if defined.
+    pub max: Option<i32>,
+}
+
+impl<'a> Base<'a> {
+    /// Returns the axis table for the given direction.
+    pub fn axis(&self, direction: Direction) -> Option<Result<Axis<'a>, ReadError>> {
+        match direction {
+            Direction::Horizontal => self.horiz_axis(),
+            Direction::Vertical => self.vert_axis(),
+        }
+    }
+
+    /// Returns the tag of the default baseline for the given script.
+    ///
+    /// If the script is not present, the `DFLT` script is used instead.
+    pub fn default_baseline(
+        &self,
+        direction: Direction,
+        script: Tag,
+    ) -> Result<Option<Tag>, ReadError> {
+        let Some((axis, base_script)) = self.base_script(direction, script)? else {
+            return Ok(None);
+        };
+        let (Some(tag_list), Some(values)) = (
+            axis.base_tag_list().transpose()?,
+            base_script.base_values().transpose()?,
+        ) else {
+            return Ok(None);
+        };
+        Ok(tag_list
+            .baseline_tags()
+            .get(values.default_baseline_index() as usize)
+            .map(|tag| tag.get()))
+    }
+
+    /// Returns the position of a baseline for the given script.
+    ///
+    /// Baselines are defined per script so there is no language parameter.
+    /// If the script is not present, the `DFLT` script is used instead.
+    ///
+    /// The result is in font units, with device and variation adjustments
+    /// applied by the given resolver, which should be created with
+    /// [`PositionResolver::from_base`]. Coordinates that reference a contour
+    /// point of a glyph (format 2) are returned unadjusted.
+    pub fn baseline(
+        &self,
+        direction: Direction,
+        script: Tag,
+        baseline: Tag,
+        resolver: &PositionResolver,
+    ) -> Result<Option<i32>, ReadError> {
+        let Some((axis, base_script)) = self.base_script(direction, script)? else {
+            return Ok(None);
+        };
+        let (Some(tag_list), Some(values)) = (
+            axis.base_tag_list().transpose()?,
+            base_script.base_values().transpose()?,
+        ) else {
+            return Ok(None);
+        };
+        let Some(index) = tag_list
+            .baseline_tags()
+            .iter()
+            .position(|tag| tag.get() == baseline)
+        else {
+            return Ok(None);
+        };
+        let coord = values.base_coords().get(index)?;
+        coord.resolve(resolver).map(Some)
+    }
+
+    /// Returns the minimum and maximum extents for the given script and
+    /// language, and optionally a feature.
+    ///
+    /// If `language` is `None` or is not present, the default extents for
+    /// the script are used. If `feature` is present, its extents override
+    /// those of the language or script.
+    ///
+    /// Adjustments are applied as for [`baseline`](Self::baseline).
+    pub fn min_max(
+        &self,
+        direction: Direction,
+        script: Tag,
+        language: Option<Tag>,
+        feature: Option<Tag>,
+        resolver: &PositionResolver,
+    ) -> Result<Option<MinMaxExtents>, ReadError> {
+        let Some((_, base_script)) = self.base_script(direction, script)? else {
+            return Ok(None);
+        };
+        let lang_min_max = language
+            .and_then(|language| {
+                base_script
+                    .base_lang_sys_records()
+                    .iter()
+                    .find(|record| record.base_lang_sys_tag() == language)
+            })
+            .map(|record| record.min_max(base_script.offset_data()))
+            .transpose()?;
+        let Some(min_max) = lang_min_max.or(base_script.default_min_max().transpose()?) else {
+            return Ok(None);
+        };
+        let (mut min, mut max) = (min_max.min_coord(), min_max.max_coord());
+        if let Some(record) = feature.and_then(|feature| {
+            min_max
+                .feat_min_max_records()
+                .iter()
+                .find(|record| record.feature_table_tag() == feature)
+        }) {
+            let data = min_max.offset_data();
+            min = record.min_coord(data).or(min);
+            max = record.max_coord(data).or(max);
+        }
+        let resolve = |coord: Option<Result<BaseCoord, ReadError>>| {
+            coord.map(|coord| coord?.resolve(resolver)).transpose()
+        };
+        Ok(Some(MinMaxExtents {
+            min: resolve(min)?,
+            max: resolve(max)?,
+        }))
+    }
+
+    /// Returns the axis and script tables for the given direction and
+    /// script, falling back to the `DFLT` script.
+    fn base_script(
+        &self,
+        direction: Direction,
+        script: Tag,
+    ) -> Result<Option<(Axis<'a>, BaseScript<'a>)>, ReadError> {
+        let Some(axis) = self.axis(direction).transpose()? else {
+            return Ok(None);
+        };
+        let script_list = axis.base_script_list()?;
+        let records = script_list.base_script_records();
+        let Some(record) = records
+            .iter()
+            .find(|record| record.base_script_tag() == script)
+            .or_else(|| {
+                records
+                    .iter()
+                    .find(|record| record.base_script_tag() == DFLT_SCRIPT)
+            })
+        else {
+            return Ok(None);
+        };
+        let base_script = record.base_script(script_list.offset_data())?;
+        Ok(Some((axis, base_script)))
+    }
+}
+
+impl BaseCoord<'_> {
+    /// Returns the coordinate in font units with device and variation
+    /// adjustments applied.
+    ///
+    /// The resolver should be created with [`PositionResolver::from_base`].
+    /// Returns an error if the device or variation index table can't be
+    /// read, or if the variation delta can't be computed.
+    pub fn resolve(&self, resolver: &PositionResolver) -> Result<i32, ReadError> {
+        let coordinate = self.coordinate() as i32;
+        match self {
+            Self::Format3(format3) => Ok(coordinate + resolver.device_delta(format3.device())?),
+            _ => Ok(coordinate),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use font_types::MajorMinor;
@@ -51,4 +241,282 @@ mod tests {
             Tag::new(b"latn")
         );
     }
+
+    /// Builds a BASE table with baselines, extents, device tables and
+    /// variations. Comments give the absolute position of each subtable.
+    fn make_base() -> BeBuffer {
+        BeBuffer::new()
+            // 0: header
+            .push(MajorMinor::VERSION_1_1)
+            .push(12u16) // horiz axis
+            .push(0u16) // vert axis
+            .push(156u32) // item var store
+            // 12: axis
+            .push(4u16) // base tag list
+            .push(14u16) // base script list
+            // 16: base tag list
+            .push(2u16)
+            .extend([Tag::new(b"ideo"), Tag::new(b"romn")])
+            // 26: base script list
+            .push(3u16)
+            .push(Tag::new(b"DFLT"))
+            .push(20u16)
+            .push(Tag::new(b"hani"))
+            .push(42u16)
+            .push(Tag::new(b"latn"))
+            .push(124u16)
+            // 46: DFLT base script
+            .push(6u16) // base values
+            .push(0u16) // default min max
+            .push(0u16) // lang sys count
+            // 52: DFLT base values
+            .push(1u16) // default baseline index
+            .push(2u16)
+            .extend([8u16, 12])
+            // 60: ideo coord
+            .push(1u16)
+            .push(-120i16)
+            // 64: romn coord
+            .push(1u16)
+            .push(0i16)
+            // 68: hani base script
+            .push(12u16) // base values
+            .push(46u16) // default min max
+            .push(1u16) // lang sys count
+            .push(Tag::new(b"JAN "))
+            .push(72u16)
+            // 80: hani base values
+            .push(0u16) // default baseline index
+            .push(2u16)
+            .extend([8u16, 22])
+            // 88: ideo coord with device table
+            .push(3u16)
+            .push(-100i16)
+            .push(6u16)
+            // 94: device table with deltas of 1 at 10ppem and -1 at 11ppem
+            .extend([10u16, 11, 1, 0x7000])
+            // 102: romn coord with variation index
+            .push(3u16)
+            .push(20i16)
+            .push(6u16)
+            // 108: variation index
+            .extend([0u16, 0, 0x8000])
+            // 114: hani default min max
+            .push(14u16) // min coord
+            .push(18u16) // max coord
+            .push(1u16) // feat min max count
+            .push(Tag::new(b"vert"))
+            .push(22u16) // min coord
+            .push(0u16) // max coord
+            // 128: min coord
+            .push(1u16)
+            .push(-150i16)
+            // 132: max coord
+            .push(1u16)
+            .push(850i16)
+            // 136: vert min coord
+            .push(1u16)
+            .push(-200i16)
+            // 140: JAN min max
+            .push(6u16) // min coord
+            .push(0u16) // max coord
+            .push(0u16) // feat min max count
+            // 146: min coord
+            .push(1u16)
+            .push(-130i16)
+            // 150: latn base script, with no values
+            .extend([0u16, 0, 0])
+            // 156: item variation store
+            .push(1u16) // format
+            .push(12u32) // region list
+            .push(1u16) // item variation data count
+            .push(22u32)
+            // 168: region list with one region peaking at 1.0
+            .extend([1u16, 1])
+            .extend([F2Dot14::ZERO, F2Dot14::ONE, F2Dot14::ONE])
+            // 178: item variation data with a single delta of 20
+            .extend([1u16, 0, 1, 0])
+            .push(20i8)
+    }
+
+    const HANI: Tag = Tag::new(b"hani");
+    const IDEO: Tag = Tag::new(b"ideo");
+    const ROMN: Tag = Tag::new(b"romn");
+
+    const UPEM: u16 = 1000;
+
+    fn resolver<'a>(
+        base: &Base<'a>,
+        ppem: Option<u16>,
+        coords: &'a [F2Dot14],
+    ) -> PositionResolver<'a> {
+        PositionResolver::from_base(base, UPEM, ppem, coords).unwrap()
+    }
+
+    #[test]
+    fn baselines() {
+        let data = make_base();
+        let base = Base::read(data.font_data()).unwrap();
+        let horiz = Direction::Horizontal;
+        assert_eq!(base.default_baseline(horiz, HANI).unwrap(), Some(IDEO));
+        assert_eq!(
+            base.baseline(horiz, HANI, IDEO, &resolver(&base, None, &[]))
+                .unwrap(),
+            Some(-100)
+        );
+        // device table adjustments, converted from pixels: +1 at 10 ppem and
+        // -1 at 11 ppem
+        assert_eq!(
+            base.baseline(horiz, HANI, IDEO, &resolver(&base, Some(10), &[]))
+                .unwrap(),
+            Some(0)
+        );
+        assert_eq!(
+            base.baseline(horiz, HANI, IDEO, &resolver(&base, Some(11), &[]))
+                .unwrap(),
+            Some(-191)
+        );
+        assert_eq!(
+            base.baseline(horiz, HANI, IDEO, &resolver(&base, Some(12), &[]))
+                .unwrap(),
+            Some(-100)
+        );
+        // variation deltas
+        assert_eq!(
+            base.baseline(horiz, HANI, ROMN, &resolver(&base, None, &[]))
+                .unwrap(),
+            Some(20)
+        );
+        assert_eq!(
+            base.baseline(horiz, HANI, ROMN, &resolver(&base, None, &[F2Dot14::ONE]))
+                .unwrap(),
+            Some(40)
+        );
+        assert_eq!(
+            base.baseline(
+                horiz,
+                HANI,
+                ROMN,
+                &resolver(&base, None, &[F2Dot14::from_f32(0.5)])
+            )
+            .unwrap(),
+            Some(30)
+        );
+        // unknown scripts use DFLT
+        let cyrl = Tag::new(b"cyrl");
+        assert_eq!(base.default_baseline(horiz, cyrl).unwrap(), Some(ROMN));
+        assert_eq!(
+            base.baseline(horiz, cyrl, IDEO, &resolver(&base, None, &[]))
+                .unwrap(),
+            Some(-120)
+        );
+        // scripts without values or baselines that aren't present
+        let latn = Tag::new(b"latn");
+        assert_eq!(
+            base.baseline(horiz, latn, ROMN, &resolver(&base, None, &[]))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            base.baseline(horiz, HANI, Tag::new(b"hang"), &resolver(&base, None, &[]))
+                .unwrap(),
+            None
+        );
+        // no vertical axis
+        assert_eq!(
+            base.baseline(Direction::Vertical, HANI, IDEO, &resolver(&base, None, &[]))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn malformed_coords() {
+        let horiz = Direction::Horizontal;
+        let patch = |offset: usize, value: u16| {
+            let mut data = make_base().to_vec();
+            data[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+            data
+        };
+        // offset to the romn coord is out of bounds
+        let data = patch(86, 0xFFFF);
+        let base = Base::read(FontData::new(&data)).unwrap();
+        assert!(base
+            .baseline(horiz, HANI, ROMN, &resolver(&base, None, &[]))
+            .is_err());
+        assert!(base
+            .baseline(horiz, HANI, IDEO, &resolver(&base, None, &[]))
+            .is_ok());
+        // offset to the ideo device table is out of bounds
+        let data = patch(92, 0xFFFF);
+        let base = Base::read(FontData::new(&data)).unwrap();
+        assert!(base
+            .baseline(horiz, HANI, IDEO, &resolver(&base, Some(10), &[]))
+            .is_err());
+        // variation index refers to missing item variation data
+        let data = patch(108, 5);
+        let base = Base::read(FontData::new(&data)).unwrap();
+        assert_eq!(
+            base.baseline(horiz, HANI, ROMN, &resolver(&base, None, &[]))
+                .unwrap(),
+            Some(20)
+        );
+        assert!(base
+            .baseline(horiz, HANI, ROMN, &resolver(&base, None, &[F2Dot14::ONE]))
+            .is_err());
+    }
+
+    #[test]
+    fn min_max() {
+        let data = make_base();
+        let base = Base::read(data.font_data()).unwrap();
+        let horiz = Direction::Horizontal;
+        let extents = |language: Option<&[u8; 4]>, feature: Option<&[u8; 4]>| {
+            base.min_max(
+                horiz,
+                HANI,
+                language.map(Tag::new),
+                feature.map(Tag::new),
+                &resolver(&base, None, &[]),
+            )
+            .unwrap()
+            .unwrap()
+        };
+        assert_eq!(
+            extents(None, None),
+            MinMaxExtents {
+                min: Some(-150),
+                max: Some(850),
+            }
+        );
+        // unknown languages use the script defaults
+        assert_eq!(extents(Some(b"KOR "), None), extents(None, None));
+        assert_eq!(
+            extents(Some(b"JAN "), None),
+            MinMaxExtents {
+                min: Some(-130),
+                max: None,
+            }
+        );
+        // feature extents override only the values that are present
+        assert_eq!(
+            extents(None, Some(b"vert")),
+            MinMaxExtents {
+                min: Some(-200),
+                max: Some(850),
+            }
+        );
+        assert_eq!(extents(None, Some(b"liga")), extents(None, None));
+        assert_eq!(
+            base.min_max(
+                horiz,
+                Tag::new(b"latn"),
+                None,
+                None,
+                &resolver(&base, None, &[])
+            )
+            .unwrap(),
+            None
+        );
+    }
 }
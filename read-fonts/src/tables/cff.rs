@@ -2,7 +2,7 @@
 
 include!("../../generated/generated_cff.rs");
 
-use super::postscript::{Index1, Latin1String, StringId};
+use super::postscript::{dict, Charset, Encoding, Error, Index1, Latin1String, StringId};
 
 /// The [Compact Font Format](https://learn.microsoft.com/en-us/typography/opentype/spec/cff) table.
 #[derive(Clone)]
@@ -79,6 +79,118 @@ impl<'a> Cff<'a> {
     pub fn global_subrs(&self) -> Index1<'a> {
         self.global_subrs.clone()
     }
+
+    /// Returns true if the font at the given index in the font set is
+    /// CID-keyed.
+    pub fn is_cid_keyed(&self, top_dict_index: usize) -> Result<bool, Error> {
+        Ok(self.top_dict_info(top_dict_index)?.is_cid_keyed)
+    }
+
+    /// Returns the charset for the font at the given index in the font set.
+    ///
+    /// See "Charsets" at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=21>
+    pub fn charset(&self, top_dict_index: usize) -> Result<Charset<'a>, Error> {
+        let info = self.top_dict_info(top_dict_index)?;
+        let charstrings_offset = info.charstrings_offset.ok_or(Error::MissingCharstrings)?;
+        let charstrings = Index1::read(
+            self.offset_data()
+                .split_off(charstrings_offset)
+                .ok_or(ReadError::OutOfBounds)?,
+        )?;
+        Ok(Charset::new(
+            self.offset_data(),
+            info.charset_offset,
+            charstrings.count() as u32,
+        )?)
+    }
+
+    /// Returns the encoding for the font at the given index in the font
+    /// set.
+    ///
+    /// Returns `None` for CID-keyed fonts, which do not have an encoding.
+    ///
+    /// See "Encodings" at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=18>
+    pub fn encoding(&self, top_dict_index: usize) -> Result<Option<Encoding<'a>>, Error> {
+        let info = self.top_dict_info(top_dict_index)?;
+        if info.is_cid_keyed {
+            return Ok(None);
+        }
+        Ok(Some(Encoding::new(
+            self.offset_data(),
+            info.encoding_offset,
+        )?))
+    }
+
+    /// Returns the name of the given glyph for the font at the given index
+    /// in the font set.
+    ///
+    /// Returns `None` for CID-keyed fonts, which do not have glyph names.
+    pub fn glyph_name(&self, top_dict_index: usize, glyph_id: GlyphId) -> Option<Latin1String<'a>> {
+        if self.is_cid_keyed(top_dict_index).ok()? {
+            return None;
+        }
+        let sid = self.charset(top_dict_index).ok()?.string_id(glyph_id)?;
+        self.string(sid)
+    }
+
+    /// Returns the glyph with the given name for the font at the given index
+    /// in the font set.
+    ///
+    /// This performs a linear search over the charset. Returns `None` for
+    /// CID-keyed fonts.
+    pub fn glyph_id_for_name(&self, top_dict_index: usize, name: &str) -> Option<GlyphId> {
+        if self.is_cid_keyed(top_dict_index).ok()? {
+            return None;
+        }
+        self.charset(top_dict_index)
+            .ok()?
+            .iter()
+            .find(|(_, sid)| self.string(*sid).is_some_and(|s| s == name))
+            .map(|(gid, _)| gid)
+    }
+
+    /// Returns the CID of the given glyph for the font at the given index
+    /// in the font set.
+    ///
+    /// Returns `None` if the font is not CID-keyed.
+    pub fn glyph_cid(&self, top_dict_index: usize, glyph_id: GlyphId) -> Option<u16> {
+        if !self.is_cid_keyed(top_dict_index).ok()? {
+            return None;
+        }
+        // For CID-keyed fonts, the charset maps glyphs to CIDs rather than
+        // string identifiers
+        Some(
+            self.charset(top_dict_index)
+                .ok()?
+                .string_id(glyph_id)?
+                .to_u16(),
+        )
+    }
+
+    fn top_dict_info(&self, top_dict_index: usize) -> Result<TopDictInfo, Error> {
+        let top_dict_data = self.top_dicts.get(top_dict_index)?;
+        let mut info = TopDictInfo::default();
+        for entry in dict::entries(top_dict_data, None) {
+            match entry? {
+                dict::Entry::Charset(offset) => info.charset_offset = offset,
+                dict::Entry::Encoding(offset) => info.encoding_offset = offset,
+                dict::Entry::CharstringsOffset(offset) => info.charstrings_offset = Some(offset),
+                dict::Entry::Ros { .. } => info.is_cid_keyed = true,
+                _ => {}
+            }
+        }
+        Ok(info)
+    }
+}
+
+/// Offsets and flags from a Top DICT that are required for resolving
+/// glyph names and encodings.
+#[derive(Default)]
+struct TopDictInfo {
+    charset_offset: usize,
+    encoding_offset: usize,
+    charstrings_offset: Option<usize>,
+    is_cid_keyed: bool,
 }
 
 impl TopLevelTable for Cff<'_> {
@@ -115,7 +227,11 @@ impl<'a> FontRead<'a> for Cff<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{tables::postscript::StringId, FontRef, TableProvider};
+    use crate::{
+        tables::postscript::{CharsetKind, StringId},
+        test_helpers::BeBuffer,
+        FontRef, TableProvider,
+    };
 
     #[test]
     fn read_noto_serif_display_cff() {
@@ -152,4 +268,77 @@ mod tests {
             "Noto Serif Display"
         );
     }
+
+    #[test]
+    fn glyph_names() {
+        let font = FontRef::new(font_test_data::NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+        let cff = font.cff().unwrap();
+        assert!(!cff.is_cid_keyed(0).unwrap());
+        let charset = cff.charset(0).unwrap();
+        assert_eq!(charset.num_glyphs(), 5);
+        assert!(matches!(charset.kind(), CharsetKind::Custom(_)));
+        let names = (0..5)
+            .map(|gid| cff.glyph_name(0, GlyphId::new(gid)).unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, [".notdef", "i", "j", "k", "l"]);
+        assert!(cff.glyph_name(0, GlyphId::new(5)).is_none());
+        assert_eq!(cff.glyph_id_for_name(0, "k"), Some(GlyphId::new(3)));
+        assert_eq!(cff.glyph_id_for_name(0, "m"), None);
+        assert_eq!(cff.glyph_cid(0, GlyphId::new(1)), None);
+        // missing font in the font set
+        assert!(cff.charset(1).is_err());
+    }
+
+    #[test]
+    fn standard_encoding() {
+        let font = FontRef::new(font_test_data::NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+        let cff = font.cff().unwrap();
+        let encoding = cff.encoding(0).unwrap().unwrap();
+        assert!(matches!(encoding, Encoding::Standard));
+        let charset = cff.charset(0).unwrap();
+        for (code, gid) in (b'i'..=b'l').zip(1..) {
+            assert_eq!(encoding.map(code, &charset), Some(GlyphId::new(gid)));
+        }
+        assert_eq!(encoding.map(b'a', &charset), None);
+    }
+
+    #[test]
+    fn cid_keyed_charset() {
+        let top_dict = [
+            // ROS: registry, ordering and supplement
+            &[28u8, 1, 135, 28, 1, 136, 139, 12, 30][..],
+            // charset
+            &[28, 0, 36, 15],
+            // CharStrings
+            &[28, 0, 41, 17],
+        ]
+        .concat();
+        let data = BeBuffer::new()
+            // header
+            .extend([1u8, 0, 4, 1])
+            // name INDEX
+            .push(1u16)
+            .extend([1u8, 1, 2, b'A'])
+            // top DICT INDEX
+            .push(1u16)
+            .extend([1u8, 1, 1 + top_dict.len() as u8])
+            .extend(top_dict)
+            // string and global subroutine INDEXes
+            .extend([0u16, 0])
+            // charset format 2: glyphs 1..=3 map to CIDs 100..=102
+            .push(2u8)
+            .extend([100u16, 2])
+            // CharStrings INDEX: 4 glyphs, each with an endchar operator
+            .push(4u16)
+            .extend([1u8, 1, 2, 3, 4, 5])
+            .extend([14u8; 4]);
+        let cff = Cff::read(data.font_data()).unwrap();
+        assert!(cff.is_cid_keyed(0).unwrap());
+        assert!(cff.encoding(0).unwrap().is_none());
+        assert!(cff.glyph_name(0, GlyphId::new(1)).is_none());
+        let cids = (0..5)
+            .map(|gid| cff.glyph_cid(0, GlyphId::new(gid)))
+            .collect::<Vec<_>>();
+        assert_eq!(cids, [Some(0), Some(100), Some(101), Some(102), None]);
+    }
 }
@@ -20,20 +20,11 @@ impl<'a> Cmap<'a> {
     /// Map a codepoint to a nominal glyph identifier
     ///
     /// This uses the first available subtable that provides a valid mapping.
-    ///
-    /// # Note:
-    ///
-    /// Mapping logic is currently only implemented for the most common subtable
-    /// formats.
     pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
         let codepoint = codepoint.into();
         for record in self.encoding_records() {
             if let Ok(subtable) = record.subtable(self.offset_data()) {
-                if let Some(gid) = match subtable {
-                    CmapSubtable::Format4(format4) => format4.map_codepoint(codepoint),
-                    CmapSubtable::Format12(format12) => format12.map_codepoint(codepoint),
-                    _ => None,
-                } {
+                if let Some(gid) = subtable.map_codepoint(codepoint) {
                     return Some(gid);
                 }
             }
@@ -58,6 +49,193 @@ impl<'a> Cmap<'a> {
     }
 }
 
+impl<'a> CmapSubtable<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    ///
+    /// Always returns `None` for format 14 subtables which only map
+    /// variation sequences.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        match self {
+            Self::Format0(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format2(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format4(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format6(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format8(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format10(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format12(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format13(subtable) => subtable.map_codepoint(codepoint),
+            Self::Format14(_) => None,
+        }
+    }
+}
+
+impl<'a> Cmap0<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        let gid = *self
+            .glyph_id_array()
+            .get(usize::try_from(codepoint).ok()?)?;
+        (gid != 0).then_some(GlyphId::new(gid as u32))
+    }
+
+    /// Returns an iterator over all (codepoint, glyph identifier) pairs
+    /// in the subtable.
+    pub fn iter(&self) -> Cmap0Iter<'a> {
+        Cmap0Iter {
+            glyph_ids: self.glyph_id_array().iter().enumerate(),
+        }
+    }
+}
+
+/// Iterator over all (codepoint, glyph identifier) pairs in
+/// the subtable.
+#[derive(Clone)]
+pub struct Cmap0Iter<'a> {
+    glyph_ids: core::iter::Enumerate<core::slice::Iter<'a, u8>>,
+}
+
+impl<'a> Iterator for Cmap0Iter<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.glyph_ids
+            .find(|(_, gid)| **gid != 0)
+            .map(|(codepoint, gid)| (codepoint as u32, GlyphId::new(*gid as u32)))
+    }
+}
+
+impl<'a> Cmap2<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    ///
+    /// The codepoint is interpreted as either a single byte character or
+    /// a two byte character with the high byte in bits 8..16.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        if codepoint > 0xFFFF {
+            return None;
+        }
+        let high_byte = (codepoint >> 8) as usize;
+        let sub_header_ix = if high_byte == 0 {
+            // Single byte character: must not also be the first byte of
+            // a two byte character
+            let key = self.sub_header_keys().get(codepoint as usize)?.get();
+            if key != 0 {
+                return None;
+            }
+            0
+        } else {
+            // Two byte character: high byte must select something other
+            // than the single byte subheader
+            let ix = self.sub_header_keys().get(high_byte)?.get() as usize / 8;
+            if ix == 0 {
+                return None;
+            }
+            ix
+        };
+        self.lookup_glyph_id(sub_header_ix, codepoint as u8)
+    }
+
+    /// Returns an iterator over all (codepoint, glyph identifier) pairs
+    /// in the subtable.
+    pub fn iter(&self) -> Cmap2Iter<'a> {
+        Cmap2Iter {
+            subtable: self.clone(),
+            high_byte: 0,
+            cur_range: 0..0,
+            cur_sub_header_ix: 0,
+        }
+    }
+
+    /// Returns the subheader at the given index.
+    ///
+    /// Subheaders immediately follow the key array.
+    fn sub_header(&self, index: usize) -> Option<&'a SubHeader> {
+        let offset = self.shape.sub_header_keys_byte_range().end + index * SubHeader::RAW_BYTE_LEN;
+        self.offset_data().read_ref_at(offset).ok()
+    }
+
+    /// Does the final phase of glyph id lookup.
+    ///
+    /// Shared between Self::map and Cmap2Iter.
+    fn lookup_glyph_id(&self, sub_header_ix: usize, low_byte: u8) -> Option<GlyphId> {
+        let sub_header = self.sub_header(sub_header_ix)?;
+        let ix = (low_byte as u16).checked_sub(sub_header.first_code())?;
+        let range_offset = sub_header.id_range_offset() as usize;
+        if ix >= sub_header.entry_count() || range_offset == 0 {
+            return None;
+        }
+        // The range offset is relative to the location of the
+        // id_range_offset field itself, which is the last field in the
+        // subheader
+        let offset = self.shape.sub_header_keys_byte_range().end
+            + (sub_header_ix + 1) * SubHeader::RAW_BYTE_LEN
+            - u16::RAW_BYTE_LEN
+            + range_offset
+            + ix as usize * u16::RAW_BYTE_LEN;
+        let gid = self.offset_data().read_at::<u16>(offset).ok()?;
+        (gid != 0).then(|| GlyphId::from((gid as i32 + sub_header.id_delta() as i32) as u16))
+    }
+}
+
+/// Iterator over all (codepoint, glyph identifier) pairs in
+/// the subtable.
+#[derive(Clone)]
+pub struct Cmap2Iter<'a> {
+    subtable: Cmap2<'a>,
+    high_byte: u32,
+    cur_range: Range<u32>,
+    cur_sub_header_ix: usize,
+}
+
+impl<'a> Iterator for Cmap2Iter<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(codepoint) = self.cur_range.next() {
+                let Some(glyph_id) = self
+                    .subtable
+                    .lookup_glyph_id(self.cur_sub_header_ix, codepoint as u8)
+                else {
+                    continue;
+                };
+                // As with format 4, skip explicit mappings to 0
+                if glyph_id == GlyphId::NOTDEF {
+                    continue;
+                }
+                return Some((codepoint, glyph_id));
+            }
+            if self.high_byte > 0xFF {
+                return None;
+            }
+            let high_byte = self.high_byte;
+            self.high_byte += 1;
+            let key = self
+                .subtable
+                .sub_header_keys()
+                .get(high_byte as usize)?
+                .get();
+            let sub_header_ix = key as usize / 8;
+            if key == 0 {
+                // Single byte character
+                self.cur_range = high_byte..high_byte + 1;
+                self.cur_sub_header_ix = 0;
+            } else if sub_header_ix != 0 {
+                // All characters with this high byte
+                let Some(sub_header) = self.subtable.sub_header(sub_header_ix) else {
+                    continue;
+                };
+                let start = (high_byte << 8) | sub_header.first_code() as u32;
+                let end = (start + sub_header.entry_count() as u32).min((high_byte << 8) + 0x100);
+                self.cur_range = start..end;
+                self.cur_sub_header_ix = sub_header_ix;
+            }
+        }
+    }
+}
+
 impl<'a> Cmap4<'a> {
     /// Maps a codepoint to a nominal glyph identifier.
     pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
@@ -169,6 +347,117 @@ impl<'a> Iterator for Cmap4Iter<'a> {
     }
 }
 
+impl<'a> Cmap6<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let ix = codepoint.into().checked_sub(self.first_code() as u32)?;
+        let gid = self.glyph_id_array().get(ix as usize)?.get();
+        (gid != 0).then_some(GlyphId::from(gid))
+    }
+
+    /// Returns an iterator over all (codepoint, glyph identifier) pairs
+    /// in the subtable.
+    pub fn iter(&self) -> Cmap6Iter<'a> {
+        Cmap6Iter {
+            first_code: self.first_code() as u32,
+            glyph_ids: self.glyph_id_array().iter().enumerate(),
+        }
+    }
+}
+
+/// Iterator over all (codepoint, glyph identifier) pairs in
+/// the subtable.
+#[derive(Clone)]
+pub struct Cmap6Iter<'a> {
+    first_code: u32,
+    glyph_ids: core::iter::Enumerate<core::slice::Iter<'a, BigEndian<u16>>>,
+}
+
+impl<'a> Iterator for Cmap6Iter<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.glyph_ids.find_map(|(ix, gid)| {
+            let gid = gid.get();
+            let codepoint = self.first_code.checked_add(ix as u32)?;
+            (gid != 0).then_some((codepoint, GlyphId::from(gid)))
+        })
+    }
+}
+
+impl<'a> Cmap8<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    ///
+    /// The codepoint is interpreted as either a 16-bit or 32-bit
+    /// character code depending on the group that contains it.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let codepoint = codepoint.into();
+        let group = find_map_group(self.groups(), codepoint)?;
+        Some(MapGroup::glyph_id(group, codepoint))
+    }
+
+    /// Returns an iterator over all (codepoint, glyph identifier) pairs
+    /// in the subtable.
+    pub fn iter(&self) -> Cmap8Iter<'a> {
+        Cmap8Iter(MapGroupIter::new(self.groups()))
+    }
+}
+
+/// Iterator over all (codepoint, glyph identifier) pairs in
+/// the subtable.
+#[derive(Clone)]
+pub struct Cmap8Iter<'a>(MapGroupIter<'a, SequentialMapGroup>);
+
+impl<'a> Iterator for Cmap8Iter<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a> Cmap10<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let ix = codepoint.into().checked_sub(self.start_char_code())?;
+        if ix >= self.num_chars() {
+            return None;
+        }
+        let gid = self.glyph_id_array().get(ix as usize)?.get();
+        (gid != 0).then_some(GlyphId::from(gid))
+    }
+
+    /// Returns an iterator over all (codepoint, glyph identifier) pairs
+    /// in the subtable.
+    pub fn iter(&self) -> Cmap10Iter<'a> {
+        let len = self.glyph_id_array().len().min(self.num_chars() as usize);
+        Cmap10Iter {
+            start_char_code: self.start_char_code(),
+            glyph_ids: self.glyph_id_array()[..len].iter().enumerate(),
+        }
+    }
+}
+
+/// Iterator over all (codepoint, glyph identifier) pairs in
+/// the subtable.
+#[derive(Clone)]
+pub struct Cmap10Iter<'a> {
+    start_char_code: u32,
+    glyph_ids: core::iter::Enumerate<core::slice::Iter<'a, BigEndian<u16>>>,
+}
+
+impl<'a> Iterator for Cmap10Iter<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.glyph_ids.find_map(|(ix, gid)| {
+            let gid = gid.get();
+            let codepoint = self.start_char_code.checked_add(ix as u32)?;
+            (gid != 0).then_some((codepoint, GlyphId::from(gid)))
+        })
+    }
+}
+
 impl<'a> Cmap12<'a> {
     /// Maps a codepoint to a nominal glyph identifier.
     pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
@@ -228,6 +517,145 @@ impl<'a> Cmap12<'a> {
     }
 }
 
+impl<'a> Cmap13<'a> {
+    /// Maps a codepoint to a nominal glyph identifier.
+    ///
+    /// All codepoints in a group map to the same glyph.
+    pub fn map_codepoint(&self, codepoint: impl Into<u32>) -> Option<GlyphId> {
+        let group = find_map_group(self.groups(), codepoint.into())?;
+        Some(GlyphId::new(group.glyph_id()))
+    }
+
+    /// Returns an iterator over all (codepoint, glyph identifier) pairs
+    /// in the subtable.
+    pub fn iter(&self) -> Cmap13Iter<'a> {
+        Cmap13Iter(MapGroupIter::new(self.groups()))
+    }
+}
+
+/// Iterator over all (codepoint, glyph identifier) pairs in
+/// the subtable.
+#[derive(Clone)]
+pub struct Cmap13Iter<'a>(MapGroupIter<'a, ConstantMapGroup>);
+
+impl<'a> Iterator for Cmap13Iter<'a> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Common interface for the map group records used by formats 8 and 13.
+trait MapGroup {
+    /// First character code in the group.
+    fn start_char_code(&self) -> u32;
+    /// Last character code in the group.
+    fn end_char_code(&self) -> u32;
+    /// Glyph identifier for the given codepoint, which must be in the
+    /// range of the group.
+    fn glyph_id(&self, codepoint: u32) -> GlyphId;
+}
+
+impl MapGroup for SequentialMapGroup {
+    fn start_char_code(&self) -> u32 {
+        self.start_char_code()
+    }
+
+    fn end_char_code(&self) -> u32 {
+        self.end_char_code()
+    }
+
+    fn glyph_id(&self, codepoint: u32) -> GlyphId {
+        GlyphId::new(
+            self.start_glyph_id()
+                .wrapping_add(codepoint.wrapping_sub(self.start_char_code())),
+        )
+    }
+}
+
+impl MapGroup for ConstantMapGroup {
+    fn start_char_code(&self) -> u32 {
+        self.start_char_code()
+    }
+
+    fn end_char_code(&self) -> u32 {
+        self.end_char_code()
+    }
+
+    fn glyph_id(&self, _codepoint: u32) -> GlyphId {
+        GlyphId::new(self.glyph_id())
+    }
+}
+
+/// Binary search for the group containing the given codepoint.
+fn find_map_group<T: MapGroup>(groups: &[T], codepoint: u32) -> Option<&T> {
+    let mut lo = 0;
+    let mut hi = groups.len();
+    while lo < hi {
+        let i = (lo + hi) / 2;
+        let group = groups.get(i)?;
+        if codepoint < group.start_char_code() {
+            hi = i;
+        } else if codepoint > group.end_char_code() {
+            lo = i + 1;
+        } else {
+            return Some(group);
+        }
+    }
+    None
+}
+
+/// Shared iterator implementation for format 8 and format 13 subtables.
+#[derive(Clone)]
+struct MapGroupIter<'a, T> {
+    groups: &'a [T],
+    cur_range: Option<RangeInclusive<u32>>,
+    cur_group_ix: usize,
+}
+
+impl<'a, T: MapGroup> MapGroupIter<'a, T> {
+    fn new(groups: &'a [T]) -> Self {
+        Self {
+            groups,
+            cur_range: Self::group_range(groups, 0),
+            cur_group_ix: 0,
+        }
+    }
+
+    fn group_range(groups: &[T], index: usize) -> Option<RangeInclusive<u32>> {
+        let group = groups.get(index)?;
+        // Limit to the valid range of Unicode characters, as with format 12
+        Some(group.start_char_code()..=group.end_char_code().min(char::MAX as u32))
+    }
+}
+
+impl<'a, T: MapGroup> Iterator for MapGroupIter<'a, T> {
+    type Item = (u32, GlyphId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let range = self.cur_range.as_mut()?;
+            if let Some(codepoint) = range.next() {
+                let glyph_id = self.groups.get(self.cur_group_ix)?.glyph_id(codepoint);
+                if glyph_id == GlyphId::NOTDEF {
+                    continue;
+                }
+                return Some((codepoint, glyph_id));
+            }
+            let prev_end = *range.end();
+            self.cur_group_ix += 1;
+            let mut next_range = Self::group_range(self.groups, self.cur_group_ix)?;
+            // Skip overlapping ranges so that each codepoint is produced at
+            // most once
+            if *next_range.start() <= prev_end {
+                next_range = prev_end.saturating_add(1)..=*next_range.end();
+            }
+            self.cur_range = Some(next_range);
+        }
+    }
+}
+
 #[derive(Clone)]
 struct Cmap12Group {
     range: RangeInclusive<u32>,
@@ -530,6 +958,120 @@ mod tests {
         assert_eq!(cmap.map_codepoint('B'), None);
     }
 
+    fn cmap_formats_subtable(format: u16) -> CmapSubtable<'static> {
+        let font = FontRef::new(font_test_data::CMAP_FORMATS).unwrap();
+        let cmap = font.cmap().unwrap();
+        cmap.encoding_records()
+            .iter()
+            .filter_map(|record| record.subtable(cmap.offset_data()).ok())
+            .find(|subtable| subtable.format() == format)
+            .unwrap()
+    }
+
+    fn check_mappings(format: u16, expected: &[(u32, u32)], unmapped: &[u32]) {
+        let subtable = cmap_formats_subtable(format);
+        for (codepoint, gid) in expected {
+            assert_eq!(
+                subtable.map_codepoint(*codepoint),
+                Some(GlyphId::new(*gid)),
+                "format {format}, {codepoint:X}"
+            );
+        }
+        for codepoint in unmapped {
+            assert_eq!(
+                subtable.map_codepoint(*codepoint),
+                None,
+                "format {format}, {codepoint:X}"
+            );
+        }
+        let iterated = match subtable {
+            CmapSubtable::Format0(subtable) => subtable.iter().collect::<Vec<_>>(),
+            CmapSubtable::Format2(subtable) => subtable.iter().collect(),
+            CmapSubtable::Format6(subtable) => subtable.iter().collect(),
+            CmapSubtable::Format8(subtable) => subtable.iter().collect(),
+            CmapSubtable::Format10(subtable) => subtable.iter().collect(),
+            CmapSubtable::Format13(subtable) => subtable.iter().collect(),
+            _ => panic!("unexpected format"),
+        };
+        let iterated = iterated
+            .into_iter()
+            .map(|(codepoint, gid)| (codepoint, gid.to_u32()))
+            .collect::<Vec<_>>();
+        assert_eq!(iterated, expected, "format {format}");
+    }
+
+    #[test]
+    fn map_format_0() {
+        check_mappings(0, &[(0x41, 1), (0x42, 2), (0xE7, 9)], &[0x43, 0x100]);
+    }
+
+    #[test]
+    fn map_format_2() {
+        check_mappings(
+            2,
+            &[(0x41, 1), (0x42, 2), (0x8141, 11), (0x8142, 12)],
+            // 0x81 is a lead byte, 0x8140 maps to 0 and 0x8241 uses
+            // the single byte subheader
+            &[0x43, 0x81, 0x8140, 0x8143, 0x8241, 0x10000],
+        );
+    }
+
+    #[test]
+    fn map_format_6() {
+        check_mappings(6, &[(0x41, 1), (0x42, 2), (0x44, 3)], &[0x40, 0x43, 0x45]);
+    }
+
+    #[test]
+    fn map_format_8() {
+        check_mappings(
+            8,
+            &[
+                (0x41, 13),
+                (0x42, 14),
+                (0x43, 15),
+                (0x10000, 16),
+                (0x10001, 17),
+            ],
+            &[0x40, 0x44, 0x10002],
+        );
+    }
+
+    #[test]
+    fn map_format_10() {
+        check_mappings(
+            10,
+            &[(0x10000, 4), (0x10001, 5), (0x10002, 6)],
+            &[0xFFFF, 0x10003],
+        );
+    }
+
+    #[test]
+    fn map_format_13() {
+        let subtable = cmap_formats_subtable(13);
+        for codepoint in 0xE000..=0xE0FF_u32 {
+            assert_eq!(subtable.map_codepoint(codepoint), Some(GlyphId::new(7)));
+        }
+        assert_eq!(subtable.map_codepoint(0xE100_u32), None);
+        assert_eq!(subtable.map_codepoint(0x10400_u32), Some(GlyphId::new(8)));
+        assert_eq!(subtable.map_codepoint(0x10401_u32), Some(GlyphId::new(8)));
+        assert_eq!(subtable.map_codepoint(0x10402_u32), None);
+        let CmapSubtable::Format13(subtable) = subtable else {
+            panic!("expected format 13");
+        };
+        assert_eq!(subtable.iter().count(), 258);
+    }
+
+    #[test]
+    fn map_codepoint_first_subtable() {
+        // the first subtable is format 6 at (0, 3) which maps 0x41 to
+        // glyph 1 while format 8 at (3, 10) maps it to 13
+        let font = FontRef::new(font_test_data::CMAP_FORMATS).unwrap();
+        let cmap = font.cmap().unwrap();
+        assert_eq!(cmap.map_codepoint('A'), Some(GlyphId::new(1)));
+        // only mapped by the format 10 subtable
+        assert_eq!(cmap.map_codepoint(0x10002_u32), Some(GlyphId::new(6)));
+    }
+
     #[test]
     fn map_variants() {
         use super::MapVariant::*;
@@ -0,0 +1,65 @@
+//! The [DSIG (Digital Signature)](https://docs.microsoft.com/en-us/typography/opentype/spec/dsig) table.
+//!
+//! Only the structure of the table is parsed; signatures are exposed as raw
+//! PKCS#7 packets and are not verified.
+
+include!("../../generated/generated_dsig.rs");
+
+impl<'a> Dsig<'a> {
+    /// Returns an iterator over the format 1 signature blocks in the table.
+    ///
+    /// Each item is the raw PKCS#7 packet of the signature. Records with an
+    /// unknown format produce an error.
+    pub fn signatures(&self) -> impl Iterator<Item = Result<&'a [u8], ReadError>> + 'a + Clone {
+        let data = self.offset_data();
+        self.signature_records().iter().map(move |record| {
+            if record.format() != 1 {
+                return Err(ReadError::InvalidFormat(record.format() as _));
+            }
+            record.signature_block(data).map(|block| block.signature())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn empty_dsig() {
+        // The common "placeholder" DSIG table
+        let buf = BeBuffer::new().push(1u32).push(0u16).push(0u16);
+        let dsig = Dsig::read(buf.font_data()).unwrap();
+        assert_eq!(dsig.version(), 1);
+        assert_eq!(dsig.num_signatures(), 0);
+        assert_eq!(dsig.signatures().count(), 0);
+    }
+
+    #[test]
+    fn signature_blocks() {
+        let buf = BeBuffer::new()
+            .push(1u32) // version
+            .push(2u16) // num signatures
+            .push(PermissionFlags::CANNOT_BE_RESIGNED)
+            // record 1
+            .extend([1u32, 12, 32])
+            // record 2, unknown format
+            .extend([2u32, 12, 32])
+            // block
+            .extend([0u16, 0])
+            .push(4u32)
+            .extend([0x30u8, 0x82, 0x01, 0x02]);
+        let dsig = Dsig::read(buf.font_data()).unwrap();
+        assert!(dsig
+            .permission_flags()
+            .contains(PermissionFlags::CANNOT_BE_RESIGNED));
+        let record = &dsig.signature_records()[0];
+        assert_eq!(record.length(), 12);
+        let block = record.signature_block(dsig.offset_data()).unwrap();
+        assert_eq!(block.signature_length(), 4);
+        let signatures = dsig.signatures().collect::<Vec<_>>();
+        assert_eq!(signatures[0].as_ref().unwrap(), &[0x30, 0x82, 0x01, 0x02]);
+        assert!(matches!(signatures[1], Err(ReadError::InvalidFormat(2))));
+    }
+}
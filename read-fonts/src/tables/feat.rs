@@ -0,0 +1,85 @@
+//! The [feature name](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6feat.html) table.
+
+include!("../../generated/generated_feat.rs");
+
+impl<'a> Feat<'a> {
+    /// Returns the name for the given feature type, if it exists.
+    pub fn find(&self, feature: u16) -> Option<FeatureName> {
+        let names = self.names();
+        let ix = names
+            .binary_search_by(|name| name.feature().cmp(&feature))
+            .ok()?;
+        names.get(ix).copied()
+    }
+}
+
+impl FeatureName {
+    /// Returns true if the feature settings are mutually exclusive.
+    pub fn is_exclusive(&self) -> bool {
+        self.feature_flags() & 0x8000 != 0
+    }
+
+    /// Returns the index of the default setting for the feature.
+    ///
+    /// This is zero unless the feature flags explicitly specify a
+    /// different index.
+    pub fn default_setting_index(&self) -> u16 {
+        let flags = self.feature_flags();
+        if flags & 0x4000 != 0 {
+            flags & 0xFF
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn feature_names() {
+        let buf = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(2u16) // feature name count
+            .push(0u16) // reserved
+            .push(0u32) // reserved
+            // FeatureName: ligatures
+            .push(1u16)
+            .push(2u16) // n_settings
+            .push(36u32) // setting table offset
+            .push(0u16) // flags
+            .push(NameId::new(258))
+            // FeatureName: letter case
+            .push(3u16)
+            .push(1u16) // n_settings
+            .push(44u32) // setting table offset
+            .push(0xC001u16) // exclusive, default setting 1
+            .push(NameId::new(261))
+            // settings for ligatures
+            .extend([0u16, 259, 2, 260])
+            // settings for letter case
+            .extend([0u16, 262]);
+        let feat = Feat::read(buf.font_data()).unwrap();
+        assert_eq!(feat.version(), MajorMinor::VERSION_1_0);
+        let names = feat.names();
+        assert_eq!(names.len(), 2);
+        assert!(!names[0].is_exclusive());
+        assert_eq!(names[0].default_setting_index(), 0);
+        let settings = names[0].setting_table(feat.offset_data()).unwrap();
+        let settings = settings
+            .settings()
+            .iter()
+            .map(|setting| (setting.setting(), setting.name_index().to_u16()))
+            .collect::<Vec<_>>();
+        assert_eq!(settings, [(0, 259), (2, 260)]);
+        let letter_case = feat.find(3).unwrap();
+        assert_eq!(letter_case.name_index(), NameId::new(261));
+        assert!(letter_case.is_exclusive());
+        assert_eq!(letter_case.default_setting_index(), 1);
+        let settings = letter_case.setting_table(feat.offset_data()).unwrap();
+        assert_eq!(settings.settings()[0].name_index(), NameId::new(262));
+        assert!(feat.find(2).is_none());
+    }
+}
@@ -0,0 +1,709 @@
+//! Selecting the features and lookups to apply for a script, language and
+//! location in variation space.
+
+use types::{F2Dot14, Tag};
+
+use super::{
+    Condition, ConditionSet, Feature, FeatureList, FeatureTableSubstitution, FeatureVariations,
+    LangSys, Script, ScriptList,
+};
+use crate::{
+    tables::variations::{DeltaSetIndex, ItemVariationStore},
+    ReadError,
+};
+
+/// The tag of the default script.
+pub const DFLT_SCRIPT: Tag = Tag::new(b"DFLT");
+
+/// The tag of the default language system.
+///
+/// Some older fonts also use this as the tag of the default script.
+pub const DFLT_LANGUAGE: Tag = Tag::new(b"dflt");
+
+/// Limit on the nesting of condition tables, which guards against unbounded
+/// recursion when evaluating malicious fonts.
+const MAX_CONDITION_DEPTH: usize = 32;
+
+impl<'a> ScriptList<'a> {
+    /// Returns the script with the given tag, if present.
+    pub fn script(&self, tag: Tag) -> Option<Result<Script<'a>, ReadError>> {
+        let records = self.script_records();
+        let idx = records
+            .binary_search_by_key(&tag, |record| record.script_tag())
+            .ok()?;
+        Some(records[idx].script(self.offset_data()))
+    }
+}
+
+impl<'a> Script<'a> {
+    /// Returns the language system with the given tag, if present.
+    ///
+    /// This does not fall back to the default language system; use
+    /// [`default_lang_sys`](Self::default_lang_sys) for that.
+    pub fn lang_sys(&self, tag: Tag) -> Option<Result<LangSys<'a>, ReadError>> {
+        let records = self.lang_sys_records();
+        let idx = records
+            .binary_search_by_key(&tag, |record| record.lang_sys_tag())
+            .ok()?;
+        Some(records[idx].lang_sys(self.offset_data()))
+    }
+}
+
+impl LangSys<'_> {
+    /// Returns the index of the required feature, if any.
+    pub fn required_feature(&self) -> Option<u16> {
+        Some(self.required_feature_index()).filter(|idx| *idx != 0xFFFF)
+    }
+}
+
+impl<'a> FeatureVariations<'a> {
+    /// Returns the feature table substitutions that apply at the given
+    /// location.
+    ///
+    /// The records are evaluated in order and the first one with a matching
+    /// condition set is selected. Returns `None` if no record matches or if
+    /// the selected record has no substitutions.
+    ///
+    /// The variation store is used to evaluate format 2 conditions; for GSUB
+    /// and GPOS this is the item variation store from the `GDEF` table.
+    pub fn find_substitutions(
+        &self,
+        coords: &[F2Dot14],
+        var_store: Option<&ItemVariationStore<'a>>,
+    ) -> Result<Option<FeatureTableSubstitution<'a>>, ReadError> {
+        let data = self.offset_data();
+        for record in self.feature_variation_records() {
+            let matches = match record.condition_set(data).transpose()? {
+                Some(condition_set) => condition_set.matches(coords, var_store)?,
+                // a missing condition set matches all locations
+                None => true,
+            };
+            if matches {
+                return record.feature_table_substitution(data).transpose();
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'a> FeatureTableSubstitution<'a> {
+    /// Returns the alternate version of the feature at the given index in
+    /// the feature list, if it is substituted.
+    pub fn alternate_feature(&self, feature_index: u16) -> Option<Result<Feature<'a>, ReadError>> {
+        let records = self.substitutions();
+        let idx = records
+            .binary_search_by_key(&feature_index, |record| record.feature_index())
+            .ok()?;
+        Some(records[idx].alternate_feature(self.offset_data()))
+    }
+}
+
+impl<'a> ConditionSet<'a> {
+    /// Returns true if all of the conditions in the set are satisfied at the
+    /// given location.
+    ///
+    /// An empty condition set matches all locations.
+    pub fn matches(
+        &self,
+        coords: &[F2Dot14],
+        var_store: Option<&ItemVariationStore<'a>>,
+    ) -> Result<bool, ReadError> {
+        for condition in self.conditions().iter() {
+            if !condition?.matches_impl(coords, var_store, 0)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<'a> Condition<'a> {
+    /// Returns true if the condition is satisfied at the given location.
+    ///
+    /// Format 2 conditions are satisfied when their value is positive. The
+    /// variation store is used to compute the value at the given location;
+    /// if it is not provided, the default value is used.
+    pub fn matches(
+        &self,
+        coords: &[F2Dot14],
+        var_store: Option<&ItemVariationStore<'a>>,
+    ) -> Result<bool, ReadError> {
+        self.matches_impl(coords, var_store, 0)
+    }
+
+    fn matches_impl(
+        &self,
+        coords: &[F2Dot14],
+        var_store: Option<&ItemVariationStore<'a>>,
+        depth: usize,
+    ) -> Result<bool, ReadError> {
+        if depth > MAX_CONDITION_DEPTH {
+            return Err(ReadError::MalformedData(
+                "condition tables nested too deeply",
+            ));
+        }
+        match self {
+            Self::Format1AxisRange(condition) => {
+                let coord = coords
+                    .get(condition.axis_index() as usize)
+                    .copied()
+                    .unwrap_or_default();
+                Ok(coord >= condition.filter_range_min_value()
+                    && coord <= condition.filter_range_max_value())
+            }
+            Self::Format2VariableValue(condition) => {
+                let mut value = condition.default_value() as i32;
+                if let Some(var_store) = var_store.filter(|_| !coords.is_empty()) {
+                    let var_index = condition.var_index();
+                    let index = DeltaSetIndex {
+                        outer: (var_index >> 16) as u16,
+                        inner: var_index as u16,
+                    };
+                    value += var_store.compute_delta(index, coords)?;
+                }
+                Ok(value > 0)
+            }
+            Self::Format3And(condition) => {
+                for child in condition.conditions().iter() {
+                    if !child?.matches_impl(coords, var_store, depth + 1)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Self::Format4Or(condition) => {
+                for child in condition.conditions().iter() {
+                    if child?.matches_impl(coords, var_store, depth + 1)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Self::Format5Negate(condition) => {
+                Ok(!condition
+                    .condition()?
+                    .matches_impl(coords, var_store, depth + 1)?)
+            }
+        }
+    }
+}
+
+/// Selects the features and lookups to apply from a GSUB or GPOS table.
+///
+/// This combines the script list, feature list and feature variations of a
+/// table to determine which lookups apply for a given script, language and
+/// location in variation space.
+#[derive(Clone)]
+pub struct FeatureSelector<'a> {
+    script_list: ScriptList<'a>,
+    feature_list: FeatureList<'a>,
+    feature_variations: Option<FeatureVariations<'a>>,
+    var_store: Option<ItemVariationStore<'a>>,
+}
+
+impl<'a> FeatureSelector<'a> {
+    /// Creates a new selector from the tables of a GSUB or GPOS table.
+    ///
+    /// See also [`Gsub::feature_selector`](crate::tables::gsub::Gsub::feature_selector)
+    /// and [`Gpos::feature_selector`](crate::tables::gpos::Gpos::feature_selector).
+    pub fn new(
+        script_list: ScriptList<'a>,
+        feature_list: FeatureList<'a>,
+        feature_variations: Option<FeatureVariations<'a>>,
+    ) -> Self {
+        Self {
+            script_list,
+            feature_list,
+            feature_variations,
+            var_store: None,
+        }
+    }
+
+    /// Sets the item variation store used to evaluate format 2 feature
+    /// variation conditions.
+    ///
+    /// This should be the variation store from the `GDEF` table.
+    pub fn with_var_store(mut self, var_store: Option<ItemVariationStore<'a>>) -> Self {
+        self.var_store = var_store;
+        self
+    }
+
+    /// Returns the language system for the given script and language.
+    ///
+    /// If the script is not present, the `DFLT` script is used, followed by
+    /// the non-standard `dflt` script. Within the chosen script, if the
+    /// language is not present, a language system tagged `dflt` is used,
+    /// followed by the default language system of the script.
+    ///
+    /// Returns `None` if no suitable language system exists.
+    pub fn lang_sys(&self, script: Tag, language: Tag) -> Result<Option<LangSys<'a>>, ReadError> {
+        let Some(script) = [script, DFLT_SCRIPT, DFLT_LANGUAGE]
+            .into_iter()
+            .find_map(|tag| self.script_list.script(tag))
+            .transpose()?
+        else {
+            return Ok(None);
+        };
+        if let Some(lang_sys) = [language, DFLT_LANGUAGE]
+            .into_iter()
+            .find_map(|tag| script.lang_sys(tag))
+        {
+            return lang_sys.map(Some);
+        }
+        script.default_lang_sys().transpose()
+    }
+
+    /// Returns the feature table substitutions that apply at the given
+    /// location, if any.
+    ///
+    /// Missing coordinates are treated as zero, so an empty slice selects
+    /// the substitutions for the default location.
+    pub fn substitutions(
+        &self,
+        coords: &[F2Dot14],
+    ) -> Result<Option<FeatureTableSubstitution<'a>>, ReadError> {
+        match &self.feature_variations {
+            Some(feature_variations) => {
+                feature_variations.find_substitutions(coords, self.var_store.as_ref())
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the tag and table of the feature at the given index in the
+    /// feature list.
+    ///
+    /// If substitutions are provided and contain an alternate version of the
+    /// feature, the alternate is returned.
+    pub fn feature(
+        &self,
+        index: u16,
+        substitutions: Option<&FeatureTableSubstitution<'a>>,
+    ) -> Result<(Tag, Feature<'a>), ReadError> {
+        let record = self
+            .feature_list
+            .feature_records()
+            .get(index as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        let feature = match substitutions.and_then(|subs| subs.alternate_feature(index)) {
+            Some(alternate) => alternate?,
+            None => record.feature(self.feature_list.offset_data())?,
+        };
+        Ok((record.feature_tag(), feature))
+    }
+
+    /// Returns the indices of the lookups to apply for the given script,
+    /// language, features and location, in the order that they should be
+    /// applied.
+    ///
+    /// The required feature of the selected language system is always
+    /// included, regardless of its tag. Lookups are returned in lookup list
+    /// order without duplicates.
+    ///
+    /// This is equivalent to HarfBuzz's `hb_ot_layout_collect_lookups` for a
+    /// single script and language, with feature variations applied at the
+    /// given location.
+    #[cfg(feature = "std")]
+    pub fn collect_lookups(
+        &self,
+        script: Tag,
+        language: Tag,
+        features: &[Tag],
+        coords: &[F2Dot14],
+    ) -> Result<std::vec::Vec<u16>, ReadError> {
+        let mut lookups = std::vec::Vec::new();
+        let Some(lang_sys) = self.lang_sys(script, language)? else {
+            return Ok(lookups);
+        };
+        let substitutions = self.substitutions(coords)?;
+        let feature_indices = lang_sys
+            .required_feature()
+            .into_iter()
+            .map(|idx| (idx, true))
+            .chain(
+                lang_sys
+                    .feature_indices()
+                    .iter()
+                    .map(|idx| (idx.get(), false)),
+            );
+        for (index, is_required) in feature_indices {
+            let (tag, feature) = self.feature(index, substitutions.as_ref())?;
+            if is_required || features.contains(&tag) {
+                lookups.extend(feature.lookup_list_indices().iter().map(|idx| idx.get()));
+            }
+        }
+        lookups.sort_unstable();
+        lookups.dedup();
+        Ok(lookups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::BeBuffer, FontRead, FontRef, TableProvider};
+    use types::{MajorMinor, Uint24};
+
+    const LATN: Tag = Tag::new(b"latn");
+    const ARAB: Tag = Tag::new(b"arab");
+    const TRK: Tag = Tag::new(b"TRK ");
+    const KERN: Tag = Tag::new(b"kern");
+    const LIGA: Tag = Tag::new(b"liga");
+    const LOCL: Tag = Tag::new(b"locl");
+    const RQD: Tag = Tag::new(b"rqd ");
+
+    // Script list:
+    //   DFLT: default lang sys with features [0]
+    //   latn: default lang sys with features [0, 1], TRK with required
+    //         feature 3 and features [1, 2]
+    fn make_script_list() -> BeBuffer {
+        BeBuffer::new()
+            .push(2u16) // script count
+            .push(DFLT_SCRIPT)
+            .push(14u16)
+            .push(LATN)
+            .push(26u16)
+            // DFLT script, at 14
+            .push(4u16) // default lang sys offset
+            .push(0u16) // lang sys count
+            // default lang sys
+            .push(0u16)
+            .push(0xFFFFu16)
+            .push(1u16)
+            .push(0u16)
+            // latn script, at 26
+            .push(10u16) // default lang sys offset
+            .push(1u16) // lang sys count
+            .push(TRK)
+            .push(20u16)
+            // default lang sys
+            .push(0u16)
+            .push(0xFFFFu16)
+            .push(2u16)
+            .extend([0u16, 1])
+            // TRK lang sys
+            .push(0u16)
+            .push(3u16)
+            .push(2u16)
+            .extend([1u16, 2])
+    }
+
+    // Feature list: kern [0, 3], liga [2], locl [1], rqd [4, 2]
+    fn make_feature_list() -> BeBuffer {
+        BeBuffer::new()
+            .push(4u16) // feature count
+            .push(KERN)
+            .push(26u16)
+            .push(LIGA)
+            .push(34u16)
+            .push(LOCL)
+            .push(40u16)
+            .push(RQD)
+            .push(46u16)
+            // kern, at 26
+            .push(0u16)
+            .push(2u16)
+            .extend([0u16, 3])
+            // liga, at 34
+            .push(0u16)
+            .push(1u16)
+            .push(2u16)
+            // locl, at 40
+            .push(0u16)
+            .push(1u16)
+            .push(1u16)
+            // rqd, at 46
+            .push(0u16)
+            .push(2u16)
+            .extend([4u16, 2])
+    }
+
+    // Feature variations with two records:
+    //   axis 0 in [0.5, 1.0]: substitute kern with lookups [5]
+    //   axis 0 in [-1.0, 1.0] and not axis 1 in [0.5, 1.0]: no
+    //     substitutions
+    fn make_feature_variations() -> BeBuffer {
+        BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(2u32) // record count
+            .push(24u32) // condition set
+            .push(38u32) // substitution
+            .push(60u32) // condition set
+            .push(0u32) // substitution
+            // condition set, at 24
+            .push(1u16)
+            .push(6u32)
+            // format 1 condition
+            .push(1u16)
+            .push(0u16)
+            .push(F2Dot14::from_f32(0.5))
+            .push(F2Dot14::from_f32(1.0))
+            // feature table substitution, at 38
+            .push(MajorMinor::VERSION_1_0)
+            .push(1u16)
+            .push(0u16) // feature index
+            .push(12u32)
+            // alternate feature, at 50
+            .push(0u16)
+            .push(1u16)
+            .push(5u16)
+            .extend([0u8; 4]) // padding
+            // condition set, at 60
+            .push(1u16)
+            .push(6u32)
+            // format 3 (and) condition, at 66
+            .push(3u16)
+            .push(2u8)
+            .push(Uint24::new(9))
+            .push(Uint24::new(17))
+            // format 1 condition, at 75
+            .push(1u16)
+            .push(0u16)
+            .push(F2Dot14::from_f32(-1.0))
+            .push(F2Dot14::from_f32(1.0))
+            // format 5 (not) condition, at 83
+            .push(5u16)
+            .push(Uint24::new(5))
+            // format 1 condition, at 88
+            .push(1u16)
+            .push(1u16)
+            .push(F2Dot14::from_f32(0.5))
+            .push(F2Dot14::from_f32(1.0))
+    }
+
+    struct TestTables {
+        script_list: BeBuffer,
+        feature_list: BeBuffer,
+        feature_variations: BeBuffer,
+    }
+
+    impl TestTables {
+        fn new() -> Self {
+            Self {
+                script_list: make_script_list(),
+                feature_list: make_feature_list(),
+                feature_variations: make_feature_variations(),
+            }
+        }
+
+        fn selector(&self) -> FeatureSelector<'_> {
+            FeatureSelector::new(
+                ScriptList::read(self.script_list.font_data()).unwrap(),
+                FeatureList::read(self.feature_list.font_data()).unwrap(),
+                Some(FeatureVariations::read(self.feature_variations.font_data()).unwrap()),
+            )
+        }
+    }
+
+    #[test]
+    fn script_and_language_fallback() {
+        let tables = TestTables::new();
+        let selector = tables.selector();
+        let features = |script, language| {
+            selector
+                .lang_sys(script, language)
+                .unwrap()
+                .map(|lang_sys| {
+                    (
+                        lang_sys.required_feature(),
+                        lang_sys
+                            .feature_indices()
+                            .iter()
+                            .map(|idx| idx.get())
+                            .collect::<Vec<_>>(),
+                    )
+                })
+        };
+        assert_eq!(features(LATN, TRK), Some((Some(3), vec![1, 2])));
+        assert_eq!(features(LATN, DFLT_LANGUAGE), Some((None, vec![0, 1])));
+        // unknown language uses the default lang sys of the script
+        assert_eq!(features(LATN, Tag::new(b"ENG ")), Some((None, vec![0, 1])));
+        // unknown script uses DFLT
+        assert_eq!(features(ARAB, TRK), Some((None, vec![0])));
+    }
+
+    #[test]
+    fn collect_lookups() {
+        let tables = TestTables::new();
+        let selector = tables.selector();
+        assert_eq!(
+            selector
+                .collect_lookups(LATN, DFLT_LANGUAGE, &[KERN, LIGA, LOCL], &[])
+                .unwrap(),
+            [0, 2, 3]
+        );
+        // the required feature is always included
+        assert_eq!(
+            selector.collect_lookups(LATN, TRK, &[LOCL], &[]).unwrap(),
+            [1, 2, 4]
+        );
+        // features not referenced by the lang sys are ignored
+        assert_eq!(
+            selector.collect_lookups(ARAB, TRK, &[LIGA], &[]).unwrap(),
+            [0u16; 0]
+        );
+        assert_eq!(
+            selector.collect_lookups(ARAB, TRK, &[KERN], &[]).unwrap(),
+            [0, 3]
+        );
+    }
+
+    #[test]
+    fn collect_lookups_no_scripts() {
+        let script_list = BeBuffer::new().push(0u16);
+        let feature_list = make_feature_list();
+        let selector = FeatureSelector::new(
+            ScriptList::read(script_list.font_data()).unwrap(),
+            FeatureList::read(feature_list.font_data()).unwrap(),
+            None,
+        );
+        assert!(selector.lang_sys(LATN, TRK).unwrap().is_none());
+        assert_eq!(
+            selector.collect_lookups(LATN, TRK, &[KERN], &[]).unwrap(),
+            [0u16; 0]
+        );
+    }
+
+    #[test]
+    fn feature_variations() {
+        let tables = TestTables::new();
+        let selector = tables.selector();
+        let kern = |coords: &[F2Dot14]| {
+            selector
+                .collect_lookups(DFLT_SCRIPT, DFLT_LANGUAGE, &[KERN], coords)
+                .unwrap()
+        };
+        let coords = |values: &[f32]| {
+            values
+                .iter()
+                .copied()
+                .map(F2Dot14::from_f32)
+                .collect::<Vec<_>>()
+        };
+        // default location
+        assert_eq!(kern(&[]), [0, 3]);
+        assert_eq!(kern(&coords(&[0.0])), [0, 3]);
+        // first record matches
+        assert_eq!(kern(&coords(&[0.5])), [5]);
+        assert_eq!(kern(&coords(&[1.0, 1.0])), [5]);
+        // second record matches, with no substitutions
+        assert_eq!(kern(&coords(&[0.25, 0.0])), [0, 3]);
+        // neither record matches
+        assert_eq!(kern(&coords(&[0.25, 0.75])), [0, 3]);
+        let substitutions = selector.substitutions(&coords(&[0.5])).unwrap().unwrap();
+        let (tag, feature) = selector.feature(0, Some(&substitutions)).unwrap();
+        assert_eq!(tag, KERN);
+        assert_eq!(feature.lookup_list_indices()[0].get(), 5);
+        // other features are unchanged
+        let (tag, feature) = selector.feature(1, Some(&substitutions)).unwrap();
+        assert_eq!(tag, LIGA);
+        assert_eq!(feature.lookup_list_indices()[0].get(), 2);
+        assert!(selector.feature(4, None).is_err());
+    }
+
+    #[test]
+    fn feature_variations_at_default_location() {
+        // a single record, axis 0 in [-0.5, 0.5]: substitute kern with
+        // lookups [5]
+        let feature_variations = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(1u32) // record count
+            .push(16u32) // condition set
+            .push(30u32) // substitution
+            // condition set, at 16
+            .push(1u16)
+            .push(6u32)
+            // format 1 condition
+            .push(1u16)
+            .push(0u16)
+            .push(F2Dot14::from_f32(-0.5))
+            .push(F2Dot14::from_f32(0.5))
+            // feature table substitution, at 30
+            .push(MajorMinor::VERSION_1_0)
+            .push(1u16)
+            .push(0u16) // feature index
+            .push(12u32)
+            // alternate feature, at 42
+            .push(0u16)
+            .push(1u16)
+            .push(5u16);
+        let script_list = make_script_list();
+        let feature_list = make_feature_list();
+        let selector = FeatureSelector::new(
+            ScriptList::read(script_list.font_data()).unwrap(),
+            FeatureList::read(feature_list.font_data()).unwrap(),
+            Some(FeatureVariations::read(feature_variations.font_data()).unwrap()),
+        );
+        let kern = |coords: &[F2Dot14]| {
+            selector
+                .collect_lookups(DFLT_SCRIPT, DFLT_LANGUAGE, &[KERN], coords)
+                .unwrap()
+        };
+        assert_eq!(kern(&[]), [5]);
+        assert_eq!(kern(&[]), kern(&[F2Dot14::ZERO]));
+        assert_eq!(kern(&[F2Dot14::from_f32(0.75)]), [0, 3]);
+    }
+
+    #[test]
+    fn condition_depth_limit() {
+        // a chain of negations, each pointing to the next, ending with a
+        // format 1 condition that matches the default location
+        let negations = |count: usize| {
+            let mut buf = BeBuffer::new();
+            for _ in 0..count {
+                buf = buf.push(5u16).push(Uint24::new(5));
+            }
+            buf.push(1u16)
+                .push(0u16)
+                .push(F2Dot14::from_f32(-1.0))
+                .push(F2Dot14::from_f32(1.0))
+        };
+        let eval = |count: usize| {
+            let data = negations(count);
+            Condition::read(data.font_data())
+                .unwrap()
+                .matches(&[], None)
+        };
+        assert!(eval(0).unwrap());
+        assert!(!eval(1).unwrap());
+        assert!(eval(MAX_CONDITION_DEPTH).unwrap());
+        assert!(matches!(
+            eval(MAX_CONDITION_DEPTH + 1),
+            Err(ReadError::MalformedData(_))
+        ));
+        assert!(matches!(eval(1000), Err(ReadError::MalformedData(_))));
+    }
+
+    #[test]
+    fn condition_null_offset() {
+        // a negation with a null offset to its condition
+        let data = BeBuffer::new().push(5u16).push(Uint24::new(0));
+        let condition = Condition::read(data.font_data()).unwrap();
+        assert!(matches!(
+            condition.matches(&[], None),
+            Err(ReadError::NullOffset)
+        ));
+    }
+
+    #[test]
+    fn gsub_feature_variations() {
+        // the condition set is wght in [700, 900] with an axis range of
+        // [200, 1000], which normalizes to [0.625, 0.875]. The alternate
+        // feature keeps the default lookup and adds the conditional one.
+        let font = FontRef::new(font_test_data::closure::VARIATIONS_CLOSURE).unwrap();
+        let selector = font.gsub().unwrap().feature_selector().unwrap();
+        let test = Tag::new(b"test");
+        assert_eq!(
+            selector
+                .collect_lookups(LATN, DFLT_LANGUAGE, &[test], &[])
+                .unwrap(),
+            [0]
+        );
+        assert_eq!(
+            selector
+                .collect_lookups(LATN, DFLT_LANGUAGE, &[test], &[F2Dot14::from_f32(0.75)])
+                .unwrap(),
+            [0, 1]
+        );
+    }
+}
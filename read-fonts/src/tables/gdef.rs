@@ -14,3 +14,33 @@ use super::variations::ItemVariationStore;
 mod tests;
 
 include!("../../generated/generated_gdef.rs");
+
+#[cfg(feature = "std")]
+impl Gdef<'_> {
+    /// Return the set of glyphs referenced by this table.
+    ///
+    /// This includes the glyphs that are assigned a glyph class or mark
+    /// attachment class, and the glyphs covered by the attachment point list,
+    /// ligature caret list and mark glyph sets.
+    pub fn collect_glyphs(&self) -> Result<std::collections::HashSet<GlyphId16>, ReadError> {
+        let mut glyphs = std::collections::HashSet::new();
+        for class_def in [self.glyph_class_def(), self.mark_attach_class_def()]
+            .into_iter()
+            .flatten()
+        {
+            glyphs.extend(class_def?.iter().map(|(gid, _)| gid));
+        }
+        if let Some(attach_list) = self.attach_list().transpose()? {
+            glyphs.extend(attach_list.coverage()?.iter());
+        }
+        if let Some(lig_caret_list) = self.lig_caret_list().transpose()? {
+            glyphs.extend(lig_caret_list.coverage()?.iter());
+        }
+        if let Some(mark_glyph_sets) = self.mark_glyph_sets_def().transpose()? {
+            for coverage in mark_glyph_sets.coverages().iter() {
+                glyphs.extend(coverage?.iter());
+            }
+        }
+        Ok(glyphs)
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! [GPOS]: https://docs.microsoft.com/en-us/typography/opentype/spec/gpos
 
+#[cfg(feature = "std")]
+mod collect;
+#[path = "./position_resolver.rs"]
+mod position_resolver;
 #[path = "./value_record.rs"]
 mod value_record;
 
@@ -9,10 +13,11 @@ use crate::array::ComputedArray;
 
 /// reexport stuff from layout that we use
 pub use super::layout::{
-    ClassDef, CoverageTable, Device, DeviceOrVariationIndex, FeatureList, FeatureVariations,
-    Lookup, ScriptList,
+    ClassDef, CoverageTable, Device, DeviceOrVariationIndex, FeatureList, FeatureSelector,
+    FeatureVariations, Lookup, ScriptList,
 };
 use super::layout::{ExtensionLookup, LookupFlag, Subtables};
+pub use position_resolver::{PositionResolver, ResolvedValue};
 pub use value_record::ValueRecord;
 
 #[cfg(test)]
@@ -50,6 +55,18 @@ impl<'a> AnchorTable<'a> {
     }
 }
 
+impl<'a> Gpos<'a> {
+    /// Returns a [`FeatureSelector`] for choosing the lookups to apply for a
+    /// script, language and set of features.
+    pub fn feature_selector(&self) -> Result<FeatureSelector<'a>, ReadError> {
+        Ok(FeatureSelector::new(
+            self.script_list()?,
+            self.feature_list()?,
+            self.feature_variations().transpose()?,
+        ))
+    }
+}
+
 impl<'a, T: FontRead<'a>> ExtensionLookup<'a, T> for ExtensionPosFormat1<'a, T> {
     fn extension(&self) -> Result<T, ReadError> {
         self.extension()
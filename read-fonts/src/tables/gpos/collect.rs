@@ -0,0 +1,412 @@
+//! Collecting the glyphs referenced by positioning lookups
+//!
+//! This is the positioning counterpart of GSUB closure: since positioning
+//! never introduces new glyphs, it is enough to collect the glyphs that each
+//! lookup refers to.
+
+use std::collections::HashSet;
+
+use font_types::GlyphId16;
+
+use crate::{
+    tables::layout::{
+        ChainedSequenceContext, ClassDef, CoverageTable, ExtensionLookup, SequenceContext,
+        Subtables, MAX_NESTING_LEVEL,
+    },
+    FontRead, ReadError,
+};
+
+use super::{
+    CursivePosFormat1, Gpos, MarkBasePosFormat1, MarkLigPosFormat1, MarkMarkPosFormat1, PairPos,
+    PositionSubtables, SinglePos,
+};
+
+/// A trait for tables which reference glyphs.
+trait CollectGlyphs {
+    /// Add the referenced glyphs to `glyphs`, and the indices of any nested
+    /// lookups to `lookups`.
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError>;
+}
+
+impl Gpos<'_> {
+    /// Return the set of glyphs referenced by the given lookups.
+    ///
+    /// If `lookups` is `None`, all lookups are used. This includes the glyphs
+    /// in all coverage and class definition tables, the second glyphs of pair
+    /// adjustments and the glyph sequences of contextual rules. Lookups
+    /// referenced by contextual lookups are followed to a depth of at most
+    /// 64.
+    pub fn collect_glyphs(
+        &self,
+        lookups: Option<&HashSet<u16>>,
+    ) -> Result<HashSet<GlyphId16>, ReadError> {
+        let lookup_list = self.lookup_list()?;
+        let lookup_count = lookup_list.lookup_count();
+        let mut glyphs = HashSet::new();
+        let mut visited = HashSet::new();
+        let mut current: HashSet<u16> = match lookups {
+            Some(lookups) => lookups.clone(),
+            None => (0..lookup_count).collect(),
+        };
+        for _ in 0..=MAX_NESTING_LEVEL {
+            let mut nested = HashSet::new();
+            for lookup_index in current {
+                // invalid lookup indices are ignored
+                if lookup_index >= lookup_count || !visited.insert(lookup_index) {
+                    continue;
+                }
+                let lookup = lookup_list.lookups().get(lookup_index as usize)?;
+                lookup
+                    .subtables()?
+                    .collect_glyphs(&mut glyphs, &mut nested)?;
+            }
+            if nested.is_empty() {
+                break;
+            }
+            current = nested;
+        }
+        Ok(glyphs)
+    }
+}
+
+impl CollectGlyphs for PositionSubtables<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        match self {
+            PositionSubtables::Single(tables) => tables.collect_glyphs(glyphs, lookups),
+            PositionSubtables::Pair(tables) => tables.collect_glyphs(glyphs, lookups),
+            PositionSubtables::Cursive(tables) => tables.collect_glyphs(glyphs, lookups),
+            PositionSubtables::MarkToBase(tables) => tables.collect_glyphs(glyphs, lookups),
+            PositionSubtables::MarkToLig(tables) => tables.collect_glyphs(glyphs, lookups),
+            PositionSubtables::MarkToMark(tables) => tables.collect_glyphs(glyphs, lookups),
+            PositionSubtables::Contextual(tables) => tables.collect_glyphs(glyphs, lookups),
+            PositionSubtables::ChainContextual(tables) => tables.collect_glyphs(glyphs, lookups),
+        }
+    }
+}
+
+impl<'a, T: FontRead<'a> + CollectGlyphs + 'a, Ext: ExtensionLookup<'a, T> + 'a> CollectGlyphs
+    for Subtables<'a, T, Ext>
+{
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        self.iter()
+            .try_for_each(|t| t?.collect_glyphs(glyphs, lookups))
+    }
+}
+
+impl CollectGlyphs for SinglePos<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        _lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        let coverage = match self {
+            SinglePos::Format1(table) => table.coverage()?,
+            SinglePos::Format2(table) => table.coverage()?,
+        };
+        add_coverage(glyphs, &coverage);
+        Ok(())
+    }
+}
+
+impl CollectGlyphs for PairPos<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        _lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        match self {
+            PairPos::Format1(table) => {
+                add_coverage(glyphs, &table.coverage()?);
+                for pair_set in table.pair_sets().iter() {
+                    for record in pair_set?.pair_value_records().iter() {
+                        glyphs.insert(record?.second_glyph());
+                    }
+                }
+            }
+            PairPos::Format2(table) => {
+                add_coverage(glyphs, &table.coverage()?);
+                add_class_def(glyphs, &table.class_def1()?);
+                add_class_def(glyphs, &table.class_def2()?);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CollectGlyphs for CursivePosFormat1<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        _lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        add_coverage(glyphs, &self.coverage()?);
+        Ok(())
+    }
+}
+
+impl CollectGlyphs for MarkBasePosFormat1<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        _lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        add_coverage(glyphs, &self.mark_coverage()?);
+        add_coverage(glyphs, &self.base_coverage()?);
+        Ok(())
+    }
+}
+
+impl CollectGlyphs for MarkLigPosFormat1<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        _lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        add_coverage(glyphs, &self.mark_coverage()?);
+        add_coverage(glyphs, &self.ligature_coverage()?);
+        Ok(())
+    }
+}
+
+impl CollectGlyphs for MarkMarkPosFormat1<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        _lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        add_coverage(glyphs, &self.mark1_coverage()?);
+        add_coverage(glyphs, &self.mark2_coverage()?);
+        Ok(())
+    }
+}
+
+impl CollectGlyphs for SequenceContext<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        match self {
+            SequenceContext::Format1(table) => {
+                add_coverage(glyphs, &table.coverage()?);
+                for rule_set in table.seq_rule_sets().iter().flatten() {
+                    for rule in rule_set?.seq_rules().iter() {
+                        let rule = rule?;
+                        glyphs.extend(rule.input_sequence().iter().map(|gid| gid.get()));
+                        lookups.extend(
+                            rule.seq_lookup_records()
+                                .iter()
+                                .map(|rec| rec.lookup_list_index()),
+                        );
+                    }
+                }
+            }
+            SequenceContext::Format2(table) => {
+                add_coverage(glyphs, &table.coverage()?);
+                add_class_def(glyphs, &table.class_def()?);
+                for rule_set in table.class_seq_rule_sets().iter().flatten() {
+                    for rule in rule_set?.class_seq_rules().iter() {
+                        lookups.extend(
+                            rule?
+                                .seq_lookup_records()
+                                .iter()
+                                .map(|rec| rec.lookup_list_index()),
+                        );
+                    }
+                }
+            }
+            SequenceContext::Format3(table) => {
+                for coverage in table.coverages().iter() {
+                    add_coverage(glyphs, &coverage?);
+                }
+                lookups.extend(
+                    table
+                        .seq_lookup_records()
+                        .iter()
+                        .map(|rec| rec.lookup_list_index()),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CollectGlyphs for ChainedSequenceContext<'_> {
+    fn collect_glyphs(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        lookups: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        match self {
+            ChainedSequenceContext::Format1(table) => {
+                add_coverage(glyphs, &table.coverage()?);
+                for rule_set in table.chained_seq_rule_sets().iter().flatten() {
+                    for rule in rule_set?.chained_seq_rules().iter() {
+                        let rule = rule?;
+                        glyphs.extend(
+                            rule.backtrack_sequence()
+                                .iter()
+                                .chain(rule.input_sequence())
+                                .chain(rule.lookahead_sequence())
+                                .map(|gid| gid.get()),
+                        );
+                        lookups.extend(
+                            rule.seq_lookup_records()
+                                .iter()
+                                .map(|rec| rec.lookup_list_index()),
+                        );
+                    }
+                }
+            }
+            ChainedSequenceContext::Format2(table) => {
+                add_coverage(glyphs, &table.coverage()?);
+                add_class_def(glyphs, &table.backtrack_class_def()?);
+                add_class_def(glyphs, &table.input_class_def()?);
+                add_class_def(glyphs, &table.lookahead_class_def()?);
+                for rule_set in table.chained_class_seq_rule_sets().iter().flatten() {
+                    for rule in rule_set?.chained_class_seq_rules().iter() {
+                        lookups.extend(
+                            rule?
+                                .seq_lookup_records()
+                                .iter()
+                                .map(|rec| rec.lookup_list_index()),
+                        );
+                    }
+                }
+            }
+            ChainedSequenceContext::Format3(table) => {
+                for coverage in table
+                    .backtrack_coverages()
+                    .iter()
+                    .chain(table.input_coverages().iter())
+                    .chain(table.lookahead_coverages().iter())
+                {
+                    add_coverage(glyphs, &coverage?);
+                }
+                lookups.extend(
+                    table
+                        .seq_lookup_records()
+                        .iter()
+                        .map(|rec| rec.lookup_list_index()),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn add_coverage(glyphs: &mut HashSet<GlyphId16>, coverage: &CoverageTable) {
+    glyphs.extend(coverage.iter());
+}
+
+/// Adds the glyphs that are explicitly assigned a class.
+fn add_class_def(glyphs: &mut HashSet<GlyphId16>, class_def: &ClassDef) {
+    glyphs.extend(class_def.iter().map(|(gid, _)| gid));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::BeBuffer, FontRef, TableProvider};
+
+    fn gid_set(gids: impl IntoIterator<Item = u16>) -> HashSet<GlyphId16> {
+        gids.into_iter().map(GlyphId16::new).collect()
+    }
+
+    #[test]
+    fn collect_all_lookups() {
+        let font = FontRef::new(font_test_data::NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+        let gpos = font.gpos().unwrap();
+        let glyphs = gpos.collect_glyphs(None).unwrap();
+        assert!(!glyphs.is_empty());
+        // every lookup contributes at least its coverage
+        let lookup_count = gpos.lookup_list().unwrap().lookup_count();
+        let mut from_each = HashSet::new();
+        for i in 0..lookup_count {
+            let subset = [i].into_iter().collect();
+            let lookup_glyphs = gpos.collect_glyphs(Some(&subset)).unwrap();
+            assert!(!lookup_glyphs.is_empty());
+            from_each.extend(lookup_glyphs);
+        }
+        assert_eq!(glyphs, from_each);
+        // invalid indices are ignored
+        let subset = [lookup_count].into_iter().collect();
+        assert!(gpos.collect_glyphs(Some(&subset)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn pair_and_context() {
+        // lookup 0: pair pos format 1, first glyph 2, second glyphs 7 and 9
+        // lookup 1: context format 3 with coverage [4, 5], applying lookup 0
+        let data = BeBuffer::new()
+            .push(1u16) // major version
+            .push(0u16) // minor version
+            .push(0u16) // script list
+            .push(0u16) // feature list
+            .push(10u16) // lookup list
+            // lookup list, at 10
+            .push(2u16)
+            .push(6u16)
+            .push(14u16)
+            // lookup 0, at 16
+            .push(2u16) // type
+            .push(0u16) // flag
+            .push(1u16)
+            .push(16u16)
+            // lookup 1, at 24
+            .push(7u16) // type
+            .push(0u16) // flag
+            .push(1u16)
+            .push(32u16)
+            // pair pos format 1, at 32
+            .push(1u16)
+            .push(12u16) // coverage
+            .push(0u16) // value format 1
+            .push(0u16) // value format 2
+            .push(1u16) // pair set count
+            .push(18u16)
+            // coverage, at 44
+            .push(1u16)
+            .push(1u16)
+            .push(2u16)
+            // pair set, at 50
+            .push(2u16)
+            .extend([7u16, 9])
+            // sequence context format 3, at 56
+            .push(3u16)
+            .push(1u16) // glyph count
+            .push(1u16) // seq lookup count
+            .push(12u16) // coverage
+            .push(0u16) // sequence index
+            .push(0u16) // lookup index
+            // coverage, at 68
+            .push(1u16)
+            .push(2u16)
+            .extend([4u16, 5]);
+        let gpos = Gpos::read(data.font_data()).unwrap();
+        assert_eq!(gpos.collect_glyphs(None).unwrap(), gid_set([2, 4, 5, 7, 9]));
+        // nested lookups are followed
+        let subset = [1].into_iter().collect();
+        assert_eq!(
+            gpos.collect_glyphs(Some(&subset)).unwrap(),
+            gid_set([2, 4, 5, 7, 9])
+        );
+        let subset = [0].into_iter().collect();
+        assert_eq!(
+            gpos.collect_glyphs(Some(&subset)).unwrap(),
+            gid_set([2, 7, 9])
+        );
+    }
+}
@@ -3,13 +3,17 @@
 //! [GSUB]: https://docs.microsoft.com/en-us/typography/opentype/spec/gsub
 
 pub use super::layout::{
-    ChainedSequenceContext, ClassDef, CoverageTable, Device, FeatureList, FeatureVariations,
-    Lookup, LookupList, ScriptList, SequenceContext,
+    ChainedSequenceContext, ClassDef, CoverageTable, Device, FeatureList, FeatureSelector,
+    FeatureVariations, Lookup, LookupList, ScriptList, SequenceContext,
 };
 use super::layout::{ExtensionLookup, LookupFlag, Subtables};
+#[cfg(feature = "std")]
+pub use queries::LigatureInfo;
 
 #[cfg(feature = "std")]
 mod closure;
+#[cfg(feature = "std")]
+mod queries;
 #[cfg(test)]
 #[path = "../tests/test_gsub.rs"]
 mod tests;
@@ -25,6 +29,18 @@ pub type SubstitutionSequenceContext<'a> = super::layout::SequenceContext<'a>;
 /// A GSUB [ChainedSequenceContext]
 pub type SubstitutionChainContext<'a> = super::layout::ChainedSequenceContext<'a>;
 
+impl<'a> Gsub<'a> {
+    /// Returns a [`FeatureSelector`] for choosing the lookups to apply for a
+    /// script, language and set of features.
+    pub fn feature_selector(&self) -> Result<FeatureSelector<'a>, ReadError> {
+        Ok(FeatureSelector::new(
+            self.script_list()?,
+            self.feature_list()?,
+            self.feature_variations().transpose()?,
+        ))
+    }
+}
+
 impl<'a, T: FontRead<'a>> ExtensionLookup<'a, T> for ExtensionSubstFormat1<'a, T> {
     fn extension(&self) -> Result<T, ReadError> {
         self.extension()
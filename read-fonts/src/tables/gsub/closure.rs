@@ -5,13 +5,13 @@
 
 use std::collections::HashSet;
 
-use font_types::GlyphId16;
+use font_types::{GlyphId16, Tag};
 
 use crate::{
     tables::layout::{
         ChainedSequenceContextFormat1, ChainedSequenceContextFormat2,
         ChainedSequenceContextFormat3, ExtensionLookup, SequenceContextFormat1,
-        SequenceContextFormat2, SequenceContextFormat3, Subtables,
+        SequenceContextFormat2, SequenceContextFormat3, Subtables, MAX_NESTING_LEVEL,
     },
     FontRead, ReadError,
 };
@@ -30,9 +30,28 @@ pub(crate) trait GlyphClosure {
 
 impl<'a> Gsub<'a> {
     /// Return the set of glyphs reachable from the input set via any substituion.
+    ///
+    /// This considers the lookups of all features, including the alternate
+    /// feature tables in the [`FeatureVariations`](super::FeatureVariations)
+    /// table.
     pub fn closure_glyphs(
+        &self,
+        glyphs: HashSet<GlyphId16>,
+    ) -> Result<HashSet<GlyphId16>, ReadError> {
+        let lookups = self.feature_lookups(None)?;
+        self.closure_glyphs_with_lookups(glyphs, &lookups)
+    }
+
+    /// Return the set of glyphs reachable from the input set via the given
+    /// lookups.
+    ///
+    /// Lookups referenced by contextual lookups in the set are also applied,
+    /// when their context can be matched by the glyphs in the closure. These
+    /// nested lookups are followed to a depth of at most 64.
+    pub fn closure_glyphs_with_lookups(
         &self,
         mut glyphs: HashSet<GlyphId16>,
+        lookups: &HashSet<u16>,
     ) -> Result<HashSet<GlyphId16>, ReadError> {
         // we need to do this iteratively, since any glyph found in one pass
         // over the lookups could also be the target of substitutions.
@@ -40,20 +59,69 @@ impl<'a> Gsub<'a> {
         // we always call this once, and then keep calling if it produces
         // additional glyphs
         let mut prev_glyph_count = glyphs.len();
-        self.closure_glyphs_once(&mut glyphs)?;
+        self.closure_glyphs_once(&mut glyphs, lookups)?;
         let mut new_glyph_count = glyphs.len();
 
         while prev_glyph_count != new_glyph_count {
             prev_glyph_count = new_glyph_count;
-            self.closure_glyphs_once(&mut glyphs)?;
+            self.closure_glyphs_once(&mut glyphs, lookups)?;
             new_glyph_count = glyphs.len();
         }
 
         Ok(glyphs)
     }
 
-    fn closure_glyphs_once(&self, glyphs: &mut HashSet<GlyphId16>) -> Result<(), ReadError> {
-        let lookups_to_use = self.find_reachable_lookups(glyphs)?;
+    /// Return the indices of the lookups referenced by the given features.
+    ///
+    /// If `features` is `None`, the lookups of all features are returned.
+    /// Features are matched by tag regardless of the scripts and languages
+    /// that reference them, and the alternate feature tables in the
+    /// [`FeatureVariations`](super::FeatureVariations) table are included.
+    ///
+    /// The result is suitable for passing to
+    /// [`closure_glyphs_with_lookups`](Self::closure_glyphs_with_lookups).
+    pub fn feature_lookups(
+        &self,
+        features: Option<&HashSet<Tag>>,
+    ) -> Result<HashSet<u16>, ReadError> {
+        let feature_list = self.feature_list()?;
+        let records = feature_list.feature_records();
+        let is_selected = |index: usize| {
+            records
+                .get(index)
+                .is_some_and(|rec| features.is_none_or(|tags| tags.contains(&rec.feature_tag())))
+        };
+        let mut lookup_ids = HashSet::new();
+        for (i, rec) in records.iter().enumerate() {
+            if is_selected(i) {
+                let feature = rec.feature(feature_list.offset_data())?;
+                lookup_ids.extend(feature.lookup_list_indices().iter().map(|idx| idx.get()));
+            }
+        }
+        if let Some(vars) = self.feature_variations().transpose()? {
+            let data = vars.offset_data();
+            for rec in vars.feature_variation_records() {
+                let Some(subs) = rec.feature_table_substitution(data).transpose()? else {
+                    continue;
+                };
+                for sub in subs.substitutions() {
+                    if is_selected(sub.feature_index() as usize) {
+                        let feature = sub.alternate_feature(subs.offset_data())?;
+                        lookup_ids
+                            .extend(feature.lookup_list_indices().iter().map(|idx| idx.get()));
+                    }
+                }
+            }
+        }
+        Ok(lookup_ids)
+    }
+
+    fn closure_glyphs_once(
+        &self,
+        glyphs: &mut HashSet<GlyphId16>,
+        lookups: &HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        let lookups_to_use = self.find_reachable_lookups(glyphs, lookups)?;
         let lookup_list = self.lookup_list()?;
         for (i, lookup) in lookup_list.lookups().iter().enumerate() {
             if !lookups_to_use.contains(&(i as u16)) {
@@ -65,60 +133,51 @@ impl<'a> Gsub<'a> {
         Ok(())
     }
 
+    /// Returns the given lookups along with any lookups that are reachable
+    /// from them via contextual lookups, given the current set of glyphs.
     fn find_reachable_lookups(
         &self,
         glyphs: &HashSet<GlyphId16>,
+        lookups: &HashSet<u16>,
     ) -> Result<HashSet<u16>, ReadError> {
-        let feature_list = self.feature_list()?;
         let lookup_list = self.lookup_list()?;
-        // first we want to get the lookups that are directly referenced by a feature
-        // (including in a feature variation table)
-        let mut lookup_ids = HashSet::with_capacity(lookup_list.lookup_count() as _);
-        let feature_variations = self
-            .feature_variations()
-            .transpose()?
-            .map(|vars| {
-                let data = vars.offset_data();
-                vars.feature_variation_records()
-                    .iter()
-                    .filter_map(move |rec| {
-                        rec.feature_table_substitution(data)
-                            .transpose()
-                            .ok()
-                            .flatten()
-                    })
-                    .flat_map(|subs| {
-                        subs.substitutions()
-                            .iter()
-                            .map(move |sub| sub.alternate_feature(subs.offset_data()))
-                    })
-            })
-            .into_iter()
-            .flatten();
-        for feature in feature_list
-            .feature_records()
-            .iter()
-            .map(|rec| rec.feature(feature_list.offset_data()))
-            .chain(feature_variations)
-        {
-            lookup_ids.extend(feature?.lookup_list_indices().iter().map(|idx| idx.get()));
+        let mut reachable = HashSet::with_capacity(lookup_list.lookup_count() as _);
+        for lookup_index in lookups {
+            self.add_reachable_lookups(glyphs, *lookup_index, 0, &mut reachable)?;
         }
+        Ok(reachable)
+    }
 
-        // and now we need to add lookups referenced by contextual lookups,
-        // IFF they are reachable via the current set of glyphs:
-        for lookup in lookup_list.lookups().iter() {
-            let subtables = lookup?.subtables()?;
-            match subtables {
-                SubstitutionSubtables::Contextual(tables) => tables
-                    .iter()
-                    .try_for_each(|t| t?.add_reachable_lookups(glyphs, &mut lookup_ids)),
-                SubstitutionSubtables::ChainContextual(tables) => tables
-                    .iter()
-                    .try_for_each(|t| t?.add_reachable_lookups(glyphs, &mut lookup_ids)),
-                _ => Ok(()),
-            }?;
+    fn add_reachable_lookups(
+        &self,
+        glyphs: &HashSet<GlyphId16>,
+        lookup_index: u16,
+        depth: usize,
+        reachable: &mut HashSet<u16>,
+    ) -> Result<(), ReadError> {
+        if depth > MAX_NESTING_LEVEL || !reachable.insert(lookup_index) {
+            return Ok(());
         }
-        Ok(lookup_ids)
+        let lookup_list = self.lookup_list()?;
+        // invalid lookup indices are ignored
+        if lookup_index >= lookup_list.lookup_count() {
+            return Ok(());
+        }
+        let lookup = lookup_list.lookups().get(lookup_index as usize)?;
+        let mut nested = HashSet::new();
+        match lookup.subtables()? {
+            SubstitutionSubtables::Contextual(tables) => tables
+                .iter()
+                .try_for_each(|t| t?.add_reachable_lookups(glyphs, &mut nested)),
+            SubstitutionSubtables::ChainContextual(tables) => tables
+                .iter()
+                .try_for_each(|t| t?.add_reachable_lookups(glyphs, &mut nested)),
+            _ => Ok(()),
+        }?;
+        for nested_index in nested {
+            self.add_reachable_lookups(glyphs, nested_index, depth + 1, reachable)?;
+        }
+        Ok(())
     }
 }
 
@@ -156,7 +215,9 @@ impl<'a> GlyphClosure for SingleSubst<'a> {
 }
 
 impl<'a> SingleSubst<'a> {
-    fn iter_subs(&self) -> Result<impl Iterator<Item = (GlyphId16, GlyphId16)> + '_, ReadError> {
+    pub(super) fn iter_subs(
+        &self,
+    ) -> Result<impl Iterator<Item = (GlyphId16, GlyphId16)> + '_, ReadError> {
         let (left, right) = match self {
             SingleSubst::Format1(t) => (Some(t.iter_subs()?), None),
             SingleSubst::Format2(t) => (None, Some(t.iter_subs()?)),
@@ -627,6 +688,84 @@ mod tests {
         assert_closure_result!(glyph_map, intermediate, &["a", "B.2", "B.3"]);
     }
 
+    fn compute_closure_with_lookups(
+        gsub: &Gsub,
+        glyph_map: &GlyphMap,
+        input: &[&str],
+        lookups: &[u16],
+    ) -> HashSet<GlyphId16> {
+        let input_glyphs = input
+            .iter()
+            .map(|name| glyph_map.get_gid(name).unwrap())
+            .collect();
+        let lookups = lookups.iter().copied().collect();
+        gsub.closure_glyphs_with_lookups(input_glyphs, &lookups)
+            .unwrap()
+    }
+
+    #[test]
+    fn lookup_subset() {
+        // lookups are in the order of simple_closure.fea
+        let gsub = get_gsub(test_data::SIMPLE);
+        let glyph_map = GlyphMap::new(test_data::SIMPLE_GLYPHS);
+
+        let single = compute_closure_with_lookups(&gsub, &glyph_map, &["a"], &[0, 2]);
+        assert_closure_result!(glyph_map, single, &["a", "A", "c", "d"]);
+
+        let reverse = compute_closure_with_lookups(&gsub, &glyph_map, &["a"], &[5]);
+        assert_closure_result!(glyph_map, reverse, &["a", "a.3"]);
+
+        let none = compute_closure_with_lookups(&gsub, &glyph_map, &["a"], &[]);
+        assert_closure_result!(glyph_map, none, &["a"]);
+
+        // invalid lookup indices are ignored
+        let invalid = compute_closure_with_lookups(&gsub, &glyph_map, &["a"], &[100]);
+        assert_closure_result!(glyph_map, invalid, &["a"]);
+    }
+
+    #[test]
+    fn feature_subset() {
+        let gsub = get_gsub(test_data::CONTEXTUAL);
+        let glyph_map = GlyphMap::new(test_data::CONTEXTUAL_GLYPHS);
+        let lookups_for = |tag: &[u8; 4]| {
+            let features = [Tag::new(tag)].into_iter().collect();
+            gsub.feature_lookups(Some(&features)).unwrap()
+        };
+
+        // MY_RULES is only reachable through the SUB5 contextual lookup
+        let sub5 = lookups_for(b"SUB5");
+        let input = ["f", "g"].map(|name| glyph_map.get_gid(name).unwrap());
+        let closure = gsub
+            .closure_glyphs_with_lookups(input.into_iter().collect(), &sub5)
+            .unwrap();
+        assert_closure_result!(glyph_map, closure, &["f", "g", "f.2"]);
+
+        let sub6 = lookups_for(b"SUB6");
+        assert!(sub5.is_disjoint(&sub6));
+        let closure = gsub
+            .closure_glyphs_with_lookups(input.into_iter().collect(), &sub6)
+            .unwrap();
+        assert_closure_result!(glyph_map, closure, &["f", "g"]);
+
+        assert!(lookups_for(b"zzzz").is_empty());
+        assert_eq!(
+            gsub.feature_lookups(None).unwrap(),
+            sub5.union(&sub6).copied().collect()
+        );
+    }
+
+    #[test]
+    fn feature_variations_subset() {
+        let gsub = get_gsub(test_data::VARIATIONS_CLOSURE);
+        let glyph_map = GlyphMap::new(test_data::VARIATIONS_GLYPHS);
+        // the alternate feature table adds a lookup to the `test` feature
+        let features = [Tag::new(b"test")].into_iter().collect();
+        let lookups = gsub.feature_lookups(Some(&features)).unwrap();
+        assert_eq!(lookups, [0, 1].into_iter().collect());
+        let input = compute_closure_with_lookups(&gsub, &glyph_map, &["a"], &[1]);
+        assert_closure_result!(glyph_map, input, &["a", "c"]);
+    }
+
     #[test]
     fn feature_variations() {
         let gsub = get_gsub(test_data::VARIATIONS_CLOSURE);
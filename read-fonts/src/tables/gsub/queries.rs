@@ -0,0 +1,243 @@
+//! Querying substitutions without applying them
+//!
+//! These answer questions such as "what are the alternates of this glyph?"
+//! directly from the lookups, which is useful for glyph pickers and similar
+//! tools that don't need a full shaper.
+
+use std::collections::HashSet;
+
+use font_types::{GlyphId16, Tag};
+
+use crate::ReadError;
+
+use super::{Gsub, SingleSubst, SubstitutionSubtables};
+
+/// A ligature, as returned by [`Gsub::ligatures_containing`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LigatureInfo {
+    /// The ligature glyph.
+    pub glyph: GlyphId16,
+    /// The sequence of glyphs that is replaced by the ligature, including
+    /// the first glyph.
+    pub components: Vec<GlyphId16>,
+}
+
+impl<'a> Gsub<'a> {
+    /// Returns the glyphs that can replace `gid` through the given feature.
+    ///
+    /// This is intended for features such as `aalt`, `salt`, `ssXX` and
+    /// `cvXX`, and includes the substitutes from both single and alternate
+    /// substitution lookups. Features are matched by tag regardless of script
+    /// and language, and alternate feature tables from feature variations are
+    /// included.
+    ///
+    /// Glyphs are returned in lookup order, without duplicates.
+    pub fn alternates_for(
+        &self,
+        gid: GlyphId16,
+        feature: Tag,
+    ) -> Result<Vec<GlyphId16>, ReadError> {
+        let mut alternates = Vec::new();
+        let mut seen = HashSet::from([gid]);
+        for subtables in self.feature_subtables(feature)? {
+            match subtables {
+                SubstitutionSubtables::Single(tables) => {
+                    for table in tables.iter() {
+                        let table = table?;
+                        if let Some(sub) = single_substitute(&table, gid)? {
+                            if seen.insert(sub) {
+                                alternates.push(sub);
+                            }
+                        }
+                    }
+                }
+                SubstitutionSubtables::Alternate(tables) => {
+                    for table in tables.iter() {
+                        let table = table?;
+                        let Some(idx) = table.coverage()?.get(gid) else {
+                            continue;
+                        };
+                        let alt_set = table.alternate_sets().get(idx as usize)?;
+                        for alt in alt_set.alternate_glyph_ids() {
+                            if seen.insert(alt.get()) {
+                                alternates.push(alt.get());
+                            }
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(alternates)
+    }
+
+    /// Returns every ligature in which `gid` is one of the components.
+    ///
+    /// All ligature substitution lookups are considered, regardless of the
+    /// features that reference them. Ligatures are returned in lookup order,
+    /// without duplicates.
+    pub fn ligatures_containing(&self, gid: GlyphId16) -> Result<Vec<LigatureInfo>, ReadError> {
+        let mut ligatures = Vec::new();
+        let mut seen = HashSet::new();
+        for lookup in self.lookup_list()?.lookups().iter() {
+            let SubstitutionSubtables::Ligature(tables) = lookup?.subtables()? else {
+                continue;
+            };
+            for table in tables.iter() {
+                let table = table?;
+                for (first, lig_set) in table.coverage()?.iter().zip(table.ligature_sets().iter()) {
+                    for lig in lig_set?.ligatures().iter() {
+                        let lig = lig?;
+                        let components = lig.component_glyph_ids();
+                        if first != gid && !components.iter().any(|c| c.get() == gid) {
+                            continue;
+                        }
+                        let info = LigatureInfo {
+                            glyph: lig.ligature_glyph(),
+                            components: std::iter::once(first)
+                                .chain(components.iter().map(|c| c.get()))
+                                .collect(),
+                        };
+                        if seen.insert(info.clone()) {
+                            ligatures.push(info);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(ligatures)
+    }
+
+    /// Returns the `(input, substitute)` pairs of the single substitution
+    /// lookups referenced by the given feature.
+    ///
+    /// Features are matched by tag as for [`alternates_for`](Self::alternates_for).
+    /// If an input glyph is substituted by more than one lookup, only the
+    /// first substitution is returned. Pairs are sorted by input glyph.
+    pub fn single_substitutions(
+        &self,
+        feature: Tag,
+    ) -> Result<Vec<(GlyphId16, GlyphId16)>, ReadError> {
+        let mut pairs = Vec::new();
+        let mut seen = HashSet::new();
+        for subtables in self.feature_subtables(feature)? {
+            let SubstitutionSubtables::Single(tables) = subtables else {
+                continue;
+            };
+            for table in tables.iter() {
+                for (target, sub) in table?.iter_subs()? {
+                    if seen.insert(target) {
+                        pairs.push((target, sub));
+                    }
+                }
+            }
+        }
+        pairs.sort_by_key(|(target, _)| *target);
+        Ok(pairs)
+    }
+
+    /// Returns the subtables of the lookups referenced by the given feature,
+    /// in lookup order.
+    fn feature_subtables(&self, feature: Tag) -> Result<Vec<SubstitutionSubtables<'a>>, ReadError> {
+        let mut lookup_indices = self
+            .feature_lookups(Some(&HashSet::from([feature])))?
+            .into_iter()
+            .collect::<Vec<_>>();
+        lookup_indices.sort_unstable();
+        let lookup_list = self.lookup_list()?;
+        lookup_indices
+            .into_iter()
+            // invalid lookup indices are ignored
+            .filter(|idx| *idx < lookup_list.lookup_count())
+            .map(|idx| lookup_list.lookups().get(idx as usize)?.subtables())
+            .collect()
+    }
+}
+
+fn single_substitute(table: &SingleSubst, gid: GlyphId16) -> Result<Option<GlyphId16>, ReadError> {
+    match table {
+        SingleSubst::Format1(table) => {
+            if table.coverage()?.get(gid).is_none() {
+                return Ok(None);
+            }
+            let raw = gid.to_u16() as i32 + table.delta_glyph_id() as i32;
+            Ok(u16::try_from(raw).ok().map(GlyphId16::new))
+        }
+        SingleSubst::Format2(table) => Ok(table
+            .coverage()?
+            .get(gid)
+            .and_then(|idx| table.substitute_glyph_ids().get(idx as usize))
+            .map(|sub| sub.get())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FontRef, TableProvider};
+    use font_test_data::closure as test_data;
+
+    // glyph order of simple_closure.ttf
+    const A: GlyphId16 = GlyphId16::new(0);
+    const B: GlyphId16 = GlyphId16::new(1);
+    const C: GlyphId16 = GlyphId16::new(2);
+    const UPPER_A: GlyphId16 = GlyphId16::new(4);
+    const A_A: GlyphId16 = GlyphId16::new(5);
+    const A_1: GlyphId16 = GlyphId16::new(6);
+    const A_2: GlyphId16 = GlyphId16::new(7);
+    const X: GlyphId16 = GlyphId16::new(9);
+    const Y: GlyphId16 = GlyphId16::new(10);
+
+    const TEST: Tag = Tag::new(b"test");
+
+    fn get_gsub(data: &'static [u8]) -> Gsub<'static> {
+        FontRef::new(data).unwrap().gsub().unwrap()
+    }
+
+    #[test]
+    fn alternates() {
+        let gsub = get_gsub(test_data::SIMPLE);
+        assert_eq!(
+            gsub.alternates_for(A, TEST).unwrap(),
+            [UPPER_A, B, A_1, A_2]
+        );
+        assert_eq!(gsub.alternates_for(X, TEST).unwrap(), [Y]);
+        assert!(gsub.alternates_for(B, TEST).unwrap().is_empty());
+        assert!(gsub
+            .alternates_for(A, Tag::new(b"salt"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn alternates_from_feature_variations() {
+        // the alternate feature table adds the substitution a -> c
+        let gsub = get_gsub(test_data::VARIATIONS_CLOSURE);
+        assert_eq!(gsub.alternates_for(A, TEST).unwrap(), [B, C]);
+    }
+
+    #[test]
+    fn ligatures() {
+        let gsub = get_gsub(test_data::SIMPLE);
+        let a_a = LigatureInfo {
+            glyph: A_A,
+            components: vec![A, A],
+        };
+        assert_eq!(gsub.ligatures_containing(A).unwrap(), [a_a]);
+        assert!(gsub.ligatures_containing(B).unwrap().is_empty());
+    }
+
+    #[test]
+    fn single_substitution_pairs() {
+        let gsub = get_gsub(test_data::SIMPLE);
+        // a -> A comes first, so a -> b is not included
+        assert_eq!(
+            gsub.single_substitutions(TEST).unwrap(),
+            [(A, UPPER_A), (X, Y)]
+        );
+        assert!(gsub
+            .single_substitutions(Tag::new(b"salt"))
+            .unwrap()
+            .is_empty());
+    }
+}
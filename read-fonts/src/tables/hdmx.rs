@@ -0,0 +1,164 @@
+//! The [Horizontal Device Metrics](https://learn.microsoft.com/en-us/typography/opentype/spec/hdmx) table
+
+include!("../../generated/generated_hdmx.rs");
+
+impl<'a> Hdmx<'a> {
+    /// Returns the device record for the given pixel size, if present.
+    pub fn record_for_size(&self, ppem: u8) -> Option<DeviceRecord<'a>> {
+        let records = self.records();
+        // Records are sorted by pixel size
+        let mut lo = 0;
+        let mut hi = records.len();
+        while lo < hi {
+            let i = (lo + hi) / 2;
+            let record = records.get(i).ok()?;
+            match ppem.cmp(&record.pixel_size) {
+                core::cmp::Ordering::Less => hi = i,
+                core::cmp::Ordering::Greater => lo = i + 1,
+                core::cmp::Ordering::Equal => return Some(record),
+            }
+        }
+        None
+    }
+
+    /// Returns the hinted advance width, in pixels, of the given glyph at
+    /// the given pixel size.
+    ///
+    /// Returns `None` if the table doesn't contain a record for the size
+    /// or the glyph identifier is out of range.
+    pub fn advance_width(&self, glyph_id: impl Into<GlyphId>, ppem: u8) -> Option<u8> {
+        self.record_for_size(ppem)?.advance_width(glyph_id)
+    }
+}
+
+/// A single device record in an [`Hdmx`] table.
+#[derive(Clone, Debug)]
+pub struct DeviceRecord<'a> {
+    /// Pixel size for following widths (as ppem).
+    pub pixel_size: u8,
+    /// Maximum width.
+    pub max_width: u8,
+    /// Array of widths, indexed by glyph ID.
+    pub widths: &'a [u8],
+}
+
+impl<'a> DeviceRecord<'a> {
+    /// Returns the advance width of the given glyph, in pixels.
+    pub fn advance_width(&self, glyph_id: impl Into<GlyphId>) -> Option<u8> {
+        self.widths
+            .get(usize::try_from(glyph_id.into().to_u32()).ok()?)
+            .copied()
+    }
+}
+
+impl ReadArgs for DeviceRecord<'_> {
+    type Args = (u16, u32);
+}
+
+impl<'a> FontReadWithArgs<'a> for DeviceRecord<'a> {
+    fn read_with_args(data: FontData<'a>, args: &Self::Args) -> Result<Self, ReadError> {
+        let num_glyphs = args.0 as usize;
+        let mut cursor = data.cursor();
+        let pixel_size = cursor.read()?;
+        let max_width = cursor.read()?;
+        let widths = cursor.read_array(num_glyphs)?;
+        Ok(Self {
+            pixel_size,
+            max_width,
+            widths,
+        })
+    }
+}
+
+impl ComputeSize for DeviceRecord<'_> {
+    #[inline]
+    fn compute_size(args: &(u16, u32)) -> Result<usize, ReadError> {
+        // The record size is stored in the table header and includes
+        // padding to 32-bit alignment
+        Ok(args.1 as usize)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> DeviceRecord<'a> {
+    pub(crate) fn get_field(&self, idx: usize, _data: FontData<'a>) -> Option<Field<'a>> {
+        match idx {
+            0 => Some(Field::new("pixel_size", self.pixel_size)),
+            1 => Some(Field::new("max_width", self.max_width)),
+            2 => Some(Field::new("widths", self.widths)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for DeviceRecord<'a> {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "DeviceRecord",
+            data,
+            get_field: Box::new(move |idx, data| self.get_field(idx, data)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn make_hdmx() -> BeBuffer {
+        // 3 glyphs, so records are 5 bytes padded to 8
+        BeBuffer::new()
+            .push(0u16) // version
+            .push(2u16) // num records
+            .push(8u32) // size device record
+            .extend([9u8, 6, 5, 0, 6, 0, 0, 0])
+            .extend([12u8, 8, 7, 0, 8, 0, 0, 0])
+    }
+
+    #[test]
+    fn read_records() {
+        let buf = make_hdmx();
+        let hdmx = Hdmx::read(buf.font_data(), 3).unwrap();
+        assert_eq!(hdmx.num_records(), 2);
+        let records = hdmx
+            .records()
+            .iter()
+            .map(|record| record.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].pixel_size, 9);
+        assert_eq!(records[0].max_width, 6);
+        assert_eq!(records[0].widths, &[5, 0, 6]);
+        assert_eq!(records[1].pixel_size, 12);
+        assert_eq!(records[1].max_width, 8);
+        assert_eq!(records[1].widths, &[7, 0, 8]);
+    }
+
+    #[test]
+    fn advance_widths() {
+        let buf = make_hdmx();
+        let hdmx = Hdmx::read(buf.font_data(), 3).unwrap();
+        assert_eq!(hdmx.advance_width(GlyphId::new(0), 9), Some(5));
+        assert_eq!(hdmx.advance_width(GlyphId::new(2), 9), Some(6));
+        assert_eq!(hdmx.advance_width(GlyphId::new(2), 12), Some(8));
+        // missing size
+        assert_eq!(hdmx.advance_width(GlyphId::new(0), 10), None);
+        // glyph out of range
+        assert_eq!(hdmx.advance_width(GlyphId::new(3), 12), None);
+    }
+
+    #[test]
+    fn truncated_record() {
+        // size device record smaller than the widths array
+        let buf = BeBuffer::new()
+            .push(0u16)
+            .push(1u16)
+            .push(4u32)
+            .extend([9u8, 6, 5, 0]);
+        let hdmx = Hdmx::read(buf.font_data(), 3).unwrap();
+        assert!(hdmx.records().get(0).is_err());
+        assert_eq!(hdmx.advance_width(GlyphId::new(0), 9), None);
+    }
+}
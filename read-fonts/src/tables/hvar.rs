@@ -1,6 +1,6 @@
 //! The [HVAR (Horizontal Metrics Variation)](https://docs.microsoft.com/en-us/typography/opentype/spec/hvar) table
 
-use super::variations::{self, DeltaSetIndexMap, ItemVariationStore};
+use super::variations::{self, DeltaSetIndex, DeltaSetIndexMap, ItemVariationStore};
 
 include!("../../generated/generated_hvar.rs");
 
@@ -41,6 +41,28 @@ impl<'a> Hvar<'a> {
             coords,
         )
     }
+
+    /// Returns the delta set index for the advance width of the specified glyph
+    /// identifier.
+    ///
+    /// The index can be passed to a
+    /// [`VarStoreInstancer`](variations::VarStoreInstancer) created from this
+    /// table's item variation store.
+    pub fn advance_width_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::advance_delta_index(self.advance_width_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index for the left side bearing of the specified glyph
+    /// identifier.
+    pub fn lsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.lsb_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index for the right side bearing of the specified glyph
+    /// identifier.
+    pub fn rsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.rsb_mapping(), glyph_id)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,135 @@
+//! The [JSTF](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) table
+
+use super::gpos::PositionLookup;
+
+include!("../../generated/generated_jstf.rs");
+
+impl<'a> Jstf<'a> {
+    /// Returns the justification data for the given script, if present.
+    pub fn script(&self, tag: Tag) -> Option<Result<JstfScript<'a>, ReadError>> {
+        let records = self.jstf_script_records();
+        let idx = records
+            .binary_search_by_key(&tag, |record| record.jstf_script_tag())
+            .ok()?;
+        Some(records[idx].jstf_script(self.offset_data()))
+    }
+}
+
+impl<'a> JstfScript<'a> {
+    /// Returns the justification data for the given language system, if
+    /// present.
+    ///
+    /// This does not fall back to the default language system; use
+    /// [`def_jstf_lang_sys`](Self::def_jstf_lang_sys) for that.
+    pub fn lang_sys(&self, tag: Tag) -> Option<Result<JstfLangSys<'a>, ReadError>> {
+        let records = self.jstf_lang_sys_records();
+        let idx = records
+            .binary_search_by_key(&tag, |record| record.jstf_lang_sys_tag())
+            .ok()?;
+        Some(records[idx].jstf_lang_sys(self.offset_data()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tables::gpos::{PositionSubtables, SinglePos},
+        test_helpers::BeBuffer,
+    };
+
+    fn make_jstf() -> BeBuffer {
+        BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(1u16) // script count
+            .push(Tag::new(b"latn"))
+            .push(12u16)
+            // JstfScript
+            .push(12u16) // extender glyph
+            .push(18u16) // default lang sys
+            .push(1u16) // lang sys count
+            .push(Tag::new(b"ENG "))
+            .push(18u16)
+            // ExtenderGlyph
+            .push(2u16)
+            .extend([GlyphId16::new(3), GlyphId16::new(4)])
+            // JstfLangSys
+            .push(1u16)
+            .push(4u16)
+            // JstfPriority
+            .push(20u16) // gsub shrinkage enable
+            .extend([0u16; 8])
+            .push(26u16) // extension jstf max
+            // JstfModList
+            .push(2u16)
+            .extend([1u16, 3])
+            // JstfMax
+            .push(1u16)
+            .push(4u16)
+            // Lookup
+            .push(1u16) // type
+            .push(0u16) // flag
+            .push(1u16) // subtable count
+            .push(8u16)
+            // SinglePosFormat1
+            .push(1u16)
+            .push(8u16) // coverage
+            .push(0x0004u16) // x advance
+            .push(-50i16)
+            // coverage
+            .push(1u16)
+            .push(1u16)
+            .push(GlyphId16::new(7))
+    }
+
+    #[test]
+    fn read_jstf() {
+        let buf = make_jstf();
+        let jstf = Jstf::read(buf.font_data()).unwrap();
+        assert!(jstf.script(Tag::new(b"arab")).is_none());
+        let script = jstf.script(Tag::new(b"latn")).unwrap().unwrap();
+        let extenders = script.extender_glyph().unwrap().unwrap();
+        let extenders = extenders
+            .extender_glyphs()
+            .iter()
+            .map(|gid| gid.get().to_u16())
+            .collect::<Vec<_>>();
+        assert_eq!(extenders, [3, 4]);
+        assert!(script.lang_sys(Tag::new(b"FRA ")).is_none());
+        let lang_sys = script.lang_sys(Tag::new(b"ENG ")).unwrap().unwrap();
+        let default_lang_sys = script.def_jstf_lang_sys().unwrap().unwrap();
+        assert_eq!(
+            lang_sys.offset_data().as_bytes(),
+            default_lang_sys.offset_data().as_bytes()
+        );
+        let priorities = lang_sys
+            .jstf_priorities()
+            .iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(priorities.len(), 1);
+        let priority = &priorities[0];
+        let gsub_enable = priority.gsub_shrinkage_enable().unwrap().unwrap();
+        let indices = gsub_enable
+            .lookup_indices()
+            .iter()
+            .map(|idx| idx.get())
+            .collect::<Vec<_>>();
+        assert_eq!(indices, [1, 3]);
+        assert!(priority.gsub_shrinkage_disable().is_none());
+        assert!(priority.gpos_extension_enable().is_none());
+        assert!(priority.shrinkage_jstf_max().is_none());
+        let jstf_max = priority.extension_jstf_max().unwrap().unwrap();
+        let lookup = jstf_max.lookups().get(0).unwrap();
+        assert_eq!(lookup.lookup_type(), 1);
+        let PositionSubtables::Single(subtables) = lookup.subtables().unwrap() else {
+            panic!("expected single positioning lookup");
+        };
+        let Ok(SinglePos::Format1(single)) = subtables.get(0) else {
+            panic!("expected single positioning format 1");
+        };
+        assert_eq!(single.value_record().x_advance(), Some(-50));
+        let coverage = single.coverage().unwrap();
+        assert_eq!(coverage.get(GlyphId16::new(7)), Some(0));
+    }
+}
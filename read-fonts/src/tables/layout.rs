@@ -1,10 +1,13 @@
 //! OpenType Layout common table formats
 
+#[path = "./feature_selection.rs"]
+mod feature_selection;
 #[path = "./lookupflag.rs"]
 mod lookupflag;
 
 use core::cmp::Ordering;
 
+pub use feature_selection::{FeatureSelector, DFLT_LANGUAGE, DFLT_SCRIPT};
 pub use lookupflag::LookupFlag;
 
 use super::variations::DeltaSetIndex;
@@ -15,6 +18,13 @@ mod spec_tests;
 
 include!("../../generated/generated_layout.rs");
 
+/// Limit on the depth of nested lookups that are followed when traversing
+/// contextual lookups.
+///
+/// This matches HarfBuzz's `HB_MAX_NESTING_LEVEL`.
+#[cfg(feature = "std")]
+pub(crate) const MAX_NESTING_LEVEL: usize = 64;
+
 impl<'a, T: FontRead<'a>> Lookup<'a, T> {
     pub fn get_subtable(&self, offset: Offset16) -> Result<T, ReadError> {
         self.resolve_offset(offset)
@@ -325,6 +335,19 @@ impl<'a> Device<'a> {
             iter
         })
     }
+
+    /// Returns the adjustment, in pixels, for the given size in pixels per em.
+    ///
+    /// Returns zero if the size is outside of the range covered by this
+    /// device table.
+    pub fn delta(&self, ppem: u16) -> i8 {
+        if !(self.start_size()..=self.end_size()).contains(&ppem) {
+            return 0;
+        }
+        self.iter()
+            .nth((ppem - self.start_size()) as usize)
+            .unwrap_or_default()
+    }
 }
 
 fn iter_packed_values(raw: u16, format: DeltaFormat, n: usize) -> impl Iterator<Item = i8> {
@@ -447,4 +470,15 @@ mod tests {
             &[1i8, -12, 30, -11, 101, 8, 42]
         );
     }
+
+    #[test]
+    fn device_delta_for_ppem() {
+        let bytes: &[u8] = &[0, 7, 0, 13, 0, 3, 1, 244, 30, 245, 101, 8, 42, 0];
+        let device = Device::read(bytes.into()).unwrap();
+        assert_eq!(device.delta(6), 0);
+        assert_eq!(device.delta(7), 1);
+        assert_eq!(device.delta(10), -11);
+        assert_eq!(device.delta(13), 42);
+        assert_eq!(device.delta(14), 0);
+    }
 }
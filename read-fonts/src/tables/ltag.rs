@@ -0,0 +1,50 @@
+//! The [language tag](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html) table.
+
+include!("../../generated/generated_ltag.rs");
+
+impl<'a> Ltag<'a> {
+    /// Returns the language tag at the given index.
+    pub fn tag(&self, index: u32) -> Option<&'a str> {
+        let range = self.tag_ranges().get(index as usize)?;
+        self.resolve_range(range)
+    }
+
+    /// Returns an iterator over all of the language tags in the table.
+    ///
+    /// Tags that cannot be read or are not valid UTF-8 are omitted.
+    pub fn tags(&self) -> impl Iterator<Item = &'a str> + 'a + Clone {
+        let this = self.clone();
+        self.tag_ranges()
+            .iter()
+            .filter_map(move |range| this.resolve_range(range))
+    }
+
+    fn resolve_range(&self, range: &FTStringRange) -> Option<&'a str> {
+        let start = range.offset() as usize;
+        let end = start + range.length() as usize;
+        let bytes = self.offset_data().slice(start..end)?.as_bytes();
+        core::str::from_utf8(bytes).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn tags() {
+        let buf = BeBuffer::new()
+            .push(1u32) // version
+            .push(0u32) // flags
+            .push(3u32) // num tags
+            .extend([24u16, 2, 26, 7, 24, 2])
+            .extend(*b"enzh-Hant");
+        let ltag = Ltag::read(buf.font_data()).unwrap();
+        assert_eq!(ltag.num_tags(), 3);
+        assert_eq!(ltag.tag(1), Some("zh-Hant"));
+        assert_eq!(ltag.tag(3), None);
+        let tags = ltag.tags().collect::<Vec<_>>();
+        assert_eq!(tags, ["en", "zh-Hant", "en"]);
+    }
+}
@@ -0,0 +1,36 @@
+//! The [Linear Threshold](https://learn.microsoft.com/en-us/typography/opentype/spec/ltsh) table
+
+include!("../../generated/generated_ltsh.rs");
+
+impl<'a> Ltsh<'a> {
+    /// Returns the pixels per em size at or above which the given glyph
+    /// can be assumed to scale linearly.
+    ///
+    /// A value of 1 means the glyph always scales linearly and 0 means
+    /// that it never does.
+    pub fn linear_threshold(&self, glyph_id: impl Into<GlyphId>) -> Option<u8> {
+        self.y_pels()
+            .get(usize::try_from(glyph_id.into().to_u32()).ok()?)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn linear_thresholds() {
+        let buf = BeBuffer::new()
+            .push(0u16) // version
+            .push(3u16) // num glyphs
+            .extend([1u8, 0, 24]);
+        let ltsh = Ltsh::read(buf.font_data()).unwrap();
+        assert_eq!(ltsh.y_pels(), &[1, 0, 24]);
+        assert_eq!(ltsh.linear_threshold(GlyphId::new(0)), Some(1));
+        assert_eq!(ltsh.linear_threshold(GlyphId::new(1)), Some(0));
+        assert_eq!(ltsh.linear_threshold(GlyphId::new(2)), Some(24));
+        assert_eq!(ltsh.linear_threshold(GlyphId::new(3)), None);
+    }
+}
@@ -0,0 +1,109 @@
+//! The [MATH](https://learn.microsoft.com/en-us/typography/opentype/spec/math) table
+
+use super::layout::{CoverageTable, DeviceOrVariationIndex};
+
+include!("../../generated/generated_math.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn math_value_records(buf: BeBuffer, values: impl IntoIterator<Item = i16>) -> BeBuffer {
+        values
+            .into_iter()
+            .fold(buf, |buf, value| buf.push(value).push(0u16))
+    }
+
+    #[test]
+    fn smoke_test() {
+        let mut buf = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(10u16) // constants offset
+            .push(224u16) // glyph info offset
+            .push(246u16) // variants offset
+            // MathConstants
+            .extend([80i16, 60])
+            .extend([1500u16, 1300]);
+        buf = math_value_records(buf, (1..=51).map(|i| i * 10));
+        buf = buf
+            .push(60i16)
+            // MathGlyphInfo
+            .extend([8u16, 0, 0, 0])
+            // MathItalicsCorrectionInfo
+            .extend([8u16, 1])
+            .extend([50i16, 0])
+            // coverage
+            .extend([1u16, 1, 5])
+            // MathVariants
+            .push(20u16) // min connector overlap
+            .extend([12u16, 0]) // coverage offsets
+            .extend([1u16, 0]) // glyph counts
+            .push(18u16) // vert construction offset
+            // vert coverage
+            .extend([1u16, 1, 7])
+            // MathGlyphConstruction
+            .extend([8u16, 1])
+            .extend([8u16, 500])
+            // GlyphAssembly
+            .extend([0i16, 0])
+            .push(2u16)
+            .extend([9u16, 0, 100, 300, 0])
+            .extend([10u16, 100, 100, 200, 1]);
+
+        let math = Math::read(buf.font_data()).unwrap();
+        assert_eq!(math.version(), MajorMinor::VERSION_1_0);
+
+        let constants = math.math_constants().unwrap();
+        assert_eq!(constants.script_percent_scale_down(), 80);
+        assert_eq!(constants.script_script_percent_scale_down(), 60);
+        assert_eq!(constants.display_operator_min_height(), UfWord::new(1300));
+        assert_eq!(constants.math_leading().value(), FWord::new(10));
+        assert_eq!(constants.axis_height().value(), FWord::new(20));
+        assert!(constants
+            .axis_height()
+            .device(constants.offset_data())
+            .is_none());
+        assert_eq!(
+            constants.radical_kern_after_degree().value(),
+            FWord::new(510)
+        );
+        assert_eq!(constants.radical_degree_bottom_raise_percent(), 60);
+
+        let glyph_info = math.math_glyph_info().unwrap();
+        assert!(glyph_info.math_top_accent_attachment().is_none());
+        let italics = glyph_info.math_italics_correction_info().unwrap().unwrap();
+        let coverage = italics.italics_correction_coverage().unwrap();
+        let index = coverage.get(GlyphId16::new(5)).unwrap();
+        assert_eq!(
+            italics.italics_correction()[index as usize].value(),
+            FWord::new(50)
+        );
+
+        let variants = math.math_variants().unwrap();
+        assert_eq!(variants.min_connector_overlap(), UfWord::new(20));
+        assert_eq!(
+            variants
+                .vert_glyph_coverage()
+                .unwrap()
+                .unwrap()
+                .get(GlyphId16::new(7)),
+            Some(0)
+        );
+        let construction = variants.vert_glyph_constructions().get(0).unwrap();
+        let variant = construction.math_glyph_variant_records()[0];
+        assert_eq!(variant.variant_glyph(), GlyphId16::new(8));
+        assert_eq!(variant.advance_measurement(), UfWord::new(500));
+        let assembly = construction.glyph_assembly().unwrap().unwrap();
+        let parts = assembly.part_records();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].glyph_id(), GlyphId16::new(9));
+        assert_eq!(parts[0].full_advance(), UfWord::new(300));
+        assert!(!parts[0]
+            .part_flags()
+            .contains(GlyphPartFlags::EXTENDER_FLAG));
+        assert!(parts[1]
+            .part_flags()
+            .contains(GlyphPartFlags::EXTENDER_FLAG));
+    }
+}
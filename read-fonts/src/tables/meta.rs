@@ -0,0 +1,130 @@
+//! The [meta (Metadata)](https://docs.microsoft.com/en-us/typography/opentype/spec/meta) table.
+
+include!("../../generated/generated_meta.rs");
+
+/// Data tag for the design languages metadata.
+pub const DLNG: Tag = Tag::new(b"dlng");
+/// Data tag for the supported languages metadata.
+pub const SLNG: Tag = Tag::new(b"slng");
+
+impl<'a> Meta<'a> {
+    /// Returns the raw data for the first data map with the given tag.
+    pub fn data(&self, tag: Tag) -> Option<&'a [u8]> {
+        self.data_maps()
+            .iter()
+            .find(|record| record.tag() == tag)
+            .and_then(|record| record.data(self.offset_data()).ok())
+    }
+
+    /// Returns the languages and/or scripts that the font was primarily
+    /// designed to support, from the `dlng` data map.
+    pub fn design_languages(&self) -> Option<ScriptLangTags<'a>> {
+        self.script_lang_tags(DLNG)
+    }
+
+    /// Returns the languages and/or scripts that the font declares to be
+    /// capable of supporting, from the `slng` data map.
+    pub fn supported_languages(&self) -> Option<ScriptLangTags<'a>> {
+        self.script_lang_tags(SLNG)
+    }
+
+    fn script_lang_tags(&self, tag: Tag) -> Option<ScriptLangTags<'a>> {
+        let data = self.data(tag)?;
+        core::str::from_utf8(data).ok().map(ScriptLangTags)
+    }
+}
+
+impl DataMapRecord {
+    /// Returns the data for this record.
+    ///
+    /// The `data` argument should be the data of the parent table.
+    pub fn data<'a>(&self, data: FontData<'a>) -> Result<&'a [u8], ReadError> {
+        let start = self.data_offset() as usize;
+        let end = start
+            .checked_add(self.data_length() as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        data.as_bytes()
+            .get(start..end)
+            .ok_or(ReadError::OutOfBounds)
+    }
+}
+
+/// An iterator over a comma separated list of
+/// [ScriptLangTag](https://learn.microsoft.com/en-us/typography/opentype/spec/meta#scriptlangtag-values)
+/// values.
+///
+/// Each item is an IETF BCP 47 language tag or ISO 15924 script code, with
+/// surrounding whitespace removed.
+#[derive(Clone, Debug)]
+pub struct ScriptLangTags<'a>(&'a str);
+
+impl<'a> ScriptLangTags<'a> {
+    /// Returns the underlying string.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> Iterator for ScriptLangTags<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.0.is_empty() {
+                return None;
+            }
+            let (item, rest) = self.0.split_once(',').unwrap_or((self.0, ""));
+            self.0 = rest;
+            let item = item.trim();
+            if !item.is_empty() {
+                return Some(item);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn make_meta() -> BeBuffer {
+        let dlng = b"Latn, Cyrl";
+        let slng = b"Latn,Cyrl,  Grek ,,sr-Latn";
+        let data_start = 16 + 3 * 12;
+        BeBuffer::new()
+            .push(1u32) // version
+            .push(0u32) // flags
+            .push(0u32) // reserved
+            .push(3u32) // data maps count
+            .push(DLNG)
+            .extend([data_start as u32, dlng.len() as u32])
+            .push(SLNG)
+            .extend([(data_start + dlng.len()) as u32, slng.len() as u32])
+            .push(Tag::new(b"appl"))
+            .extend([0u32, 200])
+            .extend(*dlng)
+            .extend(*slng)
+    }
+
+    #[test]
+    fn script_lang_tags() {
+        let buf = make_meta();
+        let meta = Meta::read(buf.font_data()).unwrap();
+        assert_eq!(meta.data_maps_count(), 3);
+        let design = meta.design_languages().unwrap();
+        assert_eq!(design.as_str(), "Latn, Cyrl");
+        assert_eq!(design.collect::<Vec<_>>(), ["Latn", "Cyrl"]);
+        let supported = meta.supported_languages().unwrap().collect::<Vec<_>>();
+        assert_eq!(supported, ["Latn", "Cyrl", "Grek", "sr-Latn"]);
+    }
+
+    #[test]
+    fn out_of_bounds_data() {
+        let buf = make_meta();
+        let meta = Meta::read(buf.font_data()).unwrap();
+        assert!(meta.data(Tag::new(b"appl")).is_none());
+        assert!(meta.data(Tag::new(b"zzzz")).is_none());
+        assert!(meta.data_maps()[2].data(meta.offset_data()).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+//! The [PCLT (PCL 5)](https://docs.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+
+include!("../../generated/generated_pclt.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn read_pclt() {
+        let buf = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(0x1234_5678u32) // font number
+            .push(512u16) // pitch
+            .push(1100u16) // x-height
+            .push(0x0001u16) // style
+            .push(0x1004u16) // type family
+            .push(1400u16) // cap height
+            .push(277u16) // symbol set
+            .extend(*b"Fake Sans       ")
+            .extend([0xFFu8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE])
+            .extend(*b"FKSR00")
+            .push(-1i8) // stroke weight
+            .push(2i8) // width type
+            .push(64u8) // serif style
+            .push(0u8); // reserved
+        let pclt = Pclt::read(buf.font_data()).unwrap();
+        assert_eq!(pclt.version(), MajorMinor::VERSION_1_0);
+        assert_eq!(pclt.font_number(), 0x1234_5678);
+        assert_eq!(pclt.pitch(), 512);
+        assert_eq!(pclt.x_height(), 1100);
+        assert_eq!(pclt.cap_height(), 1400);
+        assert_eq!(pclt.symbol_set(), 277);
+        assert_eq!(pclt.typeface(), b"Fake Sans       ");
+        assert_eq!(pclt.character_complement()[7], 0xFE);
+        assert_eq!(pclt.file_name(), b"FKSR00");
+        assert_eq!(pclt.stroke_weight(), -1);
+        assert_eq!(pclt.width_type(), 2);
+        assert_eq!(pclt.serif_style(), 64);
+    }
+}
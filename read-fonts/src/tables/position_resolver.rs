@@ -0,0 +1,311 @@
+//! Computing adjusted positioning values from GPOS value records and anchors.
+
+use types::{F2Dot14, Point};
+
+use super::{AnchorTable, DeviceOrVariationIndex, ValueRecord};
+use crate::{
+    tables::{base::Base, gdef::Gdef, variations::ItemVariationStore},
+    FontData, ReadError,
+};
+
+/// Adjustments from a [`ValueRecord`], with device and variation deltas
+/// applied.
+///
+/// All values are in font units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResolvedValue {
+    /// Horizontal adjustment for placement.
+    pub x_placement: i32,
+    /// Vertical adjustment for placement.
+    pub y_placement: i32,
+    /// Horizontal adjustment for advance.
+    pub x_advance: i32,
+    /// Vertical adjustment for advance.
+    pub y_advance: i32,
+}
+
+/// Resolves positioning values for a particular size and location in
+/// variation space.
+///
+/// GPOS value records and anchors may refer to [`Device`](super::Device)
+/// tables, which provide adjustments in pixels at specific sizes, or to
+/// [`VariationIndex`](crate::tables::layout::VariationIndex) tables, which
+/// select deltas from the item variation store in the `GDEF` table. This type
+/// applies both kinds of adjustment, producing final values in font units.
+#[derive(Clone)]
+pub struct PositionResolver<'a> {
+    units_per_em: u16,
+    ppem: Option<u16>,
+    coords: &'a [F2Dot14],
+    var_store: Option<ItemVariationStore<'a>>,
+}
+
+impl<'a> PositionResolver<'a> {
+    /// Creates a new resolver.
+    ///
+    /// Device table adjustments are only applied if `ppem` is provided, and
+    /// are converted from pixels to font units using `units_per_em`. Variation
+    /// deltas are only applied if a variation store is provided and `coords`
+    /// is not empty.
+    pub fn new(
+        units_per_em: u16,
+        ppem: Option<u16>,
+        coords: &'a [F2Dot14],
+        var_store: Option<ItemVariationStore<'a>>,
+    ) -> Self {
+        Self {
+            units_per_em,
+            ppem,
+            coords,
+            var_store,
+        }
+    }
+
+    /// Creates a new resolver using the item variation store from the given
+    /// `GDEF` table.
+    pub fn from_gdef(
+        gdef: &Gdef<'a>,
+        units_per_em: u16,
+        ppem: Option<u16>,
+        coords: &'a [F2Dot14],
+    ) -> Result<Self, ReadError> {
+        let var_store = gdef.item_var_store().transpose()?;
+        Ok(Self::new(units_per_em, ppem, coords, var_store))
+    }
+
+    /// Creates a new resolver using the item variation store from the given
+    /// `BASE` table.
+    pub fn from_base(
+        base: &Base<'a>,
+        units_per_em: u16,
+        ppem: Option<u16>,
+        coords: &'a [F2Dot14],
+    ) -> Result<Self, ReadError> {
+        let var_store = base.item_var_store().transpose()?;
+        Ok(Self::new(units_per_em, ppem, coords, var_store))
+    }
+
+    /// Returns the fully adjusted values of a value record.
+    ///
+    /// The `data` parameter is the offset data of the subtable containing
+    /// the record. Fields that are not present in the record are zero.
+    pub fn resolve_value_record(
+        &self,
+        record: &ValueRecord,
+        data: FontData<'a>,
+    ) -> Result<ResolvedValue, ReadError> {
+        Ok(ResolvedValue {
+            x_placement: record.x_placement().unwrap_or_default() as i32
+                + self.device_delta(record.x_placement_device(data))?,
+            y_placement: record.y_placement().unwrap_or_default() as i32
+                + self.device_delta(record.y_placement_device(data))?,
+            x_advance: record.x_advance().unwrap_or_default() as i32
+                + self.device_delta(record.x_advance_device(data))?,
+            y_advance: record.y_advance().unwrap_or_default() as i32
+                + self.device_delta(record.y_advance_device(data))?,
+        })
+    }
+
+    /// Returns the fully adjusted position of an anchor.
+    ///
+    /// For format 2 anchors, the position of the referenced contour point is
+    /// used if `contour_points` is provided and contains the point. These
+    /// should be the points of the (usually hinted) outline of the glyph in
+    /// font units. Otherwise the design coordinates of the anchor are used.
+    pub fn resolve_anchor(
+        &self,
+        anchor: &AnchorTable<'a>,
+        contour_points: Option<&[Point<i32>]>,
+    ) -> Result<Point<i32>, ReadError> {
+        match anchor {
+            AnchorTable::Format1(anchor) => Ok(Point::new(
+                anchor.x_coordinate() as i32,
+                anchor.y_coordinate() as i32,
+            )),
+            AnchorTable::Format2(anchor) => Ok(contour_points
+                .and_then(|points| points.get(anchor.anchor_point() as usize))
+                .copied()
+                .unwrap_or_else(|| {
+                    Point::new(anchor.x_coordinate() as i32, anchor.y_coordinate() as i32)
+                })),
+            AnchorTable::Format3(anchor) => Ok(Point::new(
+                anchor.x_coordinate() as i32 + self.device_delta(anchor.x_device())?,
+                anchor.y_coordinate() as i32 + self.device_delta(anchor.y_device())?,
+            )),
+        }
+    }
+
+    /// Returns the adjustment, in font units, for an optional device or
+    /// variation index table.
+    /// Returns the adjustment from a device or variation index table, in
+    /// font units.
+    pub fn device_delta(
+        &self,
+        device: Option<Result<DeviceOrVariationIndex<'a>, ReadError>>,
+    ) -> Result<i32, ReadError> {
+        match device.transpose()? {
+            Some(DeviceOrVariationIndex::Device(device)) => match self.ppem {
+                Some(ppem) if ppem != 0 => Ok(self.pixels_to_units(device.delta(ppem), ppem)),
+                _ => Ok(0),
+            },
+            Some(DeviceOrVariationIndex::VariationIndex(index)) => match &self.var_store {
+                Some(var_store) if !self.coords.is_empty() => {
+                    var_store.compute_delta(index.into(), self.coords)
+                }
+                _ => Ok(0),
+            },
+            None => Ok(0),
+        }
+    }
+
+    /// Converts a pixel delta at the given size to font units, rounding half
+    /// away from zero.
+    fn pixels_to_units(&self, pixels: i8, ppem: u16) -> i32 {
+        let scaled = pixels as i32 * self.units_per_em as i32;
+        let ppem = ppem as i32;
+        if scaled < 0 {
+            (scaled - ppem / 2) / ppem
+        } else {
+            (scaled + ppem / 2) / ppem
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tables::{
+            gpos::{AnchorFormat3, SinglePosFormat1},
+            variations::DeltaSetIndex,
+        },
+        test_helpers::BeBuffer,
+        FontRead, FontRef, TableProvider,
+    };
+    use font_test_data::gpos as test_data;
+
+    const UPEM: u16 = 1000;
+
+    #[test]
+    fn value_record_device_deltas() {
+        let table = SinglePosFormat1::read(test_data::VALUEFORMATTABLE.into()).unwrap();
+        let record = table.value_record();
+        let data = table.offset_data();
+        let unscaled = PositionResolver::new(UPEM, None, &[], None);
+        let base = ResolvedValue {
+            x_placement: 80,
+            y_advance: 210,
+            ..Default::default()
+        };
+        assert_eq!(unscaled.resolve_value_record(&record, data).unwrap(), base);
+        // both device tables add one pixel from 11 to 15 ppem
+        let resolver = PositionResolver::new(UPEM, Some(12), &[], None);
+        assert_eq!(
+            resolver.resolve_value_record(&record, data).unwrap(),
+            ResolvedValue {
+                // 1000 / 12 = 83.33
+                x_placement: 80 + 83,
+                y_advance: 210 + 83,
+                ..Default::default()
+            }
+        );
+        // outside of the device range
+        let resolver = PositionResolver::new(UPEM, Some(20), &[], None);
+        assert_eq!(resolver.resolve_value_record(&record, data).unwrap(), base);
+    }
+
+    #[test]
+    fn anchor_formats() {
+        let resolver = PositionResolver::new(UPEM, Some(16), &[], None);
+        let format1 = AnchorTable::read(test_data::ANCHORFORMAT1.into()).unwrap();
+        assert_eq!(
+            resolver.resolve_anchor(&format1, None).unwrap(),
+            Point::new(189, -103)
+        );
+        let format2 = AnchorTable::read(test_data::ANCHORFORMAT2.into()).unwrap();
+        assert_eq!(
+            resolver.resolve_anchor(&format2, None).unwrap(),
+            Point::new(322, 900)
+        );
+        // the anchor refers to point 13
+        let points = (0..20).map(|i| Point::new(i, -i)).collect::<Vec<_>>();
+        assert_eq!(
+            resolver.resolve_anchor(&format2, Some(&points)).unwrap(),
+            Point::new(13, -13)
+        );
+        // missing point falls back to the design coordinates
+        assert_eq!(
+            resolver
+                .resolve_anchor(&format2, Some(&points[..5]))
+                .unwrap(),
+            Point::new(322, 900)
+        );
+        // device tables add two pixels at 16 ppem
+        let format3 = AnchorTable::read(test_data::ANCHORFORMAT3.into()).unwrap();
+        assert_eq!(
+            resolver.resolve_anchor(&format3, None).unwrap(),
+            Point::new(279 + 125, 1301 + 125)
+        );
+    }
+
+    #[test]
+    fn negative_device_delta() {
+        let resolver = PositionResolver::new(UPEM, Some(12), &[], None);
+        assert_eq!(resolver.pixels_to_units(-1, 12), -83);
+        assert_eq!(resolver.pixels_to_units(-1, 8), -125);
+        assert_eq!(resolver.pixels_to_units(1, 3), 333);
+        assert_eq!(resolver.pixels_to_units(2, 3), 667);
+        assert_eq!(resolver.pixels_to_units(-2, 3), -667);
+    }
+
+    #[test]
+    fn variation_index_deltas() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let var_store = font.hvar().unwrap().item_variation_store().unwrap();
+        let coords = [F2Dot14::from_f32(1.0)];
+        // find a delta set with a non-zero delta at our location
+        let index = var_store
+            .item_variation_data()
+            .iter()
+            .enumerate()
+            .flat_map(|(outer, data)| {
+                let item_count = data.unwrap().unwrap().item_count();
+                (0..item_count).map(move |inner| DeltaSetIndex {
+                    outer: outer as u16,
+                    inner,
+                })
+            })
+            .find(|index| var_store.compute_delta(*index, &coords).unwrap() != 0)
+            .unwrap();
+        let expected_delta = var_store.compute_delta(index, &coords).unwrap();
+        // an anchor with a variation index table for x only
+        let anchor_data = BeBuffer::new()
+            .push(3u16) // format
+            .push(100i16) // x_coordinate
+            .push(200i16) // y_coordinate
+            .push(10u16) // x_device_offset
+            .push(0u16) // y_device_offset
+            .push(index.outer) // delta_set_outer_index
+            .push(index.inner) // delta_set_inner_index
+            .push(0x8000u16); // delta_format
+        let anchor = AnchorFormat3::read(anchor_data.font_data()).unwrap();
+        let anchor = AnchorTable::Format3(anchor);
+        let resolver = PositionResolver::new(UPEM, Some(12), &coords, Some(var_store.clone()));
+        assert_eq!(
+            resolver.resolve_anchor(&anchor, None).unwrap(),
+            Point::new(100 + expected_delta, 200)
+        );
+        // no deltas at the default location
+        let resolver = PositionResolver::new(UPEM, Some(12), &[], Some(var_store));
+        assert_eq!(
+            resolver.resolve_anchor(&anchor, None).unwrap(),
+            Point::new(100, 200)
+        );
+        // nor without a variation store
+        let resolver = PositionResolver::new(UPEM, Some(12), &coords, None);
+        assert_eq!(
+            resolver.resolve_anchor(&anchor, None).unwrap(),
+            Point::new(100, 200)
+        );
+    }
+}
@@ -3,6 +3,8 @@
 use std::fmt;
 
 mod blend;
+mod charset;
+mod encoding;
 mod fd_select;
 mod index;
 mod stack;
@@ -10,10 +12,14 @@ mod string;
 
 pub mod charstring;
 pub mod dict;
+#[cfg(feature = "std")]
+pub mod type1;
 
 include!("../../generated/generated_postscript.rs");
 
 pub use blend::BlendState;
+pub use charset::{Charset, CharsetKind};
+pub use encoding::Encoding;
 pub use index::Index;
 pub use stack::{Number, Stack};
 pub use string::{Latin1String, StringId, STANDARD_STRINGS};
@@ -36,6 +42,12 @@ pub enum Error {
     MissingBlendState,
     MissingPrivateDict,
     MissingCharstrings,
+    InvalidSeacCode(i32),
+    NestedSeac,
+    InvalidPfbSegment(u8),
+    MissingEexecData,
+    MissingType1CharStrings,
+    InvalidFlex,
     Read(ReadError),
 }
 
@@ -106,6 +118,27 @@ impl fmt::Display for Error {
             Self::MissingCharstrings => {
                 write!(f, "CFF table does not contain a charstrings index")
             }
+            Self::InvalidSeacCode(code) => {
+                write!(
+                    f,
+                    "seac code {code} does not map to a glyph in the standard encoding"
+                )
+            }
+            Self::NestedSeac => {
+                write!(f, "seac component glyphs may not use seac")
+            }
+            Self::InvalidPfbSegment(segment_type) => {
+                write!(f, "invalid PFB segment type {segment_type}")
+            }
+            Self::MissingEexecData => {
+                write!(f, "Type 1 font does not contain eexec encrypted data")
+            }
+            Self::MissingType1CharStrings => {
+                write!(f, "Type 1 font does not contain a CharStrings dictionary")
+            }
+            Self::InvalidFlex => {
+                write!(f, "invalid flex sequence in Type 1 charstring")
+            }
             Self::Read(err) => write!(f, "{err}"),
         }
     }
@@ -0,0 +1,245 @@
+//! Parsing for CFF charsets.
+
+use types::GlyphId;
+
+use super::{CustomCharset, StringId};
+use crate::{FontData, FontRead, ReadError};
+
+/// Character set for mapping from glyph to string identifiers.
+///
+/// For CID-keyed fonts, the charset maps glyph identifiers to CIDs
+/// instead, but these are represented here as [`StringId`] values.
+///
+/// See <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=21>
+#[derive(Clone)]
+pub struct Charset<'a> {
+    kind: CharsetKind<'a>,
+    num_glyphs: u32,
+}
+
+impl<'a> Charset<'a> {
+    /// Creates a new charset from the given offset and number of glyphs.
+    ///
+    /// The `offset_data` parameter should be the full data of the CFF table
+    /// and `charset_offset` the value of the `charset` operator in the Top
+    /// DICT. Offsets of 0, 1 and 2 select the predefined ISOAdobe, Expert
+    /// and ExpertSubset charsets, respectively.
+    pub fn new(
+        offset_data: FontData<'a>,
+        charset_offset: usize,
+        num_glyphs: u32,
+    ) -> Result<Self, ReadError> {
+        let kind = match charset_offset {
+            0 => CharsetKind::IsoAdobe,
+            1 => CharsetKind::Expert,
+            2 => CharsetKind::ExpertSubset,
+            _ => {
+                let data = offset_data
+                    .split_off(charset_offset)
+                    .ok_or(ReadError::OutOfBounds)?;
+                CharsetKind::Custom(CustomCharset::read(data)?)
+            }
+        };
+        Ok(Self { kind, num_glyphs })
+    }
+
+    /// Returns the underlying kind of the charset.
+    pub fn kind(&self) -> &CharsetKind<'a> {
+        &self.kind
+    }
+
+    /// Returns the number of glyphs covered by the charset.
+    pub fn num_glyphs(&self) -> u32 {
+        self.num_glyphs
+    }
+
+    /// Returns the string identifier for the given glyph identifier.
+    pub fn string_id(&self, glyph_id: GlyphId) -> Option<StringId> {
+        let gid = glyph_id.to_u32();
+        if gid >= self.num_glyphs {
+            return None;
+        }
+        // .notdef is always mapped to SID 0
+        if gid == 0 {
+            return Some(StringId::new(0));
+        }
+        match &self.kind {
+            CharsetKind::IsoAdobe => {
+                // The ISOAdobe charset is an identity mapping of gid->sid up
+                // to 228 entries
+                (gid <= 228).then_some(StringId::new(gid as u16))
+            }
+            CharsetKind::Expert => EXPERT_CHARSET.get(gid as usize).copied().map(StringId::new),
+            CharsetKind::ExpertSubset => EXPERT_SUBSET_CHARSET
+                .get(gid as usize)
+                .copied()
+                .map(StringId::new),
+            CharsetKind::Custom(custom) => custom.string_id(gid),
+        }
+    }
+
+    /// Returns the glyph identifier for the given string identifier.
+    ///
+    /// This performs a linear search over the charset.
+    pub fn glyph_id(&self, string_id: StringId) -> Option<GlyphId> {
+        self.iter()
+            .find(|(_, sid)| *sid == string_id)
+            .map(|(gid, _)| gid)
+    }
+
+    /// Returns an iterator over all of the glyph and string identifier
+    /// pairs in the charset.
+    pub fn iter(&self) -> impl Iterator<Item = (GlyphId, StringId)> + '_ {
+        (0..self.num_glyphs).map_while(|gid| {
+            let gid = GlyphId::new(gid);
+            Some((gid, self.string_id(gid)?))
+        })
+    }
+}
+
+/// Predefined and custom character sets.
+#[derive(Clone)]
+pub enum CharsetKind<'a> {
+    IsoAdobe,
+    Expert,
+    ExpertSubset,
+    Custom(CustomCharset<'a>),
+}
+
+impl CustomCharset<'_> {
+    /// Returns the string identifier for the given glyph index, which must
+    /// be non-zero.
+    fn string_id(&self, gid: u32) -> Option<StringId> {
+        // The first glyph (.notdef) is omitted from custom charsets
+        let ix = gid.checked_sub(1)?;
+        match self {
+            Self::Format0(fmt) => fmt
+                .glyph()
+                .get(ix as usize)
+                .map(|sid| StringId::new(sid.get())),
+            Self::Format1(fmt) => find_in_ranges(
+                ix,
+                fmt.ranges()
+                    .iter()
+                    .map(|range| (range.first(), range.n_left() as u16)),
+            ),
+            Self::Format2(fmt) => find_in_ranges(
+                ix,
+                fmt.ranges()
+                    .iter()
+                    .map(|range| (range.first(), range.n_left())),
+            ),
+        }
+    }
+}
+
+/// Finds the string identifier at index `ix` in a sequence of
+/// (first, n_left) ranges.
+fn find_in_ranges(mut ix: u32, ranges: impl Iterator<Item = (u16, u16)>) -> Option<StringId> {
+    for (first, n_left) in ranges {
+        let count = n_left as u32 + 1;
+        if ix < count {
+            return Some(StringId::new((first as u32 + ix).try_into().ok()?));
+        }
+        ix -= count;
+    }
+    None
+}
+
+/// See "Expert" charset at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=47>
+#[rustfmt::skip]
+const EXPERT_CHARSET: &[u16] = &[
+    0, 1, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243,
+    244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260,
+    261, 262, 263, 264, 265, 266, 109, 110, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277,
+    278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295, 296,
+    297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313, 314, 315,
+    316, 317, 318, 158, 155, 163, 319, 320, 321, 322, 323, 324, 325, 326, 150, 164, 169, 327, 328,
+    329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347,
+    348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366,
+    367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378,
+];
+
+/// See "Expert Subset" charset at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=49>
+#[rustfmt::skip]
+const EXPERT_SUBSET_CHARSET: &[u16] = &[
+    0, 1, 231, 232, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243, 244, 245, 246, 247,
+    248, 27, 28, 249, 250, 251, 253, 254, 255, 256, 257, 258, 259, 260, 261, 262, 263, 264, 265,
+    266, 109, 110, 267, 268, 269, 270, 272, 300, 301, 302, 305, 314, 315, 158, 155, 163, 320, 321,
+    322, 323, 324, 325, 326, 150, 164, 169, 327, 328, 329, 330, 331, 332, 333, 334, 335, 336, 337,
+    338, 339, 340, 341, 342, 343, 344, 345, 346,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn iso_adobe_charset() {
+        let charset = Charset::new(FontData::new(&[]), 0, 300).unwrap();
+        assert!(matches!(charset.kind(), CharsetKind::IsoAdobe));
+        for gid in 0..=228u32 {
+            assert_eq!(
+                charset.string_id(GlyphId::new(gid)),
+                Some(StringId::new(gid as u16))
+            );
+        }
+        assert_eq!(charset.string_id(GlyphId::new(229)), None);
+        assert_eq!(charset.iter().count(), 229);
+    }
+
+    #[test]
+    fn expert_charsets() {
+        let expert = Charset::new(FontData::new(&[]), 1, 1000).unwrap();
+        assert_eq!(expert.iter().count(), 166);
+        assert_eq!(expert.string_id(GlyphId::new(2)), Some(StringId::new(229)));
+        assert_eq!(
+            expert.string_id(GlyphId::new(165)),
+            Some(StringId::new(378))
+        );
+        let subset = Charset::new(FontData::new(&[]), 2, 1000).unwrap();
+        assert_eq!(subset.iter().count(), 87);
+        assert_eq!(subset.string_id(GlyphId::new(2)), Some(StringId::new(231)));
+        assert_eq!(subset.string_id(GlyphId::new(86)), Some(StringId::new(346)));
+        assert_eq!(subset.glyph_id(StringId::new(346)), Some(GlyphId::new(86)));
+        // limited by glyph count
+        let subset = Charset::new(FontData::new(&[]), 2, 10).unwrap();
+        assert_eq!(subset.iter().count(), 10);
+        assert_eq!(subset.string_id(GlyphId::new(10)), None);
+    }
+
+    #[test]
+    fn custom_charsets() {
+        // glyphs 1..=9 map to SIDs 400..=402, 500..=505
+        let expected = [0u16, 400, 401, 402, 500, 501, 502, 503, 504, 505];
+        let format0 = BeBuffer::new()
+            .push(0u8)
+            .extend(expected[1..].iter().copied());
+        let format1 = BeBuffer::new()
+            .push(1u8)
+            .extend([400u16])
+            .push(2u8)
+            .extend([500u16])
+            .push(5u8);
+        let format2 = BeBuffer::new().push(2u8).extend([400u16, 2, 500, 5]);
+        for (data, format) in [(format0, 0), (format1, 1), (format2, 2)] {
+            // add some padding to test non-zero offsets and trailing data
+            let mut bytes = vec![0u8; 4];
+            bytes.extend_from_slice(&data);
+            bytes.extend([0xFF; 3]);
+            let charset = Charset::new(FontData::new(&bytes), 4, 10).unwrap();
+            let CharsetKind::Custom(custom) = charset.kind() else {
+                panic!("expected custom charset");
+            };
+            assert_eq!(custom.format(), format);
+            let sids = charset
+                .iter()
+                .map(|(_, sid)| sid.to_u16())
+                .collect::<Vec<_>>();
+            assert_eq!(sids, expected);
+            assert_eq!(charset.glyph_id(StringId::new(502)), Some(GlyphId::new(6)));
+            assert_eq!(charset.glyph_id(StringId::new(403)), None);
+        }
+    }
+}
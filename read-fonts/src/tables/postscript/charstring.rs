@@ -1,6 +1,6 @@
 //! Parsing for PostScript charstrings.
 
-use super::{BlendState, Error, Index, Stack};
+use super::{BlendState, Charset, Encoding, Error, Index, Stack};
 use crate::{
     types::{Fixed, Pen, Point},
     Cursor,
@@ -12,6 +12,12 @@ use crate::{
 /// <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=33>
 pub const NESTING_DEPTH_LIMIT: u32 = 10;
 
+/// Number of elements in the transient array used by the `put` and `get`
+/// operators.
+///
+/// This matches FreeType: <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/psfont.h#L59>
+const TRANSIENT_ARRAY_SIZE: usize = 32;
+
 /// Trait for processing commands resulting from charstring evaluation.
 ///
 /// During processing, the path construction operators (see "4.1 Path
@@ -97,17 +103,80 @@ pub fn evaluate(
     blend_state: Option<BlendState>,
     sink: &mut impl CommandSink,
 ) -> Result<(), Error> {
-    let mut evaluator = Evaluator::new(global_subrs, subrs, blend_state, sink);
+    evaluate_with_seac(
+        charstring_data,
+        global_subrs,
+        subrs,
+        blend_state,
+        None,
+        sink,
+    )
+}
+
+/// Evaluates the given charstring and emits the resulting commands to the
+/// specified sink, resolving accented glyphs that use the deprecated `seac`
+/// form of the endchar operator.
+///
+/// This is the same as [`evaluate`] but, when evaluating a CFF charstring,
+/// `seac_components` should be provided to draw the base and accent
+/// components of those glyphs. Otherwise, the components will be missing.
+pub fn evaluate_with_seac(
+    charstring_data: &[u8],
+    global_subrs: Index,
+    subrs: Option<Index>,
+    blend_state: Option<BlendState>,
+    seac_components: Option<SeacComponents>,
+    sink: &mut impl CommandSink,
+) -> Result<(), Error> {
+    let mut evaluator = Evaluator::new(global_subrs, subrs, blend_state, seac_components, sink);
     evaluator.evaluate(charstring_data, 0)?;
     Ok(())
 }
 
+/// Source of component glyphs for accented characters defined by the
+/// `seac` form of the endchar operator.
+///
+/// The base and accent components are identified by character codes in
+/// the Adobe standard encoding, which are mapped to glyphs through the
+/// charset of the font.
+///
+/// See "Appendix C Compatibility and Deprecated Operators" at
+/// <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=35>
+#[derive(Clone)]
+pub struct SeacComponents<'a> {
+    charstrings: Index<'a>,
+    charset: Charset<'a>,
+}
+
+impl<'a> SeacComponents<'a> {
+    /// Creates a new set of seac components from the charstrings index
+    /// and charset of a CFF font.
+    pub fn new(charstrings: Index<'a>, charset: Charset<'a>) -> Self {
+        Self {
+            charstrings,
+            charset,
+        }
+    }
+
+    /// Returns the charstring for the glyph with the given standard
+    /// encoding character code.
+    fn charstring(&self, code: i32) -> Result<&'a [u8], Error> {
+        let glyph_id = u8::try_from(code)
+            .ok()
+            .and_then(|code| Encoding::Standard.map(code, &self.charset))
+            .ok_or(Error::InvalidSeacCode(code))?;
+        self.charstrings.get(glyph_id.to_u32() as usize)
+    }
+}
+
 /// Transient state for evaluating a charstring and handling recursive
 /// subroutine calls.
 struct Evaluator<'a, S> {
     global_subrs: Index<'a>,
     subrs: Option<Index<'a>>,
     blend_state: Option<BlendState<'a>>,
+    seac_components: Option<SeacComponents<'a>>,
+    is_seac_component: bool,
     sink: &'a mut S,
     is_open: bool,
     have_read_width: bool,
@@ -116,6 +185,8 @@ struct Evaluator<'a, S> {
     y: Fixed,
     stack: Stack,
     stack_ix: usize,
+    transient_array: [Fixed; TRANSIENT_ARRAY_SIZE],
+    random_state: u32,
 }
 
 impl<'a, S> Evaluator<'a, S>
@@ -126,12 +197,15 @@ where
         global_subrs: Index<'a>,
         subrs: Option<Index<'a>>,
         blend_state: Option<BlendState<'a>>,
+        seac_components: Option<SeacComponents<'a>>,
         sink: &'a mut S,
     ) -> Self {
         Self {
             global_subrs,
             subrs,
             blend_state,
+            seac_components,
+            is_seac_component: false,
             sink,
             is_open: false,
             have_read_width: false,
@@ -140,6 +214,8 @@ where
             x: Fixed::ZERO,
             y: Fixed::ZERO,
             stack_ix: 0,
+            transient_array: [Fixed::ZERO; TRANSIENT_ARRAY_SIZE],
+            random_state: RANDOM_SEED,
         }
     }
 
@@ -232,18 +308,27 @@ where
                 return Ok(false);
             }
             // End the current charstring
-            // TODO: handle implied 'seac' operator
             // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=21>
             // FT: <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/psintrp.c#L2463>
             EndChar => {
-                if !self.stack.is_empty() && !self.have_read_width {
+                // A width may be present with either zero or four
+                // additional arguments
+                let len = self.stack.len();
+                if (len == 1 || len == 5) && !self.have_read_width {
                     self.have_read_width = true;
-                    self.stack.clear();
                 }
                 if self.is_open {
                     self.is_open = false;
                     self.sink.close();
                 }
+                // Exactly four remaining arguments (plus an optional
+                // width) imply the deprecated 'seac' operator. Any other
+                // leftover arguments are ignored.
+                // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=35>
+                if len == 4 || len == 5 {
+                    self.evaluate_seac()?;
+                }
+                self.reset_stack();
                 return Ok(false);
             }
             // Emits a sequence of stem hints
@@ -480,10 +565,165 @@ where
                 let subr_charstring_data = subrs_index.get(biased_index)?;
                 self.evaluate(subr_charstring_data, nesting_depth + 1)?;
             }
+            // The following are the arithmetic, logical, storage and
+            // conditional operators which were removed from the
+            // specification but are still supported by FreeType. None
+            // of these clear the stack.
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=25>
+            // FT: <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/psintrp.c#L1251>
+            And | Or => {
+                let b = self.stack.pop_fixed()?;
+                let a = self.stack.pop_fixed()?;
+                let (a, b) = (a != Fixed::ZERO, b != Fixed::ZERO);
+                let result = if operator == And { a && b } else { a || b };
+                self.stack.push(result as i32)?;
+            }
+            Not => {
+                let a = self.stack.pop_fixed()?;
+                self.stack.push((a == Fixed::ZERO) as i32)?;
+            }
+            Eq => {
+                let b = self.stack.pop_fixed()?;
+                let a = self.stack.pop_fixed()?;
+                self.stack.push((a == b) as i32)?;
+            }
+            Abs | Neg => {
+                let a = self.stack.pop_fixed()?.to_bits();
+                // Saturate when the result is not representable
+                let result = if operator == Abs {
+                    a.checked_abs()
+                } else {
+                    a.checked_neg()
+                };
+                self.stack
+                    .push(Fixed::from_bits(result.unwrap_or(i32::MAX)))?;
+            }
+            Add | Sub | Mul | Div => {
+                let b = self.stack.pop_fixed()?;
+                let a = self.stack.pop_fixed()?;
+                let result = match operator {
+                    Add => a.wrapping_add(b),
+                    Sub => a.wrapping_sub(b),
+                    Mul => a * b,
+                    _ => saturate_min(a) / saturate_min(b),
+                };
+                self.stack.push(result)?;
+            }
+            Sqrt => {
+                let a = self.stack.pop_fixed()?;
+                self.stack.push(sqrt(a))?;
+            }
+            Drop => {
+                self.stack.pop_fixed()?;
+            }
+            Dup => {
+                let a = self.stack.pop_fixed()?;
+                self.stack.push(a)?;
+                self.stack.push(a)?;
+            }
+            Exch => {
+                let b = self.stack.pop_fixed()?;
+                let a = self.stack.pop_fixed()?;
+                self.stack.push(b)?;
+                self.stack.push(a)?;
+            }
+            // Duplicates the element at the given depth below the top of
+            // the stack. Out of range indices are clamped.
+            Index => {
+                let ix = self.stack.pop_i32()?;
+                let len = self.stack.len();
+                if len > 0 {
+                    let stack_ix = if ix < 0 {
+                        len - 1
+                    } else {
+                        len - 1 - (ix as usize).min(len - 1)
+                    };
+                    let value = self.stack.get_fixed(stack_ix)?;
+                    self.stack.push(value)?;
+                }
+            }
+            Roll => {
+                let shift = self.stack.pop_i32()?;
+                let count = self.stack.pop_i32()?;
+                self.stack.roll(count, shift)?;
+            }
+            // Stores a value in the transient array. Out of range
+            // indices are ignored.
+            Put => {
+                let ix = self.stack.pop_i32()?;
+                let value = self.stack.pop_fixed()?;
+                if let Some(entry) = self.transient_array.get_mut(ix as usize) {
+                    *entry = value;
+                }
+            }
+            // Pushes a value from the transient array. Out of range
+            // indices push nothing.
+            Get => {
+                let ix = self.stack.pop_i32()?;
+                if let Some(value) = self.transient_array.get(ix as usize).copied() {
+                    self.stack.push(value)?;
+                }
+            }
+            IfElse => {
+                let v2 = self.stack.pop_fixed()?;
+                let v1 = self.stack.pop_fixed()?;
+                let s2 = self.stack.pop_fixed()?;
+                let s1 = self.stack.pop_fixed()?;
+                self.stack.push(if v1 <= v2 { s1 } else { s2 })?;
+            }
+            // Pushes a pseudo-random number in the range (0, 1]
+            Random => {
+                let value = Fixed::from_bits((self.random_state & 0xFFFF) as i32 + 1);
+                self.random_state = next_random(self.random_state);
+                self.stack.push(value)?;
+            }
         }
         Ok(true)
     }
 
+    /// Evaluates the base and accent components of a glyph defined by
+    /// the deprecated `seac` form of the endchar operator.
+    ///
+    /// The accent is drawn first, offset by the given `adx` and `ady`
+    /// values, followed by the base glyph. Each component is evaluated
+    /// with fresh state.
+    ///
+    /// FT: <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/psintrp.c#L2520>
+    fn evaluate_seac(&mut self) -> Result<(), Error> {
+        // Seac is not available in CFF2 charstrings
+        let Some(components) = self.seac_components.clone() else {
+            return Ok(());
+        };
+        if self.is_seac_component {
+            return Err(Error::NestedSeac);
+        }
+        let accent_code = self.stack.pop_i32()?;
+        let base_code = self.stack.pop_i32()?;
+        let ady = self.stack.pop_fixed()?;
+        let adx = self.stack.pop_fixed()?;
+        for (code, x, y) in [
+            (accent_code, adx, ady),
+            (base_code, Fixed::ZERO, Fixed::ZERO),
+        ] {
+            let charstring_data = components.charstring(code)?;
+            let mut evaluator = Evaluator::new(
+                self.global_subrs.clone(),
+                self.subrs.clone(),
+                None,
+                Some(components.clone()),
+                &mut *self.sink,
+            );
+            evaluator.is_seac_component = true;
+            evaluator.x = x;
+            evaluator.y = y;
+            evaluator.evaluate(charstring_data, 0)?;
+            if evaluator.is_open {
+                evaluator.sink.close();
+            }
+        }
+        Ok(())
+    }
+
     fn coords_remaining(&self) -> usize {
         self.stack.len() - self.stack_ix
     }
@@ -574,6 +814,54 @@ where
     }
 }
 
+/// Initial state for the pseudo-random number generator used by the
+/// `random` operator.
+///
+/// FreeType seeds this from memory addresses unless configured otherwise
+/// but we prefer deterministic output. This is the fallback value that
+/// FreeType uses when the computed seed is zero.
+const RANDOM_SEED: u32 = 0x7384;
+
+/// 32-bit xorshift generator, matching FreeType.
+///
+/// FT: <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/psobjs.c#L2552>
+fn next_random(mut state: u32) -> u32 {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    state
+}
+
+/// Computes the square root of a fixed point value using the Babylonian
+/// method. Returns zero for non-positive values.
+///
+/// FT: <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/psintrp.c#L2265>
+fn sqrt(value: Fixed) -> Fixed {
+    if value <= Fixed::ZERO {
+        return Fixed::ZERO;
+    }
+    // Use a starting value that doesn't cause the addition to overflow
+    let mut root = if value < Fixed::from_bits(10) {
+        value
+    } else {
+        Fixed::from_bits(value.to_bits() >> 1)
+    };
+    loop {
+        let new_root = Fixed::from_bits(
+            ((root.to_bits() as i64 + (value / root).to_bits() as i64 + 1) >> 1) as i32,
+        );
+        if new_root == root {
+            return root;
+        }
+        root = new_root;
+    }
+}
+
+/// Avoids overflow when negating `Fixed::MIN` during division.
+fn saturate_min(value: Fixed) -> Fixed {
+    value.max(Fixed::from_bits(-i32::MAX))
+}
+
 /// Specifies how point coordinates for a curve are computed.
 #[derive(Copy, Clone)]
 enum PointMode {
@@ -589,8 +877,9 @@ enum PointMode {
 /// PostScript charstring operator.
 ///
 /// See <https://learn.microsoft.com/en-us/typography/opentype/spec/cff2charstr#appendix-a-cff2-charstring-command-codes>
-// TODO: This is currently missing legacy math and logical operators.
-// fonttools doesn't even implement these: <https://github.com/fonttools/fonttools/blob/65598197c8afd415781f6667a7fb647c2c987fff/Lib/fontTools/misc/psCharStrings.py#L409>
+/// and "Appendix A Type 2 Charstring Command Codes" at
+/// <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=31>
+/// for the legacy arithmetic, logical and storage operators.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 enum Operator {
     HStem,
@@ -622,6 +911,26 @@ enum Operator {
     Flex,
     HFlex1,
     Flex1,
+    And,
+    Or,
+    Not,
+    Abs,
+    Add,
+    Sub,
+    Div,
+    Neg,
+    Eq,
+    Drop,
+    Put,
+    Get,
+    IfElse,
+    Random,
+    Mul,
+    Sqrt,
+    Dup,
+    Exch,
+    Index,
+    Roll,
 }
 
 impl Operator {
@@ -676,6 +985,26 @@ impl Operator {
     pub fn from_two_byte_opcode(opcode: u8) -> Option<Self> {
         use Operator::*;
         Some(match opcode {
+            3 => And,
+            4 => Or,
+            5 => Not,
+            9 => Abs,
+            10 => Add,
+            11 => Sub,
+            12 => Div,
+            14 => Neg,
+            15 => Eq,
+            18 => Drop,
+            20 => Put,
+            21 => Get,
+            22 => IfElse,
+            23 => Random,
+            24 => Mul,
+            26 => Sqrt,
+            27 => Dup,
+            28 => Exch,
+            29 => Index,
+            30 => Roll,
             34 => HFlex,
             35 => Flex,
             36 => HFlex1,
@@ -1048,4 +1377,169 @@ mod tests {
         ];
         assert_eq!(&commands.0, expected);
     }
+
+    /// Encodes a small integer operand.
+    fn num(value: i32) -> u8 {
+        assert!((-107..=107).contains(&value));
+        (value + 139) as u8
+    }
+
+    #[test]
+    fn legacy_operators() {
+        const ESC: u8 = 12;
+        #[rustfmt::skip]
+        let charstring = [
+            // 10 20 add 3 mul 100 7 sub rmoveto
+            num(10), num(20), ESC, 10, num(3), ESC, 24, num(100), num(7), ESC, 11, 21,
+            // 5 2 div 16 sqrt rlineto
+            num(5), num(2), ESC, 12, num(16), ESC, 26, 5,
+            // -9 abs 4 neg rlineto
+            num(-9), ESC, 9, num(4), ESC, 14, 5,
+            // 1 2 exch dup drop rlineto
+            num(1), num(2), ESC, 28, ESC, 27, ESC, 18, 5,
+            // 7 0 put 8 1 put 0 get 1 get rlineto
+            num(7), num(0), ESC, 20, num(8), num(1), ESC, 20, num(0), ESC, 21, num(1), ESC, 21, 5,
+            // 1 2 3 2 index 4 1 roll rlineto
+            num(1), num(2), num(3), num(2), ESC, 29, num(4), num(1), ESC, 30, 5,
+            // 3 4 1 1 eq 1 0 and ifelse 0 1 or rlineto
+            num(3), num(4), num(1), num(1), ESC, 15, num(1), num(0), ESC, 3, ESC, 22,
+            num(0), num(1), ESC, 4, 5,
+            // 0 not 5 rlineto
+            num(0), ESC, 5, num(5), 5,
+            // random 0 rlineto
+            ESC, 23, num(0), 5,
+            // endchar
+            14,
+        ];
+        let empty_index_bytes = [0u8; 8];
+        let global_subrs = Index::new(&empty_index_bytes, false).unwrap();
+        let mut commands = CaptureCommandSink::default();
+        evaluate(&charstring, global_subrs, None, None, &mut commands).unwrap();
+        use Command::*;
+        let expected = &[
+            MoveTo(Fixed::from_i32(90), Fixed::from_i32(93)),
+            LineTo(Fixed::from_f64(92.5), Fixed::from_i32(97)),
+            LineTo(Fixed::from_f64(101.5), Fixed::from_i32(93)),
+            LineTo(Fixed::from_f64(103.5), Fixed::from_i32(94)),
+            LineTo(Fixed::from_f64(110.5), Fixed::from_i32(102)),
+            // 1 2 3 1 => 1 1 2 3
+            LineTo(Fixed::from_f64(111.5), Fixed::from_i32(103)),
+            LineTo(Fixed::from_f64(113.5), Fixed::from_i32(106)),
+            LineTo(Fixed::from_f64(117.5), Fixed::from_i32(107)),
+            LineTo(Fixed::from_f64(118.5), Fixed::from_i32(112)),
+            LineTo(
+                Fixed::from_f64(118.5) + Fixed::from_bits(RANDOM_SEED as i32 + 1),
+                Fixed::from_i32(112),
+            ),
+            // close
+            LineTo(Fixed::from_i32(90), Fixed::from_i32(93)),
+        ];
+        assert_eq!(&commands.0, expected);
+    }
+
+    #[test]
+    fn seac() {
+        // Charstrings for .notdef, A, acute and Aacute
+        #[rustfmt::skip]
+        let glyphs: [&[u8]; 4] = [
+            // endchar
+            &[14],
+            // 0 0 rmoveto 100 0 rlineto -50 100 rlineto endchar
+            &[num(0), num(0), 21, num(100), num(0), 5, num(-50), num(100), 5, 14],
+            // 0 0 rmoveto 10 10 rlineto endchar
+            &[num(0), num(0), 21, num(10), num(10), 5, 14],
+            // 200 30 120 65 194 endchar
+            &[247, 92, num(30), 247, 12, num(65), 247, 86, 14],
+        ];
+        let mut charstrings = vec![0, glyphs.len() as u8, 1, 1];
+        let mut offset = 1;
+        for glyph in glyphs {
+            offset += glyph.len() as u8;
+            charstrings.push(offset);
+        }
+        charstrings.extend(glyphs.concat());
+        let charstrings = Index::new(&charstrings, false).unwrap();
+        // Format 0 charset at offset 3 with SIDs for A, acute and Aacute
+        let charset_data = [0xFF, 0xFF, 0xFF, 0, 0, 34, 0, 125, 0, 203];
+        let charset = Charset::new(FontData::new(&charset_data), 3, 4).unwrap();
+        let components = SeacComponents::new(charstrings.clone(), charset);
+        let empty_index_bytes = [0u8; 8];
+        let global_subrs = Index::new(&empty_index_bytes, false).unwrap();
+        let mut commands = CaptureCommandSink::default();
+        evaluate_with_seac(
+            glyphs[3],
+            global_subrs.clone(),
+            None,
+            None,
+            Some(components.clone()),
+            &mut commands,
+        )
+        .unwrap();
+        use Command::*;
+        let expected = &[
+            // accent is drawn first at the offset position
+            MoveTo(Fixed::from_i32(30), Fixed::from_i32(120)),
+            LineTo(Fixed::from_i32(40), Fixed::from_i32(130)),
+            LineTo(Fixed::from_i32(30), Fixed::from_i32(120)),
+            // followed by the base
+            MoveTo(Fixed::ZERO, Fixed::ZERO),
+            LineTo(Fixed::from_i32(100), Fixed::ZERO),
+            LineTo(Fixed::from_i32(50), Fixed::from_i32(100)),
+            LineTo(Fixed::ZERO, Fixed::ZERO),
+        ];
+        assert_eq!(&commands.0, expected);
+        // Without components, we just get an empty outline
+        let mut commands = CaptureCommandSink::default();
+        evaluate(glyphs[3], global_subrs.clone(), None, None, &mut commands).unwrap();
+        assert!(commands.0.is_empty());
+        // 66 is 'B' which is not present in the charset
+        let invalid_seac = [num(30), num(10), num(66), 247, 86, 14];
+        assert!(matches!(
+            evaluate_with_seac(
+                &invalid_seac,
+                global_subrs,
+                None,
+                None,
+                Some(components),
+                &mut CaptureCommandSink::default(),
+            ),
+            Err(Error::InvalidSeacCode(66))
+        ));
+    }
+
+    #[test]
+    fn endchar_ignores_non_seac_arguments() {
+        let charstrings_data = [0, 1, 1, 1, 2, 14];
+        let charstrings = Index::new(&charstrings_data, false).unwrap();
+        let charset_data = [0xFF, 0, 0];
+        let charset = Charset::new(FontData::new(&charset_data), 1, 1).unwrap();
+        let components = SeacComponents::new(charstrings, charset);
+        let empty_index_bytes = [0u8; 8];
+        let global_subrs = Index::new(&empty_index_bytes, false).unwrap();
+        // Two and three leftover operands are not a seac and are discarded
+        // along with the rest of the stack
+        for charstring in [
+            &[num(0), num(0), 21, num(10), num(10), 14][..],
+            &[num(0), num(0), 21, num(10), num(10), num(10), 14],
+        ] {
+            let mut commands = CaptureCommandSink::default();
+            evaluate_with_seac(
+                charstring,
+                global_subrs.clone(),
+                None,
+                None,
+                Some(components.clone()),
+                &mut commands,
+            )
+            .unwrap();
+            assert_eq!(
+                commands.0,
+                [
+                    Command::MoveTo(Fixed::ZERO, Fixed::ZERO),
+                    // close
+                    Command::LineTo(Fixed::ZERO, Fixed::ZERO),
+                ]
+            );
+        }
+    }
 }
@@ -464,7 +464,7 @@ pub struct StemSnaps {
 }
 
 impl StemSnaps {
-    fn new(values: impl Iterator<Item = Fixed>) -> Self {
+    pub(super) fn new(values: impl Iterator<Item = Fixed>) -> Self {
         let mut snaps = Self::default();
         for (value, target_value) in values.take(MAX_STEM_SNAPS).zip(&mut snaps.values) {
             *target_value = value;
@@ -0,0 +1,272 @@
+//! Parsing for CFF encodings.
+
+use types::{FixedSize, GlyphId};
+
+use super::{Charset, CustomEncoding, EncodingSupplement, StringId};
+use crate::{FontData, FontRead, ReadError};
+
+/// Mapping from character codes to glyphs.
+///
+/// Encodings are only present in name-keyed (non-CID) fonts.
+///
+/// See <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=18>
+#[derive(Clone)]
+pub enum Encoding<'a> {
+    Standard,
+    Expert,
+    Custom(CustomEncoding<'a>),
+}
+
+impl<'a> Encoding<'a> {
+    /// Creates a new encoding from the given offset.
+    ///
+    /// The `offset_data` parameter should be the full data of the CFF table
+    /// and `encoding_offset` the value of the `Encoding` operator in the Top
+    /// DICT. Offsets of 0 and 1 select the predefined Standard and Expert
+    /// encodings, respectively.
+    pub fn new(offset_data: FontData<'a>, encoding_offset: usize) -> Result<Self, ReadError> {
+        Ok(match encoding_offset {
+            0 => Self::Standard,
+            1 => Self::Expert,
+            _ => {
+                let data = offset_data
+                    .split_off(encoding_offset)
+                    .ok_or(ReadError::OutOfBounds)?;
+                Self::Custom(CustomEncoding::read(data)?)
+            }
+        })
+    }
+
+    /// Returns the glyph identifier for the given character code.
+    ///
+    /// The charset is required to resolve string identifiers for the
+    /// predefined encodings and for supplemental mappings.
+    pub fn map(&self, code: u8, charset: &Charset) -> Option<GlyphId> {
+        let sid = match self {
+            Self::Standard => STANDARD_ENCODING[code as usize],
+            Self::Expert => EXPERT_ENCODING[code as usize],
+            Self::Custom(custom) => {
+                if let Some(gid) = custom.map_code(code) {
+                    return Some(gid);
+                }
+                custom
+                    .supplements()
+                    .ok()?
+                    .iter()
+                    .find(|supplement| supplement.code() == code)?
+                    .glyph()
+            }
+        };
+        // SID 0 is .notdef which means the code is not encoded
+        if sid == 0 {
+            return None;
+        }
+        charset.glyph_id(StringId::new(sid))
+    }
+}
+
+impl<'a> CustomEncoding<'a> {
+    /// Returns the glyph identifier for the given code using the primary
+    /// (non-supplemental) mapping.
+    fn map_code(&self, code: u8) -> Option<GlyphId> {
+        match self {
+            // Codes are assigned to glyphs in order, starting at glyph 1
+            Self::Format0(fmt) => fmt
+                .code()
+                .iter()
+                .position(|c| *c == code)
+                .map(|ix| GlyphId::new(ix as u32 + 1)),
+            Self::Format1(fmt) => {
+                let mut gid = 1u32;
+                for range in fmt.ranges() {
+                    let first = range.first();
+                    if let Some(offset) = code.checked_sub(first) {
+                        if offset <= range.n_left() {
+                            return Some(GlyphId::new(gid + offset as u32));
+                        }
+                    }
+                    gid += range.n_left() as u32 + 1;
+                }
+                None
+            }
+        }
+    }
+
+    /// Returns true if the encoding contains supplemental code mappings.
+    pub fn has_supplements(&self) -> bool {
+        let format = match self {
+            Self::Format0(fmt) => fmt.format(),
+            Self::Format1(fmt) => fmt.format(),
+        };
+        format & 0x80 != 0
+    }
+
+    /// Returns the supplemental mappings from codes to string identifiers.
+    ///
+    /// These allow multiple codes to map to the same glyph.
+    pub fn supplements(&self) -> Result<&'a [EncodingSupplement], ReadError> {
+        if !self.has_supplements() {
+            return Ok(&[]);
+        }
+        // Supplements immediately follow the code or range arrays
+        let (data, start) = match self {
+            Self::Format0(fmt) => (fmt.offset_data(), 2 + fmt.n_codes() as usize),
+            Self::Format1(fmt) => (fmt.offset_data(), 2 + fmt.n_ranges() as usize * 2),
+        };
+        let n_sups = data.read_at::<u8>(start)? as usize;
+        let start = start + 1;
+        data.read_array(start..start + n_sups * EncodingSupplement::RAW_BYTE_LEN)
+    }
+}
+
+impl EncodingSupplement {
+    /// Returns the string identifier for this supplement.
+    pub fn string_id(&self) -> StringId {
+        StringId::new(self.glyph())
+    }
+}
+
+/// Returns the name of the glyph that is assigned to the given code in the
+/// standard encoding.
+///
+/// This is used to resolve the components of glyphs defined with `seac` in
+/// Type 1 fonts.
+#[cfg(feature = "std")]
+pub(super) fn standard_encoding_name(code: u8) -> Option<&'static str> {
+    match STANDARD_ENCODING[code as usize] {
+        // SID 0 is .notdef which means the code is not encoded
+        0 => None,
+        sid => super::STANDARD_STRINGS.get(sid as usize).copied(),
+    }
+}
+
+/// The standard encoding, mapping codes to string identifiers.
+///
+/// See <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=37>
+#[rustfmt::skip]
+const STANDARD_ENCODING: [u16; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+    51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74,
+    75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96,
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 0, 111, 112, 113, 114, 0,
+    115, 116, 117, 118, 119, 120, 121, 122, 0, 123, 0, 124, 125, 126, 127, 128, 129, 130, 131, 0,
+    132, 133, 0, 134, 135, 136, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 139, 0,
+    0, 0, 0, 140, 141, 142, 143, 0, 0, 0, 0, 0, 144, 0, 0, 0, 145, 0, 0, 146, 147, 148, 149, 0, 0,
+    0, 0,
+];
+
+/// The expert encoding, mapping codes to string identifiers.
+///
+/// See <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=40>
+#[rustfmt::skip]
+const EXPERT_ENCODING: [u16; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 229, 230, 0, 231, 232, 233, 234, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243,
+    244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 252, 0, 253, 254, 255, 256, 257, 0, 0, 0, 258,
+    0, 0, 259, 260, 261, 262, 0, 0, 263, 264, 265, 0, 266, 109, 110, 267, 268, 269, 0, 270, 271,
+    272, 273, 274, 275, 276, 277, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290,
+    291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 301, 302, 303, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 304, 305, 306, 0, 0,
+    307, 308, 309, 310, 311, 0, 312, 0, 0, 313, 0, 0, 314, 315, 0, 0, 316, 317, 318, 0, 0, 0, 158,
+    155, 163, 319, 320, 321, 322, 323, 324, 325, 0, 0, 326, 150, 164, 169, 327, 328, 329, 330, 331,
+    332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 348, 349, 350,
+    351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369,
+    370, 371, 372, 373, 374, 375, 376, 377, 378,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn standard_encoding() {
+        let charset = Charset::new(FontData::new(&[]), 0, 229).unwrap();
+        let encoding = Encoding::new(FontData::new(&[]), 0).unwrap();
+        // space
+        assert_eq!(encoding.map(32, &charset), Some(GlyphId::new(1)));
+        // A
+        assert_eq!(encoding.map(b'A', &charset), Some(GlyphId::new(34)));
+        // fi ligature
+        assert_eq!(encoding.map(0xAE, &charset), Some(GlyphId::new(109)));
+        // unencoded
+        assert_eq!(encoding.map(0, &charset), None);
+        assert_eq!(encoding.map(0x80, &charset), None);
+    }
+
+    #[test]
+    fn expert_encoding() {
+        let charset = Charset::new(FontData::new(&[]), 1, 166).unwrap();
+        let encoding = Encoding::new(FontData::new(&[]), 1).unwrap();
+        assert_eq!(encoding.map(32, &charset), Some(GlyphId::new(1)));
+        // exclamsmall
+        assert_eq!(encoding.map(33, &charset), Some(GlyphId::new(2)));
+        assert_eq!(encoding.map(0, &charset), None);
+    }
+
+    #[test]
+    fn expert_encoding_sids() {
+        // (code, sid) pairs from the expert encoding table in appendix C
+        // of the CFF specification
+        let expected = [
+            (0x20, 1),   // space
+            (0x2C, 13),  // comma
+            (0x41, 253), // asuperior
+            (0xAA, 311), // Caronsmall
+            (0xAC, 312), // Dotaccentsmall
+            (0xAF, 313), // Macronsmall
+            (0xB2, 314), // figuredash
+            (0xBC, 158), // onequarter
+            (0xC8, 326), // zerosuperior
+            (0xFF, 378), // Ydieresissmall
+        ];
+        for (code, sid) in expected {
+            assert_eq!(EXPERT_ENCODING[code], sid, "code {code:#04X}");
+        }
+    }
+
+    #[test]
+    fn custom_encodings() {
+        // charset format 2 covering glyphs 1..=5 with SIDs 34..=38 (A-E)
+        let charset_data = BeBuffer::new().push(2u8).extend([34u16, 4]);
+        let mut bytes = vec![0u8; 3];
+        bytes.extend_from_slice(&charset_data);
+        let charset = Charset::new(FontData::new(&bytes), 3, 6).unwrap();
+        // codes 'A'..='E' for glyphs 1..=5 plus a supplement mapping 'a' to
+        // SID 36 (C)
+        let format0 = BeBuffer::new()
+            .push(0x80u8)
+            .push(5u8)
+            .extend(*b"ABCDE")
+            .push(1u8)
+            .push(b'a')
+            .push(36u16);
+        let format1 = BeBuffer::new()
+            .push(0x81u8)
+            .push(2u8)
+            .extend([b'A', 1, b'C', 2])
+            .push(1u8)
+            .push(b'a')
+            .push(36u16);
+        for data in [format0, format1] {
+            let mut bytes = vec![0u8; 2];
+            bytes.extend_from_slice(&data);
+            let encoding = Encoding::new(FontData::new(&bytes), 2).unwrap();
+            let Encoding::Custom(custom) = &encoding else {
+                panic!("expected custom encoding");
+            };
+            assert!(custom.has_supplements());
+            let supplements = custom.supplements().unwrap();
+            assert_eq!(supplements.len(), 1);
+            assert_eq!(supplements[0].string_id(), StringId::new(36));
+            for (code, gid) in (b'A'..=b'E').zip(1u32..) {
+                assert_eq!(encoding.map(code, &charset), Some(GlyphId::new(gid)));
+            }
+            assert_eq!(encoding.map(b'a', &charset), Some(GlyphId::new(3)));
+            assert_eq!(encoding.map(b'F', &charset), None);
+        }
+    }
+}
@@ -84,6 +84,28 @@ impl Stack {
         self.value_is_fixed[..self.top].reverse();
     }
 
+    /// Performs a circular shift of the top `count` elements on the stack
+    /// by `shift` positions.
+    ///
+    /// Positive values of `shift` move elements toward the top of the
+    /// stack. Used to implement the charstring `roll` operator.
+    pub fn roll(&mut self, count: i32, shift: i32) -> Result<(), Error> {
+        // Counts of 0 and 1 are a no-op, negative counts are undefined
+        // and ignored
+        if count < 2 {
+            return Ok(());
+        }
+        let count = count as usize;
+        if count > self.top {
+            return Err(Error::StackUnderflow);
+        }
+        let range = self.top - count..self.top;
+        let shift = shift.rem_euclid(count as i32) as usize;
+        self.values[range.clone()].rotate_right(shift);
+        self.value_is_fixed[range].rotate_right(shift);
+        Ok(())
+    }
+
     pub fn push(&mut self, number: impl Into<Number>) -> Result<(), Error> {
         match number.into() {
             Number::I32(value) => self.push_impl(value, false),
@@ -143,6 +165,12 @@ impl Stack {
         self.get_fixed(i)
     }
 
+    /// Pops a number from the top of the stack, preserving its type.
+    pub fn pop_number(&mut self) -> Result<Number, Error> {
+        let i = self.pop()?;
+        Ok(Number::from_stack(self.values[i], self.value_is_fixed[i]))
+    }
+
     /// Returns an iterator yielding all elements on the stack
     /// as 16.16 fixed point values.
     ///
@@ -412,6 +440,30 @@ mod tests {
         assert_eq!(stack.pop_fixed().unwrap(), Fixed::from_f64(4.2));
     }
 
+    #[test]
+    fn roll() {
+        let mut stack = Stack::new();
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(Fixed::from_f64(3.5)).unwrap();
+        stack.push(4).unwrap();
+        // a b c 3 1 roll => c a b
+        stack.roll(3, 1).unwrap();
+        assert_eq!(stack.pop_fixed().unwrap(), Fixed::from_f64(3.5));
+        assert_eq!(stack.pop_i32().unwrap(), 2);
+        assert_eq!(stack.pop_i32().unwrap(), 4);
+        assert_eq!(stack.pop_i32().unwrap(), 1);
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        stack.push(3).unwrap();
+        // a b c 3 -1 roll => b c a
+        stack.roll(3, -1).unwrap();
+        assert_eq!(stack.pop_i32().unwrap(), 1);
+        assert_eq!(stack.pop_i32().unwrap(), 3);
+        assert_eq!(stack.pop_i32().unwrap(), 2);
+        assert!(stack.roll(2, 1).is_err());
+    }
+
     #[test]
     fn delta_prefix_sum() {
         let mut stack = Stack::new();
@@ -0,0 +1,1103 @@
+//! Parsing for Type 1 fonts.
+//!
+//! Type 1 fonts are stored either in the binary PFB format, which splits the
+//! font into segments of cleartext and encrypted data, or the ASCII PFA
+//! format, where the encrypted portion is hex encoded.
+//!
+//! The cleartext portion contains the font dictionary, while the encrypted
+//! (eexec) portion contains the Private dictionary along with the subroutines
+//! and charstrings, which are themselves individually encrypted.
+//!
+//! See the [Type 1 font format](https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf)
+//! specification.
+
+mod charstring;
+
+use std::ops::Range;
+
+use types::{Fixed, GlyphId};
+
+use super::{
+    charstring::CommandSink,
+    dict::{Blues, StemSnaps},
+    encoding::standard_encoding_name,
+    Error,
+};
+use crate::ReadError;
+
+/// Key for decrypting the eexec encrypted portion of the font.
+const EEXEC_KEY: u16 = 55665;
+
+/// Key for decrypting charstrings and subroutines.
+const CHARSTRING_KEY: u16 = 4330;
+
+/// Number of random bytes at the start of the eexec encrypted data.
+const EEXEC_PREFIX_LEN: usize = 4;
+
+/// A Type 1 font.
+///
+/// This owns the decrypted font data and provides access to the glyph
+/// names, encoding, Private dictionary and charstrings. Glyph identifiers
+/// are assigned in the order that charstrings appear in the font, except
+/// that `.notdef` is always moved to glyph 0, following FreeType.
+#[derive(Clone, Debug)]
+pub struct Type1Font {
+    /// Decrypted eexec data. Charstrings and subroutines are decrypted in
+    /// place.
+    data: Vec<u8>,
+    font_name: Option<String>,
+    font_matrix: [f64; 6],
+    font_bbox: [Fixed; 4],
+    encoding: Vec<Option<GlyphId>>,
+    private_dict: PrivateDict,
+    subrs: Vec<Range<usize>>,
+    glyphs: Vec<Glyph>,
+    /// Glyph indices sorted by name for lookup.
+    sorted_names: Vec<u32>,
+}
+
+#[derive(Clone, Debug)]
+struct Glyph {
+    name: String,
+    charstring: Range<usize>,
+}
+
+impl Type1Font {
+    /// Parses a Type 1 font from data in either the PFB or PFA format.
+    pub fn new(data: &[u8]) -> Result<Self, Error> {
+        let (cleartext, encrypted) = split_sections(data)?;
+        let mut private_data = decrypt(&encrypted, EEXEC_KEY);
+        private_data.drain(..EEXEC_PREFIX_LEN.min(private_data.len()));
+        let mut parsed = ParsedFont::default();
+        parsed.parse(&cleartext, false)?;
+        parsed.parse(&private_data, true)?;
+        if parsed.glyphs.is_empty() {
+            return Err(Error::MissingType1CharStrings);
+        }
+        // Charstrings and subroutines are decrypted in place, skipping the
+        // lenIV random bytes at the start of each. A lenIV of -1 means that
+        // charstrings are not encrypted.
+        let len_iv = parsed.private_dict.len_iv;
+        let decrypt_range = |data: &mut [u8], range: &mut Range<usize>| {
+            if let Ok(skip) = usize::try_from(len_iv) {
+                let Some(charstring) = data.get_mut(range.clone()) else {
+                    return Err(Error::Read(ReadError::OutOfBounds));
+                };
+                decrypt_in_place(charstring, CHARSTRING_KEY);
+                range.start = range.start.saturating_add(skip).min(range.end);
+            }
+            Ok(())
+        };
+        for range in &mut parsed.subrs {
+            decrypt_range(&mut private_data, range)?;
+        }
+        for glyph in &mut parsed.glyphs {
+            decrypt_range(&mut private_data, &mut glyph.charstring)?;
+        }
+        let mut glyphs = parsed.glyphs;
+        if let Some(notdef_ix) = glyphs.iter().position(|glyph| glyph.name == ".notdef") {
+            glyphs.swap(0, notdef_ix);
+        }
+        let mut sorted_names = (0..glyphs.len() as u32).collect::<Vec<_>>();
+        sorted_names.sort_by(|a, b| glyphs[*a as usize].name.cmp(&glyphs[*b as usize].name));
+        let mut font = Self {
+            data: private_data,
+            font_name: parsed.font_name,
+            font_matrix: parsed.font_matrix,
+            font_bbox: parsed.font_bbox,
+            encoding: vec![],
+            private_dict: parsed.private_dict,
+            subrs: parsed.subrs,
+            glyphs,
+            sorted_names,
+        };
+        font.encoding = match parsed.encoding {
+            ParsedEncoding::Standard => (0..=255u8)
+                .map(|code| standard_encoding_name(code).and_then(|name| font.glyph_id(name)))
+                .collect(),
+            ParsedEncoding::Custom(names) => names
+                .iter()
+                .map(|name| name.as_deref().and_then(|name| font.glyph_id(name)))
+                .collect(),
+        };
+        Ok(font)
+    }
+
+    /// Returns the PostScript name of the font.
+    pub fn font_name(&self) -> Option<&str> {
+        self.font_name.as_deref()
+    }
+
+    /// Returns the matrix that maps glyph space to text space.
+    ///
+    /// This is almost always `[0.001, 0, 0, 0.001, 0, 0]`.
+    pub fn font_matrix(&self) -> [f64; 6] {
+        self.font_matrix
+    }
+
+    /// Returns the bounding box of all glyphs as `[x_min, y_min, x_max, y_max]`.
+    pub fn font_bbox(&self) -> [Fixed; 4] {
+        self.font_bbox
+    }
+
+    /// Returns the number of units per em, derived from the vertical scale
+    /// of the font matrix.
+    pub fn units_per_em(&self) -> u16 {
+        let scale = self.font_matrix[3].abs();
+        if scale > 0.0 {
+            (1.0 / scale).round().clamp(1.0, u16::MAX as f64) as u16
+        } else {
+            1000
+        }
+    }
+
+    /// Returns the Private dictionary, which contains the hinting parameters.
+    pub fn private_dict(&self) -> &PrivateDict {
+        &self.private_dict
+    }
+
+    /// Returns the number of glyphs in the font.
+    pub fn num_glyphs(&self) -> u32 {
+        self.glyphs.len() as u32
+    }
+
+    /// Returns the name of the given glyph.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&str> {
+        self.glyphs
+            .get(glyph_id.to_u32() as usize)
+            .map(|glyph| glyph.name.as_str())
+    }
+
+    /// Returns the glyph with the given name.
+    pub fn glyph_id(&self, name: &str) -> Option<GlyphId> {
+        let ix = self
+            .sorted_names
+            .binary_search_by(|gid| self.glyphs[*gid as usize].name.as_str().cmp(name))
+            .ok()?;
+        Some(GlyphId::new(self.sorted_names[ix]))
+    }
+
+    /// Returns the glyph that is mapped to the given character code by the
+    /// font's encoding.
+    pub fn map_code(&self, code: u8) -> Option<GlyphId> {
+        self.encoding.get(code as usize).copied().flatten()
+    }
+
+    /// Returns the decrypted charstring for the given glyph.
+    pub fn charstring(&self, glyph_id: GlyphId) -> Option<&[u8]> {
+        let glyph = self.glyphs.get(glyph_id.to_u32() as usize)?;
+        self.data.get(glyph.charstring.clone())
+    }
+
+    /// Evaluates the charstring for the given glyph and emits the resulting
+    /// commands to the specified sink.
+    ///
+    /// Coordinates are in font units. Stem hints are emitted as the
+    /// positions of their lower and upper edges, as for CFF charstrings. When
+    /// hints are replaced during evaluation, the new stems are emitted
+    /// followed by a hint mask selecting them, as for the Type 2 `hintmask`
+    /// operator.
+    ///
+    /// Returns the side bearing and advance width of the glyph.
+    pub fn evaluate(
+        &self,
+        glyph_id: GlyphId,
+        sink: &mut impl CommandSink,
+    ) -> Result<GlyphMetrics, Error> {
+        let charstring = self
+            .charstring(glyph_id)
+            .ok_or(Error::Read(ReadError::OutOfBounds))?;
+        charstring::evaluate(self, charstring, sink)
+    }
+
+    fn subr(&self, index: i32) -> Result<&[u8], Error> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.subrs.get(index))
+            .and_then(|range| self.data.get(range.clone()))
+            .ok_or(Error::Read(ReadError::OutOfBounds))
+    }
+}
+
+/// Side bearing and advance width of a glyph, as set by the `hsbw` or `sbw`
+/// charstring operators.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct GlyphMetrics {
+    /// Horizontal component of the side bearing point.
+    pub side_bearing_x: Fixed,
+    /// Vertical component of the side bearing point.
+    pub side_bearing_y: Fixed,
+    /// Horizontal component of the advance vector.
+    pub advance_width: Fixed,
+    /// Vertical component of the advance vector.
+    pub advance_height: Fixed,
+}
+
+/// Values from the Private dictionary of a Type 1 font.
+///
+/// See "5.3 Private Dictionary" in the
+/// [Type 1 specification](https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=39).
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct PrivateDict {
+    pub blue_values: Blues,
+    pub other_blues: Blues,
+    pub family_blues: Blues,
+    pub family_other_blues: Blues,
+    pub blue_scale: Fixed,
+    pub blue_shift: Fixed,
+    pub blue_fuzz: Fixed,
+    pub std_hw: Option<Fixed>,
+    pub std_vw: Option<Fixed>,
+    pub stem_snap_h: StemSnaps,
+    pub stem_snap_v: StemSnaps,
+    pub force_bold: bool,
+    pub language_group: i32,
+    /// Number of random bytes at the start of each charstring, or -1 if
+    /// charstrings are not encrypted.
+    pub len_iv: i32,
+}
+
+impl Default for PrivateDict {
+    fn default() -> Self {
+        Self {
+            blue_values: Blues::default(),
+            other_blues: Blues::default(),
+            family_blues: Blues::default(),
+            family_other_blues: Blues::default(),
+            blue_scale: Fixed::from_f64(0.039625),
+            blue_shift: Fixed::from_i32(7),
+            blue_fuzz: Fixed::ONE,
+            std_hw: None,
+            std_vw: None,
+            stem_snap_h: StemSnaps::default(),
+            stem_snap_v: StemSnaps::default(),
+            force_bold: false,
+            language_group: 0,
+            len_iv: 4,
+        }
+    }
+}
+
+/// Splits the font data into the cleartext and (still encrypted) binary
+/// eexec portions.
+fn split_sections(data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    const PFB_MARKER: u8 = 0x80;
+    if data.first() == Some(&PFB_MARKER) {
+        return split_pfb_segments(data);
+    }
+    let eexec_end = find_eexec(data).ok_or(Error::MissingEexecData)?;
+    let cleartext = data[..eexec_end].to_vec();
+    let mut encrypted = &data[eexec_end..];
+    // The eexec operator is followed by whitespace and then either binary or
+    // hex encoded data. The data is hex encoded if the first four bytes are
+    // hex digits.
+    while let Some((first, rest)) = encrypted.split_first() {
+        if !is_whitespace(*first) {
+            break;
+        }
+        encrypted = rest;
+    }
+    let is_hex = encrypted.len() >= 4 && encrypted[..4].iter().all(u8::is_ascii_hexdigit);
+    let encrypted = if is_hex {
+        decode_hex(encrypted)
+    } else {
+        encrypted.to_vec()
+    };
+    Ok((cleartext, encrypted))
+}
+
+/// Splits the segments of a PFB file.
+///
+/// Each segment begins with a marker byte of 0x80, followed by the segment
+/// type and, for data segments, a 32-bit little endian length.
+fn split_pfb_segments(mut data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    const ASCII_SEGMENT: u8 = 1;
+    const BINARY_SEGMENT: u8 = 2;
+    const EOF_SEGMENT: u8 = 3;
+    let mut cleartext = vec![];
+    let mut encrypted = vec![];
+    while let [0x80, segment_type, rest @ ..] = data {
+        if *segment_type == EOF_SEGMENT {
+            break;
+        }
+        let (len, rest) = rest.split_at_checked(4).ok_or(ReadError::OutOfBounds)?;
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        let (segment, rest) = rest.split_at_checked(len).ok_or(ReadError::OutOfBounds)?;
+        match *segment_type {
+            // Only the first ASCII segment is relevant. Any that follow the
+            // binary data contain the trailing zeros and `cleartomark`.
+            ASCII_SEGMENT if encrypted.is_empty() => cleartext.extend_from_slice(segment),
+            ASCII_SEGMENT => {}
+            BINARY_SEGMENT => encrypted.extend_from_slice(segment),
+            _ => return Err(Error::InvalidPfbSegment(*segment_type)),
+        }
+        data = rest;
+    }
+    if encrypted.is_empty() {
+        return Err(Error::MissingEexecData);
+    }
+    Ok((cleartext, encrypted))
+}
+
+/// Returns the position following the `eexec` operator.
+fn find_eexec(data: &[u8]) -> Option<usize> {
+    let mut tokens = Tokenizer::new(data);
+    while let Some(token) = tokens.next() {
+        if token == Token::Name(b"eexec") {
+            return Some(tokens.pos);
+        }
+    }
+    None
+}
+
+/// Decodes hex digits, ignoring whitespace and stopping at the first other
+/// character.
+fn decode_hex(data: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(data.len() / 2);
+    let mut high_nibble = None;
+    for byte in data {
+        let nibble = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'a'..=b'f' => byte - b'a' + 10,
+            b'A'..=b'F' => byte - b'A' + 10,
+            _ if is_whitespace(*byte) => continue,
+            _ => break,
+        };
+        match high_nibble.take() {
+            Some(high) => result.push(high << 4 | nibble),
+            None => high_nibble = Some(nibble),
+        }
+    }
+    result
+}
+
+/// Decrypts data with the given key.
+///
+/// See "7.1 Encryption Method" in the
+/// [Type 1 specification](https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=63).
+fn decrypt(data: &[u8], key: u16) -> Vec<u8> {
+    let mut result = data.to_vec();
+    decrypt_in_place(&mut result, key);
+    result
+}
+
+fn decrypt_in_place(data: &mut [u8], mut key: u16) {
+    const C1: u16 = 52845;
+    const C2: u16 = 22719;
+    for byte in data {
+        let cipher = *byte;
+        *byte = cipher ^ (key >> 8) as u8;
+        key = (cipher as u16)
+            .wrapping_add(key)
+            .wrapping_mul(C1)
+            .wrapping_add(C2);
+    }
+}
+
+/// Encoding as declared in the font dictionary, before glyph names are
+/// resolved.
+#[derive(Default)]
+enum ParsedEncoding {
+    #[default]
+    Standard,
+    Custom(Vec<Option<String>>),
+}
+
+/// State accumulated while parsing the font and Private dictionaries.
+struct ParsedFont {
+    font_name: Option<String>,
+    font_matrix: [f64; 6],
+    font_bbox: [Fixed; 4],
+    encoding: ParsedEncoding,
+    private_dict: PrivateDict,
+    subrs: Vec<Range<usize>>,
+    glyphs: Vec<Glyph>,
+}
+
+impl Default for ParsedFont {
+    fn default() -> Self {
+        Self {
+            font_name: None,
+            font_matrix: [0.001, 0.0, 0.0, 0.001, 0.0, 0.0],
+            font_bbox: [Fixed::ZERO; 4],
+            encoding: ParsedEncoding::default(),
+            private_dict: PrivateDict::default(),
+            subrs: vec![],
+            glyphs: vec![],
+        }
+    }
+}
+
+impl ParsedFont {
+    /// Parses the entries that we care about from the given section of
+    /// the font.
+    ///
+    /// Subroutines and charstrings are only read when `is_private` is true,
+    /// since their ranges refer to positions in the decrypted eexec data.
+    fn parse(&mut self, data: &[u8], is_private: bool) -> Result<(), Error> {
+        let mut tokens = Tokenizer::new(data);
+        while let Some(token) = tokens.next() {
+            let key = match token {
+                Token::LiteralName(key) => key,
+                // Everything after closefile is padding
+                Token::Name(b"closefile") => break,
+                _ => continue,
+            };
+            let private = &mut self.private_dict;
+            match key {
+                b"FontName" => {
+                    if let Some(Token::LiteralName(name)) = tokens.next() {
+                        self.font_name = Some(String::from_utf8_lossy(name).into_owned());
+                    }
+                }
+                b"FontMatrix" => {
+                    let values = tokens.read_number_array();
+                    if let Ok(matrix) = values.try_into() {
+                        self.font_matrix = matrix;
+                    }
+                }
+                b"FontBBox" => {
+                    let values = tokens.read_number_array();
+                    if let Ok(bbox) = <[f64; 4]>::try_from(values) {
+                        self.font_bbox = bbox.map(Fixed::from_f64);
+                    }
+                }
+                b"Encoding" => self.encoding = tokens.read_encoding(),
+                b"BlueValues" => private.blue_values = Blues::new(tokens.read_fixed_array()),
+                b"OtherBlues" => private.other_blues = Blues::new(tokens.read_fixed_array()),
+                b"FamilyBlues" => private.family_blues = Blues::new(tokens.read_fixed_array()),
+                b"FamilyOtherBlues" => {
+                    private.family_other_blues = Blues::new(tokens.read_fixed_array())
+                }
+                b"BlueScale" => {
+                    if let Some(value) = tokens.read_number() {
+                        private.blue_scale = Fixed::from_f64(value);
+                    }
+                }
+                b"BlueShift" => {
+                    if let Some(value) = tokens.read_number() {
+                        private.blue_shift = Fixed::from_f64(value);
+                    }
+                }
+                b"BlueFuzz" => {
+                    if let Some(value) = tokens.read_number() {
+                        private.blue_fuzz = Fixed::from_f64(value);
+                    }
+                }
+                b"StdHW" => private.std_hw = tokens.read_fixed_array().next(),
+                b"StdVW" => private.std_vw = tokens.read_fixed_array().next(),
+                b"StemSnapH" => private.stem_snap_h = StemSnaps::new(tokens.read_fixed_array()),
+                b"StemSnapV" => private.stem_snap_v = StemSnaps::new(tokens.read_fixed_array()),
+                b"ForceBold" => private.force_bold = tokens.next() == Some(Token::Name(b"true")),
+                b"LanguageGroup" => {
+                    if let Some(value) = tokens.read_number() {
+                        private.language_group = value as i32;
+                    }
+                }
+                b"lenIV" => {
+                    if let Some(value) = tokens.read_number() {
+                        private.len_iv = value as i32;
+                    }
+                }
+                b"Subrs" if is_private => self.subrs = tokens.read_subrs()?,
+                b"CharStrings" if is_private => self.glyphs = tokens.read_charstrings()?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A PostScript token.
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Token<'a> {
+    /// A name preceded by a slash, such as `/FontName`.
+    LiteralName(&'a [u8]),
+    /// An executable name, such as `def`.
+    Name(&'a [u8]),
+    Number(f64),
+    String,
+    ArrayStart,
+    ArrayEnd,
+    ProcStart,
+    ProcEnd,
+}
+
+/// Splits PostScript source into tokens.
+///
+/// This only supports the subset of the language that is used in Type 1
+/// fonts, with binary charstring data read separately through
+/// [`read_binary`](Self::read_binary).
+#[derive(Clone)]
+struct Tokenizer<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        loop {
+            let byte = *self.data.get(self.pos)?;
+            if is_whitespace(byte) {
+                self.pos += 1;
+            } else if byte == b'%' {
+                // Comments extend to the end of the line
+                while self
+                    .data
+                    .get(self.pos)
+                    .is_some_and(|b| !matches!(b, b'\r' | b'\n'))
+                {
+                    self.pos += 1;
+                }
+            } else {
+                break;
+            }
+        }
+        let byte = self.data[self.pos];
+        self.pos += 1;
+        Some(match byte {
+            b'/' => Token::LiteralName(self.read_regular()),
+            b'[' => Token::ArrayStart,
+            b']' => Token::ArrayEnd,
+            b'{' => Token::ProcStart,
+            b'}' => Token::ProcEnd,
+            b'(' => {
+                self.skip_string();
+                Token::String
+            }
+            // Dictionary delimiters and hex strings are never relevant to
+            // us so treat them as executable names
+            b'<' | b'>' => {
+                let start = self.pos - 1;
+                if self.data.get(self.pos) == Some(&byte) {
+                    self.pos += 1;
+                }
+                Token::Name(&self.data[start..self.pos])
+            }
+            _ => {
+                self.pos -= 1;
+                let token = self.read_regular();
+                match parse_number(token) {
+                    Some(value) => Token::Number(value),
+                    None => Token::Name(token),
+                }
+            }
+        })
+    }
+
+    /// Reads a sequence of regular (non-delimiter, non-whitespace)
+    /// characters.
+    fn read_regular(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while self
+            .data
+            .get(self.pos)
+            .is_some_and(|b| !is_whitespace(*b) && !is_delimiter(*b))
+        {
+            self.pos += 1;
+        }
+        // Always consume at least one byte to guarantee progress
+        if self.pos == start && start < self.data.len() {
+            self.pos += 1;
+        }
+        &self.data[start..self.pos]
+    }
+
+    /// Skips a string literal, accounting for nested parentheses and
+    /// escapes.
+    fn skip_string(&mut self) {
+        let mut depth = 1;
+        while let Some(byte) = self.data.get(self.pos) {
+            self.pos += 1;
+            match byte {
+                b'\\' => self.pos += 1,
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the range of binary data with the given length.
+    ///
+    /// This follows a `RD` (or `-|`) token, which is separated from the
+    /// data by a single space. The length is read from the font so may be
+    /// negative, fractional or huge; anything that doesn't fit in the
+    /// remaining data is an error.
+    fn read_binary(&mut self, len: f64) -> Result<Range<usize>, Error> {
+        if !(0.0..=self.data.len() as f64).contains(&len) || len.fract() != 0.0 {
+            return Err(ReadError::OutOfBounds.into());
+        }
+        let start = self.pos.checked_add(1).ok_or(ReadError::OutOfBounds)?;
+        let end = start
+            .checked_add(len as usize)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(ReadError::OutOfBounds)?;
+        self.pos = end;
+        Ok(start..end)
+    }
+
+    fn read_number(&mut self) -> Option<f64> {
+        match self.next()? {
+            Token::Number(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Reads an array or procedure of numbers, returning an empty array if
+    /// the next token does not begin one.
+    fn read_number_array(&mut self) -> Vec<f64> {
+        let mut values = vec![];
+        if matches!(self.next(), Some(Token::ArrayStart | Token::ProcStart)) {
+            while let Some(Token::Number(value)) = self.next() {
+                values.push(value);
+            }
+        }
+        values
+    }
+
+    fn read_fixed_array(&mut self) -> impl Iterator<Item = Fixed> {
+        self.read_number_array().into_iter().map(Fixed::from_f64)
+    }
+
+    /// Reads the value of the `Encoding` key which is either the name
+    /// `StandardEncoding` or a sequence of `dup code /name put`.
+    fn read_encoding(&mut self) -> ParsedEncoding {
+        let Some(Token::Number(_)) = self.clone().next() else {
+            return ParsedEncoding::Standard;
+        };
+        let mut names = vec![None; 256];
+        while let Some(token) = self.next() {
+            match token {
+                Token::Name(b"dup") => {
+                    let mut entry = self.clone();
+                    if let (Some(Token::Number(code)), Some(Token::LiteralName(name))) =
+                        (entry.next(), entry.next())
+                    {
+                        if let Some(slot) = names.get_mut(code as usize) {
+                            *slot = Some(String::from_utf8_lossy(name).into_owned());
+                        }
+                        *self = entry;
+                    }
+                }
+                Token::Name(b"def" | b"readonly") => break,
+                _ => {}
+            }
+        }
+        ParsedEncoding::Custom(names)
+    }
+
+    /// Reads the subroutine array, which is a sequence of entries of the
+    /// form `dup index length RD <binary> NP`.
+    fn read_subrs(&mut self) -> Result<Vec<Range<usize>>, Error> {
+        let Some(Token::Number(count)) = self.next() else {
+            return Ok(vec![]);
+        };
+        // Each entry occupies at least one byte so the count is bounded by
+        // the remaining data, which guards against absurd counts. The cast
+        // saturates, mapping negative and NaN values to zero.
+        let count = (count as usize).min(self.data.len().saturating_sub(self.pos));
+        let mut subrs = vec![0..0; count];
+        loop {
+            let mut entry = self.clone();
+            match entry.next() {
+                Some(Token::Name(b"dup")) => {}
+                Some(Token::Name(b"array")) => {
+                    *self = entry;
+                    continue;
+                }
+                _ => break,
+            }
+            let (Some(Token::Number(index)), Some(Token::Number(len)), Some(Token::Name(_))) =
+                (entry.next(), entry.next(), entry.next())
+            else {
+                break;
+            };
+            let range = entry.read_binary(len)?;
+            if let Some(subr) = subrs.get_mut(index as usize) {
+                *subr = range;
+            }
+            // Skip the NP (or `noaccess put`) that terminates the entry
+            *self = entry;
+            let mut terminator = self.clone();
+            while let Some(Token::Name(b"NP" | b"|" | b"noaccess" | b"put")) = terminator.next() {
+                *self = terminator.clone();
+            }
+        }
+        Ok(subrs)
+    }
+
+    /// Reads the charstrings dictionary, which is a sequence of entries of
+    /// the form `/name length RD <binary> ND`, terminated by `end`.
+    fn read_charstrings(&mut self) -> Result<Vec<Glyph>, Error> {
+        let mut glyphs = vec![];
+        while let Some(token) = self.next() {
+            match token {
+                Token::LiteralName(name) => {
+                    let mut entry = self.clone();
+                    let (Some(Token::Number(len)), Some(Token::Name(_))) =
+                        (entry.next(), entry.next())
+                    else {
+                        continue;
+                    };
+                    let charstring = entry.read_binary(len)?;
+                    glyphs.push(Glyph {
+                        name: String::from_utf8_lossy(name).into_owned(),
+                        charstring,
+                    });
+                    *self = entry;
+                }
+                Token::Name(b"end") => break,
+                _ => {}
+            }
+        }
+        Ok(glyphs)
+    }
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\r' | b'\n' | b'\x0C' | b'\0')
+}
+
+fn is_delimiter(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%'
+    )
+}
+
+/// Parses an integer, real or radix number.
+fn parse_number(token: &[u8]) -> Option<f64> {
+    let text = core::str::from_utf8(token).ok()?;
+    if let Some((radix, digits)) = text.split_once('#') {
+        let radix = radix.parse::<u32>().ok().filter(|r| (2..=36).contains(r))?;
+        return i64::from_str_radix(digits, radix).ok().map(|v| v as f64);
+    }
+    // Avoid accepting names like "inf" and "nan"
+    if !text.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '+' | '-' | '.')) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    //! Helpers for building Type 1 fonts from cleartext source.
+
+    use super::{CHARSTRING_KEY, EEXEC_KEY};
+
+    /// Encrypts data with the given key, prefixed by `prefix_len` bytes.
+    pub fn encrypt(data: &[u8], mut key: u16, prefix_len: usize) -> Vec<u8> {
+        let mut result = vec![];
+        for plain in std::iter::repeat_n(0, prefix_len).chain(data.iter().copied()) {
+            let cipher = plain ^ (key >> 8) as u8;
+            key = (cipher as u16)
+                .wrapping_add(key)
+                .wrapping_mul(52845)
+                .wrapping_add(22719);
+            result.push(cipher);
+        }
+        result
+    }
+
+    /// Builds the Private dictionary with the given entries, subroutines
+    /// and glyphs.
+    pub fn private_section(
+        private_entries: &str,
+        subrs: &[&[u8]],
+        glyphs: &[(&str, &[u8])],
+    ) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"dup /Private 8 dict dup begin\n");
+        data.extend_from_slice(b"/RD {string currentfile exch readstring pop} executeonly def\n");
+        data.extend_from_slice(private_entries.as_bytes());
+        data.extend_from_slice(format!("\n/Subrs {} array\n", subrs.len()).as_bytes());
+        for (i, subr) in subrs.iter().enumerate() {
+            let subr = encrypt(subr, CHARSTRING_KEY, 4);
+            data.extend_from_slice(format!("dup {i} {} RD ", subr.len()).as_bytes());
+            data.extend_from_slice(&subr);
+            data.extend_from_slice(b" NP\n");
+        }
+        data.extend_from_slice(b"ND\n2 index ");
+        data.extend_from_slice(
+            format!("/CharStrings {} dict dup begin\n", glyphs.len()).as_bytes(),
+        );
+        for (name, charstring) in glyphs {
+            let charstring = encrypt(charstring, CHARSTRING_KEY, 4);
+            data.extend_from_slice(format!("/{name} {} -| ", charstring.len()).as_bytes());
+            data.extend_from_slice(&charstring);
+            data.extend_from_slice(b" |-\n");
+        }
+        data.extend_from_slice(b"end\nend\nreadonly put\nnoaccess put\n");
+        data.extend_from_slice(
+            b"dup /FontName get exch definefont pop\nmark currentfile closefile\n",
+        );
+        data
+    }
+
+    /// Builds a PFB font from the cleartext font dictionary and the
+    /// unencrypted Private section.
+    pub fn pfb(cleartext: &str, private: &[u8]) -> Vec<u8> {
+        let encrypted = encrypt(private, EEXEC_KEY, 4);
+        let trailer = format!("{}\ncleartomark\n", "0".repeat(512));
+        let mut data = vec![];
+        for (segment_type, segment) in [
+            (1u8, cleartext.as_bytes()),
+            (2, &encrypted),
+            (1, trailer.as_bytes()),
+        ] {
+            data.extend_from_slice(&[0x80, segment_type]);
+            data.extend_from_slice(&(segment.len() as u32).to_le_bytes());
+            data.extend_from_slice(segment);
+        }
+        data.extend_from_slice(&[0x80, 3]);
+        data
+    }
+
+    /// Builds a PFA font from the cleartext font dictionary and the
+    /// unencrypted Private section.
+    pub fn pfa(cleartext: &str, private: &[u8]) -> Vec<u8> {
+        let encrypted = encrypt(private, EEXEC_KEY, 4);
+        let mut data = cleartext.as_bytes().to_vec();
+        for (i, byte) in encrypted.iter().enumerate() {
+            if i % 32 == 0 {
+                data.push(b'\n');
+            }
+            data.extend_from_slice(format!("{byte:02x}").as_bytes());
+        }
+        data.extend_from_slice(format!("\n{}\ncleartomark\n", "0".repeat(512)).as_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_helpers::*, *};
+
+    const CLEARTEXT: &str = "%!PS-AdobeFont-1.0: TestFont 001.000
+% a comment with /FontName /Wrong
+12 dict begin
+/FontInfo 2 dict dup begin
+/Notice (A (nested) string with \\) and /Names) readonly def
+end readonly def
+/FontName /TestFont def
+/Encoding 256 array
+0 1 255 {1 index exch /.notdef put} for
+dup 65 /A put
+dup 66 /B put
+readonly def
+/PaintType 0 def
+/FontType 1 def
+/FontMatrix [0.0005 0 0 0.0005 0 0] readonly def
+/FontBBox {-10 -20 1990 1800} readonly def
+currentdict end
+currentfile eexec
+";
+
+    const PRIVATE_ENTRIES: &str = "/BlueValues [-15 0 700 715] def
+/OtherBlues [-250 -240] def
+/BlueScale 0.0396 def
+/BlueShift 6 def
+/StdHW [50] def
+/StdVW [80] def
+/StemSnapH [50 60] def
+/ForceBold true def
+/lenIV 4 def
+/MinFeature {16 16} def
+/password 5839 def";
+
+    fn test_font_data() -> (String, Vec<u8>) {
+        let private = private_section(
+            PRIVATE_ENTRIES,
+            &[&[139, 11], &[140, 11]],
+            &[
+                ("A", &[139, 239, 13, 14]),
+                (".notdef", &[139, 139, 13, 14]),
+                ("B", &[139, 179, 13, 14]),
+            ],
+        );
+        (CLEARTEXT.to_string(), private)
+    }
+
+    #[test]
+    fn parse_pfb() {
+        let (cleartext, private) = test_font_data();
+        let font = Type1Font::new(&pfb(&cleartext, &private)).unwrap();
+        check_font(&font);
+    }
+
+    #[test]
+    fn parse_pfa() {
+        let (cleartext, private) = test_font_data();
+        let font = Type1Font::new(&pfa(&cleartext, &private)).unwrap();
+        check_font(&font);
+    }
+
+    fn check_font(font: &Type1Font) {
+        assert_eq!(font.font_name(), Some("TestFont"));
+        assert_eq!(font.font_matrix(), [0.0005, 0.0, 0.0, 0.0005, 0.0, 0.0]);
+        assert_eq!(font.units_per_em(), 2000);
+        assert_eq!(
+            font.font_bbox(),
+            [-10, -20, 1990, 1800].map(Fixed::from_i32)
+        );
+        // .notdef is moved to glyph 0
+        assert_eq!(font.num_glyphs(), 3);
+        assert_eq!(font.glyph_name(GlyphId::new(0)), Some(".notdef"));
+        assert_eq!(font.glyph_name(GlyphId::new(1)), Some("A"));
+        assert_eq!(font.glyph_name(GlyphId::new(2)), Some("B"));
+        assert_eq!(font.glyph_id("B"), Some(GlyphId::new(2)));
+        assert_eq!(font.glyph_id("C"), None);
+        assert_eq!(font.map_code(65), Some(GlyphId::new(1)));
+        assert_eq!(font.map_code(66), Some(GlyphId::new(2)));
+        assert_eq!(font.map_code(67), None);
+        // charstrings are decrypted with the lenIV bytes removed
+        assert_eq!(
+            font.charstring(GlyphId::new(1)),
+            Some(&[139, 239, 13, 14][..])
+        );
+        assert_eq!(font.subr(1).unwrap(), &[140, 11]);
+        assert!(font.subr(2).is_err());
+        let private = font.private_dict();
+        assert_eq!(
+            private.blue_values.values(),
+            &[
+                (Fixed::from_i32(-15), Fixed::ZERO),
+                (Fixed::from_i32(700), Fixed::from_i32(715))
+            ]
+        );
+        assert_eq!(
+            private.other_blues.values(),
+            &[(Fixed::from_i32(-250), Fixed::from_i32(-240))]
+        );
+        assert_eq!(private.blue_scale, Fixed::from_f64(0.0396));
+        assert_eq!(private.blue_shift, Fixed::from_i32(6));
+        assert_eq!(private.blue_fuzz, Fixed::ONE);
+        assert_eq!(private.std_hw, Some(Fixed::from_i32(50)));
+        assert_eq!(private.std_vw, Some(Fixed::from_i32(80)));
+        assert_eq!(
+            private.stem_snap_h.values(),
+            &[Fixed::from_i32(50), Fixed::from_i32(60)]
+        );
+        assert!(private.force_bold);
+        assert_eq!(private.len_iv, 4);
+    }
+
+    #[test]
+    fn standard_encoding() {
+        let cleartext = CLEARTEXT.replace(
+            "/Encoding 256 array\n0 1 255 {1 index exch /.notdef put} for\ndup 65 /A put\ndup 66 /B put\nreadonly def",
+            "/Encoding StandardEncoding def",
+        );
+        let (_, private) = test_font_data();
+        let font = Type1Font::new(&pfb(&cleartext, &private)).unwrap();
+        assert_eq!(font.map_code(b'A'), Some(GlyphId::new(1)));
+        assert_eq!(font.map_code(b'B'), Some(GlyphId::new(2)));
+        assert_eq!(font.map_code(b'a'), None);
+    }
+
+    #[test]
+    fn unencrypted_charstrings() {
+        let charstring = [139u8, 139, 13, 14];
+        let mut private = b"/lenIV -1 def\n/CharStrings 1 dict dup begin\n/.notdef 4 RD ".to_vec();
+        private.extend_from_slice(&charstring);
+        private.extend_from_slice(b" ND\nend\n");
+        let font = Type1Font::new(&pfb(CLEARTEXT, &private)).unwrap();
+        assert_eq!(font.charstring(GlyphId::new(0)), Some(&charstring[..]));
+    }
+
+    #[test]
+    fn malformed_lengths() {
+        for private in [
+            &b"/CharStrings 1 dict dup begin\n/a 1e30 RD x ND\nend\n"[..],
+            b"/CharStrings 1 dict dup begin\n/a -5 RD x ND\nend\n",
+            b"/CharStrings 1 dict dup begin\n/a 1.5 RD x ND\nend\n",
+            b"/Subrs 1 array\ndup 0 1e30 RD x NP\nND\n",
+        ] {
+            assert!(matches!(
+                Type1Font::new(&pfb(CLEARTEXT, private)),
+                Err(Error::Read(ReadError::OutOfBounds))
+            ));
+        }
+        // a huge subroutine count is bounded by the data
+        let private = b"/Subrs 1e18 array\ndup 0 4 RD xxxx NP\nND\n/CharStrings 1 dict dup begin\n/.notdef 4 RD xxxx ND\nend\n";
+        assert!(Type1Font::new(&pfb(CLEARTEXT, private)).is_ok());
+    }
+
+    #[test]
+    fn charstrings_in_cleartext() {
+        // binary data before eexec refers to the cleartext, so is ignored
+        let cleartext = CLEARTEXT.replace(
+            "currentfile eexec",
+            "/CharStrings 1 dict dup begin\n/.notdef 4 RD abcd ND\nend\ncurrentfile eexec",
+        );
+        let private = b"x";
+        assert!(matches!(
+            Type1Font::new(&pfa(&cleartext, private)),
+            Err(Error::MissingType1CharStrings)
+        ));
+        let (_, private) = test_font_data();
+        let font = Type1Font::new(&pfa(&cleartext, &private)).unwrap();
+        assert_eq!(font.num_glyphs(), 3);
+    }
+
+    #[test]
+    fn decryption() {
+        // Decryption is the inverse of encryption, skipping the random prefix
+        let plain = b"hello world";
+        let encrypted = encrypt(plain, EEXEC_KEY, 4);
+        assert_eq!(&decrypt(&encrypted, EEXEC_KEY)[4..], plain);
+    }
+
+    #[test]
+    fn tokens() {
+        let mut tokens =
+            Tokenizer::new(b"/Name 12 -3.5 16#ff [ ] { } (a (b) \\) c) name %comment\n<< >> -| RD");
+        let expected = [
+            Token::LiteralName(b"Name"),
+            Token::Number(12.0),
+            Token::Number(-3.5),
+            Token::Number(255.0),
+            Token::ArrayStart,
+            Token::ArrayEnd,
+            Token::ProcStart,
+            Token::ProcEnd,
+            Token::String,
+            Token::Name(b"name"),
+            Token::Name(b"<<"),
+            Token::Name(b">>"),
+            Token::Name(b"-|"),
+            Token::Name(b"RD"),
+        ];
+        for token in expected {
+            assert_eq!(tokens.next(), Some(token));
+        }
+        assert_eq!(tokens.next(), None);
+    }
+
+    #[test]
+    fn missing_sections() {
+        assert!(matches!(
+            Type1Font::new(CLEARTEXT.replace("eexec", "").as_bytes()),
+            Err(Error::MissingEexecData)
+        ));
+        assert!(matches!(
+            Type1Font::new(&[0x80, 5, 0, 0, 0, 0]),
+            Err(Error::InvalidPfbSegment(5))
+        ));
+        let private = private_section("", &[], &[]);
+        assert!(matches!(
+            Type1Font::new(&pfb(CLEARTEXT, &private)),
+            Err(Error::MissingType1CharStrings)
+        ));
+    }
+}
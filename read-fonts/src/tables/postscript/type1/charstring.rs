@@ -0,0 +1,841 @@
+//! Evaluation of Type 1 charstrings.
+//!
+//! See "6 CharString Commands" in the
+//! [Type 1 specification](https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=51).
+
+use types::Fixed;
+
+use super::{
+    super::{
+        charstring::{CommandSink, NESTING_DEPTH_LIMIT},
+        Error, Number, Stack,
+    },
+    standard_encoding_name, GlyphMetrics, Type1Font,
+};
+use crate::{Cursor, FontData};
+
+/// Number of points recorded between the start and end of a flex
+/// sequence: the reference point followed by the six points of the two
+/// curves.
+const FLEX_POINT_COUNT: usize = 7;
+
+/// Evaluates the given charstring and emits the resulting commands to the
+/// specified sink.
+pub(super) fn evaluate(
+    font: &Type1Font,
+    charstring_data: &[u8],
+    sink: &mut impl CommandSink,
+) -> Result<GlyphMetrics, Error> {
+    let mut evaluator = Evaluator::new(font, sink);
+    evaluator.evaluate(charstring_data, 0)?;
+    evaluator.close_path();
+    Ok(evaluator.metrics)
+}
+
+/// Transient state for evaluating a charstring and handling recursive
+/// subroutine calls.
+struct Evaluator<'a, S> {
+    font: &'a Type1Font,
+    sink: &'a mut S,
+    is_seac_component: bool,
+    is_open: bool,
+    is_done: bool,
+    metrics: GlyphMetrics,
+    /// Position of the glyph origin. This is only non-zero for the accent
+    /// component of a seac glyph.
+    origin_x: Fixed,
+    origin_y: Fixed,
+    x: Fixed,
+    y: Fixed,
+    stack: Stack,
+    /// Results of `callothersubr` that are retrieved by the `pop` operator.
+    ps_stack: Vec<Number>,
+    /// Points recorded during a flex sequence, or `None` when not in flex.
+    flex_points: Option<Vec<(Fixed, Fixed)>>,
+    stem_count: usize,
+    /// Index of the first stem that is active after hint replacement.
+    /// When set, a hint mask is emitted before the next path operator.
+    pending_hint_mask: Option<usize>,
+}
+
+impl<'a, S> Evaluator<'a, S>
+where
+    S: CommandSink,
+{
+    fn new(font: &'a Type1Font, sink: &'a mut S) -> Self {
+        Self {
+            font,
+            sink,
+            is_seac_component: false,
+            is_open: false,
+            is_done: false,
+            metrics: GlyphMetrics::default(),
+            origin_x: Fixed::ZERO,
+            origin_y: Fixed::ZERO,
+            x: Fixed::ZERO,
+            y: Fixed::ZERO,
+            stack: Stack::new(),
+            ps_stack: vec![],
+            flex_points: None,
+            stem_count: 0,
+            pending_hint_mask: None,
+        }
+    }
+
+    fn evaluate(&mut self, charstring_data: &[u8], nesting_depth: u32) -> Result<(), Error> {
+        if nesting_depth > NESTING_DEPTH_LIMIT {
+            return Err(Error::CharstringNestingDepthLimitExceeded);
+        }
+        let mut cursor = FontData::new(charstring_data).cursor();
+        while cursor.remaining_bytes() != 0 {
+            let b0 = cursor.read::<u8>()?;
+            match b0 {
+                // See "6.2 Charstring Number Encoding" <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=53>
+                //
+                // Unlike Type 2 charstrings, the 5 byte form is a 32-bit
+                // integer rather than 16.16 fixed point
+                255 => self.stack.push(cursor.read::<i32>()?)?,
+                32..=254 => self.stack.push(parse_int(&mut cursor, b0)?)?,
+                _ => {
+                    let operator = Operator::read(&mut cursor, b0)?;
+                    if !self.evaluate_operator(operator, nesting_depth)? {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates a single charstring operator.
+    ///
+    /// Returns `Ok(true)` if evaluation should continue.
+    fn evaluate_operator(&mut self, operator: Operator, nesting_depth: u32) -> Result<bool, Error> {
+        use Operator::*;
+        match operator {
+            // Sets the side bearing point and advance width
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=57>
+            Hsbw | Sbw => {
+                let metrics = if operator == Hsbw {
+                    let [sbx, wx] = self.stack.fixed_array::<2>(0)?;
+                    GlyphMetrics {
+                        side_bearing_x: sbx,
+                        advance_width: wx,
+                        ..Default::default()
+                    }
+                } else {
+                    let [sbx, sby, wx, wy] = self.stack.fixed_array::<4>(0)?;
+                    GlyphMetrics {
+                        side_bearing_x: sbx,
+                        side_bearing_y: sby,
+                        advance_width: wx,
+                        advance_height: wy,
+                    }
+                };
+                self.x = self.origin_x + metrics.side_bearing_x;
+                self.y = self.origin_y + metrics.side_bearing_y;
+                self.metrics = metrics;
+                self.stack.clear();
+            }
+            // Stem hints, relative to the side bearing point
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=60>
+            HStem | VStem | HStem3 | VStem3 => {
+                let count = if matches!(operator, HStem | VStem) {
+                    1
+                } else {
+                    3
+                };
+                let is_horizontal = matches!(operator, HStem | HStem3);
+                let base = if is_horizontal {
+                    self.origin_y + self.metrics.side_bearing_y
+                } else {
+                    self.origin_x + self.metrics.side_bearing_x
+                };
+                for i in 0..count {
+                    let [pos, size] = self.stack.fixed_array::<2>(i * 2)?;
+                    let min = base + pos;
+                    let max = min.wrapping_add(size);
+                    if is_horizontal {
+                        self.sink.hstem(min, max);
+                    } else {
+                        self.sink.vstem(min, max);
+                    }
+                }
+                self.stem_count += count;
+                self.stack.clear();
+            }
+            // Hint replacement is handled by othersubr 3 and flex by
+            // othersubrs 0-2, so dot sections are simply ignored
+            DotSection => self.stack.clear(),
+            // Moveto operators start a new subpath. During flex, these
+            // only update the current point
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=58>
+            RMoveTo | HMoveTo | VMoveTo => {
+                let (dx, dy) = match operator {
+                    RMoveTo => (self.stack.get_fixed(0)?, self.stack.get_fixed(1)?),
+                    HMoveTo => (self.stack.get_fixed(0)?, Fixed::ZERO),
+                    _ => (Fixed::ZERO, self.stack.get_fixed(0)?),
+                };
+                self.x += dx;
+                self.y += dy;
+                if self.flex_points.is_none() {
+                    self.close_path();
+                    self.emit_hint_mask();
+                    self.is_open = true;
+                    self.sink.move_to(self.x, self.y);
+                }
+                self.stack.clear();
+            }
+            RLineTo | HLineTo | VLineTo => {
+                match operator {
+                    RLineTo => {
+                        self.x += self.stack.get_fixed(0)?;
+                        self.y += self.stack.get_fixed(1)?;
+                    }
+                    HLineTo => self.x += self.stack.get_fixed(0)?,
+                    _ => self.y += self.stack.get_fixed(0)?,
+                }
+                self.begin_segment();
+                self.sink.line_to(self.x, self.y);
+                self.stack.clear();
+            }
+            RrCurveTo | VhCurveTo | HvCurveTo => {
+                let args = match operator {
+                    RrCurveTo => self.stack.fixed_array::<6>(0)?,
+                    VhCurveTo => {
+                        let [dy1, dx2, dy2, dx3] = self.stack.fixed_array::<4>(0)?;
+                        [Fixed::ZERO, dy1, dx2, dy2, dx3, Fixed::ZERO]
+                    }
+                    _ => {
+                        let [dx1, dx2, dy2, dy3] = self.stack.fixed_array::<4>(0)?;
+                        [dx1, Fixed::ZERO, dx2, dy2, Fixed::ZERO, dy3]
+                    }
+                };
+                self.begin_segment();
+                let [dx1, dy1, dx2, dy2, dx3, dy3] = args;
+                let (x1, y1) = (self.x + dx1, self.y + dy1);
+                let (x2, y2) = (x1 + dx2, y1 + dy2);
+                self.x = x2 + dx3;
+                self.y = y2 + dy3;
+                self.sink.curve_to(x1, y1, x2, y2, self.x, self.y);
+                self.stack.clear();
+            }
+            ClosePath => {
+                self.close_path();
+                self.stack.clear();
+            }
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=60>
+            CallSubr => {
+                let index = self.stack.pop_i32()?;
+                let subr = self.font.subr(index)?;
+                self.evaluate(subr, nesting_depth + 1)?;
+                // Terminate enclosing charstrings on endchar
+                if self.is_done {
+                    return Ok(false);
+                }
+            }
+            Return => return Ok(false),
+            EndChar => {
+                self.close_path();
+                self.stack.clear();
+                self.is_done = true;
+                return Ok(false);
+            }
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=59>
+            Seac => {
+                self.evaluate_seac()?;
+                self.stack.clear();
+                self.is_done = true;
+                return Ok(false);
+            }
+            // Division produces a real number from two integers, often
+            // used for values that don't fit in the number encoding
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=61>
+            Div => {
+                let b = number_to_f64(self.stack.pop_number()?);
+                let a = number_to_f64(self.stack.pop_number()?);
+                let result = if b != 0.0 { a / b } else { 0.0 };
+                self.stack.push(Fixed::from_f64(result))?;
+            }
+            // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=61>
+            CallOtherSubr => self.call_other_subr()?,
+            Pop => {
+                let value = self.ps_stack.pop().ok_or(Error::StackUnderflow)?;
+                self.stack.push(value)?;
+            }
+            SetCurrentPoint => {
+                let [x, y] = self.stack.fixed_array::<2>(0)?;
+                self.x = self.origin_x + x;
+                self.y = self.origin_y + y;
+                self.stack.clear();
+            }
+        }
+        Ok(true)
+    }
+
+    /// Handles the predefined other subroutines for flex and hint
+    /// replacement. Arguments to unknown other subroutines are returned
+    /// unchanged.
+    ///
+    /// Spec: "8 Using Subroutines" <https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf#page=67>
+    fn call_other_subr(&mut self) -> Result<(), Error> {
+        let other_subr = self.stack.pop_i32()?;
+        let arg_count = self.stack.pop_i32()?;
+        let arg_count = usize::try_from(arg_count).map_err(|_| Error::StackUnderflow)?;
+        self.stack.verify_at_least_len(arg_count)?;
+        let mut raw_args = Vec::with_capacity(arg_count);
+        for _ in 0..arg_count {
+            raw_args.push(self.stack.pop_number()?);
+        }
+        // The args are now in reverse order, which is the order in which
+        // they are retrieved by `pop`
+        self.ps_stack.clear();
+        match other_subr {
+            // End flex
+            0 => {
+                let points = self.flex_points.take().ok_or(Error::InvalidFlex)?;
+                if arg_count != 3 || points.len() != FLEX_POINT_COUNT {
+                    return Err(Error::InvalidFlex);
+                }
+                // The first point is the reference point which is only
+                // used by hinting
+                self.begin_segment();
+                for curve in points[1..].chunks_exact(3) {
+                    let [(x1, y1), (x2, y2), (x3, y3)] = [curve[0], curve[1], curve[2]];
+                    self.sink.curve_to(x1, y1, x2, y2, x3, y3);
+                }
+                // The results are the end point of the flex, which are
+                // then applied with setcurrentpoint
+                self.ps_stack
+                    .extend([self.y - self.origin_y, self.x - self.origin_x].map(Number::from));
+            }
+            // Start flex
+            1 => {
+                if arg_count != 0 {
+                    return Err(Error::InvalidFlex);
+                }
+                self.flex_points = Some(Vec::with_capacity(FLEX_POINT_COUNT));
+            }
+            // Add flex point
+            2 => {
+                let points = self.flex_points.as_mut().ok_or(Error::InvalidFlex)?;
+                if arg_count != 0 || points.len() >= FLEX_POINT_COUNT {
+                    return Err(Error::InvalidFlex);
+                }
+                points.push((self.x, self.y));
+            }
+            // Hint replacement. The argument is the subroutine containing
+            // the new hints, which is called after this returns
+            3 => {
+                if arg_count != 1 {
+                    return Err(Error::StackUnderflow);
+                }
+                self.pending_hint_mask = Some(self.stem_count);
+                self.ps_stack.extend(raw_args);
+            }
+            _ => self.ps_stack.extend(raw_args),
+        }
+        Ok(())
+    }
+
+    /// Evaluates the base and accent components of an accented glyph.
+    ///
+    /// The stack contains `asb adx ady bchar achar` where the characters
+    /// are codes in the standard encoding.
+    fn evaluate_seac(&mut self) -> Result<(), Error> {
+        if self.is_seac_component {
+            return Err(Error::NestedSeac);
+        }
+        let [asb, adx, ady] = self.stack.fixed_array::<3>(0)?;
+        let base_code = self.stack.get_i32(3)?;
+        let accent_code = self.stack.get_i32(4)?;
+        // This matches FreeType, which offsets the accent by the difference
+        // between the given side bearing and the accent's own side bearing
+        for (code, x, y) in [
+            (accent_code, adx - asb, ady),
+            (base_code, Fixed::ZERO, Fixed::ZERO),
+        ] {
+            let glyph_id = u8::try_from(code)
+                .ok()
+                .and_then(standard_encoding_name)
+                .and_then(|name| self.font.glyph_id(name))
+                .ok_or(Error::InvalidSeacCode(code))?;
+            let charstring = self
+                .font
+                .charstring(glyph_id)
+                .ok_or(Error::InvalidSeacCode(code))?;
+            let mut evaluator = Evaluator::new(self.font, &mut *self.sink);
+            evaluator.is_seac_component = true;
+            evaluator.origin_x = x;
+            evaluator.origin_y = y;
+            evaluator.evaluate(charstring, 0)?;
+            evaluator.close_path();
+        }
+        Ok(())
+    }
+
+    /// Emits a hint mask if hints were replaced since the last path
+    /// operator.
+    fn emit_hint_mask(&mut self) {
+        let Some(first_stem) = self.pending_hint_mask.take() else {
+            return;
+        };
+        let mut mask = vec![0u8; self.stem_count.div_ceil(8)];
+        for stem in first_stem..self.stem_count {
+            mask[stem / 8] |= 0x80 >> (stem % 8);
+        }
+        self.sink.hint_mask(&mask);
+    }
+
+    /// Prepares for a line or curve segment, starting a new subpath at the
+    /// current point if one isn't already open.
+    fn begin_segment(&mut self) {
+        self.emit_hint_mask();
+        if !self.is_open {
+            self.is_open = true;
+            self.sink.move_to(self.x, self.y);
+        }
+    }
+
+    fn close_path(&mut self) {
+        if self.is_open {
+            self.is_open = false;
+            self.sink.close();
+        }
+    }
+}
+
+fn parse_int(cursor: &mut Cursor, b0: u8) -> Result<i32, Error> {
+    // Shares the 1 and 2 byte forms with CFF
+    super::super::dict::parse_int(cursor, b0)
+}
+
+fn number_to_f64(number: Number) -> f64 {
+    match number {
+        Number::I32(value) => value as f64,
+        Number::Fixed(value) => value.to_f64(),
+    }
+}
+
+/// Type 1 charstring operators.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Operator {
+    HStem,
+    VStem,
+    VMoveTo,
+    RLineTo,
+    HLineTo,
+    VLineTo,
+    RrCurveTo,
+    ClosePath,
+    CallSubr,
+    Return,
+    Hsbw,
+    EndChar,
+    RMoveTo,
+    HMoveTo,
+    VhCurveTo,
+    HvCurveTo,
+    DotSection,
+    VStem3,
+    HStem3,
+    Seac,
+    Sbw,
+    Div,
+    CallOtherSubr,
+    Pop,
+    SetCurrentPoint,
+}
+
+impl Operator {
+    fn read(cursor: &mut Cursor, b0: u8) -> Result<Self, Error> {
+        // Escape opcode for accessing two byte operators
+        const ESCAPE: u8 = 12;
+        use Operator::*;
+        let (opcode, operator) = if b0 == ESCAPE {
+            let b1 = cursor.read::<u8>()?;
+            let operator = match b1 {
+                0 => Some(DotSection),
+                1 => Some(VStem3),
+                2 => Some(HStem3),
+                6 => Some(Seac),
+                7 => Some(Sbw),
+                12 => Some(Div),
+                16 => Some(CallOtherSubr),
+                17 => Some(Pop),
+                33 => Some(SetCurrentPoint),
+                _ => None,
+            };
+            (b1, operator)
+        } else {
+            let operator = match b0 {
+                1 => Some(HStem),
+                3 => Some(VStem),
+                4 => Some(VMoveTo),
+                5 => Some(RLineTo),
+                6 => Some(HLineTo),
+                7 => Some(VLineTo),
+                8 => Some(RrCurveTo),
+                9 => Some(ClosePath),
+                10 => Some(CallSubr),
+                11 => Some(Return),
+                13 => Some(Hsbw),
+                14 => Some(EndChar),
+                21 => Some(RMoveTo),
+                22 => Some(HMoveTo),
+                30 => Some(VhCurveTo),
+                31 => Some(HvCurveTo),
+                _ => None,
+            };
+            (b0, operator)
+        };
+        operator.ok_or(Error::InvalidCharstringOperator(opcode))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_helpers::{pfb, private_section};
+    use super::*;
+    use types::GlyphId;
+
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    enum Command {
+        MoveTo(f64, f64),
+        LineTo(f64, f64),
+        CurveTo(f64, f64, f64, f64, f64, f64),
+        Close,
+        HStem(f64, f64),
+        VStem(f64, f64),
+        HintMask(u8),
+    }
+
+    #[derive(Default)]
+    struct CaptureCommandSink(Vec<Command>);
+
+    impl CommandSink for CaptureCommandSink {
+        fn move_to(&mut self, x: Fixed, y: Fixed) {
+            self.0.push(Command::MoveTo(x.to_f64(), y.to_f64()))
+        }
+
+        fn line_to(&mut self, x: Fixed, y: Fixed) {
+            self.0.push(Command::LineTo(x.to_f64(), y.to_f64()))
+        }
+
+        fn curve_to(&mut self, cx0: Fixed, cy0: Fixed, cx1: Fixed, cy1: Fixed, x: Fixed, y: Fixed) {
+            self.0.push(Command::CurveTo(
+                cx0.to_f64(),
+                cy0.to_f64(),
+                cx1.to_f64(),
+                cy1.to_f64(),
+                x.to_f64(),
+                y.to_f64(),
+            ))
+        }
+
+        fn close(&mut self) {
+            self.0.push(Command::Close)
+        }
+
+        fn hstem(&mut self, y: Fixed, dy: Fixed) {
+            self.0.push(Command::HStem(y.to_f64(), dy.to_f64()))
+        }
+
+        fn vstem(&mut self, x: Fixed, dx: Fixed) {
+            self.0.push(Command::VStem(x.to_f64(), dx.to_f64()))
+        }
+
+        fn hint_mask(&mut self, mask: &[u8]) {
+            self.0.push(Command::HintMask(mask[0]))
+        }
+    }
+
+    /// Encodes a charstring from space separated numbers and operator
+    /// names.
+    fn assemble(source: &str) -> Vec<u8> {
+        let mut data = vec![];
+        for token in source.split_whitespace() {
+            if let Ok(value) = token.parse::<i32>() {
+                if (-107..=107).contains(&value) {
+                    data.push((value + 139) as u8);
+                } else {
+                    data.push(255);
+                    data.extend_from_slice(&value.to_be_bytes());
+                }
+                continue;
+            }
+            let op: &[u8] = match token {
+                "hstem" => &[1],
+                "vstem" => &[3],
+                "vmoveto" => &[4],
+                "rlineto" => &[5],
+                "hlineto" => &[6],
+                "vlineto" => &[7],
+                "rrcurveto" => &[8],
+                "closepath" => &[9],
+                "callsubr" => &[10],
+                "return" => &[11],
+                "hsbw" => &[13],
+                "endchar" => &[14],
+                "rmoveto" => &[21],
+                "hmoveto" => &[22],
+                "vhcurveto" => &[30],
+                "hvcurveto" => &[31],
+                "dotsection" => &[12, 0],
+                "vstem3" => &[12, 1],
+                "hstem3" => &[12, 2],
+                "seac" => &[12, 6],
+                "sbw" => &[12, 7],
+                "div" => &[12, 12],
+                "callothersubr" => &[12, 16],
+                "pop" => &[12, 17],
+                "setcurrentpoint" => &[12, 33],
+                _ => panic!("unknown operator {token}"),
+            };
+            data.extend_from_slice(op);
+        }
+        data
+    }
+
+    fn make_font(subrs: &[&str], glyphs: &[(&str, &str)]) -> Type1Font {
+        let subrs = subrs.iter().map(|s| assemble(s)).collect::<Vec<_>>();
+        let subrs = subrs.iter().map(|s| s.as_slice()).collect::<Vec<_>>();
+        let glyphs = glyphs
+            .iter()
+            .map(|(name, source)| (*name, assemble(source)))
+            .collect::<Vec<_>>();
+        let glyphs = glyphs
+            .iter()
+            .map(|(name, data)| (*name, data.as_slice()))
+            .collect::<Vec<_>>();
+        let cleartext = "/FontName /Test def\n/Encoding StandardEncoding def\ncurrentfile eexec\n";
+        Type1Font::new(&pfb(cleartext, &private_section("", &subrs, &glyphs))).unwrap()
+    }
+
+    fn draw(font: &Type1Font, name: &str) -> Result<(GlyphMetrics, Vec<Command>), Error> {
+        let mut sink = CaptureCommandSink::default();
+        let metrics = font.evaluate(font.glyph_id(name).unwrap(), &mut sink)?;
+        Ok((metrics, sink.0))
+    }
+
+    #[test]
+    fn path_operators() {
+        use Command::*;
+        let font = make_font(
+            &[],
+            &[(
+                "a",
+                "50 600 hsbw 0 10 rmoveto 100 hlineto 1000 10 div vlineto \
+                 10 20 30 40 hvcurveto 10 20 30 40 vhcurveto \
+                 1 2 3 4 5 6 rrcurveto closepath 20 hmoveto -20 -20 rlineto endchar",
+            )],
+        );
+        let (metrics, commands) = draw(&font, "a").unwrap();
+        assert_eq!(metrics.side_bearing_x, Fixed::from_i32(50));
+        assert_eq!(metrics.advance_width, Fixed::from_i32(600));
+        assert_eq!(
+            commands,
+            [
+                MoveTo(50.0, 10.0),
+                LineTo(150.0, 10.0),
+                LineTo(150.0, 110.0),
+                CurveTo(160.0, 110.0, 180.0, 140.0, 180.0, 180.0),
+                CurveTo(180.0, 190.0, 200.0, 220.0, 240.0, 220.0),
+                CurveTo(241.0, 222.0, 244.0, 226.0, 249.0, 232.0),
+                Close,
+                MoveTo(269.0, 232.0),
+                LineTo(249.0, 212.0),
+                Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn sbw_metrics() {
+        let font = make_font(&[], &[("a", "10 20 600 30 sbw 0 0 rmoveto endchar")]);
+        let (metrics, commands) = draw(&font, "a").unwrap();
+        assert_eq!(
+            metrics,
+            GlyphMetrics {
+                side_bearing_x: Fixed::from_i32(10),
+                side_bearing_y: Fixed::from_i32(20),
+                advance_width: Fixed::from_i32(600),
+                advance_height: Fixed::from_i32(30),
+            }
+        );
+        assert_eq!(commands, [Command::MoveTo(10.0, 20.0), Command::Close]);
+    }
+
+    #[test]
+    fn subroutines() {
+        use Command::*;
+        // endchar in a subroutine terminates the glyph
+        let font = make_font(
+            &["10 0 rlineto return", "0 callsubr 0 10 rlineto endchar"],
+            &[(
+                "a",
+                "0 500 hsbw 0 0 rmoveto 0 callsubr 1 callsubr 50 50 rlineto",
+            )],
+        );
+        let (_, commands) = draw(&font, "a").unwrap();
+        assert_eq!(
+            commands,
+            [
+                MoveTo(0.0, 0.0),
+                LineTo(10.0, 0.0),
+                LineTo(20.0, 0.0),
+                LineTo(20.0, 10.0),
+                Close,
+            ]
+        );
+        let font = make_font(&["0 callsubr"], &[("a", "0 callsubr")]);
+        assert!(matches!(
+            draw(&font, "a"),
+            Err(Error::CharstringNestingDepthLimitExceeded)
+        ));
+        let font = make_font(&[], &[("a", "5 callsubr")]);
+        assert!(matches!(
+            draw(&font, "a"),
+            Err(Error::Read(crate::ReadError::OutOfBounds))
+        ));
+    }
+
+    #[test]
+    fn flex() {
+        use Command::*;
+        let font = make_font(
+            &[],
+            &[(
+                "a",
+                "0 500 hsbw 0 0 rmoveto 0 1 callothersubr \
+                 50 10 rmoveto 0 2 callothersubr \
+                 -40 0 rmoveto 0 2 callothersubr \
+                 20 10 rmoveto 0 2 callothersubr \
+                 20 0 rmoveto 0 2 callothersubr \
+                 20 0 rmoveto 0 2 callothersubr \
+                 20 -10 rmoveto 0 2 callothersubr \
+                 10 -10 rmoveto 0 2 callothersubr \
+                 50 100 0 3 0 callothersubr pop pop setcurrentpoint \
+                 0 -20 rlineto closepath endchar",
+            )],
+        );
+        let (_, commands) = draw(&font, "a").unwrap();
+        assert_eq!(
+            commands,
+            [
+                MoveTo(0.0, 0.0),
+                CurveTo(10.0, 10.0, 30.0, 20.0, 50.0, 20.0),
+                CurveTo(70.0, 20.0, 90.0, 10.0, 100.0, 0.0),
+                LineTo(100.0, -20.0),
+                Close,
+            ]
+        );
+        // ending flex with too few points
+        let font = make_font(
+            &[],
+            &[(
+                "a",
+                "0 500 hsbw 0 0 rmoveto 0 1 callothersubr \
+                 50 10 rmoveto 0 2 callothersubr 50 0 0 3 0 callothersubr",
+            )],
+        );
+        assert!(matches!(draw(&font, "a"), Err(Error::InvalidFlex)));
+    }
+
+    #[test]
+    fn hint_replacement() {
+        use Command::*;
+        let font = make_font(
+            &["return", "0 30 hstem 5 10 vstem return"],
+            &[(
+                "a",
+                "5 500 hsbw 0 10 hstem 0 20 vstem 0 0 rmoveto 10 0 rlineto \
+                 1 1 3 callothersubr pop callsubr 10 0 rlineto closepath endchar",
+            )],
+        );
+        let (_, commands) = draw(&font, "a").unwrap();
+        assert_eq!(
+            commands,
+            [
+                HStem(0.0, 10.0),
+                VStem(5.0, 25.0),
+                MoveTo(5.0, 0.0),
+                LineTo(15.0, 0.0),
+                HStem(0.0, 30.0),
+                VStem(10.0, 20.0),
+                HintMask(0b0011_0000),
+                LineTo(25.0, 0.0),
+                Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_other_subr_returns_args() {
+        use Command::*;
+        let font = make_font(
+            &[],
+            &[(
+                "a",
+                "0 500 hsbw 10 20 2 99 callothersubr pop pop rmoveto endchar",
+            )],
+        );
+        let (_, commands) = draw(&font, "a").unwrap();
+        assert_eq!(commands, [MoveTo(10.0, 20.0), Close]);
+    }
+
+    #[test]
+    fn seac() {
+        use Command::*;
+        let font = make_font(
+            &[],
+            &[
+                (
+                    "A",
+                    "10 600 hsbw 0 0 rmoveto 100 0 rlineto closepath endchar",
+                ),
+                (
+                    "acute",
+                    "20 300 hsbw 0 0 rmoveto 0 50 rlineto closepath endchar",
+                ),
+                ("Aacute", "10 600 hsbw 20 100 200 65 194 seac"),
+                ("nested", "10 600 hsbw 0 0 0 65 194 seac"),
+                ("Anested", "10 600 hsbw 0 0 0 65 0 seac"),
+            ],
+        );
+        let (metrics, commands) = draw(&font, "Aacute").unwrap();
+        assert_eq!(metrics.side_bearing_x, Fixed::from_i32(10));
+        assert_eq!(metrics.advance_width, Fixed::from_i32(600));
+        assert_eq!(
+            commands,
+            [
+                MoveTo(100.0, 200.0),
+                LineTo(100.0, 250.0),
+                Close,
+                MoveTo(10.0, 0.0),
+                LineTo(110.0, 0.0),
+                Close,
+            ]
+        );
+        assert!(matches!(
+            draw(&font, "Anested"),
+            Err(Error::InvalidSeacCode(0))
+        ));
+        let font = make_font(
+            &[],
+            &[
+                ("A", "0 600 hsbw 0 0 0 65 194 seac"),
+                ("acute", "0 300 hsbw endchar"),
+                ("Aacute", "0 600 hsbw 0 0 0 65 194 seac"),
+            ],
+        );
+        assert!(matches!(draw(&font, "Aacute"), Err(Error::NestedSeac)));
+    }
+
+    #[test]
+    fn glyph_out_of_bounds() {
+        let font = make_font(&[], &[("a", "0 500 hsbw endchar")]);
+        let mut sink = CaptureCommandSink::default();
+        assert!(font.evaluate(GlyphId::new(5), &mut sink).is_err());
+    }
+}
@@ -0,0 +1,316 @@
+//! The [SVG](https://learn.microsoft.com/en-us/typography/opentype/spec/svg) table
+
+#[cfg(feature = "gzip")]
+use alloc::{borrow::Cow, vec::Vec};
+
+include!("../../generated/generated_svg.rs");
+
+/// Maximum size of a decompressed SVG document.
+#[cfg(feature = "gzip")]
+const MAX_DECOMPRESSED_LEN: usize = 64 * 1024 * 1024;
+
+impl<'a> Svg<'a> {
+    /// Returns the SVG document containing the given glyph, if any.
+    pub fn glyph_document(
+        &self,
+        glyph_id: impl Into<GlyphId>,
+    ) -> Option<Result<SvgDocument<'a>, ReadError>> {
+        let list = match self.svg_document_list() {
+            Ok(list) => list,
+            Err(e) => return Some(Err(e)),
+        };
+        let record = list.record_for_glyph(glyph_id)?;
+        Some(SvgDocument::new(list.offset_data(), record))
+    }
+
+    /// Returns an iterator over all SVG documents in the table, in the
+    /// order of the document records.
+    ///
+    /// Multiple records may refer to the same document.
+    pub fn documents(
+        &self,
+    ) -> Result<impl Iterator<Item = Result<SvgDocument<'a>, ReadError>> + 'a, ReadError> {
+        let list = self.svg_document_list()?;
+        let data = list.offset_data();
+        Ok(list
+            .document_records()
+            .iter()
+            .map(move |record| SvgDocument::new(data, record)))
+    }
+}
+
+impl<'a> SvgDocumentList<'a> {
+    /// Returns the document record for the range containing the given
+    /// glyph, if any.
+    pub fn record_for_glyph(&self, glyph_id: impl Into<GlyphId>) -> Option<&'a SvgDocumentRecord> {
+        let glyph_id: GlyphId16 = glyph_id.into().try_into().ok()?;
+        let records = self.document_records();
+        let idx = records
+            .binary_search_by(|record| {
+                if record.end_glyph_id() < glyph_id {
+                    std::cmp::Ordering::Less
+                } else if record.start_glyph_id() > glyph_id {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .ok()?;
+        records.get(idx)
+    }
+}
+
+impl SvgDocumentRecord {
+    /// Returns the raw, possibly compressed, document data for this record.
+    ///
+    /// The `data` argument should be retrieved from the parent
+    /// [`SvgDocumentList`] by calling its `offset_data` method.
+    pub fn document_data<'a>(&self, data: FontData<'a>) -> Result<&'a [u8], ReadError> {
+        let start = self.svg_doc_offset() as usize;
+        let end = start
+            .checked_add(self.svg_doc_length() as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        data.as_bytes()
+            .get(start..end)
+            .ok_or(ReadError::OutOfBounds)
+    }
+}
+
+/// An SVG document, along with the range of glyphs it contains.
+///
+/// Documents may be stored as plain text or gzip compressed. Use `data`
+/// (requires the `gzip` feature) to transparently access the uncompressed
+/// document.
+#[derive(Clone, Copy, Debug)]
+pub struct SvgDocument<'a> {
+    start_glyph_id: GlyphId16,
+    end_glyph_id: GlyphId16,
+    raw_data: &'a [u8],
+}
+
+impl<'a> SvgDocument<'a> {
+    fn new(data: FontData<'a>, record: &SvgDocumentRecord) -> Result<Self, ReadError> {
+        Ok(Self {
+            start_glyph_id: record.start_glyph_id(),
+            end_glyph_id: record.end_glyph_id(),
+            raw_data: record.document_data(data)?,
+        })
+    }
+
+    /// The first glyph in the range covered by this document.
+    pub fn start_glyph_id(&self) -> GlyphId16 {
+        self.start_glyph_id
+    }
+
+    /// The last glyph in the range covered by this document.
+    pub fn end_glyph_id(&self) -> GlyphId16 {
+        self.end_glyph_id
+    }
+
+    /// The document data, as stored in the font.
+    pub fn raw_data(&self) -> &'a [u8] {
+        self.raw_data
+    }
+
+    /// Returns true if the document data is gzip compressed.
+    pub fn is_compressed(&self) -> bool {
+        self.raw_data.starts_with(&[0x1F, 0x8B])
+    }
+
+    /// Returns the uncompressed document data.
+    ///
+    /// Documents that are not compressed are returned without copying.
+    #[cfg(feature = "gzip")]
+    pub fn data(&self) -> Result<Cow<'a, [u8]>, ReadError> {
+        if self.is_compressed() {
+            decompress_gzip(self.raw_data).map(Cow::Owned)
+        } else {
+            Ok(Cow::Borrowed(self.raw_data))
+        }
+    }
+}
+
+/// Decompresses a gzip member as described in
+/// [RFC 1952](https://www.rfc-editor.org/rfc/rfc1952).
+#[cfg(feature = "gzip")]
+fn decompress_gzip(data: &[u8]) -> Result<Vec<u8>, ReadError> {
+    const FHCRC: u8 = 0x02;
+    const FEXTRA: u8 = 0x04;
+    const FNAME: u8 = 0x08;
+    const FCOMMENT: u8 = 0x10;
+    const DEFLATE: u8 = 8;
+    let malformed = || ReadError::MalformedData("invalid gzip compressed SVG document");
+    let data = FontData::new(data);
+    let mut cursor = data.cursor();
+    let magic: u16 = cursor.read()?;
+    let method: u8 = cursor.read()?;
+    let flags: u8 = cursor.read()?;
+    if magic != 0x1F8B || method != DEFLATE {
+        return Err(malformed());
+    }
+    // skip modification time, extra flags and operating system
+    cursor.advance_by(6);
+    if flags & FEXTRA != 0 {
+        // this length is little endian
+        let len: u16 = cursor.read()?;
+        cursor.advance_by(len.swap_bytes() as usize);
+    }
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            // skip a zero terminated string
+            while cursor.read::<u8>()? != 0 {}
+        }
+    }
+    if flags & FHCRC != 0 {
+        cursor.advance_by(2);
+    }
+    let start = cursor.position()?;
+    // the trailer contains a CRC-32 and the uncompressed size
+    let end = data.len().checked_sub(8).ok_or_else(malformed)?;
+    let deflated = data.as_bytes().get(start..end).ok_or_else(malformed)?;
+    let expected_len = u32::from_le_bytes(data.as_bytes()[end + 4..].try_into().unwrap());
+    let inflated =
+        miniz_oxide::inflate::decompress_to_vec_with_limit(deflated, MAX_DECOMPRESSED_LEN)
+            .map_err(|_| malformed())?;
+    if inflated.len() as u32 != expected_len {
+        return Err(malformed());
+    }
+    Ok(inflated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    const DOC: &[u8] = b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>";
+
+    // DOC, compressed with gzip
+    const GZIP_DOC: &[u8] = &[
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 179, 41, 46, 75, 87, 168, 200, 205, 201, 43, 182, 85, 202,
+        40, 41, 41, 176, 210, 215, 47, 47, 47, 215, 43, 55, 214, 203, 47, 74, 215, 55, 50, 48, 48,
+        208, 7, 170, 80, 210, 183, 3, 0, 196, 247, 145, 147, 41, 0, 0, 0,
+    ];
+
+    fn make_svg(docs: &[&[u8]]) -> BeBuffer {
+        let mut buf = BeBuffer::new()
+            .push(0u16) // version
+            .push(10u32) // document list offset
+            .push(0u32) // reserved
+            .push(3u16); // num entries
+        let docs_start = 2 + 3 * 12;
+        // glyph ranges 2-4 and 8-8 share the first document, 10-11 use
+        // the second
+        let doc_offsets = [0, 0, 1].map(|idx| {
+            let offset = docs_start + docs[..idx].iter().map(|doc| doc.len()).sum::<usize>();
+            (offset as u32, docs[idx].len() as u32)
+        });
+        for ((start, end), (offset, len)) in
+            [(2u16, 4u16), (8, 8), (10, 11)].iter().zip(doc_offsets)
+        {
+            buf = buf
+                .push(GlyphId16::new(*start))
+                .push(GlyphId16::new(*end))
+                .push(offset)
+                .push(len);
+        }
+        for doc in docs {
+            buf = buf.extend(doc.iter().copied());
+        }
+        buf
+    }
+
+    #[test]
+    fn glyph_documents() {
+        let buf = make_svg(&[DOC, GZIP_DOC]);
+        let svg = Svg::read(buf.font_data()).unwrap();
+        for gid in [0u16, 1, 5, 7, 9, 12] {
+            assert!(svg.glyph_document(GlyphId16::new(gid)).is_none());
+        }
+        for gid in [2u16, 3, 4, 8] {
+            let doc = svg.glyph_document(GlyphId16::new(gid)).unwrap().unwrap();
+            assert_eq!(doc.raw_data(), DOC);
+            assert!(!doc.is_compressed());
+        }
+        let doc = svg.glyph_document(GlyphId16::new(11)).unwrap().unwrap();
+        assert_eq!(doc.start_glyph_id(), GlyphId16::new(10));
+        assert_eq!(doc.end_glyph_id(), GlyphId16::new(11));
+        assert_eq!(doc.raw_data(), GZIP_DOC);
+        assert!(doc.is_compressed());
+    }
+
+    #[test]
+    fn iter_documents() {
+        let buf = make_svg(&[DOC, GZIP_DOC]);
+        let svg = Svg::read(buf.font_data()).unwrap();
+        let ranges = svg
+            .documents()
+            .unwrap()
+            .map(|doc| {
+                let doc = doc.unwrap();
+                (doc.start_glyph_id().to_u16(), doc.end_glyph_id().to_u16())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, [(2, 4), (8, 8), (10, 11)]);
+    }
+
+    #[test]
+    fn unreadable_document_list() {
+        let buf = BeBuffer::new()
+            .push(0u16) // version
+            .push(0xFFFFu32) // svg document list offset
+            .push(0u32); // reserved
+        let svg = Svg::read(buf.font_data()).unwrap();
+        assert!(svg.documents().is_err());
+        assert!(matches!(
+            svg.glyph_document(GlyphId16::new(2)),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn document_out_of_bounds() {
+        let mut buf = make_svg(&[DOC, GZIP_DOC]).to_vec();
+        buf.truncate(buf.len() - 1);
+        let svg = Svg::read(FontData::new(&buf)).unwrap();
+        assert!(svg.glyph_document(GlyphId16::new(2)).unwrap().is_ok());
+        assert!(matches!(
+            svg.glyph_document(GlyphId16::new(10)),
+            Some(Err(ReadError::OutOfBounds))
+        ));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn decompress_documents() {
+        let buf = make_svg(&[DOC, GZIP_DOC]);
+        let svg = Svg::read(buf.font_data()).unwrap();
+        for gid in [2u16, 10] {
+            let doc = svg.glyph_document(GlyphId16::new(gid)).unwrap().unwrap();
+            assert_eq!(doc.data().unwrap().as_ref(), DOC);
+        }
+        assert!(matches!(
+            svg.glyph_document(GlyphId16::new(2))
+                .unwrap()
+                .unwrap()
+                .data(),
+            Ok(Cow::Borrowed(_))
+        ));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn decompress_with_optional_header_fields() {
+        // set FEXTRA, FNAME and FCOMMENT
+        let mut doc = GZIP_DOC[..10].to_vec();
+        doc[3] = 0x04 | 0x08 | 0x10;
+        doc.extend([3, 0, 1, 2, 3]);
+        doc.extend(b"emoji.svg\0");
+        doc.extend(b"comment\0");
+        doc.extend(&GZIP_DOC[10..]);
+        assert_eq!(decompress_gzip(&doc).unwrap(), DOC);
+        // truncated
+        assert!(decompress_gzip(&doc[..doc.len() - 10]).is_err());
+        assert!(decompress_gzip(&GZIP_DOC[..12]).is_err());
+    }
+}
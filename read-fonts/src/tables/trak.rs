@@ -0,0 +1,210 @@
+//! The [tracking (trak)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6trak.html) table.
+
+include!("../../generated/generated_trak.rs");
+
+impl<'a> Trak<'a> {
+    /// Returns the interpolated horizontal tracking value for the given
+    /// point size and track.
+    ///
+    /// See [`TrackData::tracking`] for details.
+    pub fn tracking(&self, size: Fixed, track: Fixed) -> Option<Fixed> {
+        self.horiz()?
+            .ok()?
+            .tracking(self.offset_data(), size, track)
+    }
+
+    /// Returns the interpolated vertical tracking value for the given
+    /// point size and track.
+    ///
+    /// See [`TrackData::tracking`] for details.
+    pub fn vertical_tracking(&self, size: Fixed, track: Fixed) -> Option<Fixed> {
+        self.vert()?.ok()?.tracking(self.offset_data(), size, track)
+    }
+}
+
+impl<'a> TrackData<'a> {
+    /// Returns the size table for this set of tracking data.
+    ///
+    /// The `offset_data` parameter comes from the [`Trak`] table.
+    pub fn size_table(
+        &self,
+        offset_data: FontData<'a>,
+    ) -> Result<&'a [BigEndian<Fixed>], ReadError> {
+        let mut cursor = offset_data
+            .split_off(self.size_table_offset() as usize)
+            .ok_or(ReadError::OutOfBounds)?
+            .cursor();
+        cursor.read_array(self.n_sizes() as usize)
+    }
+
+    /// Returns the tracking value, in font units, for the given point size
+    /// and track.
+    ///
+    /// The `track` must exactly match the value of one of the entries in the
+    /// track table; the normal track has a value of 0.
+    ///
+    /// Values for sizes between those listed in the size table are linearly
+    /// interpolated, and sizes outside that range are extrapolated from the
+    /// nearest pair of entries.
+    ///
+    /// The `offset_data` parameter comes from the [`Trak`] table.
+    pub fn tracking(&self, offset_data: FontData<'a>, size: Fixed, track: Fixed) -> Option<Fixed> {
+        let entry = self
+            .track_table()
+            .iter()
+            .find(|entry| entry.track() == track)?;
+        let sizes = self.size_table(offset_data).ok()?;
+        let values = entry.per_size_values(offset_data, self.n_sizes()).ok()?;
+        let value_at = |idx: usize| {
+            values
+                .get(idx)
+                .map(|value| Fixed::from_i32(value.get() as i32))
+        };
+        match sizes.len() {
+            0 => None,
+            1 => value_at(0),
+            len => {
+                // find the first pair of entries that brackets the size,
+                // clamping to the final pair
+                let idx = sizes
+                    .iter()
+                    .position(|s| s.get() >= size)
+                    .unwrap_or(len - 1)
+                    .clamp(1, len - 1);
+                let s0 = sizes[idx - 1].get();
+                let s1 = sizes[idx].get();
+                let v0 = value_at(idx - 1)?;
+                let v1 = value_at(idx)?;
+                if s0 == s1 {
+                    return Some(v0);
+                }
+                let t = (size - s0) / (s1 - s0);
+                Some(v0 + t * (v1 - v0))
+            }
+        }
+    }
+}
+
+impl TrackTableEntry {
+    /// Returns the list of per-size tracking values for this entry.
+    ///
+    /// The `offset_data` parameter comes from the [`Trak`] table and `n_sizes`
+    /// parameter comes from the parent [`TrackData`] table.
+    pub fn per_size_values<'a>(
+        &self,
+        offset_data: FontData<'a>,
+        n_sizes: u16,
+    ) -> Result<&'a [BigEndian<i16>], ReadError> {
+        let mut cursor = offset_data
+            .split_off(self.offset() as usize)
+            .ok_or(ReadError::OutOfBounds)?
+            .cursor();
+        cursor.read_array(n_sizes as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn make_trak() -> BeBuffer {
+        BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(0u16) // format
+            .push(12u16) // horiz offset
+            .push(0u16) // vert offset
+            .push(0u16) // reserved
+            // TrackData
+            .push(2u16) // n_tracks
+            .push(3u16) // n_sizes
+            .push(36u32) // size table offset
+            // TrackTableEntry: tight
+            .push(Fixed::from_i32(-1))
+            .push(NameId::new(256))
+            .push(48u16)
+            // TrackTableEntry: normal
+            .push(Fixed::from_i32(0))
+            .push(NameId::new(257))
+            .push(54u16)
+            // size table
+            .extend([9, 12, 24].map(Fixed::from_i32))
+            // per size values: tight
+            .extend([-15i16, -7, -2])
+            // per size values: normal
+            .extend([0i16, 0, 0])
+    }
+
+    #[test]
+    fn parse_trak() {
+        let buf = make_trak();
+        let trak = Trak::read(buf.font_data()).unwrap();
+        assert_eq!(trak.version(), MajorMinor::VERSION_1_0);
+        assert!(trak.vert().is_none());
+        let horiz = trak.horiz().unwrap().unwrap();
+        assert_eq!(horiz.n_sizes(), 3);
+        let sizes = horiz
+            .size_table(trak.offset_data())
+            .unwrap()
+            .iter()
+            .map(|size| size.get().to_i32())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [9, 12, 24]);
+        let entries = horiz.track_table();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].track(), Fixed::from_i32(-1));
+        assert_eq!(entries[0].name_index(), NameId::new(256));
+        let values = entries[0]
+            .per_size_values(trak.offset_data(), horiz.n_sizes())
+            .unwrap()
+            .iter()
+            .map(|value| value.get())
+            .collect::<Vec<_>>();
+        assert_eq!(values, [-15, -7, -2]);
+    }
+
+    #[test]
+    fn tracking_interpolation() {
+        let buf = make_trak();
+        let trak = Trak::read(buf.font_data()).unwrap();
+        let tight = Fixed::from_i32(-1);
+        // exact sizes
+        assert_eq!(
+            trak.tracking(Fixed::from_i32(9), tight),
+            Some(Fixed::from_i32(-15))
+        );
+        assert_eq!(
+            trak.tracking(Fixed::from_i32(12), tight),
+            Some(Fixed::from_i32(-7))
+        );
+        assert_eq!(
+            trak.tracking(Fixed::from_i32(24), tight),
+            Some(Fixed::from_i32(-2))
+        );
+        // interpolated
+        assert_eq!(
+            trak.tracking(Fixed::from_i32(18), tight),
+            Some(Fixed::from_f64(-4.5))
+        );
+        // extrapolated
+        assert_eq!(
+            trak.tracking(Fixed::from_i32(36), tight),
+            Some(Fixed::from_f64(3.0))
+        );
+        assert_eq!(
+            trak.tracking(Fixed::from_i32(6), tight),
+            Some(Fixed::from_f64(-23.0))
+        );
+        // normal track
+        assert_eq!(
+            trak.tracking(Fixed::from_i32(18), Fixed::ZERO),
+            Some(Fixed::ZERO)
+        );
+        // missing track and direction
+        assert_eq!(trak.tracking(Fixed::from_i32(18), Fixed::ONE), None);
+        assert_eq!(
+            trak.vertical_tracking(Fixed::from_i32(18), Fixed::ZERO),
+            None
+        );
+    }
+}
@@ -4,6 +4,8 @@ include!("../../generated/generated_variations.rs");
 
 use super::gvar::SharedTuples;
 
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicI32, AtomicU32, Ordering};
 use std::iter::Skip;
 
 /// Outer and inner indices for reading from an [ItemVariationStore].
@@ -930,12 +932,37 @@ impl<'a> ItemVariationStore<'a> {
         &self,
         index: DeltaSetIndex,
         coords: &[F2Dot14],
+    ) -> Result<i32, ReadError> {
+        let regions = self.variation_region_list()?.variation_regions();
+        self.compute_delta_with(index, |region_index| {
+            Ok(regions.get(region_index)?.compute_scalar(coords))
+        })
+    }
+
+    /// Computes the delta value in floating point for the specified index and set
+    /// of normalized variation coordinates.
+    pub fn compute_float_delta(
+        &self,
+        index: DeltaSetIndex,
+        coords: &[F2Dot14],
+    ) -> Result<FloatItemDelta, ReadError> {
+        let regions = self.variation_region_list()?.variation_regions();
+        self.compute_float_delta_with(index, |region_index| {
+            Ok(regions.get(region_index)?.compute_scalar_f32(coords))
+        })
+    }
+
+    /// Computes the delta value for the specified index, using the given
+    /// function to produce the scalar for each region index.
+    fn compute_delta_with(
+        &self,
+        index: DeltaSetIndex,
+        mut region_scalar: impl FnMut(usize) -> Result<Fixed, ReadError>,
     ) -> Result<i32, ReadError> {
         let data = match self.item_variation_data().get(index.outer as usize) {
             Some(data) => data?,
             None => return Ok(0),
         };
-        let regions = self.variation_region_list()?.variation_regions();
         let region_indices = data.region_indexes();
         // Compute deltas with 64-bit precision.
         // See <https://gitlab.freedesktop.org/freetype/freetype/-/blob/7ab541a2/src/truetype/ttgxvar.c#L1094>
@@ -947,25 +974,23 @@ impl<'a> ItemVariationStore<'a> {
                     "invalid delta sets in ItemVariationStore",
                 ))?
                 .get() as usize;
-            let region = regions.get(region_index)?;
-            let scalar = region.compute_scalar(coords);
+            let scalar = region_scalar(region_index)?;
             accum += region_delta as i64 * scalar.to_bits() as i64;
         }
         Ok(((accum + 0x8000) >> 16) as i32)
     }
 
-    /// Computes the delta value in floating point for the specified index and set
-    /// of normalized variation coordinates.
-    pub fn compute_float_delta(
+    /// Computes the delta value in floating point for the specified index,
+    /// using the given function to produce the scalar for each region index.
+    fn compute_float_delta_with(
         &self,
         index: DeltaSetIndex,
-        coords: &[F2Dot14],
+        mut region_scalar: impl FnMut(usize) -> Result<f32, ReadError>,
     ) -> Result<FloatItemDelta, ReadError> {
         let data = match self.item_variation_data().get(index.outer as usize) {
             Some(data) => data?,
             None => return Ok(FloatItemDelta::ZERO),
         };
-        let regions = self.variation_region_list()?.variation_regions();
         let region_indices = data.region_indexes();
         // Compute deltas in 64-bit floating point.
         let mut accum = 0f64;
@@ -976,14 +1001,147 @@ impl<'a> ItemVariationStore<'a> {
                     "invalid delta sets in ItemVariationStore",
                 ))?
                 .get() as usize;
-            let region = regions.get(region_index)?;
-            let scalar = region.compute_scalar_f32(coords);
+            let scalar = region_scalar(region_index)?;
             accum += region_delta as f64 * scalar as f64;
         }
         Ok(FloatItemDelta(accum))
     }
 }
 
+/// An [ItemVariationStore] combined with a location in variation space.
+///
+/// Evaluating a delta requires computing a scalar for each region that
+/// contributes to it. This reads the region list once and caches the scalar
+/// for each region the first time it is needed, so that subsequent delta
+/// computations only need to accumulate the deltas themselves.
+///
+/// When the coordinates are empty (the default location) no regions are
+/// read and all deltas are zero. Otherwise, deltas computed by this type are
+/// identical to those computed by [ItemVariationStore::compute_delta] and
+/// [ItemVariationStore::compute_float_delta] for the same coordinates.
+#[derive(Clone)]
+pub struct VarStoreInstancer<'a> {
+    store: ItemVariationStore<'a>,
+    coords: &'a [F2Dot14],
+    regions: Vec<CachedRegion<'a>>,
+}
+
+impl<'a> VarStoreInstancer<'a> {
+    /// Creates a new instancer for the given item variation store and
+    /// normalized variation coordinates.
+    pub fn new(store: ItemVariationStore<'a>, coords: &'a [F2Dot14]) -> Result<Self, ReadError> {
+        let regions = if coords.is_empty() {
+            Vec::new()
+        } else {
+            store
+                .variation_region_list()?
+                .variation_regions()
+                .iter()
+                .map(|region| region.map(CachedRegion::new))
+                .collect::<Result<_, _>>()?
+        };
+        Ok(Self {
+            store,
+            coords,
+            regions,
+        })
+    }
+
+    /// Returns the underlying item variation store.
+    pub fn store(&self) -> &ItemVariationStore<'a> {
+        &self.store
+    }
+
+    /// Returns the normalized variation coordinates for this instance.
+    pub fn coords(&self) -> &'a [F2Dot14] {
+        self.coords
+    }
+
+    /// Computes the delta value for the specified index.
+    pub fn compute_delta(&self, index: DeltaSetIndex) -> Result<i32, ReadError> {
+        if self.coords.is_empty() {
+            return Ok(0);
+        }
+        self.store.compute_delta_with(index, |region_index| {
+            Ok(self.region(region_index)?.scalar(self.coords))
+        })
+    }
+
+    /// Computes the delta value in floating point for the specified index.
+    pub fn compute_float_delta(&self, index: DeltaSetIndex) -> Result<FloatItemDelta, ReadError> {
+        if self.coords.is_empty() {
+            return Ok(FloatItemDelta::ZERO);
+        }
+        self.store.compute_float_delta_with(index, |region_index| {
+            Ok(self.region(region_index)?.scalar_f32(self.coords))
+        })
+    }
+
+    fn region(&self, region_index: usize) -> Result<&CachedRegion<'a>, ReadError> {
+        self.regions.get(region_index).ok_or(ReadError::OutOfBounds)
+    }
+}
+
+/// A variation region along with its lazily computed scalars.
+///
+/// The scalars are stored in atomics so that the instancer remains `Sync`.
+/// Concurrent first uses may both compute a scalar, but they always store
+/// the same value.
+struct CachedRegion<'a> {
+    region: VariationRegion<'a>,
+    scalar: AtomicI32,
+    scalar_f32: AtomicU32,
+}
+
+impl<'a> CachedRegion<'a> {
+    /// Marks a scalar that has not been computed yet.
+    ///
+    /// Region scalars are always in the range 0..=1, so neither of these
+    /// values can occur.
+    const UNSET: i32 = i32::MIN;
+    const UNSET_F32: u32 = u32::MAX;
+
+    fn new(region: VariationRegion<'a>) -> Self {
+        Self {
+            region,
+            scalar: AtomicI32::new(Self::UNSET),
+            scalar_f32: AtomicU32::new(Self::UNSET_F32),
+        }
+    }
+
+    fn scalar(&self, coords: &[F2Dot14]) -> Fixed {
+        match self.scalar.load(Ordering::Relaxed) {
+            Self::UNSET => {
+                let scalar = self.region.compute_scalar(coords);
+                self.scalar.store(scalar.to_bits(), Ordering::Relaxed);
+                scalar
+            }
+            bits => Fixed::from_bits(bits),
+        }
+    }
+
+    fn scalar_f32(&self, coords: &[F2Dot14]) -> f32 {
+        match self.scalar_f32.load(Ordering::Relaxed) {
+            Self::UNSET_F32 => {
+                let scalar = self.region.compute_scalar_f32(coords);
+                self.scalar_f32.store(scalar.to_bits(), Ordering::Relaxed);
+                scalar
+            }
+            bits => f32::from_bits(bits),
+        }
+    }
+}
+
+impl Clone for CachedRegion<'_> {
+    fn clone(&self) -> Self {
+        Self {
+            region: self.region.clone(),
+            scalar: AtomicI32::new(self.scalar.load(Ordering::Relaxed)),
+            scalar_f32: AtomicU32::new(self.scalar_f32.load(Ordering::Relaxed)),
+        }
+    }
+}
+
 /// Floating point item delta computed by an item variation store.
 ///
 /// These can be applied to types that implement [`FloatItemDeltaTarget`].
@@ -1149,15 +1307,22 @@ pub(crate) fn advance_delta(
     glyph_id: GlyphId,
     coords: &[F2Dot14],
 ) -> Result<Fixed, ReadError> {
+    let ix = advance_delta_index(dsim, glyph_id)?;
+    Ok(Fixed::from_i32(ivs?.compute_delta(ix, coords)?))
+}
+
+pub(crate) fn advance_delta_index(
+    dsim: Option<Result<DeltaSetIndexMap, ReadError>>,
+    glyph_id: GlyphId,
+) -> Result<DeltaSetIndex, ReadError> {
     let gid = glyph_id.to_u32();
-    let ix = match dsim {
-        Some(Ok(dsim)) => dsim.get(gid)?,
-        _ => DeltaSetIndex {
+    match dsim {
+        Some(Ok(dsim)) => dsim.get(gid),
+        _ => Ok(DeltaSetIndex {
             outer: 0,
             inner: gid as _,
-        },
-    };
-    Ok(Fixed::from_i32(ivs?.compute_delta(ix, coords)?))
+        }),
+    }
 }
 
 pub(crate) fn item_delta(
@@ -1166,14 +1331,20 @@ pub(crate) fn item_delta(
     glyph_id: GlyphId,
     coords: &[F2Dot14],
 ) -> Result<Fixed, ReadError> {
-    let gid = glyph_id.to_u32();
-    let ix = match dsim {
-        Some(Ok(dsim)) => dsim.get(gid)?,
-        _ => return Err(ReadError::NullOffset),
-    };
+    let ix = item_delta_index(dsim, glyph_id)?;
     Ok(Fixed::from_i32(ivs?.compute_delta(ix, coords)?))
 }
 
+pub(crate) fn item_delta_index(
+    dsim: Option<Result<DeltaSetIndexMap, ReadError>>,
+    glyph_id: GlyphId,
+) -> Result<DeltaSetIndex, ReadError> {
+    match dsim {
+        Some(Ok(dsim)) => dsim.get(glyph_id.to_u32()),
+        _ => Err(ReadError::NullOffset),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1324,7 +1495,7 @@ mod tests {
         static ALL_POINTS: FontData = FontData::new(&[0]);
         let (all_points, _) = PackedPointNumbers::split_off_front(ALL_POINTS);
         // in which case the iterator just keeps incrementing until u16::MAX
-        assert_eq!(all_points.iter().count(), u16::MAX as _);
+        assert_eq!(all_points.iter().count(), u16::MAX as usize);
     }
 
     /// We don't have a reference for our float delta computation, so this is
@@ -1374,6 +1545,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn var_store_instancer_matches_ivs() {
+        let font = FontRef::new(font_test_data::COLRV0V1_VARIABLE).unwrap();
+        let axis_count = font.fvar().unwrap().axis_count() as usize;
+        let colr = font.colr().unwrap();
+        let ivs = colr.item_variation_store().unwrap().unwrap();
+        for coord in (0..=20).map(|x| F2Dot14::from_f32((x as f32) / 10.0 - 1.0)) {
+            let coords = vec![coord; axis_count];
+            let instancer = VarStoreInstancer::new(ivs.clone(), &coords).unwrap();
+            for (outer_ix, data) in ivs.item_variation_data().iter().enumerate() {
+                let Some(Ok(data)) = data else {
+                    continue;
+                };
+                for inner_ix in 0..data.item_count() {
+                    let delta_ix = DeltaSetIndex {
+                        outer: outer_ix as u16,
+                        inner: inner_ix,
+                    };
+                    assert_eq!(
+                        instancer.compute_delta(delta_ix).unwrap(),
+                        ivs.compute_delta(delta_ix, &coords).unwrap()
+                    );
+                    assert_eq!(
+                        instancer.compute_float_delta(delta_ix).unwrap().0,
+                        ivs.compute_float_delta(delta_ix, &coords).unwrap().0
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn var_store_instancer_many_regions() {
+        // Many regions, each with a delta of 2 that is scaled by 0.5 at the
+        // chosen location
+        const REGION_COUNT: u16 = 300;
+        let mut buf = BeBuffer::new()
+            .push(1u16) // format
+            .push(12u32) // variation region list offset
+            .push(1u16) // item variation data count
+            .push(12u32 + 4 + REGION_COUNT as u32 * 6) // item variation data offset
+            // variation region list
+            .push(1u16) // axis count
+            .push(REGION_COUNT);
+        for _ in 0..REGION_COUNT {
+            // start, peak, end
+            buf = buf.extend([F2Dot14::ZERO, F2Dot14::ONE, F2Dot14::ONE]);
+        }
+        let data = buf
+            // item variation data
+            .push(1u16) // item count
+            .push(0u16) // word delta count
+            .push(REGION_COUNT) // region index count
+            .extend(0..REGION_COUNT) // region indices
+            .extend(core::iter::repeat_n(2u8, REGION_COUNT as usize));
+        let ivs = ItemVariationStore::read(data.font_data()).unwrap();
+        let coords = [F2Dot14::from_f32(0.5)];
+        let instancer = VarStoreInstancer::new(ivs.clone(), &coords).unwrap();
+        let delta_ix = DeltaSetIndex { outer: 0, inner: 0 };
+        assert_eq!(
+            instancer.compute_delta(delta_ix).unwrap(),
+            REGION_COUNT as i32
+        );
+        assert_eq!(
+            instancer.compute_delta(delta_ix).unwrap(),
+            ivs.compute_delta(delta_ix, &coords).unwrap()
+        );
+        assert_eq!(
+            instancer.compute_float_delta(delta_ix).unwrap().0,
+            REGION_COUNT as f64
+        );
+        // cached scalars are reused
+        assert_eq!(
+            instancer.clone().compute_float_delta(delta_ix).unwrap().0,
+            REGION_COUNT as f64
+        );
+        // no deltas at the default location
+        let instancer = VarStoreInstancer::new(ivs, &[]).unwrap();
+        assert_eq!(instancer.compute_delta(delta_ix).unwrap(), 0);
+        assert_eq!(instancer.compute_float_delta(delta_ix).unwrap().0, 0.0);
+    }
+
     #[test]
     fn ivs_data_len_short() {
         let data = BeBuffer::new()
@@ -0,0 +1,124 @@
+//! The [Vertical Device Metrics](https://learn.microsoft.com/en-us/typography/opentype/spec/vdmx) table
+
+include!("../../generated/generated_vdmx.rs");
+
+impl<'a> Vdmx<'a> {
+    /// Returns the yMax and yMin values for the given pixels per em size
+    /// on a device with an aspect ratio of `x_ratio:y_ratio`.
+    ///
+    /// The group is chosen from the first ratio range that covers the
+    /// aspect ratio or the first default (all zero) range, whichever comes
+    /// first. Returns `None` if no range matches or the selected group
+    /// doesn't contain an entry for the size.
+    pub fn extents(&self, ppem: u16, x_ratio: u16, y_ratio: u16) -> Option<VTable> {
+        let index = self
+            .ratio_ranges()
+            .iter()
+            .position(|range| range.matches(x_ratio, y_ratio))?;
+        self.vdmx_group_offsets()
+            .get(index)?
+            .get()
+            .resolve::<VdmxGroup>(self.offset_data())
+            .ok()?
+            .extents(ppem)
+    }
+}
+
+impl RatioRange {
+    /// Returns true if this range covers a device with the given aspect
+    /// ratio.
+    pub fn matches(&self, x_ratio: u16, y_ratio: u16) -> bool {
+        let (range_x, start, end) = (self.x_ratio, self.y_start_ratio, self.y_end_ratio);
+        if range_x == 0 && start == 0 && end == 0 {
+            // Default range, matches any aspect ratio
+            return true;
+        }
+        // Scale the device ratio so that the x values agree, then check
+        // that y falls within the range
+        let y = y_ratio as u32 * range_x as u32;
+        let x = x_ratio as u32;
+        start as u32 * x <= y && y <= end as u32 * x
+    }
+}
+
+impl<'a> VdmxGroup<'a> {
+    /// Returns the entry for the given pixels per em size.
+    pub fn extents(&self, ppem: u16) -> Option<VTable> {
+        if ppem < self.startsz() as u16 || ppem > self.endsz() as u16 {
+            return None;
+        }
+        let entries = self.entries();
+        // Entries are sorted by yPelHeight
+        let ix = entries
+            .binary_search_by_key(&ppem, |entry| entry.y_pel_height())
+            .ok()?;
+        entries.get(ix).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn make_vdmx() -> BeBuffer {
+        BeBuffer::new()
+            .push(1u16) // version
+            .push(2u16) // num recs
+            .push(2u16) // num ratios
+            // ratio ranges
+            .extend([0u8, 1, 1, 1]) // 1:1
+            .extend([0u8, 0, 0, 0]) // default
+            // group offsets
+            .push(18u16)
+            .push(40u16)
+            // group 1
+            .push(3u16) // recs
+            .extend([8u8, 10]) // startsz, endsz
+            .extend([8u16, 7, 0xFFFE]) // yPelHeight, yMax, yMin (-2)
+            .extend([9u16, 8, 0xFFFE])
+            .extend([10u16, 9, 0xFFFD])
+            // group 2
+            .push(1u16)
+            .extend([12u8, 12])
+            .extend([12u16, 11, 0xFFFC])
+    }
+
+    #[test]
+    fn ratio_matching() {
+        let range = |x, start, end| RatioRange {
+            b_char_set: 0,
+            x_ratio: x,
+            y_start_ratio: start,
+            y_end_ratio: end,
+        };
+        assert!(range(0, 0, 0).matches(1, 1));
+        assert!(range(0, 0, 0).matches(2, 1));
+        assert!(range(1, 1, 1).matches(1, 1));
+        assert!(range(1, 1, 1).matches(2, 2));
+        assert!(!range(1, 1, 1).matches(2, 1));
+        // 2:1 to 2:2 covers 4:3
+        assert!(range(2, 1, 2).matches(4, 3));
+        assert!(!range(2, 1, 2).matches(4, 5));
+        assert!(!range(2, 1, 2).matches(4, 1));
+    }
+
+    #[test]
+    fn extents() {
+        let buf = make_vdmx();
+        let vdmx = Vdmx::read(buf.font_data()).unwrap();
+        assert_eq!(vdmx.ratio_ranges().len(), 2);
+        let entry = vdmx.extents(9, 1, 1).unwrap();
+        assert_eq!(entry.y_pel_height(), 9);
+        assert_eq!(entry.y_max(), 8);
+        assert_eq!(entry.y_min(), -2);
+        let entry = vdmx.extents(10, 1, 1).unwrap();
+        assert_eq!((entry.y_max(), entry.y_min()), (9, -3));
+        // outside of the range of the 1:1 group
+        assert!(vdmx.extents(12, 1, 1).is_none());
+        // 2:1 selects the default group
+        let entry = vdmx.extents(12, 2, 1).unwrap();
+        assert_eq!((entry.y_max(), entry.y_min()), (11, -4));
+        assert!(vdmx.extents(9, 2, 1).is_none());
+    }
+}
@@ -0,0 +1,45 @@
+//! The [VORG (Vertical Origin)](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg) table.
+
+include!("../../generated/generated_vorg.rs");
+
+impl<'a> Vorg<'a> {
+    /// Returns the y coordinate of the vertical origin for the given glyph,
+    /// in font units.
+    ///
+    /// Falls back to the default vertical origin if the glyph has no
+    /// explicit entry.
+    pub fn vertical_origin_y(&self, glyph_id: GlyphId) -> i16 {
+        let metrics = self.vert_origin_y_metrics();
+        let Ok(glyph_id) = glyph_id.try_into() else {
+            return self.default_vert_origin_y();
+        };
+        match metrics.binary_search_by_key(&glyph_id, |rec: &VertOriginYMetrics| rec.glyph_index())
+        {
+            Ok(ix) => metrics[ix].vert_origin_y(),
+            Err(_) => self.default_vert_origin_y(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn vertical_origins() {
+        let buf = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .push(880i16) // default vert origin y
+            .push(2u16) // num metrics
+            .extend([3u16, 900])
+            .extend([5u16, 870]);
+        let vorg = Vorg::read(buf.font_data()).unwrap();
+        assert_eq!(vorg.version(), MajorMinor::VERSION_1_0);
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(0)), 880);
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(3)), 900);
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(4)), 880);
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(5)), 870);
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(70000)), 880);
+    }
+}
@@ -1,6 +1,6 @@
 //! The [VVAR (Vertical Metrics Variation)](https://docs.microsoft.com/en-us/typography/opentype/spec/vvar) table
 
-use super::variations::{self, DeltaSetIndexMap, ItemVariationStore};
+use super::variations::{self, DeltaSetIndex, DeltaSetIndexMap, ItemVariationStore};
 
 include!("../../generated/generated_vvar.rs");
 
@@ -52,4 +52,35 @@ impl<'a> Vvar<'a> {
             coords,
         )
     }
+
+    /// Returns the delta set index for the advance height of the specified glyph
+    /// identifier.
+    ///
+    /// The index can be passed to a
+    /// [`VarStoreInstancer`](variations::VarStoreInstancer) created from this
+    /// table's item variation store.
+    pub fn advance_height_delta_index(
+        &self,
+        glyph_id: GlyphId,
+    ) -> Result<DeltaSetIndex, ReadError> {
+        variations::advance_delta_index(self.advance_height_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index for the top side bearing of the specified glyph
+    /// identifier.
+    pub fn tsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.tsb_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index for the bottom side bearing of the specified glyph
+    /// identifier.
+    pub fn bsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.bsb_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index for the vertical origin of the specified glyph
+    /// identifier.
+    pub fn v_org_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.v_org_mapping(), glyph_id)
+    }
 }
@@ -69,3 +69,49 @@ fn caretvalueformat3() {
             .collect::<Vec<_>>()
     );
 }
+
+#[test]
+fn collect_glyphs() {
+    use crate::test_helpers::BeBuffer;
+
+    let data = BeBuffer::new()
+        .push(MajorMinor::VERSION_1_2)
+        .push(14u16) // glyph class def
+        .push(24u16) // attach list
+        .push(0u16) // lig caret list
+        .push(0u16) // mark attach class def
+        .push(40u16) // mark glyph sets
+        // glyph class def, at 14
+        .push(1u16)
+        .push(3u16)
+        .push(2u16)
+        .extend([1u16, 3])
+        // attach list, at 24
+        .push(6u16)
+        .push(1u16)
+        .push(12u16)
+        // coverage, at 30
+        .push(1u16)
+        .push(1u16)
+        .push(7u16)
+        // attach point, at 36
+        .push(1u16)
+        .push(0u16)
+        // mark glyph sets, at 40
+        .push(1u16)
+        .push(1u16)
+        .push(8u32)
+        // coverage, at 48
+        .push(1u16)
+        .push(2u16)
+        .extend([9u16, 10]);
+    let gdef = Gdef::read(data.font_data()).unwrap();
+    let mut glyphs = gdef
+        .collect_glyphs()
+        .unwrap()
+        .into_iter()
+        .map(|gid| gid.to_u16())
+        .collect::<Vec<_>>();
+    glyphs.sort();
+    assert_eq!(glyphs, [3, 4, 7, 9, 10]);
+}
@@ -1,6 +1,8 @@
 //! small utilities used in tests
 
-use crate::{FontData, Scalar};
+use types::Tag;
+
+use crate::{FontData, FontRef, Scalar};
 
 /// A convenience type for generating a buffer of big-endian bytes.
 #[derive(Debug, Clone, Default)]
@@ -36,3 +38,20 @@ impl std::ops::Deref for BeBuffer {
         &self.0
     }
 }
+
+/// Returns a copy of the font with `bytes` written at `offset` in the table
+/// with the given tag.
+pub fn patch(font: &[u8], tag: Tag, offset: usize, bytes: &[u8]) -> Vec<u8> {
+    let record = FontRef::new(font)
+        .unwrap()
+        .table_directory
+        .table_records()
+        .iter()
+        .find(|record| record.tag() == tag)
+        .cloned()
+        .unwrap();
+    let start = record.offset() as usize + offset;
+    let mut data = font.to_vec();
+    data[start..start + bytes.len()].copy_from_slice(bytes);
+    data
+}
@@ -21,6 +21,12 @@ use crate::{
     FontData, FontRead, FontReadWithArgs, ReadError, VarSize,
 };
 
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(feature = "serde")]
+pub use serialize::{SerializeOptions, SerializeTable};
+
 /// Types of fields in font tables.
 ///
 /// Fields can either be scalars, offsets to tables, or arrays.
@@ -0,0 +1,353 @@
+//! Serializing tables with serde.
+//!
+//! This allows any table to be converted into a generic structured format
+//! such as JSON, without any per-table code.
+
+use std::cell::Cell;
+
+use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use super::{FieldType, SomeArray, SomeTable};
+
+/// Options for serializing tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// The maximum number of offsets to follow from the root table.
+    ///
+    /// Offsets beyond this depth are emitted as raw values. This protects
+    /// against cycles in offset graphs.
+    pub max_depth: usize,
+    /// The maximum number of tables, records and array elements that will be
+    /// serialized.
+    ///
+    /// Offsets to shared subtables are followed each time they are
+    /// encountered, so this bounds the size of the output for tables that
+    /// share subtables heavily. Serialization fails if the limit is reached.
+    pub max_visited: usize,
+    /// If true, offsets are never followed and are always emitted as raw
+    /// values.
+    pub raw_offsets: bool,
+}
+
+impl SerializeOptions {
+    /// The default value of [`max_depth`](Self::max_depth).
+    pub const DEFAULT_MAX_DEPTH: usize = 64;
+    /// The default value of [`max_visited`](Self::max_visited).
+    pub const DEFAULT_MAX_VISITED: usize = 1 << 22;
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_visited: Self::DEFAULT_MAX_VISITED,
+            raw_offsets: false,
+        }
+    }
+}
+
+/// A wrapper that implements [`Serialize`] for any table.
+///
+/// Tables and records are serialized as maps from field name to value, and
+/// arrays as sequences. Resolved offsets are inlined as the subtable they
+/// point to, subject to the [`SerializeOptions`].
+///
+/// Scalars are serialized as numbers, with the exception of tags and version
+/// numbers, which are serialized as strings. Errors encountered while reading
+/// a subtable are serialized as a map with a single `error` entry.
+///
+/// # Examples
+///
+/// ```
+/// # use read_fonts::{FontRef, TableProvider};
+/// # fn example(font: &FontRef) -> Result<String, serde_json::Error> {
+/// use read_fonts::traversal::{SerializeOptions, SerializeTable};
+///
+/// let head = font.head().unwrap();
+/// serde_json::to_string_pretty(&SerializeTable::new(&head, SerializeOptions::default()))
+/// # }
+/// ```
+pub struct SerializeTable<'a, 'b> {
+    table: &'b (dyn SomeTable<'a> + 'a),
+    options: SerializeOptions,
+}
+
+impl<'a, 'b> SerializeTable<'a, 'b> {
+    /// Creates a new serializable wrapper for the given table.
+    pub fn new(table: &'b (dyn SomeTable<'a> + 'a), options: SerializeOptions) -> Self {
+        Self { table, options }
+    }
+}
+
+impl Serialize for SerializeTable<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let state = State {
+            options: self.options,
+            visited: Cell::new(0),
+        };
+        SerializeFields {
+            table: self.table,
+            state: &state,
+            depth: 0,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// State shared by all nodes in a single serialization.
+struct State {
+    options: SerializeOptions,
+    visited: Cell<usize>,
+}
+
+impl State {
+    /// Counts a visited node, failing if the budget is exhausted.
+    fn visit<E: Error>(&self) -> Result<(), E> {
+        let visited = self.visited.get();
+        if visited >= self.options.max_visited {
+            return Err(E::custom("exceeded the maximum number of visited nodes"));
+        }
+        self.visited.set(visited + 1);
+        Ok(())
+    }
+}
+
+struct SerializeFields<'a, 'b> {
+    table: &'b (dyn SomeTable<'a> + 'a),
+    state: &'b State,
+    depth: usize,
+}
+
+impl Serialize for SerializeFields<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.state.visit()?;
+        let mut map = serializer.serialize_map(None)?;
+        for field in self.table.iter() {
+            map.serialize_entry(
+                field.name,
+                &SerializeField {
+                    field: &field.value,
+                    state: self.state,
+                    depth: self.depth,
+                },
+            )?;
+        }
+        map.end()
+    }
+}
+
+struct SerializeField<'a, 'b> {
+    field: &'b FieldType<'a>,
+    state: &'b State,
+    depth: usize,
+}
+
+impl SerializeField<'_, '_> {
+    /// Returns true if offsets at the current depth should be followed.
+    fn follow_offsets(&self) -> bool {
+        let options = &self.state.options;
+        !options.raw_offsets && self.depth < options.max_depth
+    }
+}
+
+impl Serialize for SerializeField<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.field {
+            FieldType::I8(val) => serializer.serialize_i8(*val),
+            FieldType::U8(val) => serializer.serialize_u8(*val),
+            FieldType::I16(val) => serializer.serialize_i16(*val),
+            FieldType::U16(val) => serializer.serialize_u16(*val),
+            FieldType::I32(val) => serializer.serialize_i32(*val),
+            FieldType::U32(val) => serializer.serialize_u32(*val),
+            FieldType::U24(val) => serializer.serialize_u32(val.to_u32()),
+            FieldType::Tag(val) => serializer.collect_str(val),
+            FieldType::FWord(val) => serializer.serialize_i16(val.to_i16()),
+            FieldType::UfWord(val) => serializer.serialize_u16(val.to_u16()),
+            FieldType::MajorMinor(val) => serializer.collect_str(val),
+            FieldType::Version16Dot16(val) => serializer.collect_str(val),
+            FieldType::F2Dot14(val) => serializer.serialize_f32(val.to_f32()),
+            FieldType::Fixed(val) => serializer.serialize_f64(val.to_f64()),
+            FieldType::LongDateTime(val) => serializer.serialize_i64(val.as_secs()),
+            FieldType::GlyphId16(val) => serializer.serialize_u16(val.to_u16()),
+            FieldType::NameId(val) => serializer.serialize_u16(val.to_u16()),
+            FieldType::BareOffset(offset) => serializer.serialize_u32(offset.to_u32()),
+            FieldType::ResolvedOffset(resolved) if self.follow_offsets() => {
+                match &resolved.target {
+                    Ok(table) => SerializeFields {
+                        table,
+                        state: self.state,
+                        depth: self.depth + 1,
+                    }
+                    .serialize(serializer),
+                    Err(e) => serialize_error(e, serializer),
+                }
+            }
+            FieldType::ResolvedOffset(resolved) => {
+                serializer.serialize_u32(resolved.offset.to_u32())
+            }
+            FieldType::StringOffset(string) if self.follow_offsets() => match &string.target {
+                Ok(target) => serializer.collect_str(&target.iter_chars().collect::<String>()),
+                Err(e) => serialize_error(e, serializer),
+            },
+            FieldType::StringOffset(string) => serializer.serialize_u32(string.offset.to_u32()),
+            FieldType::ArrayOffset(array) if self.follow_offsets() => match &array.target {
+                Ok(target) => SerializeArray {
+                    array: target.as_ref(),
+                    state: self.state,
+                    depth: self.depth + 1,
+                }
+                .serialize(serializer),
+                Err(e) => serialize_error(e, serializer),
+            },
+            FieldType::ArrayOffset(array) => serializer.serialize_u32(array.offset.to_u32()),
+            FieldType::Record(record) => SerializeFields {
+                table: record,
+                state: self.state,
+                depth: self.depth,
+            }
+            .serialize(serializer),
+            FieldType::Array(array) => SerializeArray {
+                array: array.as_ref(),
+                state: self.state,
+                depth: self.depth,
+            }
+            .serialize(serializer),
+            FieldType::Unknown => serializer.serialize_none(),
+        }
+    }
+}
+
+struct SerializeArray<'a, 'b> {
+    array: &'b (dyn SomeArray<'a> + 'a),
+    state: &'b State,
+    depth: usize,
+}
+
+impl Serialize for SerializeArray<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.array.len()))?;
+        for item in self.array.iter() {
+            self.state.visit()?;
+            seq.serialize_element(&SerializeField {
+                field: &item,
+                state: self.state,
+                depth: self.depth,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+fn serialize_error<S: Serializer>(
+    error: &crate::ReadError,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry("error", &error.to_string())?;
+    map.end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FontRead, FontRef, TableProvider};
+    use serde_json::{json, Value};
+
+    fn to_json<'a>(table: &(dyn SomeTable<'a> + 'a), options: SerializeOptions) -> Value {
+        serde_json::to_value(SerializeTable::new(table, options)).unwrap()
+    }
+
+    #[test]
+    fn scalars() {
+        let font = FontRef::new(font_test_data::SIMPLE_GLYF).unwrap();
+        let head = font.head().unwrap();
+        let value = to_json(&head, SerializeOptions::default());
+        assert_eq!(value["version"], json!("1.0"));
+        assert_eq!(value["units_per_em"], json!(head.units_per_em()));
+        assert_eq!(value["magic_number"], json!(0x5F0F3CF5));
+        assert_eq!(value["font_revision"], json!(head.font_revision().to_f64()));
+        assert_eq!(value["x_min"], json!(head.x_min()));
+        assert_eq!(value["created"], json!(head.created().as_secs()));
+    }
+
+    #[test]
+    fn offsets_and_depth() {
+        let font = FontRef::new(font_test_data::closure::SIMPLE).unwrap();
+        let gsub = font.gsub().unwrap();
+        let full = to_json(&gsub, SerializeOptions::default());
+        let scripts = &full["script_list_offset"]["script_records"];
+        assert_eq!(scripts[0]["script_tag"], json!("DFLT"));
+        // nested offsets are resolved
+        assert!(scripts[0]["script_offset"]["default_lang_sys_offset"].is_object());
+        let lookups = &full["lookup_list_offset"]["lookup_offsets"];
+        assert_eq!(
+            lookups.as_array().unwrap().len(),
+            gsub.lookup_list().unwrap().lookup_count() as usize
+        );
+
+        // only the first level of offsets is resolved
+        let shallow = to_json(
+            &gsub,
+            SerializeOptions {
+                max_depth: 1,
+                ..Default::default()
+            },
+        );
+        let scripts = &shallow["script_list_offset"]["script_records"];
+        assert_eq!(scripts[0]["script_tag"], json!("DFLT"));
+        assert!(scripts[0]["script_offset"].is_u64());
+
+        let raw = to_json(
+            &gsub,
+            SerializeOptions {
+                raw_offsets: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            raw["script_list_offset"],
+            json!(gsub.script_list_offset().to_u32())
+        );
+        assert_eq!(raw["version"], json!("1.0"));
+    }
+
+    #[test]
+    fn visit_budget() {
+        let font = FontRef::new(font_test_data::closure::SIMPLE).unwrap();
+        let gsub = font.gsub().unwrap();
+        let options = |max_visited| SerializeOptions {
+            max_visited,
+            ..Default::default()
+        };
+        let err = serde_json::to_value(SerializeTable::new(&gsub, options(10))).unwrap_err();
+        assert!(err.to_string().contains("maximum number of visited nodes"));
+        // a budget that is large enough has no effect
+        assert_eq!(
+            to_json(&gsub, options(100_000)),
+            to_json(&gsub, SerializeOptions::default())
+        );
+    }
+
+    #[test]
+    fn strings() {
+        let font = FontRef::new(font_test_data::NAMES_ONLY).unwrap();
+        let name = font.name().unwrap();
+        let value = to_json(&name, SerializeOptions::default());
+        let first = name.name_record()[0];
+        let expected = first
+            .string(name.string_data())
+            .unwrap()
+            .chars()
+            .collect::<String>();
+        assert_eq!(value["name_record"][0]["string_offset"], json!(expected));
+    }
+
+    #[test]
+    fn read_errors() {
+        // a GSUB header with offsets pointing past the end of the data
+        let data = [0u8, 1, 0, 0, 0, 0x40, 0, 0x40, 0, 0x40];
+        let gsub = crate::tables::gsub::Gsub::read(crate::FontData::new(&data)).unwrap();
+        let value = to_json(&gsub, SerializeOptions::default());
+        assert!(value["script_list_offset"]["error"].is_string());
+    }
+}
@@ -0,0 +1,315 @@
+//! Decoding of [WOFF 1.0](https://www.w3.org/TR/WOFF/) font files.
+//!
+//! WOFF is a wrapper around an sfnt font in which each table may be
+//! compressed with zlib. Use [`Woff::to_sfnt`] to reconstruct the original
+//! font, which can then be read with [`FontRef`](crate::FontRef).
+
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::sfnt_builder::{SfntBuilder, MAX_DECODED_LEN};
+
+include!("../generated/generated_woff.rs");
+
+/// The signature of a WOFF file, 'wOFF'.
+pub const WOFF_SIGNATURE: Tag = Tag::new(b"wOFF");
+
+/// A WOFF 1.0 font file.
+#[derive(Clone)]
+pub struct Woff<'a> {
+    data: FontData<'a>,
+    header: WoffHeader<'a>,
+}
+
+impl<'a> Woff<'a> {
+    /// Reads a WOFF file from the given data.
+    pub fn new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let data = FontData::new(data);
+        let header = WoffHeader::read(data)?;
+        if header.signature() != WOFF_SIGNATURE {
+            return Err(ReadError::InvalidFormat(
+                u32::from_be_bytes(header.signature().to_be_bytes()) as _,
+            ));
+        }
+        Ok(Self { data, header })
+    }
+
+    /// Returns the WOFF header.
+    pub fn header(&self) -> &WoffHeader<'a> {
+        &self.header
+    }
+
+    /// Returns the "sfnt version" of the wrapped font.
+    pub fn flavor(&self) -> u32 {
+        self.header.flavor()
+    }
+
+    /// Returns the directory entries for all tables.
+    pub fn table_directory(&self) -> &'a [WoffTableDirectoryEntry] {
+        self.header.table_directory()
+    }
+
+    /// Returns the decoded data for the table with the given tag, if present.
+    pub fn table_data(&self, tag: Tag) -> Option<Result<Cow<'a, [u8]>, ReadError>> {
+        self.table_directory()
+            .iter()
+            .find(|entry| entry.tag() == tag)
+            .map(|entry| self.decode_table(entry))
+    }
+
+    /// Returns the decoded data for the given table directory entry.
+    pub fn decode_table(
+        &self,
+        entry: &WoffTableDirectoryEntry,
+    ) -> Result<Cow<'a, [u8]>, ReadError> {
+        let data = self.block(entry.offset(), entry.comp_length())?;
+        let orig_len = entry.orig_length() as usize;
+        match (entry.comp_length() as usize).cmp(&orig_len) {
+            core::cmp::Ordering::Less => decompress_zlib(data, orig_len).map(Cow::Owned),
+            core::cmp::Ordering::Equal => Ok(Cow::Borrowed(data)),
+            core::cmp::Ordering::Greater => Err(ReadError::MalformedData(
+                "WOFF table compressed length exceeds original length",
+            )),
+        }
+    }
+
+    /// Returns the decompressed extended metadata block, if present.
+    ///
+    /// This is an XML document encoded as UTF-8.
+    pub fn metadata(&self) -> Option<Result<Vec<u8>, ReadError>> {
+        if self.header.meta_offset() == 0 || self.header.meta_length() == 0 {
+            return None;
+        }
+        Some(
+            self.block(self.header.meta_offset(), self.header.meta_length())
+                .and_then(|data| decompress_zlib(data, self.header.meta_orig_length() as usize)),
+        )
+    }
+
+    /// Returns the private data block, if present.
+    pub fn private_data(&self) -> Option<Result<&'a [u8], ReadError>> {
+        if self.header.priv_offset() == 0 || self.header.priv_length() == 0 {
+            return None;
+        }
+        Some(self.block(self.header.priv_offset(), self.header.priv_length()))
+    }
+
+    /// Decodes the font, returning the data for the original sfnt.
+    ///
+    /// The result can be read with [`FontRef::new`](crate::FontRef::new).
+    pub fn to_sfnt(&self) -> Result<Vec<u8>, ReadError> {
+        let entries = self.table_directory();
+        let mut builder = SfntBuilder::new(self.flavor(), entries.len())?;
+        builder.reserve(self.header.total_sfnt_size() as usize);
+        // Encoders generally preserve the physical order of the tables in
+        // the original font, so we do the same in order to reproduce it
+        // exactly
+        let mut order = (0..entries.len()).collect::<Vec<_>>();
+        order.sort_by_key(|ix| entries[*ix].offset());
+        for ix in order {
+            let entry = &entries[ix];
+            let data = self.decode_table(entry)?;
+            builder.push(ix, entry.tag(), entry.orig_checksum(), &data)?;
+        }
+        Ok(builder.finish())
+    }
+
+    fn block(&self, offset: u32, len: u32) -> Result<&'a [u8], ReadError> {
+        let start = offset as usize;
+        let end = start
+            .checked_add(len as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        self.data
+            .as_bytes()
+            .get(start..end)
+            .ok_or(ReadError::OutOfBounds)
+    }
+}
+
+/// Decompresses a zlib stream that is expected to produce exactly
+/// `expected_len` bytes.
+fn decompress_zlib(data: &[u8], expected_len: usize) -> Result<Vec<u8>, ReadError> {
+    let malformed = || ReadError::MalformedData("invalid zlib compressed WOFF data");
+    if expected_len > MAX_DECODED_LEN {
+        return Err(malformed());
+    }
+    let decoded = miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, expected_len)
+        .map_err(|_| malformed())?;
+    if decoded.len() != expected_len {
+        return Err(malformed());
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{FontRef, TableProvider};
+    use font_test_data as data;
+
+    /// Encodes the given font as WOFF, compressing each table.
+    pub(crate) fn encode_woff(font: &[u8], metadata: Option<&[u8]>, private: &[u8]) -> Vec<u8> {
+        let font = FontRef::new(font).unwrap();
+        let records = font.table_directory.table_records();
+        let header_len = 44 + records.len() * 20;
+        let mut directory = vec![0u8; records.len() * 20];
+        let mut tables = Vec::new();
+        // write table data in the same order as the original font
+        let mut order = (0..records.len()).collect::<Vec<_>>();
+        order.sort_by_key(|ix| records[*ix].offset());
+        for ix in order {
+            let record = &records[ix];
+            let table = font.table_data(record.tag()).unwrap();
+            let table = table.as_bytes();
+            let compressed = miniz_oxide::deflate::compress_to_vec_zlib(table, 6);
+            // tables are only stored compressed if that makes them smaller
+            let stored = if compressed.len() < table.len() {
+                compressed
+            } else {
+                table.to_vec()
+            };
+            let offset = (header_len + tables.len()) as u32;
+            for (i, value) in [
+                u32::from_be_bytes(record.tag().to_be_bytes()),
+                offset,
+                stored.len() as u32,
+                table.len() as u32,
+                record.checksum(),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let start = ix * 20 + i * 4;
+                directory[start..start + 4].copy_from_slice(&value.to_be_bytes());
+            }
+            tables.extend_from_slice(&stored);
+            tables.resize(tables.len().next_multiple_of(4), 0);
+        }
+        let mut blocks = tables;
+        let (meta_offset, meta_len, meta_orig_len) = match metadata {
+            Some(metadata) => {
+                let compressed = miniz_oxide::deflate::compress_to_vec_zlib(metadata, 6);
+                let offset = header_len + blocks.len();
+                blocks.extend_from_slice(&compressed);
+                blocks.resize(blocks.len().next_multiple_of(4), 0);
+                (offset, compressed.len(), metadata.len())
+            }
+            None => (0, 0, 0),
+        };
+        let priv_offset = if private.is_empty() {
+            0
+        } else {
+            header_len + blocks.len()
+        };
+        blocks.extend_from_slice(private);
+        let mut woff = Vec::new();
+        woff.extend_from_slice(b"wOFF");
+        woff.extend_from_slice(&font.table_directory.sfnt_version().to_be_bytes());
+        woff.extend_from_slice(&((header_len + blocks.len()) as u32).to_be_bytes());
+        woff.extend_from_slice(&(records.len() as u16).to_be_bytes());
+        woff.extend_from_slice(&0u16.to_be_bytes());
+        woff.extend_from_slice(&(font.data.len() as u32).to_be_bytes());
+        woff.extend_from_slice(&1u16.to_be_bytes());
+        woff.extend_from_slice(&0u16.to_be_bytes());
+        for value in [
+            meta_offset,
+            meta_len,
+            meta_orig_len,
+            priv_offset,
+            private.len(),
+        ] {
+            woff.extend_from_slice(&(value as u32).to_be_bytes());
+        }
+        woff.extend_from_slice(&directory);
+        woff.extend_from_slice(&blocks);
+        woff
+    }
+
+    const METADATA: &[u8] = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <metadata version=\"1.0\"><uniqueid id=\"test\"/></metadata>";
+
+    #[test]
+    fn header() {
+        let woff_data = encode_woff(data::VAZIRMATN_VAR, None, &[]);
+        let woff = Woff::new(&woff_data).unwrap();
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        assert_eq!(woff.flavor(), font.table_directory.sfnt_version());
+        assert_eq!(woff.header().length() as usize, woff_data.len());
+        assert_eq!(
+            woff.table_directory().len(),
+            font.table_directory.table_records().len()
+        );
+        assert!(woff.metadata().is_none());
+        assert!(woff.private_data().is_none());
+    }
+
+    #[test]
+    fn decode_tables() {
+        let woff_data = encode_woff(data::VAZIRMATN_VAR, None, &[]);
+        let woff = Woff::new(&woff_data).unwrap();
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        let mut n_compressed = 0;
+        for entry in woff.table_directory() {
+            let decoded = woff.decode_table(entry).unwrap();
+            n_compressed += matches!(decoded, Cow::Owned(_)) as usize;
+            assert_eq!(
+                decoded.as_ref(),
+                font.table_data(entry.tag()).unwrap().as_bytes()
+            );
+        }
+        assert!(n_compressed > 0);
+        assert!(woff.table_data(Tag::new(b"zzzz")).is_none());
+    }
+
+    #[test]
+    fn to_sfnt() {
+        let woff_data = encode_woff(data::VAZIRMATN_VAR, None, &[]);
+        let sfnt = Woff::new(&woff_data).unwrap().to_sfnt().unwrap();
+        // the original font is reproduced exactly
+        assert_eq!(sfnt, data::VAZIRMATN_VAR);
+        let decoded = FontRef::new(&sfnt).unwrap();
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        for record in font.table_directory.table_records() {
+            let tag = record.tag();
+            assert_eq!(
+                decoded.table_data(tag).unwrap().as_bytes(),
+                font.table_data(tag).unwrap().as_bytes()
+            );
+        }
+        assert_eq!(decoded.verify_checksums().collect::<Vec<_>>(), []);
+        assert_eq!(
+            decoded.maxp().unwrap().num_glyphs(),
+            font.maxp().unwrap().num_glyphs()
+        );
+    }
+
+    #[test]
+    fn metadata_and_private_data() {
+        let woff_data = encode_woff(data::SIMPLE_GLYF, Some(METADATA), b"private");
+        let woff = Woff::new(&woff_data).unwrap();
+        assert_eq!(woff.metadata().unwrap().unwrap(), METADATA);
+        assert_eq!(woff.private_data().unwrap().unwrap(), b"private");
+    }
+
+    #[test]
+    fn corrupt_data() {
+        let mut woff_data = encode_woff(data::VAZIRMATN_VAR, None, &[]);
+        assert!(Woff::new(&woff_data[..40]).is_err());
+        let woff = Woff::new(&woff_data).unwrap();
+        let entry = woff
+            .table_directory()
+            .iter()
+            .find(|entry| entry.comp_length() < entry.orig_length())
+            .cloned()
+            .unwrap();
+        // truncate the compressed stream of the first compressed table
+        let offset = entry.offset() as usize;
+        let len = entry.comp_length() as usize;
+        woff_data[offset + len / 2..offset + len].fill(0);
+        let woff = Woff::new(&woff_data).unwrap();
+        assert!(woff.decode_table(&entry).is_err());
+        assert!(woff.to_sfnt().is_err());
+        // bad signature
+        woff_data[0] = b'x';
+        assert!(Woff::new(&woff_data).is_err());
+    }
+}
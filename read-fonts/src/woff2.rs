@@ -0,0 +1,1813 @@
+//! Decoding of [WOFF 2.0](https://www.w3.org/TR/WOFF2/) font files.
+//!
+//! WOFF2 compresses all of the tables of a font (or font collection) as a
+//! single brotli stream, after optionally transforming the `glyf`, `loca` and
+//! `hmtx` tables into a more compressible form. Use [`Woff2::to_sfnt`] to
+//! reconstruct the font, which can then be read with
+//! [`FileRef`](crate::FileRef) or [`FontRef`](crate::FontRef).
+//!
+//! This crate does not include a brotli decoder: one must be provided by
+//! implementing the [`BrotliDecompressor`] trait.
+
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use types::TTC_HEADER_TAG;
+
+use crate::{
+    checksum::{checksum, head_checksum, CHECKSUM_MAGIC, HEAD_ADJUSTMENT_OFFSET},
+    font_data::Cursor,
+    sfnt_builder::{SfntBuilder, MAX_DECODED_LEN},
+    tables::{
+        glyf::{CompositeGlyphFlags, Glyf, SimpleGlyphFlags},
+        head::Head,
+        hhea::Hhea,
+        hmtx::Hmtx,
+        loca::Loca,
+    },
+    TopLevelTable,
+};
+
+include!("../generated/generated_woff2.rs");
+
+/// The signature of a WOFF2 file, 'wOF2'.
+pub const WOFF2_SIGNATURE: Tag = Tag::new(b"wOF2");
+
+/// Size of the fixed WOFF2 header, before the table directory.
+const WOFF2_HEADER_LEN: usize = 48;
+
+/// Tags of the tables that can be encoded with a single byte in the table
+/// directory, indexed by the low six bits of the entry flags.
+const KNOWN_TAGS: [Tag; 63] = [
+    Tag::new(b"cmap"),
+    Tag::new(b"head"),
+    Tag::new(b"hhea"),
+    Tag::new(b"hmtx"),
+    Tag::new(b"maxp"),
+    Tag::new(b"name"),
+    Tag::new(b"OS/2"),
+    Tag::new(b"post"),
+    Tag::new(b"cvt "),
+    Tag::new(b"fpgm"),
+    Tag::new(b"glyf"),
+    Tag::new(b"loca"),
+    Tag::new(b"prep"),
+    Tag::new(b"CFF "),
+    Tag::new(b"VORG"),
+    Tag::new(b"EBDT"),
+    Tag::new(b"EBLC"),
+    Tag::new(b"gasp"),
+    Tag::new(b"hdmx"),
+    Tag::new(b"kern"),
+    Tag::new(b"LTSH"),
+    Tag::new(b"PCLT"),
+    Tag::new(b"VDMX"),
+    Tag::new(b"vhea"),
+    Tag::new(b"vmtx"),
+    Tag::new(b"BASE"),
+    Tag::new(b"GDEF"),
+    Tag::new(b"GPOS"),
+    Tag::new(b"GSUB"),
+    Tag::new(b"EBSC"),
+    Tag::new(b"JSTF"),
+    Tag::new(b"MATH"),
+    Tag::new(b"CBDT"),
+    Tag::new(b"CBLC"),
+    Tag::new(b"COLR"),
+    Tag::new(b"CPAL"),
+    Tag::new(b"SVG "),
+    Tag::new(b"sbix"),
+    Tag::new(b"acnt"),
+    Tag::new(b"avar"),
+    Tag::new(b"bdat"),
+    Tag::new(b"bloc"),
+    Tag::new(b"bsln"),
+    Tag::new(b"cvar"),
+    Tag::new(b"fdsc"),
+    Tag::new(b"feat"),
+    Tag::new(b"fmtx"),
+    Tag::new(b"fvar"),
+    Tag::new(b"gvar"),
+    Tag::new(b"hsty"),
+    Tag::new(b"just"),
+    Tag::new(b"lcar"),
+    Tag::new(b"mort"),
+    Tag::new(b"morx"),
+    Tag::new(b"opbd"),
+    Tag::new(b"prop"),
+    Tag::new(b"trak"),
+    Tag::new(b"Zapf"),
+    Tag::new(b"Silf"),
+    Tag::new(b"Glat"),
+    Tag::new(b"Gloc"),
+    Tag::new(b"Feat"),
+    Tag::new(b"Sill"),
+];
+
+/// The value of the tag index in the entry flags that indicates an explicit
+/// tag follows.
+const ARBITRARY_TAG_INDEX: u8 = 63;
+
+/// Size of the fixed header of a transformed `glyf` table.
+const TRANSFORMED_GLYF_HEADER_LEN: usize = 36;
+
+/// Flag in the transformed `glyf` header indicating that the overlap bitmap
+/// is present.
+const HAS_OVERLAP_BITMAP: u16 = 1;
+
+/// Flags in the transformed `hmtx` table indicating which side bearing
+/// arrays have been omitted.
+const HMTX_NO_PROPORTIONAL_LSBS: u8 = 1;
+const HMTX_NO_MONOSPACE_LSBS: u8 = 2;
+
+/// A brotli decoder, used to decompress the contents of a WOFF2 file.
+///
+/// This is implemented for closures with the same signature as
+/// [`decompress`](Self::decompress), so a decoder from another crate can be
+/// adapted without defining a new type.
+pub trait BrotliDecompressor {
+    /// Decompresses `data`, which is expected to produce exactly
+    /// `decompressed_len` bytes.
+    ///
+    /// Returns `None` if the data is not a valid brotli stream. Implementations
+    /// should stop decoding once `decompressed_len` bytes have been produced,
+    /// to avoid excessive allocation for malicious files.
+    fn decompress(&self, data: &[u8], decompressed_len: usize) -> Option<Vec<u8>>;
+}
+
+impl<F> BrotliDecompressor for F
+where
+    F: Fn(&[u8], usize) -> Option<Vec<u8>>,
+{
+    fn decompress(&self, data: &[u8], decompressed_len: usize) -> Option<Vec<u8>> {
+        self(data, decompressed_len)
+    }
+}
+
+/// An entry in the [WOFF2 table directory](https://www.w3.org/TR/WOFF2/#table_dir_format).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Woff2TableDirectoryEntry {
+    flags: u8,
+    tag: Tag,
+    orig_length: u32,
+    transform_length: Option<u32>,
+}
+
+impl Woff2TableDirectoryEntry {
+    /// The table tag.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// The transform version, from the high two bits of the entry flags.
+    ///
+    /// The meaning of the version depends on the table: for `glyf` and `loca`
+    /// version 0 is the WOFF2 transform and version 3 is the null transform,
+    /// while for all other tables version 0 is the null transform.
+    pub fn transform_version(&self) -> u8 {
+        self.flags >> 6
+    }
+
+    /// Returns true if the table data has been transformed.
+    pub fn is_transformed(&self) -> bool {
+        self.transform_length.is_some()
+    }
+
+    /// Length of the original table, excluding padding.
+    pub fn orig_length(&self) -> u32 {
+        self.orig_length
+    }
+
+    /// Length of the transformed table data, if the table is transformed.
+    pub fn transform_length(&self) -> Option<u32> {
+        self.transform_length
+    }
+
+    /// The length of the table data in the decompressed stream.
+    fn stored_length(&self) -> u32 {
+        self.transform_length.unwrap_or(self.orig_length)
+    }
+}
+
+/// The [collection directory](https://www.w3.org/TR/WOFF2/#collection_dir_format)
+/// of a WOFF2 file that contains a font collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Woff2Collection {
+    version: u32,
+    fonts: Vec<Woff2CollectionFont>,
+}
+
+impl Woff2Collection {
+    /// The version of the TrueType collection header, either 0x00010000 or
+    /// 0x00020000.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The fonts in the collection.
+    pub fn fonts(&self) -> &[Woff2CollectionFont] {
+        &self.fonts
+    }
+}
+
+/// A single font in a WOFF2 collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Woff2CollectionFont {
+    flavor: u32,
+    table_indices: Vec<u16>,
+}
+
+impl Woff2CollectionFont {
+    /// The "sfnt version" of the font.
+    pub fn flavor(&self) -> u32 {
+        self.flavor
+    }
+
+    /// The indices of the tables in this font, into the WOFF2 table
+    /// directory.
+    pub fn table_indices(&self) -> &[u16] {
+        &self.table_indices
+    }
+}
+
+/// A WOFF 2.0 font file.
+#[derive(Clone)]
+pub struct Woff2<'a> {
+    data: FontData<'a>,
+    header: Woff2Header<'a>,
+    table_directory: Vec<Woff2TableDirectoryEntry>,
+    collection: Option<Woff2Collection>,
+    compressed_offset: usize,
+}
+
+impl<'a> Woff2<'a> {
+    /// Reads a WOFF2 file from the given data.
+    pub fn new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let data = FontData::new(data);
+        let header = Woff2Header::read(data)?;
+        if header.signature() != WOFF2_SIGNATURE {
+            return Err(ReadError::InvalidFormat(
+                u32::from_be_bytes(header.signature().to_be_bytes()) as _,
+            ));
+        }
+        let mut cursor = data.cursor();
+        cursor.advance_by(WOFF2_HEADER_LEN);
+        let num_tables = header.num_tables() as usize;
+        let table_directory = (0..num_tables)
+            .map(|_| read_table_directory_entry(&mut cursor))
+            .collect::<Result<Vec<_>, _>>()?;
+        let collection = if header.flavor() == u32::from_be_bytes(TTC_HEADER_TAG.to_be_bytes()) {
+            Some(read_collection_directory(&mut cursor, num_tables)?)
+        } else {
+            None
+        };
+        let compressed_offset = cursor.position()?;
+        Ok(Self {
+            data,
+            header,
+            table_directory,
+            collection,
+            compressed_offset,
+        })
+    }
+
+    /// Returns the WOFF2 header.
+    pub fn header(&self) -> &Woff2Header<'a> {
+        &self.header
+    }
+
+    /// Returns the "sfnt version" of the wrapped font.
+    ///
+    /// This is 'ttcf' for font collections.
+    pub fn flavor(&self) -> u32 {
+        self.header.flavor()
+    }
+
+    /// Returns the directory entries for all tables.
+    pub fn table_directory(&self) -> &[Woff2TableDirectoryEntry] {
+        &self.table_directory
+    }
+
+    /// Returns the collection directory, if this file contains a font
+    /// collection.
+    pub fn collection(&self) -> Option<&Woff2Collection> {
+        self.collection.as_ref()
+    }
+
+    /// Returns the decompressed extended metadata block, if present.
+    ///
+    /// This is an XML document encoded as UTF-8.
+    pub fn metadata(&self, brotli: &impl BrotliDecompressor) -> Option<Result<Vec<u8>, ReadError>> {
+        if self.header.meta_offset() == 0 || self.header.meta_length() == 0 {
+            return None;
+        }
+        Some(
+            self.block(
+                self.header.meta_offset() as usize,
+                self.header.meta_length() as usize,
+            )
+            .and_then(|data| {
+                decompress_brotli(brotli, data, self.header.meta_orig_length() as usize)
+            }),
+        )
+    }
+
+    /// Returns the private data block, if present.
+    pub fn private_data(&self) -> Option<Result<&'a [u8], ReadError>> {
+        if self.header.priv_offset() == 0 || self.header.priv_length() == 0 {
+            return None;
+        }
+        Some(self.block(
+            self.header.priv_offset() as usize,
+            self.header.priv_length() as usize,
+        ))
+    }
+
+    /// Decodes the font, returning the data for the reconstructed sfnt.
+    ///
+    /// If the file contains a font collection, the result is a TrueType
+    /// collection in which tables shared between fonts are stored once.
+    ///
+    /// Transformed tables are not guaranteed to be reconstructed byte for
+    /// byte, so table checksums (and, for a single font, the
+    /// `checkSumAdjustment` field of the `head` table) are recomputed.
+    ///
+    /// The result can be read with [`FileRef::new`](crate::FileRef::new), or
+    /// [`FontRef::new`](crate::FontRef::new) if this is not a collection.
+    pub fn to_sfnt(&self, brotli: &impl BrotliDecompressor) -> Result<Vec<u8>, ReadError> {
+        let stream = self.decompress_tables(brotli)?;
+        let tables = self.reconstruct_tables(&stream)?;
+        match &self.collection {
+            Some(collection) => self.write_collection(collection, &tables),
+            None => self.write_font(&tables),
+        }
+    }
+
+    fn decompress_tables(&self, brotli: &impl BrotliDecompressor) -> Result<Vec<u8>, ReadError> {
+        let total_len = self
+            .table_directory
+            .iter()
+            .try_fold(0usize, |len, entry| {
+                len.checked_add(entry.stored_length() as usize)
+            })
+            .filter(|len| *len <= MAX_DECODED_LEN)
+            .ok_or(ReadError::MalformedData("WOFF2 table data is too large"))?;
+        let compressed = self.block(
+            self.compressed_offset,
+            self.header.total_compressed_size() as usize,
+        )?;
+        decompress_brotli(brotli, compressed, total_len)
+    }
+
+    /// Returns the data for each table, with transforms reversed.
+    ///
+    /// Tables that aren't referenced by any font of a collection are `None`.
+    fn reconstruct_tables<'b>(
+        &self,
+        stream: &'b [u8],
+    ) -> Result<Vec<Option<Cow<'b, [u8]>>>, ReadError> {
+        let num_tables = self.table_directory.len();
+        let mut raw = Vec::with_capacity(num_tables);
+        let mut offset = 0usize;
+        for entry in &self.table_directory {
+            let end = offset + entry.stored_length() as usize;
+            raw.push(stream.get(offset..end).ok_or(ReadError::OutOfBounds)?);
+            offset = end;
+        }
+        let mut tables: Vec<Option<Cow<[u8]>>> = vec![None; num_tables];
+        // the xMin of each glyph of a reconstructed glyf table, which is
+        // needed to reconstruct hmtx
+        let mut x_mins: Vec<Option<Vec<i16>>> = vec![None; num_tables];
+        let single_font;
+        let fonts = match &self.collection {
+            Some(collection) => collection.fonts(),
+            None => {
+                single_font = [Woff2CollectionFont {
+                    flavor: self.flavor(),
+                    table_indices: (0..num_tables as u16).collect(),
+                }];
+                &single_font[..]
+            }
+        };
+        for font in fonts {
+            let find = |tag: Tag| {
+                font.table_indices
+                    .iter()
+                    .map(|ix| *ix as usize)
+                    .find(|ix| self.table_directory[*ix].tag == tag)
+            };
+            let is_transformed = |ix: &usize| self.table_directory[*ix].is_transformed();
+            for ix in font.table_indices.iter().map(|ix| *ix as usize) {
+                let entry = &self.table_directory[ix];
+                if !entry.is_transformed() {
+                    tables[ix] = Some(Cow::Borrowed(raw[ix]));
+                } else if ![Glyf::TAG, Loca::TAG, Hmtx::TAG].contains(&entry.tag) {
+                    return Err(ReadError::MalformedData(
+                        "unsupported WOFF2 table transform",
+                    ));
+                }
+            }
+            if let Some(glyf_ix) = find(Glyf::TAG).filter(is_transformed) {
+                let loca_ix =
+                    find(Loca::TAG)
+                        .filter(is_transformed)
+                        .ok_or(ReadError::MalformedData(
+                            "WOFF2 glyf table transformed without loca",
+                        ))?;
+                if tables[glyf_ix].is_none() {
+                    let glyf = reconstruct_glyf(raw[glyf_ix])?;
+                    if glyf.loca.len() != self.table_directory[loca_ix].orig_length as usize {
+                        return Err(ReadError::MalformedData(
+                            "WOFF2 loca length does not match reconstructed glyf",
+                        ));
+                    }
+                    tables[glyf_ix] = Some(Cow::Owned(glyf.glyf));
+                    tables[loca_ix] = Some(Cow::Owned(glyf.loca));
+                    x_mins[glyf_ix] = Some(glyf.x_mins);
+                }
+            }
+            if let Some(hmtx_ix) = find(Hmtx::TAG).filter(is_transformed) {
+                if tables[hmtx_ix].is_none() {
+                    let x_mins = find(Glyf::TAG).and_then(|ix| x_mins[ix].as_deref()).ok_or(
+                        ReadError::MalformedData("WOFF2 hmtx table transformed without glyf"),
+                    )?;
+                    let hhea = find(Hhea::TAG)
+                        .and_then(|ix| tables[ix].as_deref())
+                        .ok_or(ReadError::TableIsMissing(Hhea::TAG))?;
+                    let num_h_metrics = Hhea::read(FontData::new(hhea))?.number_of_long_metrics();
+                    tables[hmtx_ix] = Some(Cow::Owned(reconstruct_hmtx(
+                        raw[hmtx_ix],
+                        num_h_metrics,
+                        x_mins,
+                    )?));
+                }
+            }
+        }
+        Ok(tables)
+    }
+
+    fn write_font(&self, tables: &[Option<Cow<[u8]>>]) -> Result<Vec<u8>, ReadError> {
+        let entries = &self.table_directory;
+        let mut builder = SfntBuilder::new(self.flavor(), entries.len())?;
+        builder.reserve(self.header.total_sfnt_size() as usize);
+        // table records must be sorted by tag, while the data is written in
+        // the order of the WOFF2 table directory
+        let mut order = (0..entries.len()).collect::<Vec<_>>();
+        order.sort_by_key(|ix| entries[*ix].tag);
+        let mut record_indices = vec![0; entries.len()];
+        for (record_ix, ix) in order.into_iter().enumerate() {
+            record_indices[ix] = record_ix;
+        }
+        let mut head_range = None;
+        for (ix, entry) in entries.iter().enumerate() {
+            let table = table_for_entry(tables, ix)?;
+            let offset = builder.push_data(table)?;
+            if entry.tag == Head::TAG {
+                head_range = Some(offset..offset + table.len());
+            }
+            builder.write_record(
+                0,
+                record_indices[ix],
+                entry.tag,
+                table_checksum(entry.tag, table),
+                offset,
+                table.len(),
+            )?;
+        }
+        let mut font = builder.finish();
+        if let Some(range) = head_range {
+            update_checksum_adjustment(&mut font, range);
+        }
+        Ok(font)
+    }
+
+    fn write_collection(
+        &self,
+        collection: &Woff2Collection,
+        tables: &[Option<Cow<[u8]>>],
+    ) -> Result<Vec<u8>, ReadError> {
+        let fonts = collection
+            .fonts
+            .iter()
+            .map(|font| (font.flavor, font.table_indices.len()))
+            .collect::<Vec<_>>();
+        let mut builder = SfntBuilder::new_collection(collection.version, &fonts)?;
+        builder.reserve(self.header.total_sfnt_size() as usize);
+        // tables may be shared between fonts, so we write the data for each
+        // table once, followed by the records of each font
+        let mut locations = vec![None; tables.len()];
+        for (ix, table) in tables.iter().enumerate() {
+            if let Some(table) = table {
+                let offset = builder.push_data(table)?;
+                let checksum = table_checksum(self.table_directory[ix].tag, table);
+                locations[ix] = Some((offset, table.len(), checksum));
+            }
+        }
+        for (font_ix, font) in collection.fonts.iter().enumerate() {
+            let mut indices = font
+                .table_indices
+                .iter()
+                .map(|ix| *ix as usize)
+                .collect::<Vec<_>>();
+            indices.sort_by_key(|ix| self.table_directory[*ix].tag);
+            for (record_ix, ix) in indices.into_iter().enumerate() {
+                let (offset, len, checksum) = locations[ix].ok_or(ReadError::MalformedData(
+                    "WOFF2 table could not be reconstructed",
+                ))?;
+                let tag = self.table_directory[ix].tag;
+                builder.write_record(font_ix, record_ix, tag, checksum, offset, len)?;
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    fn block(&self, offset: usize, len: usize) -> Result<&'a [u8], ReadError> {
+        let end = offset.checked_add(len).ok_or(ReadError::OutOfBounds)?;
+        self.data
+            .as_bytes()
+            .get(offset..end)
+            .ok_or(ReadError::OutOfBounds)
+    }
+}
+
+fn read_table_directory_entry(cursor: &mut Cursor) -> Result<Woff2TableDirectoryEntry, ReadError> {
+    let flags: u8 = cursor.read()?;
+    let tag_index = flags & 0x3F;
+    let tag = if tag_index == ARBITRARY_TAG_INDEX {
+        cursor.read()?
+    } else {
+        KNOWN_TAGS[tag_index as usize]
+    };
+    let transform_version = flags >> 6;
+    let orig_length = read_uint_base128(cursor)?;
+    // glyf and loca use version 3 for the null transform, while all other
+    // tables use version 0
+    let is_transformed = if tag == Glyf::TAG || tag == Loca::TAG {
+        transform_version != 3
+    } else {
+        transform_version != 0
+    };
+    let transform_length = if is_transformed {
+        let len = read_uint_base128(cursor)?;
+        if tag == Loca::TAG && len != 0 {
+            return Err(ReadError::MalformedData(
+                "WOFF2 transformed loca table must be empty",
+            ));
+        }
+        Some(len)
+    } else {
+        None
+    };
+    Ok(Woff2TableDirectoryEntry {
+        flags,
+        tag,
+        orig_length,
+        transform_length,
+    })
+}
+
+fn read_collection_directory(
+    cursor: &mut Cursor,
+    num_tables: usize,
+) -> Result<Woff2Collection, ReadError> {
+    let version: u32 = cursor.read()?;
+    if version != 0x0001_0000 && version != 0x0002_0000 {
+        return Err(ReadError::InvalidFormat(version as _));
+    }
+    let num_fonts = read_255_u16(cursor)?;
+    let fonts = (0..num_fonts)
+        .map(|_| {
+            let num_font_tables = read_255_u16(cursor)?;
+            let flavor = cursor.read()?;
+            let table_indices = (0..num_font_tables)
+                .map(|_| {
+                    read_255_u16(cursor).and_then(|ix| {
+                        ((ix as usize) < num_tables)
+                            .then_some(ix)
+                            .ok_or(ReadError::OutOfBounds)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Woff2CollectionFont {
+                flavor,
+                table_indices,
+            })
+        })
+        .collect::<Result<Vec<_>, ReadError>>()?;
+    Ok(Woff2Collection { version, fonts })
+}
+
+/// Reads a [UIntBase128](https://www.w3.org/TR/WOFF2/#DataTypes) value.
+fn read_uint_base128(cursor: &mut Cursor) -> Result<u32, ReadError> {
+    let mut value = 0u32;
+    for i in 0..5 {
+        let byte: u8 = cursor.read()?;
+        // leading zeros are not permitted
+        if i == 0 && byte == 0x80 {
+            return Err(ReadError::MalformedData("invalid UIntBase128 value"));
+        }
+        // the next shift would overflow
+        if value & 0xFE00_0000 != 0 {
+            return Err(ReadError::MalformedData("UIntBase128 value overflows"));
+        }
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(ReadError::MalformedData("UIntBase128 value is too long"))
+}
+
+/// Reads a [255UInt16](https://www.w3.org/TR/WOFF2/#DataTypes) value.
+fn read_255_u16(cursor: &mut Cursor) -> Result<u16, ReadError> {
+    const WORD_CODE: u8 = 253;
+    const ONE_MORE_BYTE_CODE2: u8 = 254;
+    const ONE_MORE_BYTE_CODE1: u8 = 255;
+    const LOWEST_U_CODE: u16 = 253;
+    let code: u8 = cursor.read()?;
+    Ok(match code {
+        WORD_CODE => cursor.read()?,
+        ONE_MORE_BYTE_CODE1 => cursor.read::<u8>()? as u16 + LOWEST_U_CODE,
+        ONE_MORE_BYTE_CODE2 => cursor.read::<u8>()? as u16 + LOWEST_U_CODE * 2,
+        _ => code as u16,
+    })
+}
+
+fn decompress_brotli(
+    brotli: &impl BrotliDecompressor,
+    data: &[u8],
+    expected_len: usize,
+) -> Result<Vec<u8>, ReadError> {
+    let malformed = || ReadError::MalformedData("invalid brotli compressed WOFF2 data");
+    if expected_len > MAX_DECODED_LEN {
+        return Err(malformed());
+    }
+    let decoded = brotli
+        .decompress(data, expected_len)
+        .ok_or_else(malformed)?;
+    if decoded.len() != expected_len {
+        return Err(malformed());
+    }
+    Ok(decoded)
+}
+
+fn table_for_entry<'b>(tables: &'b [Option<Cow<[u8]>>], ix: usize) -> Result<&'b [u8], ReadError> {
+    tables
+        .get(ix)
+        .and_then(|table| table.as_deref())
+        .ok_or(ReadError::MalformedData(
+            "WOFF2 table could not be reconstructed",
+        ))
+}
+
+fn table_checksum(tag: Tag, data: &[u8]) -> u32 {
+    if tag == Head::TAG {
+        head_checksum(data)
+    } else {
+        checksum(data)
+    }
+}
+
+/// Sets the `checkSumAdjustment` field of the `head` table at the given
+/// range of a single font.
+fn update_checksum_adjustment(font: &mut [u8], head: core::ops::Range<usize>) {
+    let start = head.start + HEAD_ADJUSTMENT_OFFSET;
+    if head.len() < HEAD_ADJUSTMENT_OFFSET + 4 {
+        return;
+    }
+    let current = u32::from_be_bytes(font[start..start + 4].try_into().unwrap());
+    let sum = checksum(font).wrapping_sub(current);
+    let adjustment = CHECKSUM_MAGIC.wrapping_sub(sum);
+    font[start..start + 4].copy_from_slice(&adjustment.to_be_bytes());
+}
+
+/// The result of reversing the `glyf` transform.
+struct ReconstructedGlyf {
+    glyf: Vec<u8>,
+    loca: Vec<u8>,
+    x_mins: Vec<i16>,
+}
+
+/// A point in a simple glyph, in absolute coordinates.
+#[derive(Clone, Copy)]
+struct Point {
+    x: i16,
+    y: i16,
+    on_curve: bool,
+}
+
+/// Reconstructs the `glyf` and `loca` tables from a
+/// [transformed glyf table](https://www.w3.org/TR/WOFF2/#glyf_table_format).
+fn reconstruct_glyf(data: &[u8]) -> Result<ReconstructedGlyf, ReadError> {
+    let data = FontData::new(data);
+    let mut header = data.cursor();
+    let _reserved: u16 = header.read()?;
+    let option_flags: u16 = header.read()?;
+    let num_glyphs: u16 = header.read()?;
+    let index_format: u16 = header.read()?;
+    // the streams follow the header, in the same order as their sizes
+    let mut streams = [FontData::new(&[]); 7];
+    let mut offset = TRANSFORMED_GLYF_HEADER_LEN;
+    for stream in &mut streams {
+        let len = header.read::<u32>()? as usize;
+        let end = offset.checked_add(len).ok_or(ReadError::OutOfBounds)?;
+        *stream = data.slice(offset..end).ok_or(ReadError::OutOfBounds)?;
+        offset = end;
+    }
+    let [n_contour_stream, n_points_stream, flag_stream, glyph_stream, composite_stream, bbox_stream, instruction_stream] =
+        streams;
+    let num_glyphs_usize = num_glyphs as usize;
+    let bbox_bitmap_len = num_glyphs_usize.div_ceil(32) * 4;
+    let bbox_bitmap = bbox_stream
+        .slice(..bbox_bitmap_len)
+        .ok_or(ReadError::OutOfBounds)?
+        .as_bytes();
+    let overlap_bitmap = if option_flags & HAS_OVERLAP_BITMAP != 0 {
+        let end = offset
+            .checked_add(num_glyphs_usize.div_ceil(8))
+            .ok_or(ReadError::OutOfBounds)?;
+        Some(
+            data.slice(offset..end)
+                .ok_or(ReadError::OutOfBounds)?
+                .as_bytes(),
+        )
+    } else {
+        None
+    };
+    let bit_is_set = |bitmap: &[u8], gid: usize| bitmap[gid >> 3] & (0x80 >> (gid & 7)) != 0;
+
+    let mut n_contours_cursor = n_contour_stream.cursor();
+    let mut n_points_cursor = n_points_stream.cursor();
+    let mut flag_cursor = flag_stream.cursor();
+    let mut glyph_cursor = glyph_stream.cursor();
+    let mut composite_offset = 0;
+    let mut bbox_cursor = bbox_stream.cursor();
+    bbox_cursor.advance_by(bbox_bitmap_len);
+    let mut instruction_cursor = instruction_stream.cursor();
+
+    let mut glyf = Vec::new();
+    let mut loca_offsets = Vec::with_capacity(num_glyphs_usize + 1);
+    let mut x_mins = Vec::with_capacity(num_glyphs_usize);
+    let mut points = Vec::new();
+    let mut end_points = Vec::new();
+    for gid in 0..num_glyphs_usize {
+        loca_offsets.push(glyf.len());
+        let n_contours: i16 = n_contours_cursor.read()?;
+        let has_bbox = bit_is_set(bbox_bitmap, gid);
+        match n_contours {
+            0 => {
+                if has_bbox {
+                    return Err(ReadError::MalformedData(
+                        "WOFF2 empty glyph has a bounding box",
+                    ));
+                }
+                x_mins.push(0);
+                continue;
+            }
+            -1 => {
+                // composite glyphs must have an explicit bounding box
+                if !has_bbox {
+                    return Err(ReadError::MalformedData(
+                        "WOFF2 composite glyph is missing a bounding box",
+                    ));
+                }
+                let bbox: [i16; 4] = read_bbox(&mut bbox_cursor)?;
+                let (len, have_instructions) =
+                    composite_glyph_len(composite_stream, composite_offset)?;
+                let components = composite_stream
+                    .slice(composite_offset..composite_offset + len)
+                    .ok_or(ReadError::OutOfBounds)?;
+                composite_offset += len;
+                push_i16s(&mut glyf, &[-1]);
+                push_i16s(&mut glyf, &bbox);
+                glyf.extend_from_slice(components.as_bytes());
+                if have_instructions {
+                    let instructions =
+                        read_instructions(&mut glyph_cursor, &mut instruction_cursor)?;
+                    glyf.extend_from_slice(&(instructions.len() as u16).to_be_bytes());
+                    glyf.extend_from_slice(instructions);
+                }
+                x_mins.push(bbox[0]);
+            }
+            n if n > 0 => {
+                end_points.clear();
+                let mut num_points = 0u16;
+                for _ in 0..n {
+                    let n_points = read_255_u16(&mut n_points_cursor)?;
+                    num_points = num_points
+                        .checked_add(n_points)
+                        .ok_or(ReadError::MalformedData("WOFF2 glyph has too many points"))?;
+                    end_points.push(num_points.checked_sub(1).ok_or(ReadError::MalformedData(
+                        "WOFF2 glyph contour has no points",
+                    ))?);
+                }
+                points.clear();
+                let (mut x, mut y) = (0i16, 0i16);
+                for _ in 0..num_points {
+                    let flag: u8 = flag_cursor.read()?;
+                    let (dx, dy) = decode_triplet(flag & 0x7F, &mut glyph_cursor)?;
+                    let coord_overflow =
+                        || ReadError::MalformedData("WOFF2 glyph coordinate overflows");
+                    x = i16::try_from(x as i32 + dx).map_err(|_| coord_overflow())?;
+                    y = i16::try_from(y as i32 + dy).map_err(|_| coord_overflow())?;
+                    points.push(Point {
+                        x,
+                        y,
+                        on_curve: flag & 0x80 == 0,
+                    });
+                }
+                let instructions = read_instructions(&mut glyph_cursor, &mut instruction_cursor)?;
+                let bbox = if has_bbox {
+                    read_bbox(&mut bbox_cursor)?
+                } else {
+                    compute_bbox(&points)
+                };
+                let overlap = overlap_bitmap.is_some_and(|bitmap| bit_is_set(bitmap, gid));
+                push_i16s(&mut glyf, &[n]);
+                push_i16s(&mut glyf, &bbox);
+                for end_point in &end_points {
+                    glyf.extend_from_slice(&end_point.to_be_bytes());
+                }
+                glyf.extend_from_slice(&(instructions.len() as u16).to_be_bytes());
+                glyf.extend_from_slice(instructions);
+                write_simple_glyph_points(&mut glyf, &points, overlap)?;
+                x_mins.push(bbox[0]);
+            }
+            _ => {
+                return Err(ReadError::MalformedData(
+                    "WOFF2 glyph has invalid number of contours",
+                ))
+            }
+        }
+        // glyphs are padded to a four byte boundary
+        glyf.resize(glyf.len().next_multiple_of(4), 0);
+        if glyf.len() > MAX_DECODED_LEN {
+            return Err(ReadError::MalformedData("decoded font is too large"));
+        }
+    }
+    loca_offsets.push(glyf.len());
+    let loca = match index_format {
+        0 => {
+            // short offsets are stored divided by two
+            let mut loca = Vec::with_capacity(loca_offsets.len() * 2);
+            for offset in loca_offsets {
+                let offset = u16::try_from(offset / 2).map_err(|_| {
+                    ReadError::MalformedData("WOFF2 glyf table too large for short loca")
+                })?;
+                loca.extend_from_slice(&offset.to_be_bytes());
+            }
+            loca
+        }
+        1 => loca_offsets
+            .into_iter()
+            .flat_map(|offset| (offset as u32).to_be_bytes())
+            .collect(),
+        _ => return Err(ReadError::InvalidFormat(index_format as _)),
+    };
+    Ok(ReconstructedGlyf { glyf, loca, x_mins })
+}
+
+/// Decodes the coordinate deltas of a single point of a simple glyph.
+///
+/// See the [triplet encoding](https://www.w3.org/TR/WOFF2/#triplet_decoding)
+/// in the specification.
+fn decode_triplet(flag: u8, glyphs: &mut Cursor) -> Result<(i32, i32), ReadError> {
+    fn with_sign(flag: u8, value: i32) -> i32 {
+        if flag & 1 != 0 {
+            value
+        } else {
+            -value
+        }
+    }
+    let mut next = || glyphs.read::<u8>().map(i32::from);
+    let f = flag as i32;
+    Ok(match flag {
+        0..=9 => {
+            let b0 = next()?;
+            (0, with_sign(flag, ((f & 14) << 7) + b0))
+        }
+        10..=19 => {
+            let b0 = next()?;
+            (with_sign(flag, (((f - 10) & 14) << 7) + b0), 0)
+        }
+        20..=83 => {
+            let b0 = f - 20;
+            let b1 = next()?;
+            (
+                with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+                with_sign(flag >> 1, 1 + ((b0 & 0x0C) << 2) + (b1 & 0x0F)),
+            )
+        }
+        84..=119 => {
+            let b0 = f - 84;
+            let (b1, b2) = (next()?, next()?);
+            (
+                with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+                with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+            )
+        }
+        120..=123 => {
+            let (b1, b2, b3) = (next()?, next()?, next()?);
+            (
+                with_sign(flag, (b1 << 4) + (b2 >> 4)),
+                with_sign(flag >> 1, ((b2 & 0x0F) << 8) + b3),
+            )
+        }
+        _ => {
+            let (b1, b2, b3, b4) = (next()?, next()?, next()?, next()?);
+            (
+                with_sign(flag, (b1 << 8) + b2),
+                with_sign(flag >> 1, (b3 << 8) + b4),
+            )
+        }
+    })
+}
+
+/// Returns the length of the composite glyph data at `offset`, and whether
+/// the glyph has instructions.
+fn composite_glyph_len(data: FontData, offset: usize) -> Result<(usize, bool), ReadError> {
+    let mut pos = offset;
+    let mut have_instructions = false;
+    loop {
+        let flags = CompositeGlyphFlags::from_bits_truncate(data.read_at::<u16>(pos)?);
+        // flags and glyph index
+        pos += 4;
+        pos += if flags.contains(CompositeGlyphFlags::ARG_1_AND_2_ARE_WORDS) {
+            4
+        } else {
+            2
+        };
+        pos += if flags.contains(CompositeGlyphFlags::WE_HAVE_A_SCALE) {
+            2
+        } else if flags.contains(CompositeGlyphFlags::WE_HAVE_AN_X_AND_Y_SCALE) {
+            4
+        } else if flags.contains(CompositeGlyphFlags::WE_HAVE_A_TWO_BY_TWO) {
+            8
+        } else {
+            0
+        };
+        have_instructions |= flags.contains(CompositeGlyphFlags::WE_HAVE_INSTRUCTIONS);
+        if !flags.contains(CompositeGlyphFlags::MORE_COMPONENTS) {
+            break;
+        }
+    }
+    if pos > data.len() {
+        return Err(ReadError::OutOfBounds);
+    }
+    Ok((pos - offset, have_instructions))
+}
+
+fn read_instructions<'b>(
+    glyphs: &mut Cursor,
+    instructions: &mut Cursor<'b>,
+) -> Result<&'b [u8], ReadError> {
+    let len = read_255_u16(glyphs)?;
+    instructions.read_array(len as usize)
+}
+
+fn read_bbox(cursor: &mut Cursor) -> Result<[i16; 4], ReadError> {
+    Ok([
+        cursor.read()?,
+        cursor.read()?,
+        cursor.read()?,
+        cursor.read()?,
+    ])
+}
+
+fn compute_bbox(points: &[Point]) -> [i16; 4] {
+    let Some(first) = points.first() else {
+        return [0; 4];
+    };
+    points.iter().fold(
+        [first.x, first.y, first.x, first.y],
+        |[x_min, y_min, x_max, y_max], point| {
+            [
+                x_min.min(point.x),
+                y_min.min(point.y),
+                x_max.max(point.x),
+                y_max.max(point.y),
+            ]
+        },
+    )
+}
+
+fn push_i16s(data: &mut Vec<u8>, values: &[i16]) {
+    for value in values {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+/// Writes the flags and coordinates of a simple glyph in the standard
+/// `glyf` encoding, using the smallest representation for each value.
+fn write_simple_glyph_points(
+    glyf: &mut Vec<u8>,
+    points: &[Point],
+    overlap: bool,
+) -> Result<(), ReadError> {
+    let mut flags = Vec::with_capacity(points.len());
+    let mut x_coords = Vec::new();
+    let mut y_coords = Vec::new();
+    let (mut last_x, mut last_y) = (0i32, 0i32);
+    let mut last_flag = None;
+    let mut last_flag_ix = 0;
+    let mut repeat_count = 0u8;
+    for (i, point) in points.iter().enumerate() {
+        let mut flag = SimpleGlyphFlags::empty();
+        if point.on_curve {
+            flag |= SimpleGlyphFlags::ON_CURVE_POINT;
+        }
+        if i == 0 && overlap {
+            flag |= SimpleGlyphFlags::OVERLAP_SIMPLE;
+        }
+        let dx = point.x as i32 - last_x;
+        let dy = point.y as i32 - last_y;
+        (last_x, last_y) = (point.x as i32, point.y as i32);
+        flag |= encode_delta(
+            dx,
+            &mut x_coords,
+            SimpleGlyphFlags::X_SHORT_VECTOR,
+            SimpleGlyphFlags::X_IS_SAME_OR_POSITIVE_X_SHORT_VECTOR,
+        )?;
+        flag |= encode_delta(
+            dy,
+            &mut y_coords,
+            SimpleGlyphFlags::Y_SHORT_VECTOR,
+            SimpleGlyphFlags::Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR,
+        )?;
+        if last_flag == Some(flag) && repeat_count != u8::MAX {
+            flags[last_flag_ix] |= SimpleGlyphFlags::REPEAT_FLAG.bits();
+            repeat_count += 1;
+        } else {
+            if repeat_count != 0 {
+                flags.push(repeat_count);
+            }
+            last_flag_ix = flags.len();
+            flags.push(flag.bits());
+            repeat_count = 0;
+        }
+        last_flag = Some(flag);
+    }
+    if repeat_count != 0 {
+        flags.push(repeat_count);
+    }
+    glyf.extend_from_slice(&flags);
+    glyf.extend_from_slice(&x_coords);
+    glyf.extend_from_slice(&y_coords);
+    Ok(())
+}
+
+/// Writes a single coordinate delta, returning the flags describing its
+/// encoding.
+fn encode_delta(
+    delta: i32,
+    coords: &mut Vec<u8>,
+    short: SimpleGlyphFlags,
+    same_or_positive: SimpleGlyphFlags,
+) -> Result<SimpleGlyphFlags, ReadError> {
+    if delta == 0 {
+        return Ok(same_or_positive);
+    }
+    if (-255..=255).contains(&delta) {
+        coords.push(delta.unsigned_abs() as u8);
+        return Ok(if delta > 0 {
+            short | same_or_positive
+        } else {
+            short
+        });
+    }
+    let delta = i16::try_from(delta)
+        .map_err(|_| ReadError::MalformedData("WOFF2 glyph coordinate overflows"))?;
+    coords.extend_from_slice(&delta.to_be_bytes());
+    Ok(SimpleGlyphFlags::empty())
+}
+
+/// Reconstructs the `hmtx` table from a
+/// [transformed hmtx table](https://www.w3.org/TR/WOFF2/#hmtx_table_format).
+///
+/// Omitted side bearings are taken from the xMin of the corresponding glyph.
+fn reconstruct_hmtx(data: &[u8], num_h_metrics: u16, x_mins: &[i16]) -> Result<Vec<u8>, ReadError> {
+    let mut cursor = FontData::new(data).cursor();
+    let flags: u8 = cursor.read()?;
+    let num_glyphs = x_mins.len();
+    let num_h_metrics = num_h_metrics as usize;
+    // at least one array must be omitted, and the reserved bits must be zero
+    if flags & (HMTX_NO_PROPORTIONAL_LSBS | HMTX_NO_MONOSPACE_LSBS) == 0
+        || flags & !(HMTX_NO_PROPORTIONAL_LSBS | HMTX_NO_MONOSPACE_LSBS) != 0
+    {
+        return Err(ReadError::MalformedData(
+            "invalid WOFF2 hmtx transform flags",
+        ));
+    }
+    if num_h_metrics == 0 || num_h_metrics > num_glyphs {
+        return Err(ReadError::MalformedData(
+            "WOFF2 hmtx table has invalid number of metrics",
+        ));
+    }
+    let advances = cursor.read_array::<BigEndian<u16>>(num_h_metrics)?;
+    let lsbs = if flags & HMTX_NO_PROPORTIONAL_LSBS == 0 {
+        Some(cursor.read_array::<BigEndian<i16>>(num_h_metrics)?)
+    } else {
+        None
+    };
+    let monospace_lsbs = if flags & HMTX_NO_MONOSPACE_LSBS == 0 {
+        Some(cursor.read_array::<BigEndian<i16>>(num_glyphs - num_h_metrics)?)
+    } else {
+        None
+    };
+    let mut hmtx = Vec::with_capacity(num_h_metrics * 4 + (num_glyphs - num_h_metrics) * 2);
+    for (i, advance) in advances.iter().enumerate() {
+        let lsb = lsbs.map(|lsbs| lsbs[i].get()).unwrap_or(x_mins[i]);
+        hmtx.extend_from_slice(&advance.get().to_be_bytes());
+        hmtx.extend_from_slice(&lsb.to_be_bytes());
+    }
+    for (i, x_min) in x_mins.iter().enumerate().skip(num_h_metrics) {
+        let lsb = monospace_lsbs
+            .map(|lsbs| lsbs[i - num_h_metrics].get())
+            .unwrap_or(*x_min);
+        hmtx.extend_from_slice(&lsb.to_be_bytes());
+    }
+    Ok(hmtx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tables::glyf::Glyph, FileRef, FontRef, TableProvider};
+    use font_test_data as data;
+    use types::GlyphId;
+
+    /// A "decompressor" for test files, which store data uncompressed.
+    fn identity(data: &[u8], decompressed_len: usize) -> Option<Vec<u8>> {
+        (data.len() == decompressed_len).then(|| data.to_vec())
+    }
+
+    fn brotli(data: &[u8], decompressed_len: usize) -> Option<Vec<u8>> {
+        use std::io::Read;
+        let mut out = Vec::with_capacity(decompressed_len);
+        brotli::Decompressor::new(data, 4096)
+            .take(decompressed_len as u64 + 1)
+            .read_to_end(&mut out)
+            .ok()?;
+        (out.len() == decompressed_len).then_some(out)
+    }
+
+    fn push_base128(out: &mut Vec<u8>, value: u32) {
+        let mut bytes = vec![];
+        let mut value = value;
+        loop {
+            bytes.push((value & 0x7F) as u8);
+            value >>= 7;
+            if value == 0 {
+                break;
+            }
+        }
+        for (i, byte) in bytes.iter().enumerate().rev() {
+            out.push(if i != 0 { byte | 0x80 } else { *byte });
+        }
+    }
+
+    fn push_255_u16(out: &mut Vec<u8>, value: u16) {
+        match value {
+            0..=252 => out.push(value as u8),
+            253..=505 => out.extend([255, (value - 253) as u8]),
+            506..=761 => out.extend([254, (value - 506) as u8]),
+            _ => {
+                out.push(253);
+                out.extend(value.to_be_bytes());
+            }
+        }
+    }
+
+    /// Appends the triplet encoding of a point to the flag and glyph streams.
+    fn push_triplet(flags: &mut Vec<u8>, glyphs: &mut Vec<u8>, dx: i32, dy: i32, on_curve: bool) {
+        let (abs_x, abs_y) = (dx.unsigned_abs(), dy.unsigned_abs());
+        let on_curve_bit = if on_curve { 0 } else { 128 };
+        let x_sign_bit = (dx >= 0) as u32;
+        let y_sign_bit = (dy >= 0) as u32;
+        let xy_sign_bits = x_sign_bit + 2 * y_sign_bit;
+        let flag = if dx == 0 && abs_y < 1280 {
+            glyphs.push(abs_y as u8);
+            ((abs_y & 0xF00) >> 7) + y_sign_bit
+        } else if dy == 0 && abs_x < 1280 {
+            glyphs.push(abs_x as u8);
+            10 + ((abs_x & 0xF00) >> 7) + x_sign_bit
+        } else if abs_x < 65 && abs_y < 65 {
+            glyphs.push(((((abs_x - 1) & 0xF) << 4) | ((abs_y - 1) & 0xF)) as u8);
+            20 + ((abs_x - 1) & 0x30) + (((abs_y - 1) & 0x30) >> 2) + xy_sign_bits
+        } else if abs_x < 769 && abs_y < 769 {
+            glyphs.extend([(abs_x - 1) as u8, (abs_y - 1) as u8]);
+            84 + 12 * (((abs_x - 1) & 0x300) >> 8) + (((abs_y - 1) & 0x300) >> 6) + xy_sign_bits
+        } else if abs_x < 4096 && abs_y < 4096 {
+            glyphs.extend([
+                (abs_x >> 4) as u8,
+                (((abs_x & 0xF) << 4) | (abs_y >> 8)) as u8,
+                abs_y as u8,
+            ]);
+            120 + xy_sign_bits
+        } else {
+            glyphs.extend((abs_x as u16).to_be_bytes());
+            glyphs.extend((abs_y as u16).to_be_bytes());
+            124 + xy_sign_bits
+        };
+        flags.push(flag as u8 + on_curve_bit);
+    }
+
+    /// Applies the WOFF2 transform to the glyf table of the given font.
+    fn transform_glyf(font: &FontRef) -> Vec<u8> {
+        let glyf = font.glyf().unwrap();
+        let loca = font.loca(None).unwrap();
+        let num_glyphs = loca.len();
+        let mut n_contours = vec![];
+        let mut n_points = vec![];
+        let mut flags = vec![];
+        let mut glyphs = vec![];
+        let mut composites = vec![];
+        let mut bbox_bitmap = vec![0u8; num_glyphs.div_ceil(32) * 4];
+        let mut bboxes = vec![];
+        let mut instructions = vec![];
+        let mut overlap_bitmap = vec![0u8; num_glyphs.div_ceil(8)];
+        let mut has_overlap = false;
+        for gid in 0..num_glyphs {
+            let glyph = loca.get_glyf(GlyphId::new(gid as u32), &glyf).unwrap();
+            let header_bbox = glyph
+                .as_ref()
+                .map(|glyph| [glyph.x_min(), glyph.y_min(), glyph.x_max(), glyph.y_max()]);
+            let mut explicit_bbox = false;
+            match glyph {
+                None => n_contours.extend(0i16.to_be_bytes()),
+                Some(Glyph::Simple(simple)) => {
+                    n_contours.extend(simple.number_of_contours().to_be_bytes());
+                    let mut start = 0;
+                    for end in simple.end_pts_of_contours() {
+                        push_255_u16(&mut n_points, end.get() + 1 - start);
+                        start = end.get() + 1;
+                    }
+                    let points = simple.points().collect::<Vec<_>>();
+                    let (mut x, mut y) = (0, 0);
+                    for point in &points {
+                        let (dx, dy) = (point.x as i32 - x, point.y as i32 - y);
+                        push_triplet(&mut flags, &mut glyphs, dx, dy, point.on_curve);
+                        (x, y) = (point.x as i32, point.y as i32);
+                    }
+                    push_255_u16(&mut glyphs, simple.instruction_length());
+                    instructions.extend(simple.instructions());
+                    let points = points
+                        .iter()
+                        .map(|point| Point {
+                            x: point.x,
+                            y: point.y,
+                            on_curve: point.on_curve,
+                        })
+                        .collect::<Vec<_>>();
+                    explicit_bbox = Some(compute_bbox(&points)) != header_bbox;
+                    if simple.has_overlapping_contours() {
+                        has_overlap = true;
+                        overlap_bitmap[gid >> 3] |= 0x80 >> (gid & 7);
+                    }
+                }
+                Some(Glyph::Composite(composite)) => {
+                    n_contours.extend((-1i16).to_be_bytes());
+                    let data = FontData::new(composite.component_data());
+                    let (len, have_instructions) = composite_glyph_len(data, 0).unwrap();
+                    composites.extend(&data.as_bytes()[..len]);
+                    if have_instructions {
+                        let glyph_instructions = composite.instructions().unwrap_or_default();
+                        push_255_u16(&mut glyphs, glyph_instructions.len() as u16);
+                        instructions.extend(glyph_instructions);
+                    }
+                    explicit_bbox = true;
+                }
+            }
+            if explicit_bbox {
+                bbox_bitmap[gid >> 3] |= 0x80 >> (gid & 7);
+                for value in header_bbox.unwrap() {
+                    bboxes.extend(value.to_be_bytes());
+                }
+            }
+        }
+        let bbox_stream = [bbox_bitmap, bboxes].concat();
+        let streams = [
+            n_contours,
+            n_points,
+            flags,
+            glyphs,
+            composites,
+            bbox_stream,
+            instructions,
+        ];
+        let mut out = vec![];
+        out.extend(0u16.to_be_bytes());
+        out.extend((has_overlap as u16).to_be_bytes());
+        out.extend((num_glyphs as u16).to_be_bytes());
+        out.extend((font.head().unwrap().index_to_loc_format() as u16).to_be_bytes());
+        for stream in &streams {
+            out.extend((stream.len() as u32).to_be_bytes());
+        }
+        for stream in &streams {
+            out.extend(stream);
+        }
+        if has_overlap {
+            out.extend(overlap_bitmap);
+        }
+        out
+    }
+
+    /// Applies the WOFF2 transform to the hmtx table of the given font, if
+    /// any side bearings match the glyph bounding boxes.
+    fn transform_hmtx(font: &FontRef) -> Option<Vec<u8>> {
+        let glyf = font.glyf().unwrap();
+        let loca = font.loca(None).unwrap();
+        let hmtx = font.hmtx().ok()?;
+        let x_mins = (0..loca.len())
+            .map(|gid| {
+                loca.get_glyf(GlyphId::new(gid as u32), &glyf)
+                    .unwrap()
+                    .map(|glyph| glyph.x_min())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let metrics = hmtx.h_metrics();
+        let omit_lsbs = metrics
+            .iter()
+            .zip(&x_mins)
+            .all(|(metric, x_min)| metric.side_bearing() == *x_min);
+        let omit_monospace_lsbs = hmtx
+            .left_side_bearings()
+            .iter()
+            .zip(&x_mins[metrics.len()..])
+            .all(|(lsb, x_min)| lsb.get() == *x_min);
+        if !omit_lsbs && !omit_monospace_lsbs {
+            return None;
+        }
+        let mut out = vec![omit_lsbs as u8 | (omit_monospace_lsbs as u8) << 1];
+        for metric in metrics {
+            out.extend(metric.advance().to_be_bytes());
+        }
+        if !omit_lsbs {
+            for metric in metrics {
+                out.extend(metric.side_bearing().to_be_bytes());
+            }
+        }
+        if !omit_monospace_lsbs {
+            for lsb in hmtx.left_side_bearings() {
+                out.extend(lsb.get().to_be_bytes());
+            }
+        }
+        Some(out)
+    }
+
+    struct Entry {
+        tag: Tag,
+        orig: Vec<u8>,
+        transformed: Option<Vec<u8>>,
+    }
+
+    /// Encodes the given fonts as WOFF2, storing the table data without
+    /// compression.
+    ///
+    /// If more than one font is given the result is a collection, in which
+    /// identical tables are shared between fonts.
+    fn encode_woff2(
+        fonts: &[&[u8]],
+        transform: bool,
+        metadata: Option<&[u8]>,
+        private: &[u8],
+    ) -> Vec<u8> {
+        let mut entries: Vec<Entry> = vec![];
+        let mut font_indices = vec![];
+        let mut flavors = vec![];
+        for font_data in fonts {
+            let font = FontRef::new(font_data).unwrap();
+            flavors.push(font.table_directory.sfnt_version());
+            let has_glyf = transform && font.glyf().is_ok();
+            let mut indices = vec![];
+            for record in font.table_directory.table_records() {
+                let tag = record.tag();
+                let orig = font.table_data(tag).unwrap().as_bytes().to_vec();
+                if let Some(ix) = entries
+                    .iter()
+                    .position(|entry| entry.tag == tag && entry.orig == orig)
+                {
+                    indices.push(ix as u16);
+                    continue;
+                }
+                let transformed = match tag {
+                    Glyf::TAG if has_glyf => Some(transform_glyf(&font)),
+                    Loca::TAG if has_glyf => Some(vec![]),
+                    Hmtx::TAG if has_glyf => transform_hmtx(&font),
+                    _ => None,
+                };
+                indices.push(entries.len() as u16);
+                entries.push(Entry {
+                    tag,
+                    orig,
+                    transformed,
+                });
+            }
+            font_indices.push(indices);
+        }
+        let mut directory = vec![];
+        let mut stream = vec![];
+        for entry in &entries {
+            let is_glyf_or_loca = entry.tag == Glyf::TAG || entry.tag == Loca::TAG;
+            let version = match (is_glyf_or_loca, entry.transformed.is_some()) {
+                (true, true) => 0,
+                (true, false) => 3,
+                (false, true) => 1,
+                (false, false) => 0,
+            };
+            match KNOWN_TAGS.iter().position(|tag| *tag == entry.tag) {
+                Some(ix) => directory.push(ix as u8 | version << 6),
+                None => {
+                    directory.push(ARBITRARY_TAG_INDEX | version << 6);
+                    directory.extend(entry.tag.to_be_bytes());
+                }
+            }
+            push_base128(&mut directory, entry.orig.len() as u32);
+            if let Some(transformed) = &entry.transformed {
+                push_base128(&mut directory, transformed.len() as u32);
+            }
+            stream.extend(entry.transformed.as_ref().unwrap_or(&entry.orig));
+        }
+        let flavor = if fonts.len() > 1 {
+            directory.extend(0x0001_0000u32.to_be_bytes());
+            push_255_u16(&mut directory, fonts.len() as u16);
+            for (flavor, indices) in flavors.iter().zip(&font_indices) {
+                push_255_u16(&mut directory, indices.len() as u16);
+                directory.extend(flavor.to_be_bytes());
+                for ix in indices {
+                    push_255_u16(&mut directory, *ix);
+                }
+            }
+            u32::from_be_bytes(TTC_HEADER_TAG.to_be_bytes())
+        } else {
+            flavors[0]
+        };
+        let total_sfnt_size = 12
+            + entries.len() * 16
+            + entries
+                .iter()
+                .map(|entry| entry.orig.len().next_multiple_of(4))
+                .sum::<usize>();
+        let mut blocks = stream.clone();
+        blocks.resize(blocks.len().next_multiple_of(4), 0);
+        let blocks_offset = WOFF2_HEADER_LEN + directory.len();
+        let (meta_offset, meta_len) = match metadata {
+            Some(metadata) => {
+                let offset = blocks_offset + blocks.len();
+                blocks.extend(metadata);
+                blocks.resize(blocks.len().next_multiple_of(4), 0);
+                (offset, metadata.len())
+            }
+            None => (0, 0),
+        };
+        let priv_offset = if private.is_empty() {
+            0
+        } else {
+            blocks_offset + blocks.len()
+        };
+        blocks.extend(private);
+        let mut woff = vec![];
+        woff.extend(b"wOF2");
+        woff.extend(flavor.to_be_bytes());
+        woff.extend(((blocks_offset + blocks.len()) as u32).to_be_bytes());
+        woff.extend((entries.len() as u16).to_be_bytes());
+        woff.extend(0u16.to_be_bytes());
+        woff.extend((total_sfnt_size as u32).to_be_bytes());
+        woff.extend((stream.len() as u32).to_be_bytes());
+        woff.extend(1u16.to_be_bytes());
+        woff.extend(0u16.to_be_bytes());
+        for value in [meta_offset, meta_len, meta_len, priv_offset, private.len()] {
+            woff.extend((value as u32).to_be_bytes());
+        }
+        woff.extend(directory);
+        woff.extend(blocks);
+        woff
+    }
+
+    /// Returns the data for a table, with the `checkSumAdjustment` field of
+    /// the `head` table cleared since it depends on the layout of the font.
+    fn table_without_adjustment(font: &FontRef, tag: Tag) -> Vec<u8> {
+        let mut data = font.table_data(tag).unwrap().as_bytes().to_vec();
+        if tag == Head::TAG {
+            data[HEAD_ADJUSTMENT_OFFSET..HEAD_ADJUSTMENT_OFFSET + 4].fill(0);
+        }
+        data
+    }
+
+    /// Asserts that the glyphs and metrics of two fonts are equivalent.
+    fn assert_glyphs_eq(decoded: &FontRef, original: &FontRef) {
+        let (glyf, loca) = (decoded.glyf().unwrap(), decoded.loca(None).unwrap());
+        let (orig_glyf, orig_loca) = (original.glyf().unwrap(), original.loca(None).unwrap());
+        assert_eq!(loca.len(), orig_loca.len());
+        for gid in 0..orig_loca.len() {
+            let gid = GlyphId::new(gid as u32);
+            let glyph = loca.get_glyf(gid, &glyf).unwrap();
+            let orig_glyph = orig_loca.get_glyf(gid, &orig_glyf).unwrap();
+            match (glyph, orig_glyph) {
+                (None, None) => {}
+                (Some(Glyph::Simple(glyph)), Some(Glyph::Simple(orig))) => {
+                    assert_eq!(glyph.end_pts_of_contours(), orig.end_pts_of_contours());
+                    assert_eq!(glyph.instructions(), orig.instructions());
+                    assert!(glyph.points().eq(orig.points()), "{gid}");
+                    assert_eq!(
+                        glyph.has_overlapping_contours(),
+                        orig.has_overlapping_contours()
+                    );
+                    assert_eq!(
+                        [glyph.x_min(), glyph.y_min(), glyph.x_max(), glyph.y_max()],
+                        [orig.x_min(), orig.y_min(), orig.x_max(), orig.y_max()]
+                    );
+                }
+                (Some(Glyph::Composite(glyph)), Some(Glyph::Composite(orig))) => {
+                    assert!(glyph.components().eq(orig.components()), "{gid}");
+                    assert_eq!(glyph.instructions(), orig.instructions());
+                    assert_eq!(
+                        [glyph.x_min(), glyph.y_min(), glyph.x_max(), glyph.y_max()],
+                        [orig.x_min(), orig.y_min(), orig.x_max(), orig.y_max()]
+                    );
+                }
+                (glyph, orig) => panic!("glyph {gid} mismatch: {glyph:?} != {orig:?}"),
+            }
+        }
+        // all tables other than glyf and loca are reconstructed exactly
+        for record in original.table_directory.table_records() {
+            let tag = record.tag();
+            if tag != Glyf::TAG && tag != Loca::TAG {
+                assert_eq!(
+                    table_without_adjustment(decoded, tag),
+                    table_without_adjustment(original, tag),
+                    "{tag}"
+                );
+            }
+        }
+    }
+
+    const METADATA: &[u8] = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <metadata version=\"1.0\"><uniqueid id=\"test\"/></metadata>";
+
+    #[test]
+    fn header_and_directory() {
+        let woff_data = encode_woff2(&[data::SIMPLE_GLYF], true, None, &[]);
+        let woff = Woff2::new(&woff_data).unwrap();
+        let font = FontRef::new(data::SIMPLE_GLYF).unwrap();
+        assert_eq!(woff.flavor(), font.table_directory.sfnt_version());
+        assert_eq!(woff.header().length() as usize, woff_data.len());
+        assert!(woff.collection().is_none());
+        let tags = woff
+            .table_directory()
+            .iter()
+            .map(|entry| entry.tag())
+            .collect::<Vec<_>>();
+        let expected_tags = font
+            .table_directory
+            .table_records()
+            .iter()
+            .map(|record| record.tag())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, expected_tags);
+        let find = |tag| {
+            *woff
+                .table_directory()
+                .iter()
+                .find(|entry| entry.tag() == tag)
+                .unwrap()
+        };
+        let glyf = find(Glyf::TAG);
+        assert!(glyf.is_transformed());
+        assert_eq!(glyf.transform_version(), 0);
+        assert_eq!(find(Loca::TAG).transform_length(), Some(0));
+        let cmap = find(Tag::new(b"cmap"));
+        assert!(!cmap.is_transformed());
+        assert_eq!(
+            cmap.orig_length() as usize,
+            font.table_data(cmap.tag()).unwrap().len()
+        );
+        assert!(woff.metadata(&identity).is_none());
+        assert!(woff.private_data().is_none());
+    }
+
+    #[test]
+    fn null_transform() {
+        let woff_data = encode_woff2(&[data::VAZIRMATN_VAR], false, None, &[]);
+        let woff = Woff2::new(&woff_data).unwrap();
+        assert!(woff
+            .table_directory()
+            .iter()
+            .all(|entry| !entry.is_transformed()));
+        let sfnt = woff.to_sfnt(&identity).unwrap();
+        let decoded = FontRef::new(&sfnt).unwrap();
+        let font = FontRef::new(data::VAZIRMATN_VAR).unwrap();
+        for record in font.table_directory.table_records() {
+            let tag = record.tag();
+            assert_eq!(
+                table_without_adjustment(&decoded, tag),
+                table_without_adjustment(&font, tag)
+            );
+        }
+        assert_eq!(decoded.verify_checksums().collect::<Vec<_>>(), []);
+    }
+
+    #[test]
+    fn glyf_and_hmtx_transforms() {
+        let mut n_hmtx_transformed = 0;
+        for font_data in [
+            data::SIMPLE_GLYF,
+            data::GLYF_COMPONENTS,
+            data::VAZIRMATN_VAR,
+        ] {
+            let woff_data = encode_woff2(&[font_data], true, None, &[]);
+            let woff = Woff2::new(&woff_data).unwrap();
+            n_hmtx_transformed += woff
+                .table_directory()
+                .iter()
+                .any(|entry| entry.tag() == Hmtx::TAG && entry.is_transformed())
+                as usize;
+            let sfnt = woff.to_sfnt(&identity).unwrap();
+            let decoded = FontRef::new(&sfnt).unwrap();
+            assert_eq!(decoded.verify_checksums().collect::<Vec<_>>(), []);
+            assert_glyphs_eq(&decoded, &FontRef::new(font_data).unwrap());
+        }
+        assert!(n_hmtx_transformed > 0);
+    }
+
+    #[test]
+    fn brotli_compressed() {
+        let woff = Woff2::new(data::GLYF_COMPONENTS_WOFF2).unwrap();
+        let transformed = woff
+            .table_directory()
+            .iter()
+            .filter(|entry| entry.is_transformed())
+            .map(|entry| entry.tag())
+            .collect::<Vec<_>>();
+        assert_eq!(transformed, [Glyf::TAG, Hmtx::TAG, Loca::TAG]);
+        // the data is compressed, so can't be decoded as stored
+        assert!(woff.to_sfnt(&identity).is_err());
+        let sfnt = woff.to_sfnt(&brotli).unwrap();
+        let decoded = FontRef::new(&sfnt).unwrap();
+        assert_eq!(decoded.verify_checksums().collect::<Vec<_>>(), []);
+        let original = FontRef::new(data::GLYF_COMPONENTS).unwrap();
+        assert_glyphs_eq(&decoded, &original);
+        let (hmtx, orig_hmtx) = (decoded.hmtx().unwrap(), original.hmtx().unwrap());
+        assert_eq!(hmtx.h_metrics(), orig_hmtx.h_metrics());
+        assert_eq!(hmtx.left_side_bearings(), orig_hmtx.left_side_bearings());
+    }
+
+    /// Regenerates the brotli compressed fixture used by
+    /// [`brotli_compressed`].
+    ///
+    /// Run with `cargo test -p read-fonts --features woff2 -- --ignored
+    /// regenerate_brotli_fixture`.
+    #[test]
+    #[ignore]
+    fn regenerate_brotli_fixture() {
+        use std::io::Write;
+
+        let mut woff_data = encode_woff2(&[data::GLYF_COMPONENTS], true, None, &[]);
+        let read_u32 = |data: &[u8], offset: usize| {
+            u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap()) as usize
+        };
+        // no metadata or private data, so the stream is the final block
+        let stream_len = read_u32(&woff_data, 20);
+        let stream_start = woff_data.len() - stream_len.next_multiple_of(4);
+        let mut compressed = vec![];
+        {
+            let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, 11, 22);
+            writer
+                .write_all(&woff_data[stream_start..stream_start + stream_len])
+                .unwrap();
+        }
+        woff_data.truncate(stream_start);
+        woff_data.extend(&compressed);
+        woff_data.resize(woff_data.len().next_multiple_of(4), 0);
+        let len = woff_data.len() as u32;
+        woff_data[8..12].copy_from_slice(&len.to_be_bytes());
+        woff_data[20..24].copy_from_slice(&(compressed.len() as u32).to_be_bytes());
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../font-test-data/test_data/woff2/glyf_components.woff2"
+        );
+        std::fs::write(path, woff_data).unwrap();
+    }
+
+    #[test]
+    fn collection() {
+        let fonts = [data::SIMPLE_GLYF, data::GLYF_COMPONENTS, data::SIMPLE_GLYF];
+        let woff_data = encode_woff2(&fonts, true, None, &[]);
+        let woff = Woff2::new(&woff_data).unwrap();
+        let collection = woff.collection().unwrap();
+        assert_eq!(collection.fonts().len(), 3);
+        // the first and last fonts are identical, so share all tables
+        assert_eq!(
+            collection.fonts()[0].table_indices(),
+            collection.fonts()[2].table_indices()
+        );
+        let sfnt = woff.to_sfnt(&identity).unwrap();
+        let FileRef::Collection(decoded) = FileRef::new(&sfnt).unwrap() else {
+            panic!("expected a collection");
+        };
+        assert_eq!(decoded.len(), 3);
+        for (i, font_data) in fonts.iter().enumerate() {
+            let font = decoded.get(i as u32).unwrap();
+            assert_glyphs_eq(&font, &FontRef::new(font_data).unwrap());
+            for record in font.table_directory.table_records() {
+                let data = font.table_data(record.tag()).unwrap();
+                assert_eq!(
+                    record.checksum(),
+                    table_checksum(record.tag(), data.as_bytes())
+                );
+            }
+        }
+        // shared tables are only stored once
+        assert_eq!(
+            decoded.get(0).unwrap().table_directory.table_records(),
+            decoded.get(2).unwrap().table_directory.table_records()
+        );
+    }
+
+    #[test]
+    fn metadata_and_private_data() {
+        let woff_data = encode_woff2(&[data::SIMPLE_GLYF], true, Some(METADATA), b"private");
+        let woff = Woff2::new(&woff_data).unwrap();
+        assert_eq!(woff.metadata(&identity).unwrap().unwrap(), METADATA);
+        assert_eq!(woff.private_data().unwrap().unwrap(), b"private");
+        // the data blocks don't affect the font
+        assert!(woff.to_sfnt(&identity).is_ok());
+    }
+
+    #[test]
+    fn uint_base128() {
+        let read = |bytes: &[u8]| read_uint_base128(&mut FontData::new(bytes).cursor());
+        assert_eq!(read(&[0x3F]).unwrap(), 63);
+        assert_eq!(read(&[0x81, 0x00]).unwrap(), 128);
+        assert_eq!(read(&[0x8F, 0xFF, 0xFF, 0xFF, 0x7F]).unwrap(), u32::MAX);
+        // leading zeros
+        assert!(read(&[0x80, 0x01]).is_err());
+        // overflow
+        assert!(read(&[0x90, 0x80, 0x80, 0x80, 0x00]).is_err());
+        // too long
+        assert!(read(&[0x81; 6]).is_err());
+        // truncated
+        assert!(read(&[0x81]).is_err());
+        for value in [0, 1, 127, 128, 16383, 16384, 1 << 28, u32::MAX] {
+            let mut bytes = vec![];
+            push_base128(&mut bytes, value);
+            assert_eq!(read(&bytes).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn uint_255_u16() {
+        let read = |bytes: &[u8]| read_255_u16(&mut FontData::new(bytes).cursor());
+        assert_eq!(read(&[252]).unwrap(), 252);
+        assert_eq!(read(&[255, 0]).unwrap(), 253);
+        assert_eq!(read(&[254, 0]).unwrap(), 506);
+        assert_eq!(read(&[253, 0x12, 0x34]).unwrap(), 0x1234);
+        assert!(read(&[253, 0x12]).is_err());
+        for value in (0..=1000).chain([u16::MAX]) {
+            let mut bytes = vec![];
+            push_255_u16(&mut bytes, value);
+            assert_eq!(read(&bytes).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn triplets() {
+        let values = [
+            0, 1, 15, 16, 63, 64, 65, 255, 256, 768, 769, 1279, 1280, 4095, 4096, 32767,
+        ];
+        for dx in values.iter().flat_map(|v| [*v, -v]) {
+            for dy in values.iter().flat_map(|v| [*v, -v]) {
+                for on_curve in [true, false] {
+                    let (mut flags, mut glyphs) = (vec![], vec![]);
+                    push_triplet(&mut flags, &mut glyphs, dx, dy, on_curve);
+                    let mut cursor = FontData::new(&glyphs).cursor();
+                    let decoded = decode_triplet(flags[0] & 0x7F, &mut cursor).unwrap();
+                    assert_eq!(decoded, (dx, dy));
+                    assert_eq!(flags[0] & 0x80 == 0, on_curve);
+                    assert!(cursor.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn corrupt_data() {
+        let mut woff_data = encode_woff2(&[data::SIMPLE_GLYF], true, None, &[]);
+        assert!(Woff2::new(&woff_data[..40]).is_err());
+        // failed decompression
+        let woff = Woff2::new(&woff_data).unwrap();
+        assert!(woff.to_sfnt(&|_: &[u8], _| None).is_err());
+        // truncated transformed glyf table
+        let font = FontRef::new(data::SIMPLE_GLYF).unwrap();
+        let glyf = transform_glyf(&font);
+        assert!(reconstruct_glyf(&glyf).is_ok());
+        assert!(reconstruct_glyf(&glyf[..glyf.len() - 1]).is_err());
+        // an unknown transform of the first table
+        woff_data[WOFF2_HEADER_LEN] |= 0x40;
+        let woff = Woff2::new(&woff_data).unwrap();
+        assert!(woff.to_sfnt(&identity).is_err());
+        // bad signature
+        woff_data[0] = b'x';
+        assert!(Woff2::new(&woff_data).is_err());
+    }
+}
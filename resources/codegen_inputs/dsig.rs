@@ -0,0 +1,48 @@
+#![parse_module(read_fonts::tables::dsig)]
+
+/// The [DSIG (Digital Signature)](https://docs.microsoft.com/en-us/typography/opentype/spec/dsig) table.
+#[tag = "DSIG"]
+table Dsig {
+    /// Version number of the DSIG table (0x00000001).
+    version: u32,
+    /// Number of signatures in the table.
+    num_signatures: u16,
+    /// Permission flags.
+    permission_flags: PermissionFlags,
+    /// Array of signature records.
+    #[count($num_signatures)]
+    signature_records: [SignatureRecord],
+}
+
+/// [Permission flags](https://learn.microsoft.com/en-us/typography/opentype/spec/dsig#table-structure)
+flags u16 PermissionFlags {
+    /// Bit 0: Cannot be resigned.
+    CANNOT_BE_RESIGNED = 0b0000_0000_0000_0001,
+}
+
+/// A record pointing to a single signature block.
+record SignatureRecord {
+    /// Format of the signature; only format 1 is defined.
+    format: u32,
+    /// Length of the signature block, in bytes.
+    length: u32,
+    /// Offset to the signature block from the beginning of the table.
+    signature_block_offset: Offset32<SignatureBlockFormat1>,
+}
+
+/// [Signature Block Format 1](https://learn.microsoft.com/en-us/typography/opentype/spec/dsig#table-structure)
+table SignatureBlockFormat1 {
+    /// Reserved for future use; set to 0.
+    #[skip_getter]
+    #[compile(0)]
+    _reserved1: u16,
+    /// Reserved for future use; set to 0.
+    #[skip_getter]
+    #[compile(0)]
+    _reserved2: u16,
+    /// Length (in bytes) of the PKCS#7 packet in the signature field.
+    signature_length: u32,
+    /// PKCS#7 packet.
+    #[count($signature_length)]
+    signature: [u8],
+}
@@ -0,0 +1,29 @@
+#![parse_module(read_fonts::tables::meta)]
+
+/// The [meta (Metadata)](https://docs.microsoft.com/en-us/typography/opentype/spec/meta) table.
+#[tag = "meta"]
+table Meta {
+    /// Version number of the metadata table — set to 1.
+    version: u32,
+    /// Flags — currently unused; set to 0.
+    flags: u32,
+    /// Not used; should be set to 0.
+    #[skip_getter]
+    #[compile(0)]
+    _reserved: u32,
+    /// The number of data maps in the table.
+    data_maps_count: u32,
+    /// Array of data map records.
+    #[count($data_maps_count)]
+    data_maps: [DataMapRecord],
+}
+
+/// A record describing a single item of metadata.
+record DataMapRecord {
+    /// A tag indicating the type of metadata.
+    tag: Tag,
+    /// Offset in bytes from the beginning of the metadata table to the data for this tag.
+    data_offset: u32,
+    /// Length of the data, in bytes. The data is not required to be padded to any byte boundary.
+    data_length: u32,
+}
@@ -0,0 +1,42 @@
+#![parse_module(read_fonts::tables::pclt)]
+
+/// The [PCLT (PCL 5)](https://docs.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+#[tag = "PCLT"]
+table Pclt {
+    /// Table version; set to 1.0.
+    version: MajorMinor,
+    /// Unique identifier for the font, including the vendor code.
+    font_number: u32,
+    /// Width of the space in FUnits.
+    pitch: u16,
+    /// x-height of the font in FUnits.
+    x_height: u16,
+    /// Encoded style word containing the posture, appearance width and
+    /// structure.
+    style: u16,
+    /// Encoded word containing the vendor code and typeface family.
+    type_family: u16,
+    /// Height of the optical line describing the top of flat capitals, in FUnits.
+    cap_height: u16,
+    /// Encoded PCL symbol set identifier.
+    symbol_set: u16,
+    /// ASCII name of the typeface, padded with spaces or NULs.
+    #[count(16)]
+    typeface: [u8],
+    /// Bit field identifying the symbol collections provided by the font.
+    #[count(8)]
+    character_complement: [u8],
+    /// Six character ASCII file name.
+    #[count(6)]
+    file_name: [u8],
+    /// Stroke weight, from -7 (ultra thin) to 7 (ultra black).
+    stroke_weight: i8,
+    /// Width type, from -5 (ultra compressed) to 5 (ultra expanded).
+    width_type: i8,
+    /// Encoded serif style and stroke appearance.
+    serif_style: u8,
+    /// Reserved; set to 0.
+    #[skip_getter]
+    #[compile(0)]
+    _reserved: u8,
+}
@@ -90,3 +90,105 @@ record FdSelectRange4 {
     /// FD index for all glyphs in range.
     fd: u16,
 }
+
+/// Charset with custom glyph id to string id mappings.
+format u8 CustomCharset {
+    Format0(CharsetFormat0),
+    Format1(CharsetFormat1),
+    Format2(CharsetFormat2),
+}
+
+/// Charset format 0.
+table CharsetFormat0 {
+    /// Format; set to 0.
+    #[format = 0]
+    format: u8,
+    /// Glyph name array.
+    #[count(..)]
+    glyph: [u16],
+}
+
+/// Charset format 1.
+table CharsetFormat1 {
+    /// Format; set to 1.
+    #[format = 1]
+    format: u8,
+    /// Range1 array.
+    #[count(..)]
+    ranges: [CharsetRange1],
+}
+
+/// Range struct for Charset format 1.
+record CharsetRange1 {
+    /// First glyph in range.
+    first: u16,
+    /// Glyphs left in range (excluding first).
+    n_left: u8,
+}
+
+/// Charset format 2.
+table CharsetFormat2 {
+    /// Format; set to 2.
+    #[format = 2]
+    format: u8,
+    /// Range2 array.
+    #[count(..)]
+    ranges: [CharsetRange2],
+}
+
+/// Range struct for Charset format 2.
+record CharsetRange2 {
+    /// First glyph in range.
+    first: u16,
+    /// Glyphs left in range (excluding first).
+    n_left: u16,
+}
+
+/// Encoding with custom code to glyph mappings.
+///
+/// The high bit of the format is set when the encoding contains
+/// supplemental code mappings.
+format u8 CustomEncoding {
+    #[match_if($format & 0x7F == 0)]
+    Format0(EncodingFormat0),
+    #[match_if($format & 0x7F == 1)]
+    Format1(EncodingFormat1),
+}
+
+/// Encoding format 0.
+table EncodingFormat0 {
+    /// Format; set to 0, with the high bit set if supplements are present.
+    format: u8,
+    /// Number of encoded glyphs.
+    n_codes: u8,
+    /// Code array.
+    #[count($n_codes)]
+    code: [u8],
+}
+
+/// Encoding format 1.
+table EncodingFormat1 {
+    /// Format; set to 1, with the high bit set if supplements are present.
+    format: u8,
+    /// Number of code ranges.
+    n_ranges: u8,
+    /// Range1 array.
+    #[count($n_ranges)]
+    ranges: [EncodingRange1],
+}
+
+/// Range struct for Encoding format 1.
+record EncodingRange1 {
+    /// First code in range.
+    first: u8,
+    /// Codes left in range (excluding first).
+    n_left: u8,
+}
+
+/// Supplemental encoding record.
+record EncodingSupplement {
+    /// Encoding.
+    code: u8,
+    /// Name.
+    glyph: u16,
+}
@@ -0,0 +1,37 @@
+#![parse_module(read_fonts::tables::svg)]
+
+/// The [SVG](https://learn.microsoft.com/en-us/typography/opentype/spec/svg) table
+#[tag = "SVG "]
+table Svg {
+    /// Table version (starting at 0). Set to 0.
+    version: u16,
+    /// Offset to the SVGDocumentList, from the start of the SVG table.
+    /// Must be non-zero.
+    svg_document_list_offset: Offset32<SvgDocumentList>,
+    /// Set to 0.
+    #[skip_getter]
+    #[compile(0)]
+    _reserved: u32,
+}
+
+/// [SVGDocumentList](https://learn.microsoft.com/en-us/typography/opentype/spec/svg#svg-document-list)
+table SvgDocumentList {
+    /// Number of SVGDocumentRecords. Must be non-zero.
+    num_entries: u16,
+    /// Array of SVGDocumentRecords.
+    #[count($num_entries)]
+    document_records: [SvgDocumentRecord],
+}
+
+/// [SVGDocumentRecord](https://learn.microsoft.com/en-us/typography/opentype/spec/svg#svg-document-list)
+record SvgDocumentRecord {
+    /// The first glyph ID for the range covered by this record.
+    start_glyph_id: GlyphId16,
+    /// The last glyph ID for the range covered by this record.
+    end_glyph_id: GlyphId16,
+    /// Offset from the beginning of the SVGDocumentList to an SVG
+    /// document. Must be non-zero.
+    svg_doc_offset: u32,
+    /// Length of the SVG document data. Must be non-zero.
+    svg_doc_length: u32,
+}
@@ -42,3 +42,9 @@ record ContainsOffests {
     array_offset: Offset16<[SimpleRecord]>,
     other_offset: Offset32<BasicTable>,
 }
+
+table InlineRecord {
+    leading: u16,
+    inline: SimpleRecord,
+    trailing: u16,
+}
@@ -0,0 +1,275 @@
+//! Support for baseline alignment.
+//!
+//! This provides access to the baseline positions and min/max extents
+//! stored in the
+//! [BASE](https://learn.microsoft.com/en-us/typography/opentype/spec/base)
+//! table, scaled to a specific size and adjusted for a location in
+//! variation space. This allows aligning text in different scripts, such as
+//! placing Latin text on the roman baseline alongside CJK ideographs that
+//! sit on the ideographic baseline.
+
+use read_fonts::{
+    tables::{base as raw_base, gpos::PositionResolver},
+    types::Tag,
+    TableProvider,
+};
+
+use super::instance::{LocationRef, NormalizedCoord, Size};
+
+pub use read_fonts::tables::base::Direction;
+
+/// Tag for the hanging baseline, used by scripts such as Devanagari.
+pub const HANGING: Tag = Tag::new(b"hang");
+/// Tag for the bottom edge of the ideographic character face.
+pub const IDEOGRAPHIC_FACE_BOTTOM: Tag = Tag::new(b"icfb");
+/// Tag for the top edge of the ideographic character face.
+pub const IDEOGRAPHIC_FACE_TOP: Tag = Tag::new(b"icft");
+/// Tag for the bottom edge of the ideographic em-box.
+pub const IDEOGRAPHIC_BOTTOM: Tag = Tag::new(b"ideo");
+/// Tag for the top edge of the ideographic em-box.
+pub const IDEOGRAPHIC_TOP: Tag = Tag::new(b"idtp");
+/// Tag for the baseline about which mathematical characters are centered.
+pub const MATH: Tag = Tag::new(b"math");
+/// Tag for the roman baseline, used by Latin, Greek and Cyrillic.
+pub const ROMAN: Tag = Tag::new(b"romn");
+
+/// Minimum and maximum extents for a script, language or feature.
+///
+/// Values are scaled to the requested size.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct MinMax {
+    /// The minimum extent, if defined.
+    pub min: Option<f32>,
+    /// The maximum extent, if defined.
+    pub max: Option<f32>,
+}
+
+/// Baseline data for a specific size and location in variation space.
+#[derive(Clone)]
+pub struct Baselines<'a> {
+    base: Option<(raw_base::Base<'a>, PositionResolver<'a>)>,
+    scale: f32,
+}
+
+impl<'a> Baselines<'a> {
+    /// Creates new baseline data for the given font, size and location in
+    /// normalized variation space.
+    pub fn new(
+        font: &impl TableProvider<'a>,
+        size: Size,
+        location: impl Into<LocationRef<'a>>,
+    ) -> Self {
+        let upem = font.head().map(|head| head.units_per_em()).unwrap_or(0);
+        let coords: &'a [NormalizedCoord] = location.into().coords();
+        let ppem = size.ppem().map(|ppem| ppem.round() as u16);
+        let base = font.base().ok().and_then(|base| {
+            let resolver = PositionResolver::from_base(&base, upem, ppem, coords).ok()?;
+            Some((base, resolver))
+        });
+        Self {
+            base,
+            scale: size.linear_scale(upem),
+        }
+    }
+
+    /// Returns true if the font contains a BASE table.
+    pub fn has_data(&self) -> bool {
+        self.base.is_some()
+    }
+
+    /// Returns the tag of the default baseline for the given script.
+    ///
+    /// If the script is not present, the `DFLT` script is used instead.
+    pub fn default_baseline(&self, direction: Direction, script: Tag) -> Option<Tag> {
+        self.base
+            .as_ref()?
+            .0
+            .default_baseline(direction, script)
+            .ok()
+            .flatten()
+    }
+
+    /// Returns the scaled position of a baseline for the given script.
+    ///
+    /// For horizontal text, this is a y coordinate and for vertical text, an
+    /// x coordinate. If the script is not present, the `DFLT` script is used
+    /// instead.
+    pub fn baseline(&self, direction: Direction, script: Tag, baseline: Tag) -> Option<f32> {
+        let (base, resolver) = self.base.as_ref()?;
+        let coord = base
+            .baseline(direction, script, baseline, resolver)
+            .ok()??;
+        Some(self.scale_coord(coord))
+    }
+
+    /// Returns the scaled minimum and maximum extents for the given script
+    /// and language, and optionally a feature.
+    ///
+    /// If `language` is `None` or is not present, the default extents for
+    /// the script are used. If `feature` is present, its extents override
+    /// those of the language or script.
+    pub fn min_max(
+        &self,
+        direction: Direction,
+        script: Tag,
+        language: Option<Tag>,
+        feature: Option<Tag>,
+    ) -> Option<MinMax> {
+        let (base, resolver) = self.base.as_ref()?;
+        let extents = base
+            .min_max(direction, script, language, feature, resolver)
+            .ok()??;
+        Some(MinMax {
+            min: extents.min.map(|coord| self.scale_coord(coord)),
+            max: extents.max.map(|coord| self.scale_coord(coord)),
+        })
+    }
+
+    fn scale_coord(&self, coord: i32) -> f32 {
+        coord as f32 * self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MetadataProvider;
+    use font_test_data::bebuffer::BeBuffer;
+    use read_fonts::FontData;
+
+    /// Serves hand built head and BASE tables.
+    struct BaseFont {
+        head: Vec<u8>,
+        base: Vec<u8>,
+    }
+
+    impl<'a> TableProvider<'a> for &'a BaseFont {
+        fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+            match &tag.to_be_bytes() {
+                b"head" => Some(FontData::new(&self.head)),
+                b"BASE" => Some(FontData::new(&self.base)),
+                _ => None,
+            }
+        }
+    }
+
+    fn make_head(upem: u16) -> Vec<u8> {
+        BeBuffer::new()
+            .push(1u16) // major version
+            .push(0u16) // minor version
+            .push(0u32) // font revision
+            .push(0u32) // checksum adjustment
+            .push(0x5F0F3CF5u32) // magic
+            .push(0u16) // flags
+            .push(upem)
+            // remaining fields are unused
+            .pad_to(54)
+            .to_vec()
+    }
+
+    /// Builds a BASE table with ideographic and roman baselines for the
+    /// `hani` script. Comments give the absolute position of each subtable.
+    fn make_base() -> Vec<u8> {
+        BeBuffer::new()
+            // 0: header
+            .push(1u16)
+            .push(0u16)
+            .push(8u16) // horiz axis
+            .push(0u16) // vert axis
+            // 8: axis
+            .push(4u16) // base tag list
+            .push(14u16) // base script list
+            // 12: base tag list
+            .push(2u16)
+            .push(Tag::new(b"ideo"))
+            .push(Tag::new(b"romn"))
+            // 22: base script list
+            .push(1u16)
+            .push(Tag::new(b"hani"))
+            .push(8u16)
+            // 30: base script
+            .push(6u16) // base values
+            .push(32u16) // default min max
+            .push(0u16) // lang sys count
+            // 36: base values
+            .push(0u16) // default baseline index
+            .push(2u16)
+            .push(8u16)
+            .push(12u16)
+            // 44: ideo coord
+            .push(1u16)
+            .push(-120i16)
+            // 48: romn coord with device table
+            .push(3u16)
+            .push(0i16)
+            .push(6u16)
+            // 54: device table with a delta of 1 at 32ppem
+            .push(32u16)
+            .push(32u16)
+            .push(1u16)
+            .push(0x4000u16)
+            // 62: default min max
+            .push(6u16) // min coord
+            .push(10u16) // max coord
+            .push(0u16) // feat min max count
+            // 68: min coord
+            .push(1u16)
+            .push(-150i16)
+            // 72: max coord
+            .push(1u16)
+            .push(850i16)
+            .to_vec()
+    }
+
+    const HANI: Tag = Tag::new(b"hani");
+
+    #[test]
+    fn scaled_baselines() {
+        let font = BaseFont {
+            head: make_head(1024),
+            base: make_base(),
+        };
+        let baselines = (&font).baselines(Size::new(16.0), LocationRef::default());
+        assert!(baselines.has_data());
+        let horiz = Direction::Horizontal;
+        assert_eq!(
+            baselines.default_baseline(horiz, HANI),
+            Some(IDEOGRAPHIC_BOTTOM)
+        );
+        assert_eq!(
+            baselines.baseline(horiz, HANI, IDEOGRAPHIC_BOTTOM),
+            Some(-1.875)
+        );
+        assert_eq!(baselines.baseline(horiz, HANI, ROMAN), Some(0.0));
+        assert_eq!(baselines.baseline(horiz, HANI, HANGING), None);
+        assert_eq!(
+            baselines.min_max(horiz, HANI, None, None),
+            Some(MinMax {
+                min: Some(-2.34375),
+                max: Some(13.28125),
+            })
+        );
+        // device deltas apply at 32ppem
+        let baselines = (&font).baselines(Size::new(32.0), LocationRef::default());
+        assert_eq!(baselines.baseline(horiz, HANI, ROMAN), Some(1.0));
+        // no device deltas when unscaled
+        let baselines = (&font).baselines(Size::unscaled(), LocationRef::default());
+        assert_eq!(
+            baselines.baseline(horiz, HANI, IDEOGRAPHIC_BOTTOM),
+            Some(-120.0)
+        );
+        assert_eq!(baselines.baseline(horiz, HANI, ROMAN), Some(0.0));
+    }
+
+    #[test]
+    fn missing_table() {
+        let font = read_fonts::FontRef::new(font_test_data::SIMPLE_GLYF).unwrap();
+        let baselines = font.baselines(Size::new(16.0), LocationRef::default());
+        assert!(!baselines.has_data());
+        assert_eq!(baselines.baseline(Direction::Horizontal, HANI, ROMAN), None);
+        assert_eq!(
+            baselines.min_max(Direction::Horizontal, HANI, None, None),
+            None
+        );
+    }
+}
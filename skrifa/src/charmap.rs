@@ -16,8 +16,9 @@
 
 use read_fonts::{
     tables::cmap::{
-        self, Cmap, Cmap12, Cmap12Iter, Cmap14, Cmap14Iter, Cmap4, Cmap4Iter, CmapSubtable,
-        EncodingRecord, PlatformId,
+        self, Cmap, Cmap0, Cmap0Iter, Cmap10, Cmap10Iter, Cmap12, Cmap12Iter, Cmap13, Cmap13Iter,
+        Cmap14, Cmap14Iter, Cmap2, Cmap2Iter, Cmap4, Cmap4Iter, Cmap6, Cmap6Iter, Cmap8, Cmap8Iter,
+        CmapSubtable, EncodingRecord, PlatformId,
     },
     types::GlyphId,
     FontData, TableProvider,
@@ -41,14 +42,21 @@ pub use read_fonts::tables::cmap::MapVariant;
 /// coverage:
 ///
 /// * Unicode characters: a symbol mapping subtable is selected if available. Otherwise, subtables supporting
-/// the Unicode full repertoire or Basic Multilingual Plane (BMP) are preferred, in that order. Formats
-/// [4](https://learn.microsoft.com/en-us/typography/opentype/spec/cmap#format-4-segment-mapping-to-delta-values)
-/// and [12](https://learn.microsoft.com/en-us/typography/opentype/spec/cmap#format-12-segmented-coverage) are
-/// supported.
+///   the Unicode full repertoire or Basic Multilingual Plane (BMP) are preferred, in that order. Within each
+///   group, the last matching subtable in the encoding records wins, following FreeType. All formats that map
+///   single characters are supported: 0, 2,
+///   [4](https://learn.microsoft.com/en-us/typography/opentype/spec/cmap#format-4-segment-mapping-to-delta-values),
+///   6, 8, 10, [12](https://learn.microsoft.com/en-us/typography/opentype/spec/cmap#format-12-segmented-coverage)
+///   and 13.
+///
+/// * Legacy characters: if no symbol or Unicode subtable is available, a subtable with a legacy encoding
+///   (Macintosh Roman, or one of the Windows Shift-JIS, PRC, Big5, Wansung and Johab encodings) is selected
+///   instead. In this case, character codes are interpreted in that encoding rather than as Unicode code
+///   points.
 ///
 /// * Unicode variation sequences: these are provided by a format
-/// [14](https://learn.microsoft.com/en-us/typography/opentype/spec/cmap#format-14-unicode-variation-sequences)
-/// subtable.
+///   [14](https://learn.microsoft.com/en-us/typography/opentype/spec/cmap#format-14-unicode-variation-sequences)
+///   subtable.
 ///
 #[derive(Clone, Default)]
 pub struct Charmap<'a> {
@@ -106,8 +114,14 @@ impl<'a> Charmap<'a> {
             .as_ref()
             .map(|subtable| {
                 Mappings(match &subtable.subtable {
+                    SupportedSubtable::Format0(cmap0) => MappingsInner::Format0(cmap0.iter()),
+                    SupportedSubtable::Format2(cmap2) => MappingsInner::Format2(cmap2.iter()),
                     SupportedSubtable::Format4(cmap4) => MappingsInner::Format4(cmap4.iter()),
+                    SupportedSubtable::Format6(cmap6) => MappingsInner::Format6(cmap6.iter()),
+                    SupportedSubtable::Format8(cmap8) => MappingsInner::Format8(cmap8.iter()),
+                    SupportedSubtable::Format10(cmap10) => MappingsInner::Format10(cmap10.iter()),
                     SupportedSubtable::Format12(cmap12) => MappingsInner::Format12(cmap12.iter()),
+                    SupportedSubtable::Format13(cmap13) => MappingsInner::Format13(cmap13.iter()),
                 })
             })
             .unwrap_or(Mappings(MappingsInner::None))
@@ -197,8 +211,14 @@ impl<'a> Iterator for Mappings<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         match &mut self.0 {
             MappingsInner::None => None,
+            MappingsInner::Format0(iter) => iter.next(),
+            MappingsInner::Format2(iter) => iter.next(),
             MappingsInner::Format4(iter) => iter.next(),
+            MappingsInner::Format6(iter) => iter.next(),
+            MappingsInner::Format8(iter) => iter.next(),
+            MappingsInner::Format10(iter) => iter.next(),
             MappingsInner::Format12(iter) => iter.next(),
+            MappingsInner::Format13(iter) => iter.next(),
         }
     }
 }
@@ -206,8 +226,14 @@ impl<'a> Iterator for Mappings<'a> {
 #[derive(Clone)]
 enum MappingsInner<'a> {
     None,
+    Format0(Cmap0Iter<'a>),
+    Format2(Cmap2Iter<'a>),
     Format4(Cmap4Iter<'a>),
+    Format6(Cmap6Iter<'a>),
+    Format8(Cmap8Iter<'a>),
+    Format10(Cmap10Iter<'a>),
     Format12(Cmap12Iter<'a>),
+    Format13(Cmap13Iter<'a>),
 }
 
 /// Iterator over all mappings of character and variation selector to
@@ -262,24 +288,42 @@ impl<'a> CodepointSubtable<'a> {
 
     fn map_impl(&self, codepoint: u32) -> Option<GlyphId> {
         match &self.subtable {
+            SupportedSubtable::Format0(subtable) => subtable.map_codepoint(codepoint),
+            SupportedSubtable::Format2(subtable) => subtable.map_codepoint(codepoint),
             SupportedSubtable::Format4(subtable) => subtable.map_codepoint(codepoint),
+            SupportedSubtable::Format6(subtable) => subtable.map_codepoint(codepoint),
+            SupportedSubtable::Format8(subtable) => subtable.map_codepoint(codepoint),
+            SupportedSubtable::Format10(subtable) => subtable.map_codepoint(codepoint),
             SupportedSubtable::Format12(subtable) => subtable.map_codepoint(codepoint),
+            SupportedSubtable::Format13(subtable) => subtable.map_codepoint(codepoint),
         }
     }
 }
 
 #[derive(Clone)]
 enum SupportedSubtable<'a> {
+    Format0(Cmap0<'a>),
+    Format2(Cmap2<'a>),
     Format4(Cmap4<'a>),
+    Format6(Cmap6<'a>),
+    Format8(Cmap8<'a>),
+    Format10(Cmap10<'a>),
     Format12(Cmap12<'a>),
+    Format13(Cmap13<'a>),
 }
 
 impl<'a> SupportedSubtable<'a> {
     fn new(subtable: CmapSubtable<'a>) -> Option<Self> {
         Some(match subtable {
+            CmapSubtable::Format0(cmap0) => Self::Format0(cmap0),
+            CmapSubtable::Format2(cmap2) => Self::Format2(cmap2),
             CmapSubtable::Format4(cmap4) => Self::Format4(cmap4),
+            CmapSubtable::Format6(cmap6) => Self::Format6(cmap6),
+            CmapSubtable::Format8(cmap8) => Self::Format8(cmap8),
+            CmapSubtable::Format10(cmap10) => Self::Format10(cmap10),
             CmapSubtable::Format12(cmap12) => Self::Format12(cmap12),
-            _ => return None,
+            CmapSubtable::Format13(cmap13) => Self::Format13(cmap13),
+            CmapSubtable::Format14(_) => return None,
         })
     }
 
@@ -295,24 +339,26 @@ impl<'a> SupportedSubtable<'a> {
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
 enum MappingKind {
     None = 0,
-    UnicodeBmp = 1,
-    UnicodeFull = 2,
-    Symbol = 3,
+    Legacy = 1,
+    UnicodeBmp = 2,
+    UnicodeFull = 3,
+    Symbol = 4,
 }
 
 /// The result of searching the cmap table for the "best" available
 /// subtables.
 ///
 /// For `codepoint_subtable`, best means either symbol (which is preferred)
-/// or a Unicode subtable with the greatest coverage.
+/// or a Unicode subtable with the greatest coverage, falling back to a
+/// subtable with a legacy encoding.
 ///
 /// For `variant_subtable`, best means a format 14 subtable.
 struct MappingSelection<'a> {
     /// The mapping index accelerator that holds indices of the following
     /// subtables.
     mapping_index: MappingIndex,
-    /// Either a symbol subtable or the Unicode subtable with the
-    /// greatest coverage.
+    /// Either a symbol subtable, the Unicode subtable with the
+    /// greatest coverage or a legacy subtable.
     codepoint_subtable: Option<SupportedSubtable<'a>>,
     /// Subtable that supports mapping Unicode variation sequences.
     variant_subtable: Option<Cmap14<'a>>,
@@ -322,6 +368,9 @@ impl<'a> MappingSelection<'a> {
     fn new(cmap: &Cmap<'a>) -> Self {
         const ENCODING_MS_SYMBOL: u16 = 0;
         const ENCODING_MS_UNICODE_CS: u16 = 1;
+        const ENCODING_MS_SHIFT_JIS: u16 = 2;
+        const ENCODING_MS_JOHAB: u16 = 6;
+        const ENCODING_MAC_ROMAN: u16 = 0;
         const ENCODING_APPLE_ID_UNICODE_32: u16 = 4;
         const ENCODING_APPLE_ID_VARIANT_SELECTOR: u16 = 5;
         const ENCODING_MS_ID_UCS_4: u16 = 10;
@@ -377,6 +426,14 @@ impl<'a> MappingSelection<'a> {
                         maybe_choose_subtable(MappingKind::UnicodeBmp, i, subtable);
                     }
                 }
+                (PlatformId::Macintosh, ENCODING_MAC_ROMAN)
+                | (PlatformId::Windows, ENCODING_MS_SHIFT_JIS..=ENCODING_MS_JOHAB) => {
+                    // Legacy encodings, only used when nothing better is
+                    // available
+                    if let Some(subtable) = SupportedSubtable::from_cmap_record(cmap, record) {
+                        maybe_choose_subtable(MappingKind::Legacy, i, subtable);
+                    }
+                }
                 _ => {}
             }
         }
@@ -392,7 +449,7 @@ impl<'a> MappingSelection<'a> {
 mod tests {
     use super::*;
     use crate::MetadataProvider;
-    use read_fonts::FontRef;
+    use read_fonts::{types::Tag, FontRef};
 
     #[test]
     fn choose_format_12_over_4() {
@@ -425,6 +482,85 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn choose_last_ucs4_subtable() {
+        // Both (0, 4) and (3, 10) are full repertoire mappings and the
+        // reverse search finds (3, 10) first
+        let font = FontRef::new(font_test_data::CMAP_FORMATS).unwrap();
+        let charmap = font.charmap();
+        assert!(!charmap.is_symbol());
+        assert!(matches!(
+            charmap.codepoint_subtable.unwrap().subtable,
+            SupportedSubtable::Format8(..)
+        ));
+        let index = MappingIndex::new(&font);
+        assert_eq!(index.codepoint_subtable, Some(5));
+        assert!(matches!(
+            index.charmap(&font).codepoint_subtable.unwrap().subtable,
+            SupportedSubtable::Format8(..)
+        ));
+    }
+
+    /// Serves a cmap table containing only the given encoding record from
+    /// the cmap_formats test font.
+    struct SingleRecordCmap(Vec<u8>);
+
+    impl SingleRecordCmap {
+        fn new(record_index: usize) -> Self {
+            let font = FontRef::new(font_test_data::CMAP_FORMATS).unwrap();
+            let mut cmap = font
+                .table_data(Tag::new(b"cmap"))
+                .unwrap()
+                .as_bytes()
+                .to_vec();
+            // Subtable offsets are relative to the start of the table so we
+            // can keep the subtables in place and replace the record list
+            // with the chosen record
+            const RECORD_LEN: usize = 8;
+            let record_start = 4 + record_index * RECORD_LEN;
+            let record = cmap[record_start..record_start + RECORD_LEN].to_vec();
+            cmap[2..4].copy_from_slice(&1u16.to_be_bytes());
+            cmap[4..4 + RECORD_LEN].copy_from_slice(&record);
+            Self(cmap)
+        }
+    }
+
+    impl<'a> TableProvider<'a> for &'a SingleRecordCmap {
+        fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+            (tag == Tag::new(b"cmap")).then(|| FontData::new(&self.0))
+        }
+    }
+
+    #[test]
+    fn choose_legacy_subtables() {
+        // (record index, platform, encoding, format) for the legacy
+        // subtables in the cmap_formats test font
+        for (record_index, platform_id, encoding_id, format) in [
+            (3, PlatformId::Macintosh, 0, 0),
+            (4, PlatformId::Windows, 3, 2),
+        ] {
+            let font = SingleRecordCmap::new(record_index);
+            let font = &font;
+            let record = font.cmap().unwrap().encoding_records()[0];
+            assert_eq!(record.platform_id(), platform_id);
+            assert_eq!(record.encoding_id(), encoding_id);
+            let charmap = Charmap::new(&font);
+            assert!(!charmap.is_symbol());
+            let subtable_format = match charmap.codepoint_subtable.as_ref().unwrap().subtable {
+                SupportedSubtable::Format0(_) => 0,
+                SupportedSubtable::Format2(_) => 2,
+                _ => panic!("unexpected subtable format"),
+            };
+            assert_eq!(subtable_format, format);
+            assert_eq!(MappingIndex::new(&font).codepoint_subtable, Some(0));
+            let mappings = charmap.mappings().collect::<Vec<_>>();
+            assert!(!mappings.is_empty());
+            for (codepoint, glyph_id) in mappings {
+                assert_eq!(charmap.map(codepoint), Some(glyph_id));
+            }
+        }
+    }
+
     #[test]
     fn map_format_4() {
         let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
@@ -490,6 +626,7 @@ mod tests {
             font_test_data::CMAP12_FONT1,
             font_test_data::SIMPLE_GLYF,
             font_test_data::CMAP4_SYMBOL_PUA,
+            font_test_data::CMAP_FORMATS,
         ] {
             let font = FontRef::new(font_data).unwrap();
             let charmap = font.charmap();
@@ -5,7 +5,7 @@ use read_fonts::{
         colr::*,
         variations::{
             DeltaSetIndex, DeltaSetIndexMap, FloatItemDelta, FloatItemDeltaTarget,
-            ItemVariationStore,
+            VarStoreInstancer,
         },
     },
     types::{BoundingBox, F2Dot14, GlyphId16, Point},
@@ -25,8 +25,7 @@ pub type PaintId = usize;
 pub struct ColrInstance<'a> {
     colr: Colr<'a>,
     index_map: Option<DeltaSetIndexMap<'a>>,
-    var_store: Option<ItemVariationStore<'a>>,
-    coords: &'a [F2Dot14],
+    var_store: Option<VarStoreInstancer<'a>>,
 }
 
 impl<'a> ColrInstance<'a> {
@@ -34,10 +33,12 @@ impl<'a> ColrInstance<'a> {
     /// coordinates.
     pub fn new(colr: Colr<'a>, coords: &'a [F2Dot14]) -> Self {
         let index_map = colr.var_index_map().and_then(|res| res.ok());
-        let var_store = colr.item_variation_store().and_then(|res| res.ok());
+        let var_store = colr
+            .item_variation_store()
+            .and_then(|res| res.ok())
+            .and_then(|var_store| VarStoreInstancer::new(var_store, coords).ok());
         Self {
             colr,
-            coords,
             index_map,
             var_store,
         }
@@ -53,20 +54,17 @@ impl<'a> ColrInstance<'a> {
         // errors.
         // See <https://gitlab.freedesktop.org/freetype/freetype/-/blob/fc01e7dd/src/sfnt/ttcolr.c#L574>
         let mut deltas = [FloatItemDelta::ZERO; N];
-        if self.coords.is_empty()
-            || self.var_store.is_none()
-            || var_index_base == NO_VARIATION_DELTAS
-        {
+        let Some(var_store) = self.var_store.as_ref() else {
+            return deltas;
+        };
+        if var_index_base == NO_VARIATION_DELTAS {
             return deltas;
         }
-        let var_store = self.var_store.as_ref().unwrap();
         if let Some(index_map) = self.index_map.as_ref() {
             for (i, delta) in deltas.iter_mut().enumerate() {
                 let var_index = var_index_base + i as u32;
                 if let Ok(delta_ix) = index_map.get(var_index) {
-                    *delta = var_store
-                        .compute_float_delta(delta_ix, self.coords)
-                        .unwrap_or_default();
+                    *delta = var_store.compute_float_delta(delta_ix).unwrap_or_default();
                 }
             }
         } else {
@@ -78,9 +76,7 @@ impl<'a> ColrInstance<'a> {
                     outer: 0,
                     inner: var_index as u16,
                 };
-                *delta = var_store
-                    .compute_float_delta(delta_ix, self.coords)
-                    .unwrap_or_default();
+                *delta = var_store.compute_float_delta(delta_ix).unwrap_or_default();
             }
         }
         deltas
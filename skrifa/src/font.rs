@@ -1,3 +1,143 @@
 //! Basic representation of an in-memory font resource.
 
 pub use read_fonts::FontRef;
+
+/// An owned font that shares its data.
+///
+/// All metadata is available through the [`MetadataProvider`]
+/// implementation for `&SharedFont`. The returned objects borrow from the
+/// shared font, so the font itself can be stored in long-lived caches and the
+/// objects recreated cheaply when needed. See [`SharedMetadata`] for an owned
+/// alternative that caches the character map selection.
+#[cfg(feature = "std")]
+pub use read_fonts::SharedFont;
+
+#[cfg(feature = "std")]
+use crate::{
+    charmap::{Charmap, MappingIndex},
+    instance::{LocationRef, Size},
+    metrics::GlyphMetrics,
+    GlyphId, MetadataProvider, OutlineGlyphCollection,
+};
+
+/// Character mapping, metrics and outlines for a shared font.
+///
+/// This owns its font rather than borrowing it, so it has a `'static`
+/// lifetime and can be stored directly in long-lived caches. The most
+/// suitable character mapping subtables are selected once, on construction.
+///
+/// Characters are mapped without creating any intermediate objects. Metrics
+/// and outlines are returned as lightweight views that borrow from this
+/// object.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct SharedMetadata {
+    font: SharedFont,
+    mapping_index: MappingIndex,
+}
+
+#[cfg(feature = "std")]
+impl SharedMetadata {
+    /// Creates metadata for the given shared font.
+    pub fn new(font: SharedFont) -> Self {
+        let mapping_index = MappingIndex::new(&&font);
+        Self {
+            font,
+            mapping_index,
+        }
+    }
+
+    /// Returns the underlying shared font.
+    pub fn font(&self) -> &SharedFont {
+        &self.font
+    }
+
+    /// Maps a character to a nominal glyph identifier.
+    ///
+    /// Returns `None` if a mapping does not exist.
+    pub fn map(&self, ch: impl Into<u32>) -> Option<GlyphId> {
+        self.charmap().map(ch)
+    }
+
+    /// Returns the character map, using the precomputed subtable selection.
+    pub fn charmap(&self) -> Charmap<'_> {
+        self.mapping_index.charmap(&&self.font)
+    }
+
+    /// Returns the glyph specific metrics for the specified size and
+    /// location in normalized variation space.
+    pub fn glyph_metrics<'a>(
+        &'a self,
+        size: Size,
+        location: impl Into<LocationRef<'a>>,
+    ) -> GlyphMetrics<'a> {
+        (&self.font).glyph_metrics(size, location)
+    }
+
+    /// Returns the collection of scalable glyph outlines.
+    pub fn outline_glyphs(&self) -> OutlineGlyphCollection<'_> {
+        (&self.font).outline_glyphs()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<SharedFont> for SharedMetadata {
+    fn from(font: SharedFont) -> Self {
+        Self::new(font)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, raw::TableProvider};
+
+    #[test]
+    fn shared_font_metadata() {
+        let data = font_test_data::VAZIRMATN_VAR;
+        let font = SharedFont::new(data.to_vec()).unwrap();
+        let font_ref = FontRef::new(data).unwrap();
+        let shared = &font;
+        assert_eq!(
+            shared.charmap().mappings().collect::<Vec<_>>(),
+            font_ref.charmap().mappings().collect::<Vec<_>>()
+        );
+        assert_eq!(shared.axes().len(), font_ref.axes().len());
+        assert_eq!(
+            shared.outline_glyphs().get(GlyphId::new(1)).is_some(),
+            font_ref.outline_glyphs().get(GlyphId::new(1)).is_some()
+        );
+        assert_eq!(
+            shared.head().unwrap().units_per_em(),
+            font_ref.head().unwrap().units_per_em()
+        );
+    }
+
+    #[test]
+    fn owned_metadata() {
+        fn cached() -> SharedMetadata {
+            let data = font_test_data::VAZIRMATN_VAR.to_vec();
+            SharedMetadata::new(SharedFont::new(data).unwrap())
+        }
+        // the metadata outlives the data it was created from
+        let metadata: Box<dyn std::any::Any> = Box::new(cached());
+        let metadata = metadata.downcast_ref::<SharedMetadata>().unwrap();
+        let font_ref = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let charmap = font_ref.charmap();
+        for (ch, gid) in charmap.mappings() {
+            assert_eq!(metadata.map(ch), Some(gid));
+        }
+        assert_eq!(metadata.map('\u{10FFFF}'), None);
+        let coords = [NormalizedCoord::from_f32(0.5)];
+        let size = Size::new(16.0);
+        let metrics = metadata.glyph_metrics(size, coords.as_slice());
+        let expected = font_ref.glyph_metrics(size, coords.as_slice());
+        for gid in 0..expected.glyph_count() {
+            let gid = GlyphId::new(gid);
+            assert_eq!(metrics.advance_width(gid), expected.advance_width(gid));
+        }
+        let glyph = metadata.outline_glyphs().get(GlyphId::new(1)).unwrap();
+        let expected = font_ref.outline_glyphs().get(GlyphId::new(1)).unwrap();
+        assert_eq!(glyph.format(), expected.format());
+    }
+}
@@ -28,10 +28,12 @@ extern crate alloc;
 pub extern crate read_fonts as raw;
 
 pub mod attribute;
+pub mod baseline;
 pub mod charmap;
 pub mod color;
 pub mod font;
 pub mod instance;
+pub mod math;
 pub mod metrics;
 pub mod outline;
 pub mod setting;
@@ -0,0 +1,1051 @@
+//! Support for mathematical typesetting.
+//!
+//! This provides access to the values stored in the
+//! [MATH](https://learn.microsoft.com/en-us/typography/opentype/spec/math)
+//! table, scaled to a specific size and adjusted for a location in
+//! variation space. The functionality mirrors the `hb_ot_math_*` family
+//! of functions in HarfBuzz:
+//!
+//! * Global constants such as the axis height and fraction rule thickness
+//!   are available through [`Math::constants`].
+//! * Per glyph values: [`Math::italics_correction`],
+//!   [`Math::top_accent_attachment`], [`Math::is_extended_shape`] and
+//!   [`Math::kerning`].
+//! * Stretchy glyphs: the raw variants and assemblies are available through
+//!   [`Math::variants`] and [`Math::assembly`] while [`Math::stretchy_glyph`]
+//!   selects a variant or builds an assembly that covers a requested extent.
+
+#[cfg(feature = "libm")]
+#[allow(unused_imports)]
+use core_maths::CoreFloat;
+
+use alloc::vec::Vec;
+
+use read_fonts::{
+    tables::{
+        layout::DeviceOrVariationIndex,
+        math::{self as raw_math, GlyphPartFlags, MathValueRecord},
+        variations::{DeltaSetIndex, ItemVariationStore},
+    },
+    types::GlyphId,
+    FontData, TableProvider,
+};
+
+use super::instance::{LocationRef, NormalizedCoord, Size};
+
+/// Maximum number of times each extender will be repeated when building
+/// a glyph assembly.
+const MAX_EXTENDER_REPEAT: usize = 256;
+
+/// Direction for stretchy glyph queries.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Glyphs that stretch horizontally, such as over and under braces.
+    Horizontal,
+    /// Glyphs that stretch vertically, such as parentheses and radicals.
+    Vertical,
+}
+
+/// Corner of a glyph for math kerning.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MathKernCorner {
+    /// Kerning for a superscript attached after the glyph.
+    TopRight,
+    /// Kerning for a superscript attached before the glyph.
+    TopLeft,
+    /// Kerning for a subscript attached after the glyph.
+    BottomRight,
+    /// Kerning for a subscript attached before the glyph.
+    BottomLeft,
+}
+
+/// Global constants for mathematical layout.
+///
+/// Percentage values are unscaled. All other values are scaled to the
+/// requested size and include any device or variation adjustments.
+///
+/// See the [MathConstants](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathconstants-table)
+/// table for more detail on each value.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct MathConstants {
+    /// Percentage of scaling down for level 1 superscripts and subscripts.
+    pub script_percent_scale_down: i16,
+    /// Percentage of scaling down for level 2 (scriptScript) superscripts and
+    /// subscripts.
+    pub script_script_percent_scale_down: i16,
+    /// Minimum height required for a delimited expression to be treated as a
+    /// sub-formula.
+    pub delimited_sub_formula_min_height: f32,
+    /// Minimum height of n-ary operators (such as integral and summation) for
+    /// formulas in display mode.
+    pub display_operator_min_height: f32,
+    /// White space to be left between math formulas to ensure proper line
+    /// spacing.
+    pub math_leading: f32,
+    /// Axis height of the font: the position of the math axis above the
+    /// baseline, on which fraction bars and operators are centered.
+    pub axis_height: f32,
+    /// Maximum (ink) height of an accent base that does not require raising
+    /// the accents.
+    pub accent_base_height: f32,
+    /// Maximum (ink) height of an accent base that does not require flattening
+    /// the accents.
+    pub flattened_accent_base_height: f32,
+    /// The standard shift down applied to subscript elements.
+    pub subscript_shift_down: f32,
+    /// Maximum allowed height of the (ink) top of subscripts that does not
+    /// require moving subscripts further down.
+    pub subscript_top_max: f32,
+    /// Minimum allowed drop of the baseline of subscripts relative to the
+    /// (ink) bottom of the base.
+    pub subscript_baseline_drop_min: f32,
+    /// Standard shift up applied to superscript elements.
+    pub superscript_shift_up: f32,
+    /// Standard shift up applied to superscript elements when the current
+    /// style is cramped.
+    pub superscript_shift_up_cramped: f32,
+    /// Minimum allowed height of the (ink) bottom of superscripts that does
+    /// not require moving subscripts further up.
+    pub superscript_bottom_min: f32,
+    /// Maximum allowed drop of the baseline of superscripts relative to the
+    /// (ink) top of the base.
+    pub superscript_baseline_drop_max: f32,
+    /// Minimum gap between the superscript and subscript ink.
+    pub sub_superscript_gap_min: f32,
+    /// Maximum height of the (ink) bottom of the superscript that should be
+    /// maintained when the gap between superscript and subscript is
+    /// increased.
+    pub superscript_bottom_max_with_subscript: f32,
+    /// Extra white space to be added after each subscript and superscript.
+    pub space_after_script: f32,
+    /// Minimum gap between the (ink) bottom of the upper limit and the (ink)
+    /// top of the base operator.
+    pub upper_limit_gap_min: f32,
+    /// Minimum distance between the baseline of an upper limit and the (ink)
+    /// top of the base operator.
+    pub upper_limit_baseline_rise_min: f32,
+    /// Minimum gap between the (ink) top of the lower limit and the (ink)
+    /// bottom of the base operator.
+    pub lower_limit_gap_min: f32,
+    /// Minimum distance between the baseline of the lower limit and the (ink)
+    /// bottom of the base operator.
+    pub lower_limit_baseline_drop_min: f32,
+    /// Standard shift up applied to the top element of a stack.
+    pub stack_top_shift_up: f32,
+    /// Standard shift up applied to the top element of a stack in display
+    /// style.
+    pub stack_top_display_style_shift_up: f32,
+    /// Standard shift down applied to the bottom element of a stack.
+    pub stack_bottom_shift_down: f32,
+    /// Standard shift down applied to the bottom element of a stack in
+    /// display style.
+    pub stack_bottom_display_style_shift_down: f32,
+    /// Minimum gap between the (ink) bottom of the top element of a stack and
+    /// the (ink) top of the bottom element.
+    pub stack_gap_min: f32,
+    /// Minimum gap between the (ink) bottom of the top element of a stack and
+    /// the (ink) top of the bottom element in display style.
+    pub stack_display_style_gap_min: f32,
+    /// Standard shift up applied to the top element of a stretch stack.
+    pub stretch_stack_top_shift_up: f32,
+    /// Standard shift down applied to the bottom element of a stretch stack.
+    pub stretch_stack_bottom_shift_down: f32,
+    /// Minimum gap between the (ink) bottom of the element above a stretch
+    /// stack and the (ink) top of the stretched element.
+    pub stretch_stack_gap_above_min: f32,
+    /// Minimum gap between the (ink) bottom of the stretched element and the
+    /// (ink) top of the element below a stretch stack.
+    pub stretch_stack_gap_below_min: f32,
+    /// Standard shift up applied to the numerator.
+    pub fraction_numerator_shift_up: f32,
+    /// Standard shift up applied to the numerator in display style.
+    pub fraction_numerator_display_style_shift_up: f32,
+    /// Standard shift down applied to the denominator.
+    pub fraction_denominator_shift_down: f32,
+    /// Standard shift down applied to the denominator in display style.
+    pub fraction_denominator_display_style_shift_down: f32,
+    /// Minimum tolerated gap between the (ink) bottom of the numerator and the
+    /// (ink) top of the fraction bar.
+    pub fraction_numerator_gap_min: f32,
+    /// Minimum tolerated gap between the (ink) bottom of the numerator and the
+    /// (ink) top of the fraction bar in display style.
+    pub fraction_num_display_style_gap_min: f32,
+    /// Thickness of the fraction bar.
+    pub fraction_rule_thickness: f32,
+    /// Minimum tolerated gap between the (ink) top of the denominator and the
+    /// (ink) bottom of the fraction bar.
+    pub fraction_denominator_gap_min: f32,
+    /// Minimum tolerated gap between the (ink) top of the denominator and the
+    /// (ink) bottom of the fraction bar in display style.
+    pub fraction_denom_display_style_gap_min: f32,
+    /// Horizontal distance between the top and bottom elements of a skewed
+    /// fraction.
+    pub skewed_fraction_horizontal_gap: f32,
+    /// Vertical distance between the ink of the top and bottom elements of a
+    /// skewed fraction.
+    pub skewed_fraction_vertical_gap: f32,
+    /// Distance between the overbar and the (ink) top of the base.
+    pub overbar_vertical_gap: f32,
+    /// Thickness of the overbar.
+    pub overbar_rule_thickness: f32,
+    /// Extra white space reserved above the overbar.
+    pub overbar_extra_ascender: f32,
+    /// Distance between the underbar and the (ink) bottom of the base.
+    pub underbar_vertical_gap: f32,
+    /// Thickness of the underbar.
+    pub underbar_rule_thickness: f32,
+    /// Extra white space reserved below the underbar.
+    pub underbar_extra_descender: f32,
+    /// Space between the (ink) top of the expression and the bar over it.
+    pub radical_vertical_gap: f32,
+    /// Space between the (ink) top of the expression and the bar over it in
+    /// display style.
+    pub radical_display_style_vertical_gap: f32,
+    /// Thickness of the radical rule.
+    pub radical_rule_thickness: f32,
+    /// Extra white space reserved above the radical.
+    pub radical_extra_ascender: f32,
+    /// Extra horizontal kern before the degree of a radical, if present.
+    pub radical_kern_before_degree: f32,
+    /// Negative kern after the degree of a radical, if present.
+    pub radical_kern_after_degree: f32,
+    /// Height of the bottom of the radical degree, if present, in proportion
+    /// to the height (ascender + descender) of the radical sign.
+    pub radical_degree_bottom_raise_percent: i16,
+}
+
+/// Pre-built variant of a stretchy glyph.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GlyphVariant {
+    /// Identifier of the variant glyph.
+    pub glyph_id: GlyphId,
+    /// Scaled advance of the variant in the direction of stretching.
+    pub advance: f32,
+}
+
+/// Part of a glyph assembly.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GlyphPart {
+    /// Identifier of the glyph for this part.
+    pub glyph_id: GlyphId,
+    /// Scaled length of the connector at the start of the part.
+    pub start_connector_length: f32,
+    /// Scaled length of the connector at the end of the part.
+    pub end_connector_length: f32,
+    /// Scaled advance of the part in the direction of stretching.
+    pub full_advance: f32,
+    /// True if the part can be repeated to extend the assembly.
+    pub is_extender: bool,
+}
+
+/// Recipe for building a stretchy glyph from a sequence of parts.
+///
+/// Parts are ordered from bottom to top for vertical assemblies and from
+/// left to right for horizontal assemblies.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct GlyphAssembly {
+    /// Scaled italics correction of the assembled glyph.
+    pub italics_correction: f32,
+    /// Sequence of parts.
+    pub parts: Vec<GlyphPart>,
+}
+
+/// Part of an assembled glyph with a computed position.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PositionedPart {
+    /// Identifier of the glyph for this part.
+    pub glyph_id: GlyphId,
+    /// Scaled offset of the part from the start of the assembly in the
+    /// direction of stretching.
+    pub offset: f32,
+}
+
+/// Glyph assembly with repeated extenders and computed part positions.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct AssembledGlyph {
+    /// Scaled italics correction of the assembled glyph.
+    pub italics_correction: f32,
+    /// Scaled total extent of the assembly in the direction of stretching.
+    pub extent: f32,
+    /// Scaled overlap between each pair of adjacent parts.
+    pub overlap: f32,
+    /// Sequence of positioned parts.
+    pub parts: Vec<PositionedPart>,
+}
+
+/// Result of a stretchy glyph query.
+#[derive(Clone, PartialEq, Debug)]
+pub enum StretchyGlyph {
+    /// A single pre-built variant.
+    Variant(GlyphVariant),
+    /// A glyph built from a sequence of parts.
+    Assembly(AssembledGlyph),
+}
+
+impl StretchyGlyph {
+    /// Returns the scaled extent in the direction of stretching.
+    pub fn extent(&self) -> f32 {
+        match self {
+            Self::Variant(variant) => variant.advance,
+            Self::Assembly(assembly) => assembly.extent,
+        }
+    }
+}
+
+/// Mathematical layout data for a specific size and location in
+/// variation space.
+#[derive(Clone)]
+pub struct Math<'a> {
+    math: Option<raw_math::Math<'a>>,
+    var_store: Option<ItemVariationStore<'a>>,
+    coords: &'a [NormalizedCoord],
+    scale: f32,
+    ppem: Option<u16>,
+}
+
+impl<'a> Math<'a> {
+    /// Creates new math layout data for the given font, size and location in
+    /// normalized variation space.
+    pub fn new(
+        font: &impl TableProvider<'a>,
+        size: Size,
+        location: impl Into<LocationRef<'a>>,
+    ) -> Self {
+        let upem = font.head().map(|head| head.units_per_em()).unwrap_or(0);
+        let coords = location.into().coords();
+        // Variation deltas for MATH are stored in the GDEF item variation
+        // store
+        let var_store = if coords.is_empty() {
+            None
+        } else {
+            font.gdef()
+                .ok()
+                .and_then(|gdef| gdef.item_var_store()?.ok())
+        };
+        Self {
+            math: font.math().ok(),
+            var_store,
+            coords,
+            scale: size.linear_scale(upem),
+            ppem: size.ppem().map(|ppem| ppem.round() as u16),
+        }
+    }
+
+    /// Returns true if the font contains a MATH table.
+    pub fn has_data(&self) -> bool {
+        self.math.is_some()
+    }
+
+    /// Returns the global constants for mathematical layout.
+    pub fn constants(&self) -> MathConstants {
+        let Some(constants) = self
+            .math
+            .as_ref()
+            .and_then(|math| math.math_constants().ok())
+        else {
+            return MathConstants::default();
+        };
+        let data = constants.offset_data();
+        let value = |record: &MathValueRecord| self.value(record, data);
+        let unit = |value: read_fonts::types::UfWord| value.to_u16() as f32 * self.scale;
+        MathConstants {
+            script_percent_scale_down: constants.script_percent_scale_down(),
+            script_script_percent_scale_down: constants.script_script_percent_scale_down(),
+            delimited_sub_formula_min_height: unit(constants.delimited_sub_formula_min_height()),
+            display_operator_min_height: unit(constants.display_operator_min_height()),
+            math_leading: value(constants.math_leading()),
+            axis_height: value(constants.axis_height()),
+            accent_base_height: value(constants.accent_base_height()),
+            flattened_accent_base_height: value(constants.flattened_accent_base_height()),
+            subscript_shift_down: value(constants.subscript_shift_down()),
+            subscript_top_max: value(constants.subscript_top_max()),
+            subscript_baseline_drop_min: value(constants.subscript_baseline_drop_min()),
+            superscript_shift_up: value(constants.superscript_shift_up()),
+            superscript_shift_up_cramped: value(constants.superscript_shift_up_cramped()),
+            superscript_bottom_min: value(constants.superscript_bottom_min()),
+            superscript_baseline_drop_max: value(constants.superscript_baseline_drop_max()),
+            sub_superscript_gap_min: value(constants.sub_superscript_gap_min()),
+            superscript_bottom_max_with_subscript: value(
+                constants.superscript_bottom_max_with_subscript(),
+            ),
+            space_after_script: value(constants.space_after_script()),
+            upper_limit_gap_min: value(constants.upper_limit_gap_min()),
+            upper_limit_baseline_rise_min: value(constants.upper_limit_baseline_rise_min()),
+            lower_limit_gap_min: value(constants.lower_limit_gap_min()),
+            lower_limit_baseline_drop_min: value(constants.lower_limit_baseline_drop_min()),
+            stack_top_shift_up: value(constants.stack_top_shift_up()),
+            stack_top_display_style_shift_up: value(constants.stack_top_display_style_shift_up()),
+            stack_bottom_shift_down: value(constants.stack_bottom_shift_down()),
+            stack_bottom_display_style_shift_down: value(
+                constants.stack_bottom_display_style_shift_down(),
+            ),
+            stack_gap_min: value(constants.stack_gap_min()),
+            stack_display_style_gap_min: value(constants.stack_display_style_gap_min()),
+            stretch_stack_top_shift_up: value(constants.stretch_stack_top_shift_up()),
+            stretch_stack_bottom_shift_down: value(constants.stretch_stack_bottom_shift_down()),
+            stretch_stack_gap_above_min: value(constants.stretch_stack_gap_above_min()),
+            stretch_stack_gap_below_min: value(constants.stretch_stack_gap_below_min()),
+            fraction_numerator_shift_up: value(constants.fraction_numerator_shift_up()),
+            fraction_numerator_display_style_shift_up: value(
+                constants.fraction_numerator_display_style_shift_up(),
+            ),
+            fraction_denominator_shift_down: value(constants.fraction_denominator_shift_down()),
+            fraction_denominator_display_style_shift_down: value(
+                constants.fraction_denominator_display_style_shift_down(),
+            ),
+            fraction_numerator_gap_min: value(constants.fraction_numerator_gap_min()),
+            fraction_num_display_style_gap_min: value(
+                constants.fraction_num_display_style_gap_min(),
+            ),
+            fraction_rule_thickness: value(constants.fraction_rule_thickness()),
+            fraction_denominator_gap_min: value(constants.fraction_denominator_gap_min()),
+            fraction_denom_display_style_gap_min: value(
+                constants.fraction_denom_display_style_gap_min(),
+            ),
+            skewed_fraction_horizontal_gap: value(constants.skewed_fraction_horizontal_gap()),
+            skewed_fraction_vertical_gap: value(constants.skewed_fraction_vertical_gap()),
+            overbar_vertical_gap: value(constants.overbar_vertical_gap()),
+            overbar_rule_thickness: value(constants.overbar_rule_thickness()),
+            overbar_extra_ascender: value(constants.overbar_extra_ascender()),
+            underbar_vertical_gap: value(constants.underbar_vertical_gap()),
+            underbar_rule_thickness: value(constants.underbar_rule_thickness()),
+            underbar_extra_descender: value(constants.underbar_extra_descender()),
+            radical_vertical_gap: value(constants.radical_vertical_gap()),
+            radical_display_style_vertical_gap: value(
+                constants.radical_display_style_vertical_gap(),
+            ),
+            radical_rule_thickness: value(constants.radical_rule_thickness()),
+            radical_extra_ascender: value(constants.radical_extra_ascender()),
+            radical_kern_before_degree: value(constants.radical_kern_before_degree()),
+            radical_kern_after_degree: value(constants.radical_kern_after_degree()),
+            radical_degree_bottom_raise_percent: constants.radical_degree_bottom_raise_percent(),
+        }
+    }
+
+    /// Returns the italics correction for the given glyph.
+    pub fn italics_correction(&self, glyph_id: GlyphId) -> Option<f32> {
+        let info = self
+            .math
+            .as_ref()?
+            .math_glyph_info()
+            .ok()?
+            .math_italics_correction_info()?
+            .ok()?;
+        let index = info.italics_correction_coverage().ok()?.get(glyph_id)?;
+        let record = info.italics_correction().get(index as usize)?;
+        Some(self.value(record, info.offset_data()))
+    }
+
+    /// Returns the horizontal position for attaching top accents to the
+    /// given glyph.
+    ///
+    /// When this is `None`, the accent should be centered over the glyph.
+    pub fn top_accent_attachment(&self, glyph_id: GlyphId) -> Option<f32> {
+        let attachment = self
+            .math
+            .as_ref()?
+            .math_glyph_info()
+            .ok()?
+            .math_top_accent_attachment()?
+            .ok()?;
+        let index = attachment.top_accent_coverage().ok()?.get(glyph_id)?;
+        let record = attachment.top_accent_attachment().get(index as usize)?;
+        Some(self.value(record, attachment.offset_data()))
+    }
+
+    /// Returns true if the given glyph is an extended shape.
+    pub fn is_extended_shape(&self, glyph_id: GlyphId) -> bool {
+        (|| {
+            self.math
+                .as_ref()?
+                .math_glyph_info()
+                .ok()?
+                .extended_shape_coverage()?
+                .ok()?
+                .get(glyph_id)
+        })()
+        .is_some()
+    }
+
+    /// Returns the kerning adjustment for the given glyph, corner and
+    /// scaled correction height.
+    pub fn kerning(
+        &self,
+        glyph_id: GlyphId,
+        corner: MathKernCorner,
+        correction_height: f32,
+    ) -> Option<f32> {
+        let kern_info = self
+            .math
+            .as_ref()?
+            .math_glyph_info()
+            .ok()?
+            .math_kern_info()?
+            .ok()?;
+        let index = kern_info.math_kern_coverage().ok()?.get(glyph_id)?;
+        let record = kern_info.math_kern_info_records().get(index as usize)?;
+        let data = kern_info.offset_data();
+        let kern = match corner {
+            MathKernCorner::TopRight => record.top_right_math_kern(data),
+            MathKernCorner::TopLeft => record.top_left_math_kern(data),
+            MathKernCorner::BottomRight => record.bottom_right_math_kern(data),
+            MathKernCorner::BottomLeft => record.bottom_left_math_kern(data),
+        }?
+        .ok()?;
+        let kern_data = kern.offset_data();
+        // The kern value index is the number of heights that are less than
+        // or equal to the correction height
+        let index = kern
+            .correction_height()
+            .iter()
+            .take_while(|height| self.value(height, kern_data) <= correction_height)
+            .count();
+        let value = kern.kern_values().get(index)?;
+        Some(self.value(value, kern_data))
+    }
+
+    /// Returns the scaled minimum overlap of connecting glyphs during glyph
+    /// construction.
+    pub fn min_connector_overlap(&self) -> f32 {
+        self.math
+            .as_ref()
+            .and_then(|math| math.math_variants().ok())
+            .map(|variants| variants.min_connector_overlap().to_u16() as f32 * self.scale)
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over the pre-built variants of the given glyph
+    /// in the given direction.
+    ///
+    /// Variants are ordered by increasing size.
+    pub fn variants(&self, glyph_id: GlyphId, direction: Direction) -> GlyphVariants<'a> {
+        let records = self
+            .construction(glyph_id, direction)
+            .map(|construction| construction.math_glyph_variant_records())
+            .unwrap_or_default();
+        GlyphVariants {
+            records: records.iter(),
+            scale: self.scale,
+        }
+    }
+
+    /// Returns the recipe for assembling the given glyph from parts in the
+    /// given direction.
+    pub fn assembly(&self, glyph_id: GlyphId, direction: Direction) -> Option<GlyphAssembly> {
+        let assembly = self
+            .construction(glyph_id, direction)?
+            .glyph_assembly()?
+            .ok()?;
+        let scale = |value: read_fonts::types::UfWord| value.to_u16() as f32 * self.scale;
+        Some(GlyphAssembly {
+            italics_correction: self.value(assembly.italics_correction(), assembly.offset_data()),
+            parts: assembly
+                .part_records()
+                .iter()
+                .map(|part| GlyphPart {
+                    glyph_id: part.glyph_id().into(),
+                    start_connector_length: scale(part.start_connector_length()),
+                    end_connector_length: scale(part.end_connector_length()),
+                    full_advance: scale(part.full_advance()),
+                    is_extender: part.part_flags().contains(GlyphPartFlags::EXTENDER_FLAG),
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns a stretched form of the given glyph that covers the scaled
+    /// `target` extent in the given direction.
+    ///
+    /// The first variant with an advance that is at least `target` is
+    /// preferred. Otherwise, a glyph assembly is built by repeating the
+    /// extenders the minimum number of times required to reach the target
+    /// and distributing the overlap between parts uniformly. If the font
+    /// provides no assembly, the largest variant is returned.
+    ///
+    /// Returns `None` if the glyph has no variants or assembly in the given
+    /// direction.
+    pub fn stretchy_glyph(
+        &self,
+        glyph_id: GlyphId,
+        direction: Direction,
+        target: f32,
+    ) -> Option<StretchyGlyph> {
+        let mut largest: Option<GlyphVariant> = None;
+        for variant in self.variants(glyph_id, direction) {
+            if variant.advance >= target {
+                return Some(StretchyGlyph::Variant(variant));
+            }
+            if largest.is_none_or(|largest| variant.advance > largest.advance) {
+                largest = Some(variant);
+            }
+        }
+        if let Some(assembled) = self
+            .assembly(glyph_id, direction)
+            .and_then(|assembly| assemble(&assembly, self.min_connector_overlap(), target))
+        {
+            return Some(StretchyGlyph::Assembly(assembled));
+        }
+        largest.map(StretchyGlyph::Variant)
+    }
+
+    fn construction(
+        &self,
+        glyph_id: GlyphId,
+        direction: Direction,
+    ) -> Option<raw_math::MathGlyphConstruction<'a>> {
+        let variants = self.math.as_ref()?.math_variants().ok()?;
+        let (coverage, constructions) = match direction {
+            Direction::Horizontal => (
+                variants.horiz_glyph_coverage(),
+                variants.horiz_glyph_constructions(),
+            ),
+            Direction::Vertical => (
+                variants.vert_glyph_coverage(),
+                variants.vert_glyph_constructions(),
+            ),
+        };
+        let index = coverage?.ok()?.get(glyph_id)?;
+        constructions.get(index as usize).ok()
+    }
+
+    /// Computes the scaled value of the given record, including any device
+    /// or variation adjustments.
+    ///
+    /// The `data` parameter is the offset data of the table containing the
+    /// record.
+    fn value(&self, record: &MathValueRecord, data: FontData<'a>) -> f32 {
+        let mut value = record.value().to_i16() as f32 * self.scale;
+        match record.device(data) {
+            Some(Ok(DeviceOrVariationIndex::Device(device))) => {
+                // Device deltas are specified in pixels and are only
+                // meaningful when scaling to a specific size
+                if let Some(ppem) = self.ppem {
+                    value += device.delta(ppem) as f32;
+                }
+            }
+            Some(Ok(DeviceOrVariationIndex::VariationIndex(index))) => {
+                if let Some(var_store) = &self.var_store {
+                    let index = DeltaSetIndex {
+                        outer: index.delta_set_outer_index(),
+                        inner: index.delta_set_inner_index(),
+                    };
+                    if let Ok(delta) = var_store.compute_delta(index, self.coords) {
+                        value += delta as f32 * self.scale;
+                    }
+                }
+            }
+            _ => {}
+        }
+        value
+    }
+}
+
+/// Iterator over the pre-built variants of a stretchy glyph.
+#[derive(Clone)]
+pub struct GlyphVariants<'a> {
+    records: core::slice::Iter<'a, raw_math::MathGlyphVariantRecord>,
+    scale: f32,
+}
+
+impl Iterator for GlyphVariants<'_> {
+    type Item = GlyphVariant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.records.next()?;
+        Some(GlyphVariant {
+            glyph_id: record.variant_glyph().into(),
+            advance: record.advance_measurement().to_u16() as f32 * self.scale,
+        })
+    }
+}
+
+/// Builds an assembled glyph that covers the `target` extent.
+fn assemble(assembly: &GlyphAssembly, min_overlap: f32, target: f32) -> Option<AssembledGlyph> {
+    let parts = &assembly.parts;
+    let (mut fixed_count, mut fixed_advance) = (0usize, 0f32);
+    let (mut extender_count, mut extender_advance) = (0usize, 0f32);
+    for part in parts {
+        if part.is_extender {
+            extender_count += 1;
+            extender_advance += part.full_advance;
+        } else {
+            fixed_count += 1;
+            fixed_advance += part.full_advance;
+        }
+    }
+    // The maximum extent for a given repeat count is reached when adjacent
+    // parts overlap by exactly the minimum amount
+    let base_extent = fixed_advance - fixed_count.saturating_sub(1) as f32 * min_overlap;
+    let extender_growth = extender_advance - extender_count as f32 * min_overlap;
+    let repeat_count = if extender_count == 0 || extender_growth <= 0.0 || target <= base_extent {
+        // Always include the extenders when the assembly has no other parts
+        (fixed_count == 0) as usize
+    } else {
+        (((target - base_extent) / extender_growth).ceil() as usize).min(MAX_EXTENDER_REPEAT)
+    };
+    let expanded = parts
+        .iter()
+        .flat_map(|part| {
+            let count = if part.is_extender { repeat_count } else { 1 };
+            core::iter::repeat_n(part, count)
+        })
+        .collect::<Vec<_>>();
+    if expanded.is_empty() {
+        return None;
+    }
+    let total_advance: f32 = expanded.iter().map(|part| part.full_advance).sum();
+    let overlap = if expanded.len() > 1 {
+        // Adjacent parts can overlap by at most the smaller of the two
+        // connector lengths
+        let max_overlap = expanded
+            .windows(2)
+            .map(|pair| {
+                pair[0]
+                    .end_connector_length
+                    .min(pair[1].start_connector_length)
+            })
+            .fold(f32::MAX, f32::min)
+            .max(min_overlap);
+        ((total_advance - target) / (expanded.len() - 1) as f32).clamp(min_overlap, max_overlap)
+    } else {
+        0.0
+    };
+    let mut offset = 0.0;
+    let positioned = expanded
+        .iter()
+        .map(|part| {
+            let positioned = PositionedPart {
+                glyph_id: part.glyph_id,
+                offset,
+            };
+            offset += part.full_advance - overlap;
+            positioned
+        })
+        .collect();
+    Some(AssembledGlyph {
+        italics_correction: assembly.italics_correction,
+        extent: total_advance - (expanded.len() - 1) as f32 * overlap,
+        overlap,
+        parts: positioned,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    // Avoid glob importing the parent module to prevent TableProvider::math
+    // from conflicting with MetadataProvider::math
+    use super::{
+        Direction, GlyphId, LocationRef, MathConstants, MathKernCorner, Size, StretchyGlyph,
+    };
+    use crate::MetadataProvider;
+    use font_test_data::bebuffer::BeBuffer;
+    use read_fonts::{types::Tag, FontData};
+
+    /// Serves hand built head and MATH tables.
+    struct MathFont {
+        head: Vec<u8>,
+        math: Vec<u8>,
+    }
+
+    impl<'a> read_fonts::TableProvider<'a> for &'a MathFont {
+        fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+            match &tag.to_be_bytes() {
+                b"head" => Some(FontData::new(&self.head)),
+                b"MATH" => Some(FontData::new(&self.math)),
+                _ => None,
+            }
+        }
+    }
+
+    fn make_head(upem: u16) -> Vec<u8> {
+        BeBuffer::new()
+            .push(1u16) // major version
+            .push(0u16) // minor version
+            .push(0u32) // font revision
+            .push(0u32) // checksum adjustment
+            .push(0x5F0F3CF5u32) // magic
+            .push(0u16) // flags
+            .push(upem)
+            .pad_to(54)
+            .to_vec()
+    }
+
+    // Layout of the test MATH table:
+    // 0: header
+    // 10: MathConstants (214 bytes)
+    // 224: MathGlyphInfo
+    // 232: MathItalicsCorrectionInfo
+    // 240: italics correction coverage
+    // 246: device table for the italics correction
+    // 256: MathTopAccentAttachment
+    // 264: top accent coverage
+    // 270: MathKernInfo
+    // 282: math kern coverage
+    // 288: MathKern (top right)
+    // 310: MathVariants
+    // 322: vertical coverage
+    // 328: MathGlyphConstruction
+    // 340: GlyphAssembly
+    const GLYPH: u16 = 5;
+    const ACCENT_GLYPH: u16 = 6;
+
+    fn make_math() -> Vec<u8> {
+        let mut buf = BeBuffer::new()
+            .push(1u16)
+            .push(0u16)
+            .push(10u16) // constants
+            .push(224u16) // glyph info
+            .push(310u16) // variants
+            // MathConstants
+            .push(80i16) // script percent scale down
+            .push(60i16) // script script percent scale down
+            .push(1300u16) // delimited sub formula min height
+            .push(1500u16); // display operator min height
+                            // 51 value records, all with a value of 10 * index
+        for i in 0..51i16 {
+            buf = buf.push(i * 10).push(0u16);
+        }
+        buf = buf
+            .push(-55i16) // radical degree bottom raise percent
+            .pad_to(224)
+            // MathGlyphInfo
+            .push(8u16) // italics correction info
+            .push(32u16) // top accent attachment
+            .push(0u16) // extended shape coverage
+            .push(46u16) // kern info
+            // MathItalicsCorrectionInfo
+            .push(8u16) // coverage
+            .push(1u16) // count
+            .push(100i16)
+            .push(14u16) // device
+            // coverage format 1
+            .push(1u16)
+            .push(1u16)
+            .push(GLYPH)
+            // device: sizes 10-12, 8-bit deltas
+            .push(10u16)
+            .push(12u16)
+            .push(3u16)
+            .push(0x0102u16) // 10: 1, 11: 2
+            .push(0u16) // 12: 0
+            // MathTopAccentAttachment
+            .push(8u16) // coverage
+            .push(1u16)
+            .push(250i16)
+            .push(0u16)
+            // coverage format 1
+            .push(1u16)
+            .push(1u16)
+            .push(ACCENT_GLYPH)
+            // MathKernInfo
+            .push(12u16) // coverage
+            .push(1u16)
+            .push(18u16) // top right
+            .push(0u16)
+            .push(0u16)
+            .push(0u16)
+            // coverage format 1
+            .push(1u16)
+            .push(1u16)
+            .push(GLYPH)
+            // MathKern: heights [100, 200], kerns [10, 20, 30]
+            .push(2u16)
+            .push(100i16)
+            .push(0u16)
+            .push(200i16)
+            .push(0u16)
+            .push(10i16)
+            .push(0u16)
+            .push(20i16)
+            .push(0u16)
+            .push(30i16)
+            .push(0u16)
+            // MathVariants
+            .push(20u16) // min connector overlap
+            .push(12u16) // vert coverage
+            .push(0u16) // horiz coverage
+            .push(1u16) // vert count
+            .push(0u16) // horiz count
+            .push(18u16) // construction
+            // coverage format 1
+            .push(1u16)
+            .push(1u16)
+            .push(GLYPH)
+            // MathGlyphConstruction
+            .push(12u16) // assembly
+            .push(2u16)
+            .push(GLYPH)
+            .push(500u16)
+            .push(10u16)
+            .push(1000u16);
+        assert_eq!(buf.len(), 340);
+        buf = buf
+            // GlyphAssembly
+            .push(15i16)
+            .push(0u16)
+            .push(3u16); // part count
+                         // bottom, extender, top
+        for (glyph, start, end, advance, flags) in [
+            (20u16, 0u16, 100u16, 600u16, 0u16),
+            (21, 100, 100, 500, 1),
+            (22, 100, 0, 600, 0),
+        ] {
+            buf = buf
+                .push(glyph)
+                .push(start)
+                .push(end)
+                .push(advance)
+                .push(flags);
+        }
+        buf.to_vec()
+    }
+
+    fn make_font() -> MathFont {
+        MathFont {
+            head: make_head(1000),
+            math: make_math(),
+        }
+    }
+
+    #[test]
+    fn constants() {
+        let font = make_font();
+        let font = &font;
+        let constants = font
+            .math(Size::unscaled(), LocationRef::default())
+            .constants();
+        assert_eq!(constants.script_percent_scale_down, 80);
+        assert_eq!(constants.delimited_sub_formula_min_height, 1300.0);
+        assert_eq!(constants.math_leading, 0.0);
+        assert_eq!(constants.axis_height, 10.0);
+        assert_eq!(constants.radical_kern_after_degree, 500.0);
+        assert_eq!(constants.radical_degree_bottom_raise_percent, -55);
+        let scaled = font
+            .math(Size::new(500.0), LocationRef::default())
+            .constants();
+        assert_eq!(scaled.script_percent_scale_down, 80);
+        assert_eq!(scaled.delimited_sub_formula_min_height, 650.0);
+        assert_eq!(scaled.axis_height, 5.0);
+    }
+
+    #[test]
+    fn italics_correction_with_device() {
+        let font = make_font();
+        let font = &font;
+        let glyph = GlyphId::from(GLYPH);
+        let math = font.math(Size::unscaled(), LocationRef::default());
+        assert_eq!(math.italics_correction(glyph), Some(100.0));
+        assert_eq!(math.italics_correction(GlyphId::new(1)), None);
+        // 10ppem: 1.0 scaled + 1 pixel delta
+        let math = font.math(Size::new(10.0), LocationRef::default());
+        assert_eq!(math.italics_correction(glyph), Some(2.0));
+        // 11ppem: 1.1 scaled + 2 pixel delta
+        let math = font.math(Size::new(11.0), LocationRef::default());
+        assert_eq!(math.italics_correction(glyph), Some(1.1 + 2.0));
+        // out of device range
+        let math = font.math(Size::new(20.0), LocationRef::default());
+        assert_eq!(math.italics_correction(glyph), Some(2.0));
+    }
+
+    #[test]
+    fn glyph_info() {
+        let font = make_font();
+        let font = &font;
+        let math = font.math(Size::unscaled(), LocationRef::default());
+        assert_eq!(
+            math.top_accent_attachment(GlyphId::from(ACCENT_GLYPH)),
+            Some(250.0)
+        );
+        assert_eq!(math.top_accent_attachment(GlyphId::from(GLYPH)), None);
+        assert!(!math.is_extended_shape(GlyphId::from(GLYPH)));
+        let glyph = GlyphId::from(GLYPH);
+        let kern = |height| math.kerning(glyph, MathKernCorner::TopRight, height);
+        assert_eq!(kern(50.0), Some(10.0));
+        assert_eq!(kern(100.0), Some(20.0));
+        assert_eq!(kern(150.0), Some(20.0));
+        assert_eq!(kern(500.0), Some(30.0));
+        assert_eq!(math.kerning(glyph, MathKernCorner::TopLeft, 50.0), None);
+    }
+
+    #[test]
+    fn variants_and_assembly() {
+        let font = make_font();
+        let font = &font;
+        let glyph = GlyphId::from(GLYPH);
+        let math = font.math(Size::unscaled(), LocationRef::default());
+        assert_eq!(math.min_connector_overlap(), 20.0);
+        let variants = math
+            .variants(glyph, Direction::Vertical)
+            .map(|variant| (variant.glyph_id.to_u32(), variant.advance))
+            .collect::<Vec<_>>();
+        assert_eq!(variants, [(5, 500.0), (10, 1000.0)]);
+        assert_eq!(math.variants(glyph, Direction::Horizontal).count(), 0);
+        let assembly = math.assembly(glyph, Direction::Vertical).unwrap();
+        assert_eq!(assembly.italics_correction, 15.0);
+        assert_eq!(assembly.parts.len(), 3);
+        assert!(assembly.parts[1].is_extender);
+        assert_eq!(assembly.parts[2].start_connector_length, 100.0);
+    }
+
+    #[test]
+    fn stretchy_glyph() {
+        let font = make_font();
+        let font = &font;
+        let glyph = GlyphId::from(GLYPH);
+        let math = font.math(Size::unscaled(), LocationRef::default());
+        // variants
+        let Some(StretchyGlyph::Variant(variant)) =
+            math.stretchy_glyph(glyph, Direction::Vertical, 400.0)
+        else {
+            panic!("expected variant");
+        };
+        assert_eq!(variant.glyph_id, GlyphId::new(5));
+        let Some(StretchyGlyph::Variant(variant)) =
+            math.stretchy_glyph(glyph, Direction::Vertical, 1000.0)
+        else {
+            panic!("expected variant");
+        };
+        assert_eq!(variant.glyph_id, GlyphId::new(10));
+        // fixed parts alone reach 600 + 600 - 20 = 1180
+        let Some(StretchyGlyph::Assembly(assembled)) =
+            math.stretchy_glyph(glyph, Direction::Vertical, 1100.0)
+        else {
+            panic!("expected assembly");
+        };
+        assert_eq!(assembled.parts.len(), 2);
+        assert_eq!(assembled.overlap, 100.0);
+        assert_eq!(assembled.extent, 1100.0);
+        assert_eq!(assembled.parts[1].offset, 500.0);
+        // each extender adds at most 500 - 20 = 480
+        let Some(StretchyGlyph::Assembly(assembled)) =
+            math.stretchy_glyph(glyph, Direction::Vertical, 2000.0)
+        else {
+            panic!("expected assembly");
+        };
+        let glyphs = assembled
+            .parts
+            .iter()
+            .map(|part| part.glyph_id.to_u32())
+            .collect::<Vec<_>>();
+        assert_eq!(glyphs, [20, 21, 21, 22]);
+        assert_eq!(assembled.extent, 2000.0);
+        assert_eq!(assembled.italics_correction, 15.0);
+        assert!(math
+            .stretchy_glyph(glyph, Direction::Horizontal, 100.0)
+            .is_none());
+    }
+
+    #[test]
+    fn missing_table() {
+        let font = MathFont {
+            head: make_head(1000),
+            math: vec![],
+        };
+        let font = &font;
+        let math = font.math(Size::new(16.0), LocationRef::default());
+        assert!(!math.has_data());
+        assert_eq!(math.constants(), MathConstants::default());
+        assert_eq!(math.italics_correction(GlyphId::new(5)), None);
+        assert_eq!(math.min_connector_overlap(), 0.0);
+    }
+}
@@ -30,6 +30,7 @@ use read_fonts::{
         hvar::Hvar,
         loca::Loca,
         os2::SelectionFlags,
+        variations::VarStoreInstancer,
     },
     types::{BigEndian, Fixed, GlyphId},
     TableProvider,
@@ -228,6 +229,7 @@ pub struct GlyphMetrics<'a> {
     default_advance_width: u16,
     lsbs: &'a [BigEndian<i16>],
     hvar: Option<Hvar<'a>>,
+    hvar_instancer: Option<VarStoreInstancer<'a>>,
     gvar: Option<Gvar<'a>>,
     loca_glyf: Option<(Loca<'a>, Glyf<'a>)>,
     coords: &'a [NormalizedCoord],
@@ -261,6 +263,9 @@ impl<'a> GlyphMetrics<'a> {
             })
             .unwrap_or_default();
         let hvar = font.hvar().ok();
+        let hvar_instancer = hvar.as_ref().and_then(|hvar| {
+            VarStoreInstancer::new(hvar.item_variation_store().ok()?, coords).ok()
+        });
         let gvar = font.gvar().ok();
         let loca_glyf = if let (Ok(loca), Ok(glyf)) = (font.loca(None), font.glyf()) {
             Some((loca, glyf))
@@ -274,6 +279,7 @@ impl<'a> GlyphMetrics<'a> {
             default_advance_width,
             lsbs,
             hvar,
+            hvar_instancer,
             gvar,
             loca_glyf,
             coords,
@@ -302,11 +308,14 @@ impl<'a> GlyphMetrics<'a> {
             .map(|metric| metric.advance())
             .unwrap_or(self.default_advance_width) as i32;
         if let Some(hvar) = &self.hvar {
-            advance += hvar
-                .advance_width_delta(glyph_id, self.coords)
-                // FreeType truncates metric deltas...
-                // https://github.com/freetype/freetype/blob/7838c78f53f206ac5b8e9cefde548aa81cb00cf4/src/truetype/ttgxvar.c#L1027
-                .map(|delta| delta.to_f64() as i32)
+            advance += self
+                .hvar_instancer
+                .as_ref()
+                .and_then(|instancer| {
+                    instancer
+                        .compute_delta(hvar.advance_width_delta_index(glyph_id).ok()?)
+                        .ok()
+                })
                 .unwrap_or(0);
         } else if self.gvar.is_some() {
             advance += self.metric_deltas_from_gvar(glyph_id)[1];
@@ -337,11 +346,14 @@ impl<'a> GlyphMetrics<'a> {
                     .unwrap_or_default()
             }) as i32;
         if let Some(hvar) = &self.hvar {
-            lsb += hvar
-                .lsb_delta(glyph_id, self.coords)
-                // FreeType truncates metric deltas...
-                // https://github.com/freetype/freetype/blob/7838c78f53f206ac5b8e9cefde548aa81cb00cf4/src/truetype/ttgxvar.c#L1027
-                .map(|delta| delta.to_f64() as i32)
+            lsb += self
+                .hvar_instancer
+                .as_ref()
+                .and_then(|instancer| {
+                    instancer
+                        .compute_delta(hvar.lsb_delta_index(glyph_id).ok()?)
+                        .ok()
+                })
                 .unwrap_or(0);
         } else if self.gvar.is_some() {
             lsb += self.metric_deltas_from_gvar(glyph_id)[0];
@@ -9,8 +9,8 @@ use read_fonts::{
         cff::Cff,
         cff2::Cff2,
         postscript::{
-            charstring::{self, CommandSink},
-            dict, BlendState, Error, FdSelect, Index,
+            charstring::{self, CommandSink, SeacComponents},
+            dict, BlendState, Charset, Error, FdSelect, Index,
         },
         variations::ItemVariationStore,
     },
@@ -209,35 +209,42 @@ impl<'a> Outlines<'a> {
         hint: bool,
         pen: &mut impl Pen,
     ) -> Result<(), Error> {
-        let charstring_data = self
+        let charstrings = self
             .top_dict
             .charstrings
             .as_ref()
-            .ok_or(Error::MissingCharstrings)?
-            .get(glyph_id.to_u32() as usize)?;
+            .ok_or(Error::MissingCharstrings)?;
+        let charstring_data = charstrings.get(glyph_id.to_u32() as usize)?;
         let subrs = subfont.subrs(self)?;
         let blend_state = subfont.blend_state(self, coords)?;
+        let seac_components = self
+            .top_dict
+            .charset
+            .clone()
+            .map(|charset| SeacComponents::new(charstrings.clone(), charset));
         let mut pen_sink = charstring::PenSink::new(pen);
         let mut simplifying_adapter = NopFilteringSink::new(&mut pen_sink);
         if hint {
             let mut hinting_adapter =
                 HintingSink::new(&subfont.hint_state, &mut simplifying_adapter);
-            charstring::evaluate(
+            charstring::evaluate_with_seac(
                 charstring_data,
                 self.global_subrs(),
                 subrs,
                 blend_state,
+                seac_components,
                 &mut hinting_adapter,
             )?;
             hinting_adapter.finish();
         } else {
             let mut scaling_adapter =
                 ScalingSink26Dot6::new(&mut simplifying_adapter, subfont.scale);
-            charstring::evaluate(
+            charstring::evaluate_with_seac(
                 charstring_data,
                 self.global_subrs(),
                 subrs,
                 blend_state,
+                seac_components,
                 &mut scaling_adapter,
             )?;
         }
@@ -341,13 +348,18 @@ struct TopDict<'a> {
     fd_select: Option<FdSelect<'a>>,
     private_dict_range: Option<Range<usize>>,
     var_store: Option<ItemVariationStore<'a>>,
+    /// Only present for CFF tables; used to resolve components of
+    /// accented glyphs defined with the `seac` operator.
+    charset: Option<Charset<'a>>,
 }
 
 impl<'a> TopDict<'a> {
     fn new(table_data: &'a [u8], top_dict_data: &'a [u8], is_cff2: bool) -> Result<Self, Error> {
         let mut items = TopDict::default();
+        let mut charset_offset = 0;
         for entry in dict::entries(top_dict_data, None) {
             match entry? {
+                dict::Entry::Charset(offset) => charset_offset = offset,
                 dict::Entry::CharstringsOffset(offset) => {
                     items.charstrings = Some(Index::new(
                         table_data.get(offset..).unwrap_or_default(),
@@ -377,6 +389,18 @@ impl<'a> TopDict<'a> {
                 _ => {}
             }
         }
+        if !is_cff2 {
+            if let Some(charstrings) = &items.charstrings {
+                // A malformed charset only affects seac glyphs so don't
+                // fail here
+                items.charset = Charset::new(
+                    FontData::new(table_data),
+                    charset_offset,
+                    charstrings.count(),
+                )
+                .ok();
+            }
+        }
         Ok(items)
     }
 }
@@ -386,13 +410,13 @@ impl<'a> TopDict<'a> {
 /// This assumes a 26.6 scaling factor packed into a Fixed and thus,
 /// this is not public and exists only to match FreeType's exact
 /// scaling process.
-struct ScalingSink26Dot6<'a, S> {
+pub(super) struct ScalingSink26Dot6<'a, S> {
     inner: &'a mut S,
     scale: Fixed,
 }
 
 impl<'a, S> ScalingSink26Dot6<'a, S> {
-    fn new(sink: &'a mut S, scale: Fixed) -> Self {
+    pub(super) fn new(sink: &'a mut S, scale: Fixed) -> Self {
         Self { scale, inner: sink }
     }
 
@@ -475,7 +499,7 @@ impl<'a, S: CommandSink> CommandSink for ScalingSink26Dot6<'a, S> {
 /// elements regardless to match the output.
 ///
 /// See <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/pshints.c#L1786>
-struct NopFilteringSink<'a, S> {
+pub(super) struct NopFilteringSink<'a, S> {
     start: Option<(Fixed, Fixed)>,
     last: Option<(Fixed, Fixed)>,
     pending_move: Option<(Fixed, Fixed)>,
@@ -486,7 +510,7 @@ impl<'a, S> NopFilteringSink<'a, S>
 where
     S: CommandSink,
 {
-    fn new(inner: &'a mut S) -> Self {
+    pub(super) fn new(inner: &'a mut S) -> Self {
         Self {
             start: None,
             last: None,
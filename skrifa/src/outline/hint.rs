@@ -167,6 +167,9 @@ impl HintingInstance {
                 self.kind = HinterKind::Cff(subfonts);
             }
             OutlineCollectionKind::None => {}
+            // Type 1 outlines are drawn unhinted
+            #[cfg(feature = "std")]
+            OutlineCollectionKind::Type1(..) => {}
         }
         Ok(())
     }
@@ -82,6 +82,8 @@ mod autohint;
 mod cff;
 mod glyf;
 mod hint;
+#[cfg(feature = "std")]
+mod type1;
 
 pub mod error;
 
@@ -105,6 +107,7 @@ use super::{
 
 /// Source format for an outline glyph.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum OutlineGlyphFormat {
     /// TrueType outlines sourced from the `glyf` table.
     Glyf,
@@ -112,6 +115,10 @@ pub enum OutlineGlyphFormat {
     Cff,
     /// PostScript outlines sourced from the `CFF2` table.
     Cff2,
+    /// PostScript outlines sourced from a Type 1 font.
+    ///
+    /// Loading these outlines requires the `std` feature.
+    Type1,
 }
 
 /// Specifies the hinting strategy for memory size calculations.
@@ -246,8 +253,9 @@ impl<'a> From<&'a HintingInstance> for DrawSettings<'a> {
 /// This can be sourced from the [`glyf`](https://learn.microsoft.com/en-us/typography/opentype/spec/glyf),
 /// [`CFF`](https://learn.microsoft.com/en-us/typography/opentype/spec/cff) or
 /// [`CFF2`](https://learn.microsoft.com/en-us/typography/opentype/spec/cff2)
-/// tables. Use the [`format`](OutlineGlyph::format) method to determine which
-/// was chosen for this glyph.
+/// tables or from a [`Type1Font`](read_fonts::tables::postscript::type1::Type1Font).
+/// Use the [`format`](OutlineGlyph::format) method to determine which was
+/// chosen for this glyph.
 #[derive(Clone)]
 pub struct OutlineGlyph<'a> {
     kind: OutlineKind<'a>,
@@ -265,6 +273,8 @@ impl<'a> OutlineGlyph<'a> {
                     OutlineGlyphFormat::Cff
                 }
             }
+            #[cfg(feature = "std")]
+            OutlineKind::Type1(..) => OutlineGlyphFormat::Type1,
         }
     }
 
@@ -407,6 +417,15 @@ impl<'a> OutlineGlyph<'a> {
                 cff.draw(&subfont, *glyph_id, coords, false, pen)?;
                 Ok(AdjustedMetrics::default())
             }
+            #[cfg(feature = "std")]
+            OutlineKind::Type1(type1, glyph_id) => {
+                let (lsb, advance_width) = type1.draw(*glyph_id, ppem, pen)?;
+                Ok(AdjustedMetrics {
+                    has_overlaps: false,
+                    lsb: Some(lsb),
+                    advance_width: Some(advance_width),
+                })
+            }
         }
     }
 }
@@ -416,6 +435,8 @@ enum OutlineKind<'a> {
     Glyf(glyf::Outlines<'a>, glyf::Outline<'a>),
     // Third field is subfont index
     Cff(cff::Outlines<'a>, GlyphId, u32),
+    #[cfg(feature = "std")]
+    Type1(type1::Outlines<'a>, GlyphId),
 }
 
 impl Debug for OutlineKind<'_> {
@@ -427,6 +448,8 @@ impl Debug for OutlineKind<'_> {
                 .field(gid)
                 .field(subfont_index)
                 .finish(),
+            #[cfg(feature = "std")]
+            Self::Type1(_, gid) => f.debug_tuple("Type1").field(gid).finish(),
         }
     }
 }
@@ -450,6 +473,14 @@ impl<'a> OutlineGlyphCollection<'a> {
         Self { kind }
     }
 
+    /// Creates a new outline collection for the given Type 1 font.
+    #[cfg(feature = "std")]
+    pub fn from_type1(font: &'a read_fonts::tables::postscript::type1::Type1Font) -> Self {
+        Self {
+            kind: OutlineCollectionKind::Type1(type1::Outlines::new(font)),
+        }
+    }
+
     /// Creates a new outline collection for the given font and outline
     /// format.
     ///
@@ -466,6 +497,8 @@ impl<'a> OutlineGlyphCollection<'a> {
                 let upem = font.head().ok()?.units_per_em();
                 OutlineCollectionKind::Cff(cff::Outlines::from_cff2(font.cff2().ok()?, upem).ok()?)
             }
+            // Type 1 fonts are not stored in tables; use `from_type1`
+            OutlineGlyphFormat::Type1 => return None,
         };
         Some(Self { kind })
     }
@@ -478,6 +511,8 @@ impl<'a> OutlineGlyphCollection<'a> {
                 .is_cff2()
                 .then_some(OutlineGlyphFormat::Cff2)
                 .or(Some(OutlineGlyphFormat::Cff)),
+            #[cfg(feature = "std")]
+            OutlineCollectionKind::Type1(..) => Some(OutlineGlyphFormat::Type1),
             _ => None,
         }
     }
@@ -492,6 +527,11 @@ impl<'a> OutlineGlyphCollection<'a> {
             OutlineCollectionKind::Cff(cff) => Some(OutlineGlyph {
                 kind: OutlineKind::Cff(cff.clone(), glyph_id, cff.subfont_index(glyph_id)),
             }),
+            #[cfg(feature = "std")]
+            OutlineCollectionKind::Type1(type1) => (glyph_id.to_u32() < type1.glyph_count() as u32)
+                .then(|| OutlineGlyph {
+                    kind: OutlineKind::Type1(type1.clone(), glyph_id),
+                }),
         }
     }
 
@@ -500,6 +540,8 @@ impl<'a> OutlineGlyphCollection<'a> {
         let len = match &self.kind {
             OutlineCollectionKind::Glyf(glyf) => glyf.glyph_count(),
             OutlineCollectionKind::Cff(cff) => cff.glyph_count(),
+            #[cfg(feature = "std")]
+            OutlineCollectionKind::Type1(type1) => type1.glyph_count(),
             _ => 0,
         } as u16;
         let copy = self.clone();
@@ -516,6 +558,8 @@ enum OutlineCollectionKind<'a> {
     None,
     Glyf(glyf::Outlines<'a>),
     Cff(cff::Outlines<'a>),
+    #[cfg(feature = "std")]
+    Type1(type1::Outlines<'a>),
 }
 
 impl Debug for OutlineCollectionKind<'_> {
@@ -524,6 +568,8 @@ impl Debug for OutlineCollectionKind<'_> {
             Self::None => write!(f, "None"),
             Self::Glyf(..) => f.debug_tuple("Glyf").finish(),
             Self::Cff(..) => f.debug_tuple("Cff").finish(),
+            #[cfg(feature = "std")]
+            Self::Type1(..) => f.debug_tuple("Type1").finish(),
         }
     }
 }
@@ -1257,4 +1303,61 @@ mod tests {
             ],
         );
     }
+
+    /// Builds a PFA font with a single square glyph. Charstrings are not
+    /// encrypted (lenIV is -1) so only eexec encryption is applied.
+    #[cfg(feature = "std")]
+    fn square_type1_font() -> Vec<u8> {
+        // 0 500 hsbw 0 0 rmoveto 100 0 rlineto 0 100 rlineto closepath endchar
+        let charstring = [
+            139u8, 248, 136, 13, 139, 139, 21, 239, 139, 5, 139, 239, 5, 9, 14,
+        ];
+        let mut private = b"dup /Private 2 dict dup begin\n/lenIV -1 def\n".to_vec();
+        private.extend_from_slice(b"/CharStrings 1 dict dup begin\n/square 15 RD ");
+        private.extend_from_slice(&charstring);
+        private.extend_from_slice(b" ND\nend\nend\nmark currentfile closefile\n");
+        let mut data =
+            b"/FontName /Square def\n/FontMatrix [0.001 0 0 0.001 0 0] def\ncurrentfile eexec\n"
+                .to_vec();
+        let mut key = 55665u16;
+        for plain in [0u8; 4].iter().chain(&private) {
+            let cipher = plain ^ (key >> 8) as u8;
+            key = (cipher as u16)
+                .wrapping_add(key)
+                .wrapping_mul(52845)
+                .wrapping_add(22719);
+            data.extend_from_slice(format!("{cipher:02x}").as_bytes());
+        }
+        data
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn draw_type1() {
+        use read_fonts::tables::postscript::type1::Type1Font;
+        let font = Type1Font::new(&square_type1_font()).unwrap();
+        let outlines = OutlineGlyphCollection::from_type1(&font);
+        assert_eq!(outlines.format(), Some(OutlineGlyphFormat::Type1));
+        assert_eq!(outlines.iter().count(), 1);
+        assert!(outlines.get(GlyphId::new(1)).is_none());
+        let glyph = outlines.get(GlyphId::new(0)).unwrap();
+        assert_eq!(glyph.format(), OutlineGlyphFormat::Type1);
+        let mut pen = PointPen::new();
+        let metrics = glyph
+            .draw(
+                DrawSettings::unhinted(Size::new(20.0), LocationRef::default()),
+                &mut pen,
+            )
+            .unwrap();
+        assert_eq!(metrics.lsb, Some(0.0));
+        assert_eq!(metrics.advance_width, Some(10.0));
+        assert_eq!(
+            pen.into_points(),
+            [
+                GlyphPoint::On { x: 0.0, y: 0.0 },
+                GlyphPoint::On { x: 2.0, y: 0.0 },
+                GlyphPoint::On { x: 2.0, y: 2.0 },
+            ]
+        );
+    }
 }
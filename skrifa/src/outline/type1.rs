@@ -0,0 +1,68 @@
+//! Support for scaling Type 1 outlines.
+
+use read_fonts::{
+    tables::postscript::{
+        charstring::PenSink,
+        type1::{GlyphMetrics, Type1Font},
+        Error,
+    },
+    types::{Fixed, GlyphId, Pen},
+};
+
+use super::cff::{NopFilteringSink, ScalingSink26Dot6};
+
+/// Type for loading and scaling outlines from a Type 1 font.
+///
+/// Hinting is not yet supported so outlines are always drawn unhinted.
+#[derive(Clone)]
+pub(crate) struct Outlines<'a> {
+    font: &'a Type1Font,
+}
+
+impl<'a> Outlines<'a> {
+    pub fn new(font: &'a Type1Font) -> Self {
+        Self { font }
+    }
+
+    /// Returns the number of glyphs in the font.
+    pub fn glyph_count(&self) -> usize {
+        self.font.num_glyphs() as usize
+    }
+
+    /// Loads and scales an outline for the given glyph identifier and
+    /// emits the result to the specified pen.
+    ///
+    /// Returns the scaled side bearing and advance width.
+    pub fn draw(
+        &self,
+        glyph_id: GlyphId,
+        ppem: Option<f32>,
+        pen: &mut impl Pen,
+    ) -> Result<(f32, f32), Error> {
+        let upem = self.font.units_per_em();
+        // This matches the scale factor used for CFF outlines
+        let scale = match ppem {
+            Some(ppem) if upem > 0 => {
+                Fixed::from_bits((ppem * 64.) as i32) / Fixed::from_bits(upem as i32)
+            }
+            _ => Fixed::ONE,
+        };
+        let mut pen_sink = PenSink::new(pen);
+        let mut simplifying_adapter = NopFilteringSink::new(&mut pen_sink);
+        let mut scaling_adapter = ScalingSink26Dot6::new(&mut simplifying_adapter, scale);
+        let GlyphMetrics {
+            side_bearing_x,
+            advance_width,
+            ..
+        } = self.font.evaluate(glyph_id, &mut scaling_adapter)?;
+        simplifying_adapter.finish();
+        let metric_scale = match ppem {
+            Some(ppem) if upem > 0 => ppem / upem as f32,
+            _ => 1.0,
+        };
+        Ok((
+            side_bearing_x.to_f32() * metric_scale,
+            advance_width.to_f32() * metric_scale,
+        ))
+    }
+}
@@ -1,8 +1,10 @@
 use super::{
     attribute::Attributes,
+    baseline::Baselines,
     charmap::Charmap,
     color::ColorGlyphCollection,
     instance::{LocationRef, Size},
+    math::Math,
     metrics::{GlyphMetrics, Metrics},
     outline::OutlineGlyphCollection,
     string::{LocalizedStrings, StringId},
@@ -45,6 +47,18 @@ pub trait MetadataProvider<'a>: raw::TableProvider<'a> + Sized {
         GlyphMetrics::new(self, size, location)
     }
 
+    /// Returns the mathematical layout data for the specified size and
+    /// location in normalized variation space.
+    fn math(&self, size: Size, location: impl Into<LocationRef<'a>>) -> Math<'a> {
+        Math::new(self, size, location)
+    }
+
+    /// Returns the baseline data for the specified size and location in
+    /// normalized variation space.
+    fn baselines(&self, size: Size, location: impl Into<LocationRef<'a>>) -> Baselines<'a> {
+        Baselines::new(self, size, location)
+    }
+
     /// Returns the character to nominal glyph identifier mapping.
     fn charmap(&self) -> Charmap<'a> {
         Charmap::new(self)
@@ -135,11 +135,14 @@ impl<'a> AxisCollection<'a> {
     ///
     /// * Setting selectors that don't match an axis are ignored.
     /// * Setting values are clamped to the range of their associated axis
-    ///     before normalization.
+    ///   before normalization.
     /// * If more than one setting for an axis is provided, the last one is
-    ///     used.
+    ///   used.
     /// * Omitted settings are set to 0.0, representing the default position
-    ///     in variation space.
+    ///   in variation space.
+    /// * The normalized coordinates are then remapped by the
+    ///   [avar](https://learn.microsoft.com/en-us/typography/opentype/spec/avar)
+    ///   table, if present, including the version 2 inter-axis mappings.
     ///
     /// # Examples
     ///
@@ -165,12 +168,15 @@ impl<'a> AxisCollection<'a> {
     ///
     /// * Setting selectors that don't match an axis are ignored.
     /// * Setting values are clamped to the range of their associated axis
-    ///     before normalization.
+    ///   before normalization.
     /// * If more than one setting for an axis is provided, the last one is
-    ///     used.
+    ///   used.
     /// * If no setting for an axis is provided, the associated coordinate is
-    ///     set to the normalized value 0.0, representing the default position
-    ///     in variation space.
+    ///   set to the normalized value 0.0, representing the default position
+    ///   in variation space.
+    /// * The normalized coordinates are then remapped by the
+    ///   [avar](https://learn.microsoft.com/en-us/typography/opentype/spec/avar)
+    ///   table, if present, including the version 2 inter-axis mappings.
     ///
     /// # Examples
     ///
@@ -187,10 +193,10 @@ impl<'a> AxisCollection<'a> {
         I: IntoIterator,
         I::Item: Into<VariationSetting>,
     {
-        for coord in location.iter_mut() {
-            *coord = NormalizedCoord::default();
-        }
-        let avar_mappings = self.avar.as_ref().map(|avar| avar.axis_segment_maps());
+        // Normalize in 16.16 so that the avar mappings are applied at full
+        // precision
+        let mut coords = SmallArray::<_, 8>::new(Fixed::ZERO, location.len());
+        let coords = coords.as_mut_slice();
         for setting in settings.into_iter() {
             let setting = setting.into();
             // To permit non-linear interpolation, iterate over all axes to ensure we match
@@ -203,18 +209,20 @@ impl<'a> AxisCollection<'a> {
                 .enumerate()
                 .filter(|v| v.1.tag() == setting.selector)
             {
-                if let Some(target_coord) = location.get_mut(i) {
-                    let coord = axis.record.normalize(Fixed::from_f64(setting.value as f64));
-                    *target_coord = avar_mappings
-                        .as_ref()
-                        .and_then(|mappings| mappings.get(i).transpose().ok())
-                        .flatten()
-                        .map(|mapping| mapping.apply(coord))
-                        .unwrap_or(coord)
-                        .to_f2dot14();
+                if let Some(coord) = coords.get_mut(i) {
+                    *coord = axis.record.normalize(Fixed::from_f64(setting.value as f64));
                 }
             }
         }
+        // The avar version 2 deltas for each axis depend on the full set of
+        // coordinates, so this must happen after all settings are processed
+        if let Some(avar) = self.avar.as_ref() {
+            let len = coords.len().min(self.len());
+            avar.normalize(&mut coords[..len]);
+        }
+        for (target_coord, coord) in location.iter_mut().zip(coords.iter()) {
+            *target_coord = coord.to_f2dot14();
+        }
     }
 
     /// Given an iterator of variation settings in user space, returns a
@@ -510,6 +518,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn location_avar2() {
+        let font = FontRef::new(font_test_data::AVAR2).unwrap();
+        let axes = font.axes();
+        // The avar table narrows the font as weight increases
+        assert_eq!(
+            axes.location([("wght", 900.0)]).coords(),
+            &[
+                NormalizedCoord::from_f32(1.0),
+                NormalizedCoord::from_f32(-0.5)
+            ]
+        );
+        // wght is also remapped from 0.5 to 0.75 by the segment map
+        assert_eq!(
+            axes.location([("wght", 650.0), ("wdth", 200.0)]).coords(),
+            &[
+                NormalizedCoord::from_f32(0.75),
+                NormalizedCoord::from_f32(0.625)
+            ]
+        );
+        assert_eq!(
+            axes.location([("wght", 900.0), ("wdth", 50.0)]).coords(),
+            &[
+                NormalizedCoord::from_f32(1.0),
+                NormalizedCoord::from_f32(-1.0)
+            ]
+        );
+        assert_eq!(
+            axes.location([("wdth", 75.0)]).coords(),
+            &[
+                NormalizedCoord::from_f32(0.0),
+                NormalizedCoord::from_f32(-0.5)
+            ]
+        );
+    }
+
     #[test]
     fn filter() {
         let font = FontRef::from_index(VAZIRMATN_VAR, 0).unwrap();
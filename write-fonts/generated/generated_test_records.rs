@@ -226,3 +226,60 @@ impl FromObjRef<read_fonts::codegen_test::records::ContainsOffests> for Contains
         }
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InlineRecord {
+    pub leading: u16,
+    pub inline: SimpleRecord,
+    pub trailing: u16,
+}
+
+impl InlineRecord {
+    /// Construct a new `InlineRecord`
+    pub fn new(leading: u16, inline: SimpleRecord, trailing: u16) -> Self {
+        Self {
+            leading,
+            inline,
+            trailing,
+        }
+    }
+}
+
+impl FontWrite for InlineRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.leading.write_into(writer);
+        self.inline.write_into(writer);
+        self.trailing.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("InlineRecord")
+    }
+}
+
+impl Validate for InlineRecord {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+impl<'a> FromObjRef<read_fonts::codegen_test::records::InlineRecord<'a>> for InlineRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::codegen_test::records::InlineRecord<'a>,
+        _: FontData,
+    ) -> Self {
+        let offset_data = obj.offset_data();
+        InlineRecord {
+            leading: obj.leading(),
+            inline: obj.inline().to_owned_obj(offset_data),
+            trailing: obj.trailing(),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::codegen_test::records::InlineRecord<'a>> for InlineRecord {}
+
+impl<'a> FontRead<'a> for InlineRecord {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::codegen_test::records::InlineRecord as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
@@ -47,6 +47,13 @@ mod records {
         let contains_offsets = ContainsOffests::new(simple, basic);
         assert_eq!(contains_offsets.other.simple_records.len(), 1);
     }
+
+    #[test]
+    fn inline_record() {
+        let table = InlineRecord::new(1, SimpleRecord::new(2, 3), 4);
+        let bytes = crate::dump_table(&table).unwrap();
+        assert_eq!(bytes, [0, 1, 0, 2, 0xde, 0xad, 0xbe, 0xef, 0, 4])
+    }
 }
 
 mod formats {
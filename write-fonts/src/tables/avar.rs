@@ -2,6 +2,18 @@
 
 include!("../../generated/generated_avar.rs");
 
+use super::variations::{DeltaSetIndexMap, ItemVariationStore};
+
+impl Avar {
+    fn compute_version(&self) -> MajorMinor {
+        if self.axis_index_map.is_some() || self.var_store.is_some() {
+            MajorMinor::VERSION_2_0
+        } else {
+            MajorMinor::VERSION_1_0
+        }
+    }
+}
+
 impl SegmentMaps {
     /// Returns true if all the axis value maps are identity maps.
     pub fn is_identity(&self) -> bool {
@@ -16,6 +28,7 @@ mod tests {
 
     use super::*;
     use font_types::F2Dot14;
+    use read_fonts::{FontRead, FontRef, TableProvider};
 
     #[test]
     fn test_is_identity() {
@@ -45,4 +58,19 @@ mod tests {
 
         assert!(!segment_maps.is_identity());
     }
+
+    #[test]
+    fn version_2_round_trip() {
+        let font = FontRef::new(font_test_data::AVAR2).unwrap();
+        let avar: Avar = font.avar().unwrap().to_owned_table();
+        assert_eq!(avar.compute_version(), MajorMinor::VERSION_2_0);
+        let bytes = crate::dump_table(&avar).unwrap();
+        let read_back = read_fonts::tables::avar::Avar::read(bytes.as_slice().into()).unwrap();
+        assert_eq!(read_back.version(), MajorMinor::VERSION_2_0);
+        assert!(read_back.axis_index_map().unwrap().is_ok());
+        assert!(read_back.var_store().unwrap().is_ok());
+
+        let v1 = Avar::new(avar.axis_segment_maps);
+        assert_eq!(v1.compute_version(), MajorMinor::VERSION_1_0);
+    }
 }